@@ -0,0 +1,23 @@
+//! Generates the bindings of the integration tests that need a non-default compiler configuration.
+//! The `asn1!` macro always compiles with the default configuration, so bindings that
+//! are generated with e.g. `generate_validation` are compiled from the build script instead.
+use std::{env, fs, path::PathBuf};
+
+use rasn_compiler::prelude::*;
+
+fn main() {
+    println!("cargo:rerun-if-changed=tests/validation.asn1");
+    let result = Compiler::<RasnBackend, _>::new_with_config(RasnConfig {
+        generate_validation: true,
+        ..Default::default()
+    })
+    .add_asn_by_path("tests/validation.asn1")
+    .compile_to_string()
+    .unwrap();
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    fs::write(
+        PathBuf::from(env::var("OUT_DIR").unwrap()).join("validation.rs"),
+        result.generated,
+    )
+    .unwrap();
+}
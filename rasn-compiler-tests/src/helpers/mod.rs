@@ -14,7 +14,11 @@ macro_rules! e2e_pdu {
             rasn_compiler_derive::asn1!($asn1);
             assert_eq!(
                 rasn_compiler::Compiler::<rasn_compiler::prelude::RasnBackend, _>::new()
-                    .add_asn_literal(&format!("TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END", $asn1))
+                    .format_bindings(true)
+                    .add_asn_literal(&match $asn1 {
+                        v if v.contains("BEGIN") => v.to_string(),
+                        v => format!("TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END", v),
+                    })
                     .compile_to_string()
                     .unwrap()
                     .generated
@@ -32,7 +36,11 @@ macro_rules! e2e_pdu {
             rasn_compiler_derive::asn1!($asn1);
             assert_eq!(
                 rasn_compiler::Compiler::<rasn_compiler::prelude::RasnBackend, _>::new_with_config($config)
-                    .add_asn_literal(&format!("TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END", $asn1))
+                    .format_bindings(true)
+                    .add_asn_literal(&match $asn1 {
+                        v if v.contains("BEGIN") => v.to_string(),
+                        v => format!("TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END", v),
+                    })
                     .compile_to_string()
                     .unwrap()
                     .generated
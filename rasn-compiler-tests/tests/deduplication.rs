@@ -0,0 +1,100 @@
+use rasn_compiler::prelude::*;
+
+const MODULE_A: &str = r#"
+ModuleA DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    Flag ::= BOOLEAN
+    Id ::= INTEGER (0..255)
+    Pair ::= SEQUENCE { id Id, flag BOOLEAN }
+END"#;
+
+const MODULE_B: &str = r#"
+ModuleB DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    Flag-B ::= BOOLEAN
+    Ident ::= INTEGER (0..255)
+    Holder ::= SEQUENCE { ident Ident, flag Flag-B }
+END"#;
+
+/// Includes the expected bindings and keeps their source for comparison with the generated bindings
+macro_rules! expected_bindings {
+    ($($bindings:tt)*) => {
+        $($bindings)*
+        const EXPECTED: &str = stringify!($($bindings)*);
+    };
+}
+
+expected_bindings! {
+    #[allow(non_camel_case_types, non_snake_case, non_upper_case_globals, unused)]
+    pub mod module_a {
+        extern crate alloc;
+        use core::borrow::Borrow;
+        use rasn::prelude::*;
+        use lazy_static::lazy_static;
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate)]
+        pub struct Flag(pub bool);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=255"))]
+        pub struct Id(pub u8);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Pair {
+            pub id: Id,
+            pub flag: bool,
+        }
+        impl Pair {
+            pub fn new(id: Id, flag: bool) -> Self {
+                Self { id, flag }
+            }
+        }
+    }
+    #[allow(non_camel_case_types, non_snake_case, non_upper_case_globals, unused)]
+    pub mod module_b {
+        extern crate alloc;
+        use core::borrow::Borrow;
+        use rasn::prelude::*;
+        use lazy_static::lazy_static;
+        use super::module_a::{Flag, Id};
+        pub type FlagB = Flag;
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Holder {
+            pub ident: Ident,
+            pub flag: FlagB,
+        }
+        impl Holder {
+            pub fn new(ident: Ident, flag: FlagB) -> Self {
+                Self { ident, flag }
+            }
+        }
+        pub type Ident = Id;
+    }
+}
+
+#[test]
+fn generates_aliases_for_identical_types() {
+    assert_eq!(
+        Compiler::<RasnBackend, _>::new()
+            .deduplicate_types(true)
+            .add_asn_literal(MODULE_A)
+            .add_asn_literal(MODULE_B)
+            .compile_to_string()
+            .unwrap()
+            .generated
+            .replace(|c: char| c.is_whitespace(), ""),
+        EXPECTED.replace(|c: char| c.is_whitespace(), "")
+    );
+}
+
+#[test]
+fn uses_deduplicated_types_interchangeably() {
+    let flag: module_b::FlagB = module_a::Flag(true);
+    let holder = module_b::Holder::new(module_a::Id(7), flag);
+    let pair = module_a::Pair::new(holder.ident.clone(), holder.flag.0);
+    assert_eq!(pair.id, holder.ident);
+    assert!(holder.flag > module_a::Flag(false));
+
+    let encoded = rasn::der::encode(&holder).unwrap();
+    let decoded: module_b::Holder = rasn::der::decode(&encoded).unwrap();
+    assert_eq!(decoded.flag, module_a::Flag(true));
+    assert_eq!(decoded.ident, module_a::Id(7));
+}
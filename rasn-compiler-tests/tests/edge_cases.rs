@@ -19,6 +19,18 @@ e2e_pdu!(
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(delegate, value("1..=10"))]
         pub struct Distinguished(pub u8);
+        impl Distinguished {
+            pub const FIRST: Self = Self(1);
+            pub const SECOND: Self = Self(2);
+            pub const THIRD: Self = Self(3);
+            pub const FOURTH: Self = Self(4);
+            pub const FIFTH: Self = Self(5);
+            pub const SIXTH: Self = Self(6);
+            pub const SEVENTH: Self = Self(7);
+            pub const EIGHTH: Self = Self(8);
+            pub const NINTH: Self = Self(9);
+            pub const TENTH: Self = Self(10);
+        }
         #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
         #[rasn(delegate, value("2..=8"))]
         pub struct Restricted(pub Distinguished);         "#
@@ -46,6 +58,18 @@ e2e_pdu!(
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(delegate, value("1..=10"))]
         pub struct Distinguished(pub u8);
+        impl Distinguished {
+            pub const FIRST: Self = Self(1);
+            pub const SECOND: Self = Self(2);
+            pub const THIRD: Self = Self(3);
+            pub const FOURTH: Self = Self(4);
+            pub const FIFTH: Self = Self(5);
+            pub const SIXTH: Self = Self(6);
+            pub const SEVENTH: Self = Self(7);
+            pub const EIGHTH: Self = Self(8);
+            pub const NINTH: Self = Self(9);
+            pub const TENTH: Self = Self(10);
+        }
         #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
         #[rasn(choice, automatic_tags)]
         #[non_exhaustive]
@@ -83,6 +107,10 @@ e2e_pdu!(
         #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(delegate, value("1..=10"))]
         pub struct IntWithDefault(pub u8);
+        impl IntWithDefault {
+            pub const FIRST: Self = Self(1);
+            pub const SECOND: Self = Self(2);
+        }
         #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
         #[rasn(automatic_tags)]
         pub struct Test {
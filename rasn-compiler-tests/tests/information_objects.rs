@@ -1,3 +1,4 @@
+use rasn_compiler::prelude::*;
 use rasn_compiler_tests::e2e_pdu;
 
 // This suite contains tests to test x681 Information Object Classes and its
@@ -184,3 +185,1200 @@ e2e_pdu!(
     }
           "#
 );
+
+e2e_pdu!(
+    information_object_with_default_type,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          ERROR ::= CLASS {
+            &errorCode INTEGER UNIQUE,
+            &ParameterType DEFAULT BOOLEAN
+          } WITH SYNTAX { [&ParameterType] IDENTIFIED BY &errorCode }
+
+          Errors ERROR ::= {
+              { INTEGER IDENTIFIED BY 1 } |
+              { IDENTIFIED BY 2 }
+          }
+
+          Actual ::= SEQUENCE {
+            errorCode ERROR.&errorCode ({Errors}),
+            parameter ERROR.&ParameterType ({Errors}{@errorCode})
+          }
+END
+          "#,
+    r#"
+    #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+    #[rasn(automatic_tags)]
+    pub struct Actual {
+        #[rasn(identifier = "errorCode")]
+        pub error_code: Integer,
+        pub parameter: Any,
+    }
+    impl Actual {
+        pub fn new(error_code: Integer, parameter: Any) -> Self {
+            Self {
+                error_code,
+                parameter,
+            }
+        }
+    }
+    impl Actual {
+        pub fn decode_parameter<D: Decoder>(
+            &self,
+            decoder: &mut D,
+        ) -> Result<Errors_ParameterType, D::Error> {
+            Errors_ParameterType::decode(decoder, Some(&self.parameter), &self.error_code)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Errors_ParameterType {
+        Errors_ParameterType_0(Integer),
+        Errors_ParameterType_1(bool),
+    }
+    impl Errors_ParameterType {
+        pub fn decode<D: Decoder>(
+            decoder: &mut D,
+            open_type_payload: Option<&Any>,
+            identifier: &Integer,
+        ) -> Result<Self, D::Error> {
+            match identifier {
+                i if i == &Integer::from(1) => Ok(decoder
+                    .codec()
+                    .decode_from_binary(
+                        open_type_payload
+                            .ok_or_else(|| {
+                                rasn::error::DecodeError::from_kind(
+                                    rasn::error::DecodeErrorKind::Custom {
+                                        msg: "Failed to decode open type! No input data given."
+                                            .into(),
+                                    },
+                                    decoder.codec(),
+                                )
+                                .into()
+                            })?
+                            .as_bytes(),
+                    )
+                    .map(Self::Errors_ParameterType_0)?),
+                i if i == &Integer::from(2) => Ok(decoder
+                    .codec()
+                    .decode_from_binary(
+                        open_type_payload
+                            .ok_or_else(|| {
+                                rasn::error::DecodeError::from_kind(
+                                    rasn::error::DecodeErrorKind::Custom {
+                                        msg: "Failed to decode open type! No input data given."
+                                            .into(),
+                                    },
+                                    decoder.codec(),
+                                )
+                                .into()
+                            })?
+                            .as_bytes(),
+                    )
+                    .map(Self::Errors_ParameterType_1)?),
+                _ => Err(rasn::error::DecodeError::from_kind(
+                    rasn::error::DecodeErrorKind::Custom {
+                        msg: alloc::format!(
+                            "Unknown unique identifier for information object class instance."
+                        ),
+                    },
+                    decoder.codec(),
+                )
+                .into()),
+            }
+        }
+        pub fn encode<E: Encoder>(
+            &self,
+            encoder: &mut E,
+            identifier: &Integer,
+        ) -> Result<(), E::Error> {
+            match (self, identifier) {
+                (Self::Errors_ParameterType_0(inner), i) if i == &Integer::from(1) => {
+                    inner.encode(encoder)
+                }
+                (Self::Errors_ParameterType_1(inner), i) if i == &Integer::from(2) => {
+                    inner.encode(encoder)
+                }
+                _ => Err(rasn::error::EncodeError::from_kind(
+                    rasn::error::EncodeErrorKind::Custom {
+                        msg: alloc::format!(
+                            "Unknown unique identifier for information object class instance."
+                        ),
+                    },
+                    encoder.codec(),
+                )
+                .into()),
+            }
+        }
+    }
+    "#
+);
+
+e2e_pdu!(
+    built_in_type_identifier_and_abstract_syntax_classes,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          Extension ::= SEQUENCE {
+            id TYPE-IDENTIFIER.&id({Extensions}),
+            value TYPE-IDENTIFIER.&Type({Extensions}{@id})
+          }
+
+          Extensions TYPE-IDENTIFIER ::= {
+            { INTEGER IDENTIFIED BY {1 2 3} } |
+            { IA5String IDENTIFIED BY {1 2 4} }
+          }
+
+          extension-syntax ABSTRACT-SYNTAX ::= {
+            Extension IDENTIFIED BY {1 2 5} HAS PROPERTY {handles-invalid-encodings}
+          }
+          "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Extension {
+            pub id: ObjectIdentifier,
+            pub value: Any,
+        }
+        impl Extension {
+            pub fn new(id: ObjectIdentifier, value: Any) -> Self {
+                Self { id, value }
+            }
+        }
+        impl Extension {
+            pub fn decode_value<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<Extensions_Type, D::Error> {
+                Extensions_Type::decode(decoder, Some(&self.value), &self.id)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Extensions_Type {
+            Extensions_Type_0(Integer),
+            Extensions_Type_1(Ia5String),
+        }
+        impl Extensions_Type {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &ObjectIdentifier,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Oid::const_new(&[1, 2, 3]).to_owned() => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Extensions_Type_0)?),
+                    i if i == &Oid::const_new(&[1, 2, 4]).to_owned() => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Extensions_Type_1)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &ObjectIdentifier,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Extensions_Type_0(inner), i)
+                        if i == &Oid::const_new(&[1, 2, 3]).to_owned() =>
+                    {
+                        inner.encode(encoder)
+                    }
+                    (Self::Extensions_Type_1(inner), i)
+                        if i == &Oid::const_new(&[1, 2, 4]).to_owned() =>
+                    {
+                        inner.encode(encoder)
+                    }
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    nested_class_field_reference,
+    r#"
+          INNER-CLASS ::= CLASS { &id INTEGER (0..255) UNIQUE, &Type }
+          OUTER-CLASS ::= CLASS { &code INTEGER, &inner INNER-CLASS }
+
+          Wrapper ::= SEQUENCE {
+            code OUTER-CLASS.&code,
+            id OUTER-CLASS.&inner.&id
+          }
+          "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Wrapper {
+            pub code: Integer,
+            #[rasn(value("0..=255"))]
+            pub id: u8,
+        }
+        impl Wrapper {
+            pub fn new(code: Integer, id: u8) -> Self {
+                Self { code, id }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    custom_syntax_with_nested_optional_groups_and_keyword_literals,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          OPERATION ::= CLASS {
+            &ArgumentType OPTIONAL,
+            &argumentTypeOptional BOOLEAN OPTIONAL,
+            &ResultType OPTIONAL,
+            &Errors ERROR OPTIONAL,
+            &alwaysReturns BOOLEAN DEFAULT TRUE,
+            &operationCode INTEGER UNIQUE
+          } WITH SYNTAX {
+            [ARGUMENT &ArgumentType [OPTIONAL &argumentTypeOptional]]
+            [RESULT &ResultType]
+            [ERRORS &Errors]
+            [ALWAYS RESPONDS &alwaysReturns]
+            CODE &operationCode
+          }
+
+          ERROR ::= CLASS { &errorCode INTEGER UNIQUE } WITH SYNTAX { CODE &errorCode }
+
+          unknown-error ERROR ::= { CODE 7 }
+
+          Operations OPERATION ::= {
+              { ARGUMENT BOOLEAN RESULT INTEGER ERRORS { unknown-error } CODE 1 } |
+              { ARGUMENT OCTET STRING OPTIONAL TRUE ALWAYS RESPONDS FALSE CODE 2 } |
+              { CODE 3 }
+          }
+
+          Invoke ::= SEQUENCE {
+            opcode OPERATION.&operationCode ({Operations}),
+            argument OPERATION.&ArgumentType ({Operations}{@opcode}) OPTIONAL
+          }
+          "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Invoke {
+            pub opcode: Integer,
+            pub argument: Option<Any>,
+        }
+        impl Invoke {
+            pub fn new(opcode: Integer, argument: Option<Any>) -> Self {
+                Self { opcode, argument }
+            }
+        }
+        impl Invoke {
+            pub fn decode_argument<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<Operations_ArgumentType, D::Error> {
+                Operations_ArgumentType::decode(decoder, self.argument.as_ref(), &self.opcode)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Operations_ArgumentType {
+            Operations_ArgumentType_0(bool),
+            Operations_ArgumentType_1(OctetString),
+        }
+        impl Operations_ArgumentType {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Integer,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Integer::from(1) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Operations_ArgumentType_0)?),
+                    i if i == &Integer::from(2) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Operations_ArgumentType_1)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Integer,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Operations_ArgumentType_0(inner), i) if i == &Integer::from(1) => {
+                        inner.encode(encoder)
+                    }
+                    (Self::Operations_ArgumentType_1(inner), i) if i == &Integer::from(2) => {
+                        inner.encode(encoder)
+                    }
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Operations_ResultType {
+            Operations_ResultType_0(Integer),
+        }
+        impl Operations_ResultType {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Integer,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Integer::from(1) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Operations_ResultType_0)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Integer,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Operations_ResultType_0(inner), i) if i == &Integer::from(1) => {
+                        inner.encode(encoder)
+                    }
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    default_syntax_objects,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          ERROR ::= CLASS { &errorCode INTEGER UNIQUE }
+
+          OPERATION ::= CLASS {
+            &ArgumentType OPTIONAL,
+            &Errors ERROR OPTIONAL,
+            &critical BOOLEAN DEFAULT FALSE,
+            &code Code UNIQUE
+          }
+
+          Code ::= INTEGER
+
+          unknown-error ERROR ::= { &errorCode 7 }
+
+          op-a OPERATION ::= { &ArgumentType UTF8String, &Errors { unknown-error }, &code 1 }
+          op-b OPERATION ::= { &code code-b, &critical TRUE, &ArgumentType INTEGER (0..7) }
+
+          code-b Code ::= 2
+
+          Operations OPERATION ::= { op-a | op-b | { &ArgumentType BOOLEAN, &code 3 } }
+
+          Invoke ::= SEQUENCE {
+            code OPERATION.&code ({Operations}),
+            argument OPERATION.&ArgumentType ({Operations}{@code}) OPTIONAL
+          }
+          "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate)]
+        pub struct Code(pub Integer);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Invoke {
+            pub code: Code,
+            pub argument: Option<Any>,
+        }
+        impl Invoke {
+            pub fn new(code: Code, argument: Option<Any>) -> Self {
+                Self { code, argument }
+            }
+        }
+        impl Invoke {
+            pub fn decode_argument<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<Operations_ArgumentType, D::Error> {
+                Operations_ArgumentType::decode(decoder, self.argument.as_ref(), &self.code)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, AsnType, Decode, Encode)]
+        #[rasn(value("0..=7"), delegate)]
+        pub struct Inner_Operations_ArgumentType_1(pub u8);
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Operations_ArgumentType {
+            Operations_ArgumentType_0(Utf8String),
+            Operations_ArgumentType_1(Inner_Operations_ArgumentType_1),
+            Operations_ArgumentType_2(bool),
+        }
+        impl Operations_ArgumentType {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Code,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Code(Integer::from(1)) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Operations_ArgumentType_0)?),
+                    i if i == &*CODE_B => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Operations_ArgumentType_1)?),
+                    i if i == &Code(Integer::from(3)) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Operations_ArgumentType_2)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Code,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Operations_ArgumentType_0(inner), i) if i == &Code(Integer::from(1)) => {
+                        inner.encode(encoder)
+                    }
+                    (Self::Operations_ArgumentType_1(inner), i) if i == &*CODE_B => {
+                        inner.encode(encoder)
+                    }
+                    (Self::Operations_ArgumentType_2(inner), i) if i == &Code(Integer::from(3)) => {
+                        inner.encode(encoder)
+                    }
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+        lazy_static! {
+            pub static ref CODE_B: Code = Code(Integer::from(2));
+        }
+    "#
+);
+
+e2e_pdu!(
+    object_set_unions_with_parentheses_and_extension_additions,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          IE ::= CLASS { &id INTEGER UNIQUE, &Value }
+
+          ie-a IE ::= { &id 1, &Value BOOLEAN }
+          ie-b IE ::= { &id 2, &Value INTEGER }
+          ie-c IE ::= { &id 3, &Value NULL }
+
+          Single IE ::= { ie-b }
+          Combined IE ::= { ie-a UNION (Single | { &id 4, &Value UTF8String }), ..., (ie-c) }
+
+          Field ::= SEQUENCE {
+            id IE.&id ({Combined}),
+            value IE.&Value ({Combined}{@id})
+          }
+          "#,
+    r#"
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Combined_Value {
+            Combined_Value_0(bool),
+            Combined_Value_1(Integer),
+            Combined_Value_2(Utf8String),
+            Combined_Value_3(()),
+        }
+        impl Combined_Value {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Integer,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Integer::from(1) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Combined_Value_0)?),
+                    i if i == &Integer::from(2) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Combined_Value_1)?),
+                    i if i == &Integer::from(4) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Combined_Value_2)?),
+                    i if i == &Integer::from(3) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Combined_Value_3)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Integer,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Combined_Value_0(inner), i) if i == &Integer::from(1) => {
+                        inner.encode(encoder)
+                    }
+                    (Self::Combined_Value_1(inner), i) if i == &Integer::from(2) => {
+                        inner.encode(encoder)
+                    }
+                    (Self::Combined_Value_2(inner), i) if i == &Integer::from(4) => {
+                        inner.encode(encoder)
+                    }
+                    (Self::Combined_Value_3(inner), i) if i == &Integer::from(3) => {
+                        inner.encode(encoder)
+                    }
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Field {
+            pub id: Integer,
+            pub value: Any,
+        }
+        impl Field {
+            pub fn new(id: Integer, value: Any) -> Self {
+                Self { id, value }
+            }
+        }
+        impl Field {
+            pub fn decode_value<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<Combined_Value, D::Error> {
+                Combined_Value::decode(decoder, Some(&self.value), &self.id)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Single_Value {
+            Single_Value_0(Integer),
+        }
+        impl Single_Value {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Integer,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Integer::from(2) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Single_Value_0)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Integer,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Single_Value_0(inner), i) if i == &Integer::from(2) => inner.encode(encoder),
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    table_constraint_with_component_path,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          MESSAGE ::= CLASS { &id INTEGER UNIQUE, &Content }
+
+          msg-a MESSAGE ::= { &id 1, &Content BOOLEAN }
+          msg-b MESSAGE ::= { &id 2, &Content INTEGER }
+
+          SUPPORTED-MESSAGES MESSAGE ::= { msg-a | msg-b }
+
+          Header ::= SEQUENCE { messageId MESSAGE.&id ({SUPPORTED-MESSAGES}) }
+
+          Frame ::= SEQUENCE {
+            header Header,
+            content MESSAGE.&Content ({SUPPORTED-MESSAGES}{@header.messageId})
+          }
+          "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Frame {
+            pub header: Header,
+            pub content: Any,
+        }
+        impl Frame {
+            pub fn new(header: Header, content: Any) -> Self {
+                Self { header, content }
+            }
+        }
+        impl Frame {
+            pub fn decode_content<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<SUPPORTEDMESSAGES_Content, D::Error> {
+                SUPPORTEDMESSAGES_Content::decode(decoder, Some(&self.content), &self.header.message_id)
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Header {
+            #[rasn(identifier = "messageId")]
+            pub message_id: Integer,
+        }
+        impl Header {
+            pub fn new(message_id: Integer) -> Self {
+                Self { message_id }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum SUPPORTEDMESSAGES_Content {
+            SUPPORTEDMESSAGES_Content_0(bool),
+            SUPPORTEDMESSAGES_Content_1(Integer),
+        }
+        impl SUPPORTEDMESSAGES_Content {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Integer,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Integer::from(1) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::SUPPORTEDMESSAGES_Content_0)?),
+                    i if i == &Integer::from(2) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::SUPPORTEDMESSAGES_Content_1)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Integer,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::SUPPORTEDMESSAGES_Content_0(inner), i) if i == &Integer::from(1) => {
+                        inner.encode(encoder)
+                    }
+                    (Self::SUPPORTEDMESSAGES_Content_1(inner), i) if i == &Integer::from(2) => {
+                        inner.encode(encoder)
+                    }
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    open_type_fields_with_inline_types,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+          EXT ::= CLASS { &id INTEGER UNIQUE, &Type OPTIONAL }
+          ext-a EXT ::= { &id 1, &Type SEQUENCE { a BOOLEAN, b INTEGER OPTIONAL } }
+          ext-b EXT ::= { &id 2, &Type CHOICE { x INTEGER, y BOOLEAN } }
+          ext-c EXT ::= { &id 3 }
+          Exts EXT ::= { ext-a | ext-b | ext-c }
+          Extension ::= SEQUENCE {
+            id EXT.&id ({Exts}),
+            value EXT.&Type ({Exts}{@id}) OPTIONAL
+          }
+          Unconstrained ::= SET { id [0] EXT.&id, content [1] EXT.&Type }
+          "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Extension {
+            pub id: Integer,
+            pub value: Option<Any>,
+        }
+        impl Extension {
+            pub fn new(id: Integer, value: Option<Any>) -> Self {
+                Self { id, value }
+            }
+        }
+        impl Extension {
+            pub fn decode_value<D: Decoder>(&self, decoder: &mut D) -> Result<Exts_Type, D::Error> {
+                Exts_Type::decode(decoder, self.value.as_ref(), &self.id)
+            }
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "SEQUENCE")]
+        pub struct InnerExtsType0 {
+            pub a: bool,
+            pub b: Option<Integer>,
+        }
+        impl InnerExtsType0 {
+            pub fn new(a: bool, b: Option<Integer>) -> Self {
+                Self { a, b }
+            }
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags, identifier = "CHOICE")]
+        pub enum InnerExtsType1 {
+            x(Integer),
+            y(bool),
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Exts_Type {
+            Exts_Type_0(InnerExtsType0),
+            Exts_Type_1(InnerExtsType1),
+        }
+        impl Exts_Type {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &Integer,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &Integer::from(1) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Exts_Type_0)?),
+                    i if i == &Integer::from(2) => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::Exts_Type_1)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(
+                &self,
+                encoder: &mut E,
+                identifier: &Integer,
+            ) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::Exts_Type_0(inner), i) if i == &Integer::from(1) => inner.encode(encoder),
+                    (Self::Exts_Type_1(inner), i) if i == &Integer::from(2) => inner.encode(encoder),
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(set)]
+        pub struct Unconstrained {
+            #[rasn(tag(context, 0))]
+            pub id: Integer,
+            #[rasn(tag(context, 1))]
+            pub content: Any,
+        }
+        impl Unconstrained {
+            pub fn new(id: Integer, content: Any) -> Self {
+                Self { id, content }
+            }
+        }
+    "#
+);
+
+#[test]
+fn links_outer_level_relational_constraints() {
+    let result = Compiler::<RasnBackend, _>::new_with_config(RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    })
+    .add_asn_literal(
+        r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            ERR ::= CLASS { &code INTEGER UNIQUE, &Param }
+            Errs ERR ::= { { &code 1, &Param INTEGER } }
+            Outer ::= SEQUENCE {
+                code ERR.&code ({Errs}),
+                inner SEQUENCE {
+                    id ERR.&code ({Errs}),
+                    byId ERR.&Param ({Errs}{@.id}),
+                    byCode ERR.&Param ({Errs}{@code})
+                }
+            }
+            Escaping ::= SEQUENCE {
+                code ERR.&code ({Errs}),
+                param ERR.&Param ({Errs}{@..code})
+            }
+        END"#,
+    )
+    .compile_to_string()
+    .unwrap();
+    assert!(result.generated.contains("fn decode_by_id"));
+    assert!(!result.generated.contains("fn decode_by_code"));
+    assert!(!result.generated.contains("fn decode_param"));
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].to_string().contains(
+        "Relational constraint @..code of component param escapes the outermost level of the type."
+    ));
+}
@@ -0,0 +1,115 @@
+use rasn_compiler::prelude::*;
+
+#[test]
+fn expands_components_of_across_imported_modules() {
+    let result = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"Module-A DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Base, Base-Set FROM Module-B;
+                Derived ::= SEQUENCE { COMPONENTS OF Module-B.Base, own BOOLEAN }
+                Derived-Set ::= SET { COMPONENTS OF Base-Set, own BOOLEAN }
+            END"#,
+        )
+        .add_asn_literal(
+            r#"Module-B DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Root, Root-Set FROM Module-C;
+                Base ::= SEQUENCE { COMPONENTS OF Root, base INTEGER }
+                Base-Set ::= Root-Set
+            END"#,
+        )
+        .add_asn_literal(
+            r#"Module-C DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Root ::= SEQUENCE { root IA5String, ..., ext BOOLEAN }
+                Root-Set ::= SET { root INTEGER }
+            END"#,
+        )
+        .compile_to_string()
+        .unwrap();
+    let generated = result.generated.replace(' ', "");
+    let members = |name: &str| {
+        let start = generated.find(&format!("pubstruct{name}{{")).unwrap();
+        generated[start..start + generated[start..].find('}').unwrap()].to_string()
+    };
+    let derived = members("Derived");
+    assert!(["pubown:bool", "pubbase:Integer", "pubroot:Ia5String"]
+        .iter()
+        .all(|member| derived.contains(member)));
+    assert!(!derived.contains("ext"));
+    let derived_set = members("DerivedSet");
+    assert!(derived_set.contains("pubown:bool") && derived_set.contains("pubroot:Integer"));
+}
+
+#[test]
+fn links_value_references_of_other_modules_in_constraint_bounds() {
+    let result = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"Limits DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                maxCells INTEGER ::= 16
+                maxNrOfCells INTEGER ::= maxCells
+                maxValue INTEGER ::= 1000
+            END"#,
+        )
+        .add_asn_literal(
+            r#"Main DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS maxNrOfCells, maxValue FROM Limits;
+                Cells ::= SEQUENCE (SIZE(1..maxNrOfCells)) OF Cell
+                Cell ::= SEQUENCE { id INTEGER (0..<maxValue), name OCTET STRING (SIZE(maxNrOfCells)) }
+                Weight ::= INTEGER (1..maxNrOfCells)
+            END"#,
+        )
+        .compile_to_string()
+        .unwrap();
+    let generated = result.generated.replace(' ', "");
+    assert!(generated.contains(r#"#[rasn(delegate,size("1..=16"))]pubstructCells"#));
+    assert!(generated.contains(r#"#[rasn(value("0..=999"))]pubid:u16,"#));
+    assert!(generated.contains(r#"#[rasn(size("16"))]pubname:OctetString,"#));
+    assert!(generated.contains(r#"#[rasn(delegate,value("1..=16"))]pubstructWeight(pubu8);"#));
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn links_value_sets_in_constraints_of_other_modules() {
+    let result = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"Value-Sets DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Lengths INTEGER ::= { 8 | 16 }
+                Color ::= ENUMERATED { red, green, blue }
+                Warm-Color Color ::= { red | green }
+            END"#,
+        )
+        .add_asn_literal(
+            r#"Main DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Lengths, Color, Warm-Color FROM Value-Sets;
+                Key ::= OCTET STRING (SIZE(Lengths))
+                Setting ::= SEQUENCE { color Color (Warm-Color) }
+            END"#,
+        )
+        .compile_to_string()
+        .unwrap();
+    let generated = result.generated.replace(' ', "");
+    assert!(generated.contains(r#"#[rasn(delegate,size("8..=16"))]pubstructKey"#));
+    assert!(generated.contains("pubcolor:Color,"));
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn applies_implied_extensibility_only_to_its_module() {
+    let result = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"Implied DEFINITIONS AUTOMATIC TAGS EXTENSIBILITY IMPLIED ::= BEGIN
+                Record ::= SEQUENCE { flag BOOLEAN }
+            END"#,
+        )
+        .add_asn_literal(
+            r#"Explicit DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Record FROM Implied;
+                Wrapper ::= SEQUENCE { record Record, flag BOOLEAN }
+            END"#,
+        )
+        .compile_to_string()
+        .unwrap();
+    let generated = result.generated.replace(' ', "");
+    assert!(generated.contains("#[non_exhaustive]pubstructRecord{"));
+    assert!(generated.contains("#[rasn(automatic_tags)]pubstructWrapper{"));
+    assert!(result.warnings.is_empty());
+}
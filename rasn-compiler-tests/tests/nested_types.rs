@@ -6,7 +6,7 @@ e2e_pdu!(
     r#" Test-Boolean ::= BOOLEAN
         Wrapping-Boolean ::= Test-Boolean
         value Wrapping-Boolean ::= FALSE"#,
-    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq)]
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(delegate, identifier = "Test-Boolean")]
         pub struct TestBoolean(pub bool);
 
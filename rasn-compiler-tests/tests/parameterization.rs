@@ -114,13 +114,13 @@ e2e_pdu! {
     pub struct A2XPC5FlowBitRates {
         #[rasn(identifier = "a2X-GuaranteedFlowBitRate")]
         pub a2_x__guaranteed_flow_bit_rate: bool,
-        #[rasn(size("1.."), identifier = "iE-Extensions")]
-        pub i_e__extensions: Option<SequenceOf<A2XPC5FlowBitRatesIEExtensions>>,
+        #[rasn(identifier = "iE-Extensions")]
+        pub i_e__extensions: Option<A2XPC5FlowBitRatesIEExtensions>,
     }
     impl A2XPC5FlowBitRates {
         pub fn new(
             a2_x__guaranteed_flow_bit_rate: bool,
-            i_e__extensions: Option<SequenceOf<A2XPC5FlowBitRatesIEExtensions>>,
+            i_e__extensions: Option<A2XPC5FlowBitRatesIEExtensions>,
         ) -> Self {
             Self {
                 a2_x__guaranteed_flow_bit_rate,
@@ -282,3 +282,271 @@ e2e_pdu! {
     }
     "#
 }
+
+e2e_pdu! {
+    parameterized_class_and_object_set,
+    r#"
+        SIGNATURE-ALGORITHM ::= CLASS {
+            &id     OBJECT IDENTIFIER UNIQUE,
+            &Value  OPTIONAL
+        } WITH SYNTAX { IDENTIFIER &id [VALUE &Value] }
+
+        AlgorithmIdentifier{ALGORITHM-TYPE, ALGORITHM-TYPE:AlgorithmSet} ::= SEQUENCE {
+            algorithm   ALGORITHM-TYPE.&id({AlgorithmSet}),
+            parameters  ALGORITHM-TYPE.&Value({AlgorithmSet}{@algorithm}) OPTIONAL
+        }
+
+        sa-rsa SIGNATURE-ALGORITHM ::= { IDENTIFIER { 1 2 3 } VALUE NULL }
+        SignatureAlgorithms SIGNATURE-ALGORITHM ::= { sa-rsa, ... }
+
+        SignatureAlgorithmIdentifier ::= AlgorithmIdentifier{SIGNATURE-ALGORITHM, {SignatureAlgorithms}}
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct SignatureAlgorithmIdentifier {
+            pub algorithm: ObjectIdentifier,
+            pub parameters: Option<Any>,
+        }
+        impl SignatureAlgorithmIdentifier {
+            pub fn new(algorithm: ObjectIdentifier, parameters: Option<Any>) -> Self {
+                Self {
+                    algorithm,
+                    parameters,
+                }
+            }
+        }
+    "#
+}
+
+e2e_pdu! {
+    parameterized_value,
+    r#"
+        maxOf{INTEGER: n} INTEGER ::= n
+
+        Limits ::= SEQUENCE {
+            upper INTEGER DEFAULT maxOf{7}
+        }
+
+        limit INTEGER ::= maxOf{5}
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Limits {
+            #[rasn(default = "limits_upper_default")]
+            pub upper: Integer,
+        }
+        impl Limits {
+            pub fn new(upper: Integer) -> Self {
+                Self { upper }
+            }
+        }
+        fn limits_upper_default() -> Integer {
+            Integer::from(7)
+        }
+        lazy_static! {
+            pub static ref LIMIT: Integer = Integer::from(5);
+        }
+    "#
+}
+
+e2e_pdu! {
+    parameterized_type_with_reference_and_nested_arguments,
+    r#"
+        Bounded {INTEGER: maxBound} ::= SEQUENCE {
+            count INTEGER (0..maxBound)
+        }
+        BoundedList {ElementType, INTEGER: maxItems} ::= SEQUENCE (SIZE (1..maxItems)) OF ElementType
+        Batch {INTEGER: maxBatch} ::= SEQUENCE {
+            items BoundedList {Bounded {maxBatch}, maxBatch}
+        }
+        maxEntries INTEGER ::= 16
+        Entry ::= Bounded {maxEntries}
+        EntryBatch ::= Batch {4}
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Entry {
+            #[rasn(value("0..=16"))]
+            pub count: u8,
+        }
+        impl Entry {
+            pub fn new(count: u8) -> Self {
+                Self { count }
+            }
+        }
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "SEQUENCE")]
+        pub struct AnonymousEntryBatchItems {
+            #[rasn(value("0..=4"))]
+            pub count: u8,
+        }
+        impl AnonymousEntryBatchItems {
+            pub fn new(count: u8) -> Self {
+                Self { count }
+            }
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("1..=4"))]
+        pub struct EntryBatchItems(pub SequenceOf<AnonymousEntryBatchItems>);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct EntryBatch {
+            pub items: EntryBatchItems,
+        }
+        impl EntryBatch {
+            pub fn new(items: EntryBatchItems) -> Self {
+                Self { items }
+            }
+        }
+        lazy_static! {
+            pub static ref MAX_ENTRIES: Integer = Integer::from(16);
+        }
+    "#
+}
+
+e2e_pdu! {
+    parameterized_value_set,
+    r#"
+        Bounded {INTEGER: upper} INTEGER ::= { 0..upper }
+        maxCount INTEGER ::= 255
+        Counter ::= Bounded {maxCount}
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=255"))]
+        pub struct Counter(pub u8);
+        lazy_static! {
+            pub static ref MAX_COUNT: Integer = Integer::from(255);
+        }
+    "#
+}
+
+e2e_pdu! {
+    parameterized_class_and_parameterized_object_set,
+    rasn_compiler::prelude::RasnConfig {
+        opaque_open_types: false,
+        ..Default::default()
+    },
+    r#"
+        BOUNDED-IES {INTEGER: maxId} ::= CLASS {
+            &id     INTEGER (0..maxId) UNIQUE,
+            &Value
+        } WITH SYNTAX { ID &id TYPE &Value }
+
+        PROTOCOL-IES ::= BOUNDED-IES {255}
+
+        ie-flag PROTOCOL-IES ::= { ID 1 TYPE BOOLEAN }
+        ie-data PROTOCOL-IES ::= { ID 2 TYPE OCTET STRING }
+
+        BaseIEs {PROTOCOL-IES: Extra} PROTOCOL-IES ::= { ie-flag | Extra, ... }
+
+        MessageIEs PROTOCOL-IES ::= { BaseIEs { {ie-data} } }
+
+        ProtocolIE-Field {PROTOCOL-IES: IESet} ::= SEQUENCE {
+            id    PROTOCOL-IES.&id ({IESet}),
+            value PROTOCOL-IES.&Value ({IESet}{@id})
+        }
+
+        Message ::= ProtocolIE-Field {{MessageIEs}}
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Message {
+            #[rasn(value("0..=255"))]
+            pub id: u8,
+            pub value: Any,
+        }
+        impl Message {
+            pub fn new(id: u8, value: Any) -> Self {
+                Self { id, value }
+            }
+        }
+        impl Message {
+            pub fn decode_value<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<MessageIEs_Value, D::Error> {
+                MessageIEs_Value::decode(decoder, Some(&self.value), &self.id)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum MessageIEs_Value {
+            MessageIEs_Value_0(bool),
+            MessageIEs_Value_1(OctetString),
+        }
+        impl MessageIEs_Value {
+            pub fn decode<D: Decoder>(
+                decoder: &mut D,
+                open_type_payload: Option<&Any>,
+                identifier: &u8,
+            ) -> Result<Self, D::Error> {
+                match identifier {
+                    i if i == &1 => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::MessageIEs_Value_0)?),
+                    i if i == &2 => Ok(decoder
+                        .codec()
+                        .decode_from_binary(
+                            open_type_payload
+                                .ok_or_else(|| {
+                                    rasn::error::DecodeError::from_kind(
+                                        rasn::error::DecodeErrorKind::Custom {
+                                            msg: "Failed to decode open type! No input data given."
+                                                .into(),
+                                        },
+                                        decoder.codec(),
+                                    )
+                                    .into()
+                                })?
+                                .as_bytes(),
+                        )
+                        .map(Self::MessageIEs_Value_1)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+            pub fn encode<E: Encoder>(&self, encoder: &mut E, identifier: &u8) -> Result<(), E::Error> {
+                match (self, identifier) {
+                    (Self::MessageIEs_Value_0(inner), i) if i == &1 => inner.encode(encoder),
+                    (Self::MessageIEs_Value_1(inner), i) if i == &2 => inner.encode(encoder),
+                    _ => Err(rasn::error::EncodeError::from_kind(
+                        rasn::error::EncodeErrorKind::Custom {
+                            msg: alloc::format!(
+                                "Unknown unique identifier for information object class instance."
+                            ),
+                        },
+                        encoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+    "#
+}
@@ -1,10 +1,11 @@
 #![allow(non_camel_case_types)]
+use rasn_compiler::prelude::*;
 use rasn_compiler_tests::e2e_pdu;
 
 e2e_pdu!(
     boolean,
     "Test-Boolean ::= BOOLEAN",
-    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq)]
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(delegate, identifier = "Test-Boolean")]
         pub struct TestBoolean(pub bool);                                 "#
 );
@@ -41,6 +42,40 @@ e2e_pdu!(
     r#"lazy_static! { pub static ref TEST_INT: Integer = Integer::from(4); }"#
 );
 
+e2e_pdu!(
+    real_type_is_skipped,
+    r#"Test-Real ::= REAL
+    Test-Flag ::= BOOLEAN
+    test-real REAL ::= PLUS-INFINITY
+    typed-real Test-Real ::= 1.5"#,
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, identifier = "Test-Flag")]
+        pub struct TestFlag(pub bool);
+
+        pub const TEST_REAL: f64 = f64::INFINITY;                    "#
+);
+
+#[test]
+fn real_type_is_reported() {
+    let result = rasn_compiler::Compiler::<rasn_compiler::prelude::RasnBackend, _>::new()
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN Test-Real ::= REAL typed-real Test-Real ::= 1.5 END",
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(!result.generated.contains("f64"));
+    assert_eq!(
+        result
+            .warnings
+            .iter()
+            .filter(|w| w
+                .to_string()
+                .contains("Real types are currently unsupported!"))
+            .count(),
+        2
+    );
+}
+
 e2e_pdu!(
     integer_distinguished_values,
     "Test-Int ::= INTEGER {
@@ -50,7 +85,31 @@ e2e_pdu!(
     } (0..2)",
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(delegate, value("0..=2"), identifier = "Test-Int")]
-        pub struct TestInt(pub u8);                                 "#
+        pub struct TestInt(pub u8);
+
+        impl TestInt {
+            pub const FIRST: Self = Self(0);
+            pub const SECOND: Self = Self(1);
+            pub const THIRD: Self = Self(2);
+        }                                                           "#
+);
+
+e2e_pdu!(
+    integer_distinguished_values_unbounded,
+    "Test-Int ::= INTEGER { disabled(0), enabled(1) }",
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, identifier = "Test-Int")]
+        pub struct TestInt(pub Integer);
+
+        impl TestInt {
+            pub fn disabled() -> Self {
+                Self(Integer::from(0i128))
+            }
+
+            pub fn enabled() -> Self {
+                Self(Integer::from(1i128))
+            }
+        }                                                           "#
 );
 
 e2e_pdu!(
@@ -115,6 +174,34 @@ e2e_pdu!(
         }                                                                            "#
 );
 
+e2e_pdu!(
+    integer_range_exclusive_endpoints,
+    r#" max-len INTEGER ::= 16
+        Positive ::= INTEGER (0<..MAX)
+        Index ::= INTEGER (0..<max-len)
+        Buffer ::= OCTET STRING (SIZE(0<..<max-len))
+        Letters ::= IA5String (FROM ("a"<..<"z"))"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("1..=15"))]
+        pub struct Buffer(pub OctetString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=15"))]
+        pub struct Index(pub u8);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, from("b..=y"))]
+        pub struct Letters(pub Ia5String);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("1.."))]
+        pub struct Positive(pub Integer);
+
+        lazy_static! {
+            pub static ref MAX_LEN: Integer = Integer::from(16);
+        }                                                           "#
+);
+
 e2e_pdu!(
     null,
     "Test-Int ::= NULL",
@@ -163,7 +250,83 @@ e2e_pdu!(
         } SIZE(2)                                           "#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
         #[rasn(delegate, size("2"), identifier = "Test-Bits")]
-        pub struct TestBits(pub BitString);                     "#
+        pub struct TestBits(pub BitString);
+
+        impl TestBits {
+            pub const FIRST: usize = 0;
+
+            pub fn first(&self) -> bool {
+                self.0.get(Self::FIRST).is_some_and(|bit| *bit)
+            }
+
+            pub fn set_first(&mut self, value: bool) {
+                if self.0.len() <= Self::FIRST {
+                    self.0.resize(Self::FIRST + 1, false);
+                }
+                self.0.set(Self::FIRST, value);
+            }
+
+            pub const LAST: usize = 1;
+
+            pub fn last(&self) -> bool {
+                self.0.get(Self::LAST).is_some_and(|bit| *bit)
+            }
+
+            pub fn set_last(&mut self, value: bool) {
+                if self.0.len() <= Self::LAST {
+                    self.0.resize(Self::LAST + 1, false);
+                }
+                self.0.set(Self::LAST, value);
+            }
+        }                                                       "#
+);
+
+e2e_pdu!(
+    bit_string_named_bits_value,
+    r#" Test-Bits ::= BIT STRING { ack(0), retry(2) }
+        test-bits-val Test-Bits ::= { ack, retry }
+        test-bits-single Test-Bits ::= { retry }
+        test-bits-empty Test-Bits ::= { }                   "#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Test-Bits")]
+        pub struct TestBits(pub BitString);
+
+        impl TestBits {
+            pub const ACK: usize = 0;
+
+            pub fn ack(&self) -> bool {
+                self.0.get(Self::ACK).is_some_and(|bit| *bit)
+            }
+
+            pub fn set_ack(&mut self, value: bool) {
+                if self.0.len() <= Self::ACK {
+                    self.0.resize(Self::ACK + 1, false);
+                }
+                self.0.set(Self::ACK, value);
+            }
+
+            pub const RETRY: usize = 2;
+
+            pub fn retry(&self) -> bool {
+                self.0.get(Self::RETRY).is_some_and(|bit| *bit)
+            }
+
+            pub fn set_retry(&mut self, value: bool) {
+                if self.0.len() <= Self::RETRY {
+                    self.0.resize(Self::RETRY + 1, false);
+                }
+                self.0.set(Self::RETRY, value);
+            }
+        }
+        lazy_static! {
+            pub static ref TEST_BITS_EMPTY: TestBits = TestBits(core::iter::empty::<bool>().collect());
+        }
+        lazy_static! {
+            pub static ref TEST_BITS_SINGLE: TestBits = TestBits([false, false, true].into_iter().collect());
+        }
+        lazy_static! {
+            pub static ref TEST_BITS_VAL: TestBits = TestBits([true, false, true].into_iter().collect());
+        }                                                       "#
 );
 
 e2e_pdu!(
@@ -280,6 +443,300 @@ e2e_pdu!(
         }                                                                       "#
 );
 
+e2e_pdu!(
+    octet_string_size_value_reference,
+    r#" Test-Octets ::= OCTET STRING (SIZE(min-len..max-len))
+        Test-Fixed-Octets ::= OCTET STRING (SIZE(max-len))
+        min-len INTEGER ::= 2
+        max-len INTEGER ::= 32"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("32"), identifier = "Test-Fixed-Octets")]
+        pub struct TestFixedOctets(pub OctetString);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("2..=32"), identifier = "Test-Octets")]
+        pub struct TestOctets(pub OctetString);
+        lazy_static!{
+            pub static ref MAX_LEN: Integer = Integer::from(32);
+        }
+        lazy_static!{
+            pub static ref MIN_LEN: Integer = Integer::from(2);
+        }                                                                       "#
+);
+
+e2e_pdu!(
+    contents_constraints,
+    r#" Payload ::= SEQUENCE { id INTEGER }
+        Wrapped ::= OCTET STRING (CONTAINING Payload)
+        Wrapped-Flag ::= BIT STRING (CONTAINING BOOLEAN)"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Payload {
+            pub id: Integer,
+        }
+        impl Payload {
+            pub fn new(id: Integer) -> Self {
+                Self { id }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Wrapped(pub OctetString);
+        impl Wrapped {
+            #[doc = " Decodes the value that the octets contain as specified by `(CONTAINING Payload)`."]
+            pub fn decode_contained(
+                &self,
+                codec: rasn::Codec,
+            ) -> Result<Payload, rasn::error::DecodeError> {
+                codec.decode_from_binary(&self.0)
+            }
+            #[doc = " Encodes a value as the octets that `(CONTAINING Payload)` specifies."]
+            pub fn encode_contained(
+                value: &Payload,
+                codec: rasn::Codec,
+            ) -> Result<Self, rasn::error::EncodeError> {
+                codec
+                    .encode_to_binary(value)
+                    .map(|contents| Self(OctetString::from(contents)))
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Wrapped-Flag")]
+        pub struct WrappedFlag(pub BitString);
+        impl WrappedFlag {
+            #[doc = " Decodes the value that the bits contain as specified by `(CONTAINING BOOLEAN)`."]
+            pub fn decode_contained(
+                &self,
+                codec: rasn::Codec,
+            ) -> Result<bool, rasn::error::DecodeError> {
+                codec.decode_from_binary(self.0.as_raw_slice())
+            }
+            #[doc = " Encodes a value as the bits that `(CONTAINING BOOLEAN)` specifies."]
+            pub fn encode_contained(
+                value: &bool,
+                codec: rasn::Codec,
+            ) -> Result<Self, rasn::error::EncodeError> {
+                codec
+                    .encode_to_binary(value)
+                    .map(|contents| Self(BitString::from_vec(contents)))
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    pattern_constraints,
+    rasn_compiler::prelude::RasnConfig {
+        generate_validation: true,
+        ..Default::default()
+    },
+    r#" Country-Code ::= PrintableString (SIZE(2) ^ PATTERN "[A-Z]#2")
+
+        Greeting ::= SEQUENCE {
+            text UTF8String (PATTERN "(hello|hi)[!.]?")
+        }"#,
+    r#" #[doc = r" A violated ASN.1 constraint."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ConstraintError {
+            #[doc = r" ASN.1 identifier of the constrained type or component."]
+            pub identifier: &'static str,
+            #[doc = r" The violated constraint in ASN.1 notation."]
+            pub constraint: &'static str,
+        }
+        impl core::fmt::Display for ConstraintError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} violates constraint {}",
+                    self.identifier, self.constraint
+                )
+            }
+        }
+
+        #[doc = r" Checks the constraints of a value at runtime."]
+        pub trait Validate {
+            #[doc = r" Returns the first violated constraint of `self` or its components."]
+            fn validate(&self) -> Result<(), ConstraintError>;
+        }
+
+        #[doc = r" An instruction of a compiled `PATTERN` constraint."]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum PatternInstruction {
+            #[doc = r" Consumes a character whose code point lies in one of the ranges,"]
+            #[doc = r" or in none of the ranges if the class is negated."]
+            Class(&'static [(u32, u32)], bool),
+            #[doc = r" Continues at both instructions."]
+            Split(usize, usize),
+            #[doc = r" Continues at the instruction."]
+            Jump(usize),
+            #[doc = r" Accepts the string if all of its characters have been consumed."]
+            Match,
+        }
+
+        #[doc = r" Returns `true` if the code points of a string match a compiled `PATTERN` constraint."]
+        pub fn matches_pattern(
+            program: &[PatternInstruction],
+            characters: impl Iterator<Item = u32>,
+        ) -> bool {
+            fn follow(program: &[PatternInstruction], threads: &mut alloc::vec::Vec<usize>, pc: usize) {
+                if threads.contains(&pc) {
+                    return;
+                }
+                threads.push(pc);
+                match program[pc] {
+                    PatternInstruction::Split(first, second) => {
+                        follow(program, threads, first);
+                        follow(program, threads, second);
+                    }
+                    PatternInstruction::Jump(target) => follow(program, threads, target),
+                    _ => (),
+                }
+            }
+            let mut threads = alloc::vec::Vec::new();
+            follow(program, &mut threads, 0);
+            for character in characters {
+                let mut next = alloc::vec::Vec::new();
+                for pc in threads {
+                    if let PatternInstruction::Class(ranges, negated) = program[pc] {
+                        if ranges
+                            .iter()
+                            .any(|(from, to)| (*from..=*to).contains(&character))
+                            != negated
+                        {
+                            follow(program, &mut next, pc + 1);
+                        }
+                    }
+                }
+                threads = next;
+            }
+            threads
+                .iter()
+                .any(|pc| program[*pc] == PatternInstruction::Match)
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("2"), identifier = "Country-Code")]
+        pub struct CountryCode(pub PrintableString);
+        impl CountryCode {
+            #[doc = r" Regular expression of the `PATTERN` constraint, in the notation of Rec. ITU-T X.680 Annex A."]
+            pub const PATTERN: &'static str = "[A-Z]#2";
+        }
+        impl Validate for CountryCode {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                let value = &self.0;
+                let length = value.as_bytes().len();
+                if length < 2 || length > 2 {
+                    return Err(ConstraintError {
+                        identifier: "Country-Code",
+                        constraint: "(SIZE(2))",
+                    });
+                }
+                if !matches_pattern(
+                    &[
+                        PatternInstruction::Class(&[(65, 90)], false),
+                        PatternInstruction::Class(&[(65, 90)], false),
+                        PatternInstruction::Match,
+                    ],
+                    value.as_bytes().iter().map(|b| u32::from(*b)),
+                ) {
+                    return Err(ConstraintError {
+                        identifier: "Country-Code",
+                        constraint: "(PATTERN \"[A-Z]#2\")",
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Greeting {
+            pub text: Utf8String,
+        }
+        impl Greeting {
+            pub fn new(text: Utf8String) -> Self {
+                Self { text }
+            }
+        }
+        impl Validate for Greeting {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                {
+                    let value = &self.text;
+                    if !matches_pattern(
+                        &[
+                            PatternInstruction::Split(1, 7),
+                            PatternInstruction::Class(&[(104, 104)], false),
+                            PatternInstruction::Class(&[(101, 101)], false),
+                            PatternInstruction::Class(&[(108, 108)], false),
+                            PatternInstruction::Class(&[(108, 108)], false),
+                            PatternInstruction::Class(&[(111, 111)], false),
+                            PatternInstruction::Jump(9),
+                            PatternInstruction::Class(&[(104, 104)], false),
+                            PatternInstruction::Class(&[(105, 105)], false),
+                            PatternInstruction::Split(10, 11),
+                            PatternInstruction::Class(&[(33, 33), (46, 46)], false),
+                            PatternInstruction::Match,
+                        ],
+                        value.chars().map(u32::from),
+                    ) {
+                        return Err(ConstraintError {
+                            identifier: "Greeting.text",
+                            constraint: "(PATTERN \"(hello|hi)[!.]?\")",
+                        });
+                    }
+                }
+                Ok(())
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    permitted_alphabet_ranges_and_unions,
+    r#" Digits ::= IA5String (FROM ("0".."9"))
+        Code ::= IA5String (FROM ("A".."Z" | "-") ^ SIZE (2..8))
+        Number ::= IA5String (FROM (Digits | "+" | " "))
+        Hex ::= VisibleString (FROM ("0".."9" | "A".."F" | "a".."f"))
+        Consonants ::= PrintableString (FROM ("A".."Z" EXCEPT ("A" | "E" | "I" | "O" | "U")))
+        Entry ::= SEQUENCE {
+            code Code,
+            tag IA5String (FROM ("a".."z")),
+            number Number OPTIONAL
+        }"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("2..=8"), from("-", "A..=Z"))]
+        pub struct Code(pub Ia5String);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, from("B..=D", "F..=H", "J..=N", "P..=T", "V..=Z"))]
+        pub struct Consonants(pub PrintableString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, from("0..=9"))]
+        pub struct Digits(pub Ia5String);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Entry {
+            pub code: Code,
+            #[rasn(from("a..=z"))]
+            pub tag: Ia5String,
+            pub number: Option<Number>,
+        }
+        impl Entry {
+            pub fn new(code: Code, tag: Ia5String, number: Option<Number>) -> Self {
+                Self { code, tag, number }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, from("0..=9", "A..=F", "a..=f"))]
+        pub struct Hex(pub VisibleString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, from(" ", "+", "0..=9"))]
+        pub struct Number(pub Ia5String);                                                           "#
+);
+
 e2e_pdu!(
     enumerated,
     r#" Test-Enum ::= ENUMERATED {
@@ -308,10 +765,10 @@ e2e_pdu!(
     r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[rasn(enumerated, identifier = "Test-Enum")]
         pub enum TestEnum {
-            #[rasn(identifier = "test-1")]
-            test_1 = 3,
             #[rasn(identifier = "test-2")]
             test_2 = -7,
+            #[rasn(identifier = "test-1")]
+            test_1 = 3,
         }
         pub const TEST_ENUM_VAL: TestEnum = TestEnum::test_2;                                "#
 );
@@ -346,6 +803,223 @@ e2e_pdu!(
         pub const TEST_ENUM_VAL: TestEnum = TestEnum::test_2;                                "#
 );
 
+e2e_pdu!(
+    extended_enumerated_with_numbering_gaps,
+    r#" Status ::= ENUMERATED { idle(0), busy(1), ..., paused(5), stopped(9) }"#,
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        #[non_exhaustive]
+        pub enum Status {
+            idle = 0,
+            busy = 1,
+            #[rasn(extension_addition)]
+            paused = 5,
+            #[rasn(extension_addition)]
+            stopped = 9,
+        }                                                           "#
+);
+
+#[test]
+fn rejects_unknown_enumerated_extension_addition() {
+    rasn_compiler_derive::asn1!(
+        r#"Status ::= ENUMERATED { idle(0), busy(1), ..., paused(5), stopped(9) }
+        Status-V2 ::= ENUMERATED { idle(0), busy(1), ..., paused(5), stopped(9), failed(12) }"#
+    );
+    let encoded = rasn::uper::encode(&asn1::StatusV2::stopped).unwrap();
+    assert_eq!(
+        rasn::uper::decode::<asn1::Status>(&encoded).unwrap(),
+        asn1::Status::stopped
+    );
+    let encoded = rasn::uper::encode(&asn1::StatusV2::failed).unwrap();
+    assert!(rasn::uper::decode::<asn1::Status>(&encoded).is_err());
+}
+
+e2e_pdu!(
+    extended_enumerated_with_mixed_numbering,
+    r#" Test-Enum ::= ENUMERATED { a(3), b, c(0), ..., d, e(9), f }"#,
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated, identifier = "Test-Enum")]
+        #[non_exhaustive]
+        pub enum TestEnum {
+            c = 0,
+            b = 1,
+            a = 3,
+            #[rasn(extension_addition)]
+            d = 2,
+            #[rasn(extension_addition)]
+            e = 9,
+            #[rasn(extension_addition)]
+            f = 10,
+        }                                                           "#
+);
+
+#[test]
+fn encodes_enumeration_indices_in_order_of_enumeral_numbers() {
+    rasn_compiler_derive::asn1!(r#"Test-Enum ::= ENUMERATED { a(3), b, c(0), ..., d, e(9) }"#);
+    // The root enumerals are indexed in ascending order of their numbers, i.e. `c`, `b`, `a`
+    assert_eq!(rasn::uper::encode(&asn1::TestEnum::c).unwrap(), vec![0x00]);
+    assert_eq!(rasn::uper::encode(&asn1::TestEnum::a).unwrap(), vec![0x40]);
+    assert_eq!(
+        rasn::uper::decode::<asn1::TestEnum>(&[0x20]).unwrap(),
+        asn1::TestEnum::b
+    );
+}
+
+e2e_pdu!(
+    enumerated_with_value_reference,
+    r#" base-value INTEGER ::= 0
+        Test-Enum ::= ENUMERATED { first, second(base-value), third, ..., fourth }"#,
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated, identifier = "Test-Enum")]
+        #[non_exhaustive]
+        pub enum TestEnum {
+            second = 0,
+            first = 1,
+            third = 2,
+            #[rasn(extension_addition)]
+            fourth = 3,
+        }
+        lazy_static! {
+            pub static ref BASE_VALUE: Integer = Integer::from(0);
+        }                                                           "#
+);
+
+e2e_pdu!(
+    enumerated_subset,
+    r#" Color ::= ENUMERATED { red, green, blue }
+        Warm-Color ::= Color (red | green)          "#,
+    r#" #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        pub enum Color {
+            red = 0,
+            green = 1,
+            blue = 2,
+        }
+
+        #[doc = " Permitted values: red | green"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Warm-Color")]
+        pub struct WarmColor(pub Color);                                "#
+);
+
+e2e_pdu!(
+    value_set_assignments,
+    rasn_compiler::prelude::RasnConfig {
+        generate_validation: true,
+        ..Default::default()
+    },
+    r#" Supported-Values INTEGER ::= { 1 | 2 | 4 | 8 }
+        Color ::= ENUMERATED { red, green, blue }
+        Warm-Color Color ::= { red | green }
+        Setting ::= SEQUENCE { value INTEGER (Supported-Values), color Color (Warm-Color) }"#,
+    r#" #[doc = r" A violated ASN.1 constraint."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ConstraintError {
+            #[doc = r" ASN.1 identifier of the constrained type or component."]
+            pub identifier: &'static str,
+            #[doc = r" The violated constraint in ASN.1 notation."]
+            pub constraint: &'static str,
+        }
+        impl core::fmt::Display for ConstraintError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} violates constraint {}",
+                    self.identifier, self.constraint
+                )
+            }
+        }
+
+        #[doc = r" Checks the constraints of a value at runtime."]
+        pub trait Validate {
+            #[doc = r" Returns the first violated constraint of `self` or its components."]
+            fn validate(&self) -> Result<(), ConstraintError>;
+        }
+
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        pub enum Color {
+            red = 0,
+            green = 1,
+            blue = 2,
+        }
+        impl Validate for Color {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                Ok(())
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Setting {
+            #[rasn(value("1..=8"))]
+            pub value: u8,
+            pub color: Color,
+        }
+        impl Setting {
+            pub fn new(value: u8, color: Color) -> Self {
+                Self { value, color }
+            }
+        }
+        impl Validate for Setting {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                {
+                    let value = &self.value;
+                    if i128::from(*value) < 1 || i128::from(*value) > 8 {
+                        return Err(ConstraintError {
+                            identifier: "Setting.value",
+                            constraint: "(1..8)",
+                        });
+                    }
+                }
+                {
+                    let value = &self.color;
+                    value.validate()?;
+                    if !matches!(value, Color::red | Color::green) {
+                        return Err(ConstraintError {
+                            identifier: "Setting.color",
+                            constraint: "(red | green)",
+                        });
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("1..=8"), identifier = "Supported-Values")]
+        pub struct SupportedValues(pub u8);
+        impl Validate for SupportedValues {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                let value = &self.0;
+                if i128::from(*value) < 1 || i128::from(*value) > 8 {
+                    return Err(ConstraintError {
+                        identifier: "Supported-Values",
+                        constraint: "(1..8)",
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        #[doc = " Permitted values: red | green"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Warm-Color")]
+        pub struct WarmColor(pub Color);
+        impl Validate for WarmColor {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                self.0.validate()?;
+                if !matches!(self.0, Color::red | Color::green) {
+                    return Err(ConstraintError {
+                        identifier: "Warm-Color",
+                        constraint: "(red | green)",
+                    });
+                }
+                Ok(())
+            }
+        }                                                           "#
+);
+
 // REAL Types are currently not supported by rasn
 
 e2e_pdu!(
@@ -706,6 +1380,46 @@ e2e_pdu!(
             );
         }                                                           "#
 );
+e2e_pdu!(
+    bmp_string,
+    r#" Test-String ::= BMPString SIZE (4..6)
+        test-string-val Test-String ::= "0123"
+        test-strings SEQUENCE OF BMPString ::= { "0123" }"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("4..=6"), identifier = "Test-String")]
+        pub struct TestString(pub BmpString);
+        lazy_static!{
+            pub static ref TEST_STRING_VAL: TestString = TestString(
+                BmpString::try_from("0123").unwrap()
+            );
+        }
+        lazy_static!{
+            pub static ref TEST_STRINGS: Vec<BmpString> = alloc::vec![
+                BmpString::try_from("0123").unwrap()
+            ];
+        }                                                           "#
+);
+
+e2e_pdu!(
+    universal_string,
+    r#" Test-String ::= UniversalString SIZE (4..6)
+        test-string-val Test-String ::= "0123"
+        test-strings SEQUENCE OF UniversalString ::= { "0123" }"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("4..=6"), identifier = "Test-String")]
+        pub struct TestString(pub UniversalString);
+        lazy_static!{
+            pub static ref TEST_STRING_VAL: TestString = TestString(
+                UniversalString::from(String::from("0123"))
+            );
+        }
+        lazy_static!{
+            pub static ref TEST_STRINGS: Vec<UniversalString> = alloc::vec![
+                UniversalString::from(String::from("0123"))
+            ];
+        }                                                           "#
+);
+
 e2e_pdu!(
     utf8_strict,
     r#" Test-String ::= UTF8String SIZE (4)
@@ -830,3 +1544,662 @@ e2e_pdu!(
             );
         }                                                           "#
 );
+
+e2e_pdu!(
+    enumerated_and_named_integer_with_display,
+    rasn_compiler::prelude::RasnConfig {
+        generate_display: true,
+        ..Default::default()
+    },
+    r#" Color ::= ENUMERATED { red, light-blue(5), ... }
+        Priority ::= INTEGER { low(0), high(10), too-high(300) } (0..255)"#,
+    r#" #[doc = r" An unknown ASN.1 identifier."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ParseIdentifierError {
+            #[doc = r" ASN.1 identifier of the parsed type."]
+            pub type_name: &'static str,
+            #[doc = r" The input that does not match any identifier of the type."]
+            pub input: alloc::string::String,
+        }
+
+        impl core::fmt::Display for ParseIdentifierError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{} is not an identifier of {}", self.input, self.type_name)
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        #[non_exhaustive]
+        pub enum Color {
+            red = 0,
+            #[rasn(identifier = "light-blue")]
+            light_blue = 5,
+        }
+
+        impl core::fmt::Display for Color {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(match self {
+                    Self::red => "red",
+                    Self::light_blue => "light-blue",
+                })
+            }
+        }
+
+        impl core::str::FromStr for Color {
+            type Err = ParseIdentifierError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let error = || ParseIdentifierError {
+                    type_name: "Color",
+                    input: s.into(),
+                };
+                match s {
+                    "red" => Ok(Self::red),
+                    "light-blue" => Ok(Self::light_blue),
+                    _ => Err(error()),
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=255"))]
+        pub struct Priority(pub u8);
+
+        impl Priority {
+            pub const LOW: Self = Self(0);
+            pub const HIGH: Self = Self(10);
+        }
+
+        impl core::fmt::Display for Priority {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self.0 {
+                    0 => f.write_str("low"),
+                    10 => f.write_str("high"),
+                    _ => write!(f, "{}", self.0),
+                }
+            }
+        }
+
+        impl core::str::FromStr for Priority {
+            type Err = ParseIdentifierError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let error = || ParseIdentifierError {
+                    type_name: "Priority",
+                    input: s.into(),
+                };
+                match s {
+                    "low" => Ok(Self(0)),
+                    "high" => Ok(Self(10)),
+                    _ => s.parse().map(Self).map_err(|_| error()),
+                }
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    newtypes_with_conversions,
+    rasn_compiler::prelude::RasnConfig {
+        generate_newtype_conversions: true,
+        ..Default::default()
+    },
+    r#" Age ::= INTEGER (0..150)
+        Name ::= UTF8String (SIZE(1..16))"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=150"))]
+        pub struct Age(pub u8);
+
+        impl core::ops::Deref for Age {
+            type Target = u8;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<u8> for Age {
+            fn as_ref(&self) -> &u8 {
+                &self.0
+            }
+        }
+
+        impl From<u8> for Age {
+            fn from(value: u8) -> Self {
+                Self(value)
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("1..=16"))]
+        pub struct Name(pub Utf8String);
+
+        impl core::ops::Deref for Name {
+            type Target = Utf8String;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<Utf8String> for Name {
+            fn as_ref(&self) -> &Utf8String {
+                &self.0
+            }
+        }
+
+        impl From<Utf8String> for Name {
+            fn from(value: Utf8String) -> Self {
+                Self(value)
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    newtype_with_checked_conversion,
+    rasn_compiler::prelude::RasnConfig {
+        generate_newtype_conversions: true,
+        generate_validation: true,
+        ..Default::default()
+    },
+    r#" Age ::= INTEGER (0..150)"#,
+    r#" #[doc = r" A violated ASN.1 constraint."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ConstraintError {
+            #[doc = r" ASN.1 identifier of the constrained type or component."]
+            pub identifier: &'static str,
+            #[doc = r" The violated constraint in ASN.1 notation."]
+            pub constraint: &'static str,
+        }
+
+        impl core::fmt::Display for ConstraintError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} violates constraint {}",
+                    self.identifier, self.constraint
+                )
+            }
+        }
+
+        #[doc = r" Checks the constraints of a value at runtime."]
+        pub trait Validate {
+            #[doc = r" Returns the first violated constraint of `self` or its components."]
+            fn validate(&self) -> Result<(), ConstraintError>;
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=150"))]
+        pub struct Age(pub u8);
+
+        impl Validate for Age {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                let value = &self.0;
+                if i128::from(*value) < 0 || i128::from(*value) > 150 {
+                    return Err(ConstraintError {
+                        identifier: "Age",
+                        constraint: "(0..150)",
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        impl core::ops::Deref for Age {
+            type Target = u8;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<u8> for Age {
+            fn as_ref(&self) -> &u8 {
+                &self.0
+            }
+        }
+
+        impl TryFrom<u8> for Age {
+            type Error = ConstraintError;
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                let value = Self(value);
+                value.validate()?;
+                Ok(value)
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    exclusions_from_all_values,
+    rasn_compiler::prelude::RasnConfig {
+        generate_validation: true,
+        ..Default::default()
+    },
+    r#" Color ::= ENUMERATED { red, green, blue }
+        Not-Red ::= Color (ALL EXCEPT red)
+        Valid-Index ::= INTEGER (ALL EXCEPT (MIN..-1 | 0))"#,
+    r#" #[doc = r" A violated ASN.1 constraint."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ConstraintError {
+            #[doc = r" ASN.1 identifier of the constrained type or component."]
+            pub identifier: &'static str,
+            #[doc = r" The violated constraint in ASN.1 notation."]
+            pub constraint: &'static str,
+        }
+
+        impl core::fmt::Display for ConstraintError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} violates constraint {}",
+                    self.identifier, self.constraint
+                )
+            }
+        }
+
+        #[doc = r" Checks the constraints of a value at runtime."]
+        pub trait Validate {
+            #[doc = r" Returns the first violated constraint of `self` or its components."]
+            fn validate(&self) -> Result<(), ConstraintError>;
+        }
+
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        pub enum Color {
+            red = 0,
+            green = 1,
+            blue = 2,
+        }
+
+        impl Validate for Color {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                Ok(())
+            }
+        }
+
+        #[doc = " Permitted values: green | blue"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Not-Red")]
+        pub struct NotRed(pub Color);
+
+        impl Validate for NotRed {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                self.0.validate()?;
+                if !matches!(self.0, Color::green | Color::blue) {
+                    return Err(ConstraintError {
+                        identifier: "Not-Red",
+                        constraint: "(green | blue)",
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, identifier = "Valid-Index")]
+        pub struct ValidIndex(pub Integer);
+
+        impl Validate for ValidIndex {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                let value = &self.0;
+                if *value <= Integer::from(-1i128) || *value == Integer::from(0i128) {
+                    return Err(ConstraintError {
+                        identifier: "Valid-Index",
+                        constraint: "(ALL EXCEPT (MIN..-1 | 0))",
+                    });
+                }
+                Ok(())
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    object_identifier_name_lookup,
+    rasn_compiler::prelude::RasnConfig {
+        generate_oid_names: true,
+        ..Default::default()
+    },
+    r#" id-member-body OBJECT IDENTIFIER ::= { iso member-body(2) }
+        id-rsadsi OBJECT IDENTIFIER ::= { id-member-body us(840) rsadsi(113549) }"#,
+    r#" #[doc = r" Returns the ASN.1 identifier of an object identifier defined in this module."]
+        pub fn oid_name(oid: &Oid) -> Option<&'static str> {
+            match &**oid {
+                [1, 2] => Some("id-member-body"),
+                [1, 2, 840, 113549] => Some("id-rsadsi"),
+                _ => None,
+            }
+        }
+
+        lazy_static! {
+            pub static ref ID_MEMBER_BODY: ObjectIdentifier = Oid::const_new(&[1, 2]).to_owned();
+        }
+
+        lazy_static! {
+            pub static ref ID_RSADSI: ObjectIdentifier =
+                Oid::const_new(&[1, 2, 840, 113549]).to_owned();
+        }                                                           "#
+);
+
+e2e_pdu!(
+    octet_string_with_value_range,
+    r#" Address ::= OCTET STRING (SIZE(4) ^ ('00000000'H..'0000FFFF'H))"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("4"))]
+        pub struct Address(pub OctetString);
+
+        impl Address {
+            #[doc = " Checks whether the value lies within the value range ('00000000'H..'0000FFFF'H)."]
+            pub fn is_in_value_range(&self) -> bool {
+                let octets: &[u8] = &self.0;
+                octets >= &[0, 0, 0, 0][..] && octets <= &[0, 0, 255, 255][..]
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    time_types,
+    r#" Moment ::= TIME (SETTINGS "Basic=Date-Time Date=YMD Year=Basic Time=HMS Local-or-UTC=Z")
+        Date-Only ::= DATE
+        Clock ::= TIME-OF-DAY
+        Timestamp ::= DATE-TIME
+        Period ::= DURATION
+        Tagged ::= [APPLICATION 3] IMPLICIT DATE
+        span Period ::= "P1Y2M""#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 32))]
+        pub struct Clock(pub VisibleString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 31), identifier = "Date-Only")]
+        pub struct DateOnly(pub VisibleString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 14))]
+        pub struct Moment(pub VisibleString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 34))]
+        pub struct Period(pub VisibleString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(application, 3))]
+        pub struct Tagged(pub VisibleString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 33))]
+        pub struct Timestamp(pub VisibleString);
+
+        lazy_static! {
+            pub static ref SPAN: Period = Period(VisibleString::try_from("P1Y2M").unwrap());
+        }                                                           "#
+);
+
+e2e_pdu!(
+    relative_oid,
+    r#" Rel ::= RELATIVE-OID
+        Message ::= SEQUENCE { module RELATIVE-OID, item Rel }
+        base RELATIVE-OID ::= {5 6}
+        item Rel ::= {base 200}"#,
+    r#" #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 13))]
+        pub struct MessageModule(pub OctetString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Message {
+            pub module: MessageModule,
+            pub item: Rel,
+        }
+        impl Message {
+            pub fn new(module: MessageModule, item: Rel) -> Self {
+                Self { module, item }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 13))]
+        pub struct Rel(pub OctetString);
+
+        lazy_static! {
+            pub static ref BASE: OctetString = <OctetString as From<&'static [u8]>>::from(&[5, 6]);
+        }
+
+        lazy_static! {
+            pub static ref ITEM: Rel =
+                Rel(<OctetString as From<&'static [u8]>>::from(&[5, 6, 129, 72]));
+        }                                                           "#
+);
+
+e2e_pdu!(
+    oid_iri,
+    r#" Iri ::= OID-IRI
+        Registration ::= SEQUENCE { authority OID-IRI, path RELATIVE-OID-IRI }
+        cbeff Iri ::= "/ISO/Registration_Authority/19785.CBEFF""#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 35))]
+        pub struct Iri(pub Utf8String);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 35))]
+        pub struct RegistrationAuthority(pub Utf8String);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 36))]
+        pub struct RegistrationPath(pub Utf8String);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Registration {
+            pub authority: RegistrationAuthority,
+            pub path: RegistrationPath,
+        }
+        impl Registration {
+            pub fn new(authority: RegistrationAuthority, path: RegistrationPath) -> Self {
+                Self { authority, path }
+            }
+        }
+
+        lazy_static! {
+            pub static ref CBEFF: Iri = Iri(String::from("/ISO/Registration_Authority/19785.CBEFF"));
+        }                                                           "#
+);
+
+e2e_pdu!(
+    object_descriptor,
+    r#" Desc ::= ObjectDescriptor
+        Record ::= SEQUENCE { objDesc ObjectDescriptor, other [0] ObjectDescriptor OPTIONAL }
+        desc Desc ::= "record descriptor""#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 7))]
+        pub struct Desc(pub GeneralString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 7))]
+        pub struct RecordObjDesc(pub GeneralString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 7))]
+        pub struct RecordOther(pub GeneralString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        pub struct Record {
+            #[rasn(identifier = "objDesc")]
+            pub obj_desc: RecordObjDesc,
+            #[rasn(tag(context, 0))]
+            pub other: Option<RecordOther>,
+        }
+        impl Record {
+            pub fn new(obj_desc: RecordObjDesc, other: Option<RecordOther>) -> Self {
+                Self { obj_desc, other }
+            }
+        }
+
+        lazy_static! {
+            pub static ref DESC: Desc =
+                Desc(GeneralString::try_from(String::from("record descriptor")).unwrap());
+        }                                                           "#
+);
+
+e2e_pdu!(
+    legacy_strings,
+    r#" Directory-Name ::= SEQUENCE {
+            teletex TeletexString (SIZE (1..64)),
+            videotex VideotexString,
+            graphic GraphicString,
+            general GeneralString,
+            universal UniversalString
+        }
+        Label ::= GraphicString (SIZE (1..16))
+        label Label ::= "directory""#,
+    r#" #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 21))]
+        pub struct DirectoryNameVideotex(pub OctetString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 25))]
+        pub struct DirectoryNameGraphic(pub GeneralString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "Directory-Name")]
+        pub struct DirectoryName {
+            #[rasn(size("1..=64"))]
+            pub teletex: TeletexString,
+            pub videotex: DirectoryNameVideotex,
+            pub graphic: DirectoryNameGraphic,
+            pub general: GeneralString,
+            pub universal: UniversalString,
+        }
+        impl DirectoryName {
+            pub fn new(
+                teletex: TeletexString,
+                videotex: DirectoryNameVideotex,
+                graphic: DirectoryNameGraphic,
+                general: GeneralString,
+                universal: UniversalString,
+            ) -> Self {
+                Self {
+                    teletex,
+                    videotex,
+                    graphic,
+                    general,
+                    universal,
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("1..=16"), tag(universal, 25))]
+        pub struct Label(pub GeneralString);
+
+        lazy_static! {
+            pub static ref LABEL: Label =
+                Label(GeneralString::try_from(String::from("directory")).unwrap());
+        }                                                           "#
+);
+
+e2e_pdu!(
+    legacy_strings_as_octets,
+    rasn_compiler::prelude::RasnConfig {
+        legacy_strings: rasn_compiler::prelude::LegacyStringRepresentation::Octets,
+        ..Default::default()
+    },
+    r#" Directory-Name ::= SEQUENCE {
+            teletex TeletexString (SIZE (1..64)),
+            videotex VideotexString,
+            graphic GraphicString,
+            general GeneralString,
+            universal UniversalString
+        }
+        Label ::= GraphicString (SIZE (1..16))
+        label Label ::= "directory""#,
+    r#" #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 20))]
+        pub struct DirectoryNameTeletex(pub OctetString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 21))]
+        pub struct DirectoryNameVideotex(pub OctetString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 25))]
+        pub struct DirectoryNameGraphic(pub OctetString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 27))]
+        pub struct DirectoryNameGeneral(pub OctetString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 28))]
+        pub struct DirectoryNameUniversal(pub OctetString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "Directory-Name")]
+        pub struct DirectoryName {
+            pub teletex: DirectoryNameTeletex,
+            pub videotex: DirectoryNameVideotex,
+            pub graphic: DirectoryNameGraphic,
+            pub general: DirectoryNameGeneral,
+            pub universal: DirectoryNameUniversal,
+        }
+        impl DirectoryName {
+            pub fn new(
+                teletex: DirectoryNameTeletex,
+                videotex: DirectoryNameVideotex,
+                graphic: DirectoryNameGraphic,
+                general: DirectoryNameGeneral,
+                universal: DirectoryNameUniversal,
+            ) -> Self {
+                Self {
+                    teletex,
+                    videotex,
+                    graphic,
+                    general,
+                    universal,
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 25))]
+        pub struct Label(pub OctetString);
+
+        lazy_static! {
+            pub static ref LABEL: Label = Label(<OctetString as From<&'static [u8]>>::from(&[
+                100, 105, 114, 101, 99, 116, 111, 114, 121
+            ]));
+        }                                                           "#
+);
+
+#[test]
+fn evaluates_arithmetic_in_constraint_bounds() {
+    let result = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                maxEntries INTEGER ::= 16
+                Index ::= INTEGER (0..maxEntries-1)
+                Payload ::= OCTET STRING (SIZE((maxEntries + 2) / 3..maxEntries * 2))
+                Broken ::= INTEGER (0..maxEntries / 0)
+                Fraction ::= INTEGER (0..maxEntries / 3)
+            END"#,
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.generated.contains(r#""0..=15""#));
+    assert!(result.generated.contains(r#""6..=32""#));
+    let warnings = result
+        .warnings
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("Division by zero in arithmetic expression 16 / 0"));
+    assert!(warnings[1]
+        .contains("Arithmetic expression 16 / 3 of constraint does not result in an integer!"));
+}
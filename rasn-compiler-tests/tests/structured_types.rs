@@ -97,3 +97,1794 @@ e2e_pdu!(
             pub static ref NESTED_TYPE_VAL: NestedType = NestedType::new(NestedTypeChoiceField::one(Integer::from(4)));
         }          "#
 );
+
+e2e_pdu!(
+    sequence_with_visitor,
+    rasn_compiler::prelude::RasnConfig {
+        generate_visitor: true,
+        ..Default::default()
+    },
+    r#"Record ::= SEQUENCE {
+        id INTEGER,
+        tags SEQUENCE OF Tag,
+        tag Tag OPTIONAL
+    }
+    Tag ::= CHOICE { label UTF8String, code INTEGER }"#,
+    r#"
+        #[doc = r" Walks the components of a decoded value depth-first."]
+        pub trait Visit {
+            #[doc = r" Calls `visitor` with the identifier and value of every field,"]
+            #[doc = r" chosen alternative, and collection element of `self`."]
+            fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn core::any::Any));
+        }
+
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            pub id: Integer,
+            pub tags: SequenceOf<Tag>,
+            pub tag: Option<Tag>,
+        }
+
+        impl Record {
+            pub fn new(id: Integer, tags: SequenceOf<Tag>, tag: Option<Tag>) -> Self {
+                Self { id, tags, tag }
+            }
+        }
+
+        impl Visit for Record {
+            fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn core::any::Any)) {
+                {
+                    let value = &self.id;
+                    visitor("id", value);
+                }
+                {
+                    let value = &self.tags;
+                    visitor("tags", value);
+                    for item in value.iter() {
+                        visitor("tags", item);
+                        item.visit(visitor);
+                    }
+                }
+                if let Some(value) = &self.tag {
+                    visitor("tag", value);
+                    value.visit(visitor);
+                }
+            }
+        }
+
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Tag {
+            label(Utf8String),
+            code(Integer),
+        }
+
+        impl Visit for Tag {
+            fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn core::any::Any)) {
+                match self {
+                    Self::label(value) => {
+                        visitor("label", value);
+                    }
+                    Self::code(value) => {
+                        visitor("code", value);
+                    }
+                }
+            }
+        }          "#
+);
+
+e2e_pdu!(
+    sequence_with_debug_notation,
+    rasn_compiler::prelude::RasnConfig {
+        generate_debug_notation: true,
+        ..Default::default()
+    },
+    r#"Record ::= SEQUENCE {
+        id INTEGER,
+        flag BOOLEAN OPTIONAL,
+        tag Tag
+    }
+    Tag ::= CHOICE { label IA5String, code OCTET STRING }"#,
+    r#"
+        #[derive(AsnType, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            pub id: Integer,
+            pub flag: Option<bool>,
+            pub tag: Tag,
+        }
+
+        impl Record {
+            pub fn new(id: Integer, flag: Option<bool>, tag: Tag) -> Self {
+                Self { id, flag, tag }
+            }
+        }
+
+        impl core::fmt::Debug for Record {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut separator = "";
+                f.write_str("{")?;
+                {
+                    let value = &self.id;
+                    f.write_str(separator)?;
+                    f.write_str(" id ")?;
+                    write!(f, "{:?}", value)?;
+                    separator = ",";
+                }
+                if let Some(value) = &self.flag {
+                    f.write_str(separator)?;
+                    f.write_str(" flag ")?;
+                    f.write_str(if *value { "TRUE" } else { "FALSE" })?;
+                    separator = ",";
+                }
+                {
+                    let value = &self.tag;
+                    f.write_str(separator)?;
+                    f.write_str(" tag ")?;
+                    write!(f, "{:?}", value)?;
+                    separator = ",";
+                }
+                f.write_str(" }")
+            }
+        }
+
+        #[derive(AsnType, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Tag {
+            label(Ia5String),
+            code(OctetString),
+        }
+
+        impl core::fmt::Debug for Tag {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    Self::label(value) => {
+                        f.write_str("label : ")?;
+                        write!(f, "\"{}\"", value)?;
+                    }
+                    Self::code(value) => {
+                        f.write_str("code : ")?;
+                        f.write_str("'")?;
+                        for byte in value.iter() {
+                            write!(f, "{byte:02X}")?;
+                        }
+                        f.write_str("'H")?;
+                    }
+                }
+                Ok(())
+            }
+        }          "#
+);
+
+e2e_pdu!(
+    empty_sequence_and_set,
+    r#"
+        Empty-Sequence ::= [APPLICATION 2] SEQUENCE {}
+        Empty-Set ::= SET { ... }"#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(tag(application, 2), identifier = "Empty-Sequence")]
+        pub struct EmptySequence {}
+
+        impl EmptySequence {
+            pub fn new() -> Self {
+                Self {}
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(tag(universal, 17), identifier = "Empty-Set")]
+        #[non_exhaustive]
+        pub struct EmptySet {}
+
+        impl EmptySet {
+            pub fn new() -> Self {
+                Self {}
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequence_with_presence_constraints,
+    r#"
+        Base ::= SEQUENCE {
+            id INTEGER,
+            flag BOOLEAN OPTIONAL,
+            note IA5String OPTIONAL
+        }
+        Profile ::= Base (WITH COMPONENTS { ..., flag ABSENT, note PRESENT })"#,
+    r#"
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Base {
+            pub id: Integer,
+            pub flag: Option<bool>,
+            pub note: Option<Ia5String>,
+        }
+
+        impl Base {
+            pub fn new(id: Integer, flag: Option<bool>, note: Option<Ia5String>) -> Self {
+                Self { id, flag, note }
+            }
+        }
+
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        pub struct Profile {
+            #[rasn(tag(context, 0))]
+            pub id: Integer,
+            #[rasn(tag(context, 2))]
+            pub note: Ia5String,
+        }
+
+        impl Profile {
+            pub fn new(id: Integer, note: Ia5String) -> Self {
+                Self { id, note }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequence_with_presence_constrained_components,
+    r#"
+        Base ::= SEQUENCE {
+            id INTEGER,
+            flag BOOLEAN OPTIONAL,
+            note IA5String OPTIONAL
+        }
+        Profile ::= SEQUENCE {
+            full Base (WITH COMPONENTS { id, note PRESENT }),
+            partial Base (WITH COMPONENTS { ..., note PRESENT }),
+            unchanged Base (WITH COMPONENTS { ..., id })
+        }"#,
+    r#"
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Base {
+            pub id: Integer,
+            pub flag: Option<bool>,
+            pub note: Option<Ia5String>,
+        }
+
+        impl Base {
+            pub fn new(id: Integer, flag: Option<bool>, note: Option<Ia5String>) -> Self {
+                Self { id, flag, note }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        pub struct ProfileFull {
+            #[rasn(tag(context, 0))]
+            pub id: Integer,
+            #[rasn(tag(context, 2))]
+            pub note: Ia5String,
+        }
+
+        impl ProfileFull {
+            pub fn new(id: Integer, note: Ia5String) -> Self {
+                Self { id, note }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct ProfilePartial {
+            pub id: Integer,
+            pub flag: Option<bool>,
+            pub note: Ia5String,
+        }
+
+        impl ProfilePartial {
+            pub fn new(id: Integer, flag: Option<bool>, note: Ia5String) -> Self {
+                Self { id, flag, note }
+            }
+        }
+
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Profile {
+            pub full: ProfileFull,
+            pub partial: ProfilePartial,
+            pub unchanged: Base,
+        }
+
+        impl Profile {
+            pub fn new(full: ProfileFull, partial: ProfilePartial, unchanged: Base) -> Self {
+                Self {
+                    full,
+                    partial,
+                    unchanged,
+                }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    inner_subtyping_as_documentation,
+    r#" Base ::= SEQUENCE { id INTEGER, note IA5String OPTIONAL }
+        Bases ::= SEQUENCE OF Base
+        Short-Notes ::= Base (WITH COMPONENTS { ..., note (SIZE(1..4)) })
+        Small-Ids ::= Bases (WITH COMPONENT (WITH COMPONENTS { ..., id (0..7) }))"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Base {
+            pub id: Integer,
+            pub note: Option<Ia5String>,
+        }
+        impl Base {
+            pub fn new(id: Integer, note: Option<Ia5String>) -> Self {
+                Self { id, note }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Bases(pub SequenceOf<Base>);
+
+        #[doc = " Inner subtyping: (WITH COMPONENTS { ..., note (SIZE (1..4)) })"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Short-Notes")]
+        pub struct ShortNotes(pub Base);
+
+        #[doc = " Inner subtyping: (WITH COMPONENT (WITH COMPONENTS { ..., id (0..7) }))"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Small-Ids")]
+        pub struct SmallIds(pub Bases);                                                           "#
+);
+
+e2e_pdu!(
+    sequence_with_validation,
+    rasn_compiler::prelude::RasnConfig {
+        generate_validation: true,
+        ..Default::default()
+    },
+    r#"
+        Code ::= IA5String (SIZE(1..4)) (FROM("A".."Z"))
+        Record ::= SEQUENCE {
+            id INTEGER (0..255),
+            code Code OPTIONAL,
+            values SEQUENCE SIZE(1..8) OF INTEGER
+        }"#,
+    r#"
+        #[doc = r" A violated ASN.1 constraint."]
+        #[derive (Debug, Clone, PartialEq)]
+        pub struct ConstraintError {
+            #[doc = r" ASN.1 identifier of the constrained type or component."]
+            pub identifier: &'static str,
+            #[doc = r" The violated constraint in ASN.1 notation."]
+            pub constraint: &'static str,
+        }
+
+        impl core::fmt::Display for ConstraintError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} violates constraint {}",
+                    self.identifier, self.constraint
+                )
+            }
+        }
+
+        #[doc = r" Checks the constraints of a value at runtime."]
+        pub trait Validate {
+            #[doc = r" Returns the first violated constraint of `self` or its components."]
+            fn validate(&self) -> Result<(), ConstraintError>;
+        }
+
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, size("1..=4"), from("A..=Z"))]
+        pub struct Code(pub Ia5String);
+        impl Validate for Code {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                let value = &self.0;
+                let length = value.as_iso646_bytes().len();
+                if length < 1 || length > 4 {
+                    return Err(ConstraintError {
+                        identifier: "Code",
+                        constraint: "(SIZE(1..4))",
+                    });
+                }
+                if !value
+                    .as_iso646_bytes()
+                    .iter()
+                    .map(|b| u32::from(*b))
+                    .all(|c| matches!(c, 65..=90))
+                {
+                    return Err(ConstraintError {
+                        identifier: "Code",
+                        constraint: "(FROM(\"A\"..\"Z\"))",
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        #[derive (AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            #[rasn(value("0..=255"))]
+            pub id: u8,
+            pub code: Option<Code>,
+            #[rasn(size("1..=8"))]
+            pub values: SequenceOf<Integer>,
+        }
+
+        impl Record {
+            pub fn new(id: u8, code: Option<Code>, values: SequenceOf<Integer>) -> Self {
+                Self { id, code, values }
+            }
+        }
+
+        impl Validate for Record {
+            fn validate(&self) -> Result<(), ConstraintError> {
+                {
+                    let value = &self.id;
+                    if i128::from(*value) < 0 || i128::from(*value) > 255 {
+                        return Err(ConstraintError {
+                            identifier: "Record.id",
+                            constraint: "(0..255)",
+                        });
+                    }
+                }
+                if let Some(value) = &self.code {
+                    value.validate()?;
+                }
+                {
+                    let value = &self.values;
+                    let length = value.len();
+                    if length < 1 || length > 8 {
+                        return Err(ConstraintError {
+                            identifier: "Record.values",
+                            constraint: "(SIZE(1..8))",
+                        });
+                    }
+                }
+                Ok(())
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    extension_additions_behind_features,
+    rasn_compiler::prelude::RasnConfig {
+        extension_features: std::collections::BTreeMap::from([
+            ("Record.note".into(), "v2".into()),
+            ("Tag.code".into(), "v2".into()),
+        ]),
+        ..Default::default()
+    },
+    r#"
+        Record ::= SEQUENCE {
+            id INTEGER (0..255),
+            ...,
+            note IA5String OPTIONAL,
+            [[ extra BOOLEAN ]]
+        }
+        Tag ::= CHOICE { label UTF8String, ..., code INTEGER }"#,
+    r#"
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct RecordExtGroupExtra {
+            pub extra: bool,
+        }
+        impl RecordExtGroupExtra {
+            pub fn new(extra: bool) -> Self {
+                Self { extra }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        pub struct Record {
+            #[rasn(value("0..=255"))]
+            pub id: u8,
+            #[cfg(feature = "v2")]
+            #[rasn(extension_addition)]
+            pub note: Option<Ia5String>,
+            #[cfg(feature = "v2")]
+            #[rasn(extension_addition_group, identifier = "SEQUENCE")]
+            pub ext_group_extra: Option<RecordExtGroupExtra>,
+        }
+        impl Record {
+            pub fn new(
+                id: u8,
+                #[cfg(feature = "v2")] note: Option<Ia5String>,
+                #[cfg(feature = "v2")] ext_group_extra: Option<RecordExtGroupExtra>,
+            ) -> Self {
+                Self {
+                    id,
+                    #[cfg(feature = "v2")]
+                    note,
+                    #[cfg(feature = "v2")]
+                    ext_group_extra,
+                }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        #[non_exhaustive]
+        pub enum Tag {
+            label(Utf8String),
+            #[cfg(feature = "v2")]
+            #[rasn(extension_addition)]
+            code(Integer),
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequence_and_choice_with_arbitrary,
+    rasn_compiler::prelude::RasnConfig {
+        generate_arbitrary: true,
+        ..Default::default()
+    },
+    r#" Reading ::= SEQUENCE {
+            sensor IA5String (SIZE(1..4)) (FROM("A".."Z")),
+            value INTEGER (-20..50) OPTIONAL,
+            samples SEQUENCE (SIZE(2)) OF BOOLEAN
+        }
+        Payload ::= CHOICE { reading Reading, raw OCTET STRING (SIZE(8)) }"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Payload {
+            reading(Reading),
+            #[rasn(size("8"))]
+            raw(OctetString),
+        }
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for Payload {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                let count = 2;
+                Ok(match u.int_in_range(0..=count - 1)? {
+                    0 => Self::reading(u.arbitrary()?),
+                    1 => Self::raw({
+                        let length = u.int_in_range::<usize>(8..=8)?;
+                        OctetString::from(u.bytes(length)?.to_vec())
+                    }),
+                    _ => unreachable!(),
+                })
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Reading {
+            #[rasn(size("1..=4"), from("A..=Z"))]
+            pub sensor: Ia5String,
+            #[rasn(value("-20..=50"))]
+            pub value: Option<i8>,
+            #[rasn(size("2"))]
+            pub samples: SequenceOf<bool>,
+        }
+        impl Reading {
+            pub fn new(sensor: Ia5String, value: Option<i8>, samples: SequenceOf<bool>) -> Self {
+                Self {
+                    sensor,
+                    value,
+                    samples,
+                }
+            }
+        }
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for Reading {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Self {
+                    sensor: {
+                        let length = u.int_in_range::<usize>(1..=4)?;
+                        let mut string = alloc::string::String::new();
+                        for _ in 0..length {
+                            string.push(
+                                char::from_u32(u.int_in_range(65..=90)?)
+                                    .ok_or(arbitrary::Error::IncorrectFormat)?,
+                            );
+                        }
+                        Ia5String::try_from(string).map_err(|_| arbitrary::Error::IncorrectFormat)?
+                    },
+                    value: if u.arbitrary()? {
+                        Some(u.int_in_range::<i8>(-20..=50)?)
+                    } else {
+                        None
+                    },
+                    samples: {
+                        let length = u.int_in_range::<usize>(2..=2)?;
+                        let mut items = SequenceOf::new();
+                        for _ in 0..length {
+                            items.push(u.arbitrary::<bool>()?);
+                        }
+                        items
+                    },
+                })
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    extensible_size_of_sequence_of,
+    r#"Samples ::= SEQUENCE {
+        values SEQUENCE (SIZE(1..4, ...)) OF INTEGER,
+        flags SEQUENCE (SIZE(1..4, ...) INTERSECTION SIZE(2..8)) OF BOOLEAN
+    }"#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Samples {
+            #[rasn(size("1..=4", extensible))]
+            pub values: SequenceOf<Integer>,
+            #[rasn(size("2..=4", extensible))]
+            pub flags: SequenceOf<bool>,
+        }
+
+        impl Samples {
+            pub fn new(values: SequenceOf<Integer>, flags: SequenceOf<bool>) -> Self {
+                Self { values, flags }
+            }
+        }                                                       "#
+);
+
+e2e_pdu!(
+    sequence_for_rasn_v0_13,
+    rasn_compiler::prelude::RasnConfig {
+        rasn_version: rasn_compiler::prelude::RasnVersion::V0_13,
+        ..Default::default()
+    },
+    r#"Sensor-Reading ::= SEQUENCE {
+        sensor-id INTEGER (0..255),
+        status ENUMERATED { ok, out-of-range }
+    }"#,
+    r#"
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        pub enum SensorReadingStatus {
+            ok = 0,
+            out_of_range = 1,
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct SensorReading {
+            #[rasn(value("0..=255"))]
+            pub sensor_id: u8,
+            pub status: SensorReadingStatus,
+        }
+
+        impl SensorReading {
+            pub fn new(sensor_id: u8, status: SensorReadingStatus) -> Self {
+                Self { sensor_id, status }
+            }
+        }                                                       "#
+);
+
+e2e_pdu!(
+    sequence_for_rasn_v0_14,
+    rasn_compiler::prelude::RasnConfig {
+        rasn_version: rasn_compiler::prelude::RasnVersion::V0_14,
+        ..Default::default()
+    },
+    r#"Sensor-Reading ::= SEQUENCE {
+        sensor-id INTEGER (0..255),
+        status ENUMERATED { ok, out-of-range }
+    }"#,
+    r#"
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        pub enum SensorReadingStatus {
+            ok = 0,
+            #[rasn(identifier = "out-of-range")]
+            out_of_range = 1,
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "Sensor-Reading")]
+        pub struct SensorReading {
+            #[rasn(value("0..=255"), identifier = "sensor-id")]
+            pub sensor_id: u8,
+            pub status: SensorReadingStatus,
+        }
+
+        impl SensorReading {
+            pub fn new(sensor_id: u8, status: SensorReadingStatus) -> Self {
+                Self { sensor_id, status }
+            }
+        }                                                       "#
+);
+
+e2e_pdu!(
+    sequence_with_preserved_casing,
+    rasn_compiler::prelude::RasnConfig {
+        casing: rasn_compiler::prelude::CasingStrategy::PreserveOriginal,
+        ..Default::default()
+    },
+    r#"Sensor-Reading ::= SEQUENCE {
+        sensorId INTEGER (0..255),
+        status ENUMERATED { ok, out-of-range }
+    }
+    max-Reading INTEGER ::= 255"#,
+    r#"
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        pub enum Sensor_Reading_status {
+            ok = 0,
+            #[rasn(identifier = "out-of-range")]
+            out_of_range = 1,
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "Sensor-Reading")]
+        pub struct Sensor_Reading {
+            #[rasn(value("0..=255"))]
+            pub sensorId: u8,
+            pub status: Sensor_Reading_status,
+        }
+
+        impl Sensor_Reading {
+            pub fn new(sensorId: u8, status: Sensor_Reading_status) -> Self {
+                Self { sensorId, status }
+            }
+        }
+
+        lazy_static! {
+            pub static ref max_Reading: Integer = Integer::from(255);
+        }                                                       "#
+);
+
+e2e_pdu!(
+    anonymous_types_with_preserved_casing,
+    rasn_compiler::prelude::RasnConfig {
+        casing: rasn_compiler::prelude::CasingStrategy::PreserveOriginal,
+        ..Default::default()
+    },
+    r#"Record ::= SEQUENCE {
+        flags SET OF BOOLEAN,
+        items SEQUENCE OF CHOICE { p INTEGER, q BOOLEAN },
+        inner SEQUENCE { b BOOLEAN },
+        alt CHOICE { x INTEGER, y BOOLEAN }
+    }
+    Record-list ::= SEQUENCE OF SEQUENCE { c INTEGER }"#,
+    r#"
+        #[doc = " Anonymous SET OF member "]
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate)]
+        pub struct Anonymous_Record_flags(pub bool);
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Record_flags(pub SetOf<Anonymous_Record_flags>);
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Anonymous_Record_items {
+            p(Integer),
+            q(bool),
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Record_items(pub SequenceOf<Anonymous_Record_items>);
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record_inner {
+            pub b: bool,
+        }
+        impl Record_inner {
+            pub fn new(b: bool) -> Self {
+                Self { b }
+            }
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Record_alt {
+            x(Integer),
+            y(bool),
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            pub flags: Record_flags,
+            pub items: Record_items,
+            pub inner: Record_inner,
+            pub alt: Record_alt,
+        }
+        impl Record {
+            pub fn new(
+                flags: Record_flags,
+                items: Record_items,
+                inner: Record_inner,
+                alt: Record_alt,
+            ) -> Self {
+                Self {
+                    flags,
+                    items,
+                    inner,
+                    alt,
+                }
+            }
+        }
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Anonymous_Record_list {
+            pub c: Integer,
+        }
+        impl Anonymous_Record_list {
+            pub fn new(c: Integer) -> Self {
+                Self { c }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Record-list")]
+        pub struct Record_list(pub SequenceOf<Anonymous_Record_list>);
+    "#
+);
+
+e2e_pdu!(
+    anonymous_types_with_screaming_snake_constants,
+    rasn_compiler::prelude::RasnConfig {
+        casing: rasn_compiler::prelude::CasingStrategy::ScreamingSnakeConstants,
+        ..Default::default()
+    },
+    r#"Record ::= SEQUENCE {
+        flags SET OF BOOLEAN,
+        items SEQUENCE OF CHOICE { p INTEGER, q BOOLEAN },
+        inner SEQUENCE { b BOOLEAN },
+        alt CHOICE { x INTEGER, y BOOLEAN }
+    }
+    Record-list ::= SEQUENCE OF SEQUENCE { c INTEGER }
+    max-items INTEGER ::= 255"#,
+    r#"
+        #[doc = " Anonymous SET OF member "]
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate)]
+        pub struct Anonymous_Record_flags(pub bool);
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Record_flags(pub SetOf<Anonymous_Record_flags>);
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Anonymous_Record_items {
+            p(Integer),
+            q(bool),
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Record_items(pub SequenceOf<Anonymous_Record_items>);
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record_inner {
+            pub b: bool,
+        }
+        impl Record_inner {
+            pub fn new(b: bool) -> Self {
+                Self { b }
+            }
+        }
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Record_alt {
+            x(Integer),
+            y(bool),
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            pub flags: Record_flags,
+            pub items: Record_items,
+            pub inner: Record_inner,
+            pub alt: Record_alt,
+        }
+        impl Record {
+            pub fn new(
+                flags: Record_flags,
+                items: Record_items,
+                inner: Record_inner,
+                alt: Record_alt,
+            ) -> Self {
+                Self {
+                    flags,
+                    items,
+                    inner,
+                    alt,
+                }
+            }
+        }
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Anonymous_Record_list {
+            pub c: Integer,
+        }
+        impl Anonymous_Record_list {
+            pub fn new(c: Integer) -> Self {
+                Self { c }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, identifier = "Record-list")]
+        pub struct Record_list(pub SequenceOf<Anonymous_Record_list>);
+        lazy_static! {
+            pub static ref MAX_ITEMS: Integer = Integer::from(255);
+        }
+    "#
+);
+
+e2e_pdu!(
+    collections_of_inline_types,
+    r#"Parent ::= SEQUENCE {
+        flags SET OF BOOLEAN,
+        values SEQUENCE OF INTEGER,
+        entries SEQUENCE OF SEQUENCE { key INTEGER }
+    }"#,
+    r#"
+        #[doc = " Anonymous SET OF member "]
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, identifier = "BOOLEAN")]
+        pub struct AnonymousParentFlags(pub bool);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct ParentFlags(pub SetOf<AnonymousParentFlags>);
+
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, identifier = "SEQUENCE")]
+        pub struct AnonymousParentEntries {
+            pub key: Integer,
+        }
+
+        impl AnonymousParentEntries {
+            pub fn new(key: Integer) -> Self {
+                Self { key }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct ParentEntries(pub SequenceOf<AnonymousParentEntries>);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Parent {
+            pub flags: ParentFlags,
+            pub values: SequenceOf<Integer>,
+            pub entries: ParentEntries,
+        }
+
+        impl Parent {
+            pub fn new(
+                flags: ParentFlags,
+                values: SequenceOf<Integer>,
+                entries: ParentEntries,
+            ) -> Self {
+                Self {
+                    flags,
+                    values,
+                    entries,
+                }
+            }
+        }                                                       "#
+);
+
+e2e_pdu!(
+    selection_type_conversions,
+    r#"My-Choice ::= CHOICE { num INTEGER (0..255), flag BOOLEAN }
+    Selected-Num ::= num < My-Choice"#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags, identifier = "My-Choice")]
+        pub enum MyChoice {
+            #[rasn(value("0..=255"))]
+            num(u8),
+            flag(bool),
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=255"), identifier = "Selected-Num")]
+        pub struct SelectedNum(pub u8);
+
+        impl From<SelectedNum> for MyChoice {
+            fn from(value: SelectedNum) -> Self {
+                MyChoice::num(value.0)
+            }
+        }
+
+        impl TryFrom<MyChoice> for SelectedNum {
+            type Error = MyChoice;
+
+            fn try_from(value: MyChoice) -> Result<Self, Self::Error> {
+                match value {
+                    MyChoice::num(inner) => Ok(Self(inner)),
+                    _ => Err(value),
+                }
+            }
+        }                                                       "#
+);
+
+e2e_pdu!(
+    selection_types_in_components,
+    r#" Alternatives ::= CHOICE { name [0] IA5String, id INTEGER (0..255) }
+        Record ::= SEQUENCE {
+            name name < Alternatives,
+            ids SEQUENCE OF id < Alternatives,
+            extra [2] BOOLEAN OPTIONAL
+        }
+        Id ::= INTEGER (INCLUDES id < Alternatives)"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Alternatives {
+            #[rasn(tag(context, 0))]
+            name(Ia5String),
+            #[rasn(value("0..=255"))]
+            id(u8),
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(delegate, value("0..=255"))]
+        pub struct Id(pub u8);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        pub struct Record {
+            #[rasn(tag(context, 0))]
+            pub name: Ia5String,
+            pub ids: SequenceOf<u8>,
+            #[rasn(tag(context, 2))]
+            pub extra: Option<bool>,
+        }
+        impl Record {
+            pub fn new(name: Ia5String, ids: SequenceOf<u8>, extra: Option<bool>) -> Self {
+                Self { name, ids, extra }
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    sequence_with_escaped_quote_default,
+    r#"Greeting ::= SEQUENCE { text UTF8String DEFAULT "He said ""hi""" }"#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Greeting {
+            #[rasn(default = "greeting_text_default")]
+            pub text: Utf8String,
+        }
+
+        impl Greeting {
+            pub fn new(text: Utf8String) -> Self {
+                Self { text }
+            }
+        }
+
+        fn greeting_text_default() -> Utf8String {
+            String::from("He said \"hi\"")
+        }                                                       "#
+);
+
+e2e_pdu!(
+    sequence_with_time_defaults,
+    r#"Event ::= SEQUENCE { time GeneralizedTime DEFAULT "20240101000000Z", utc UTCTime DEFAULT "240101120000+0100" }"#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Event {
+            #[rasn(default = "event_time_default")]
+            pub time: GeneralizedTime,
+            #[rasn(default = "event_utc_default")]
+            pub utc: UtcTime,
+        }
+
+        impl Event {
+            pub fn new(time: GeneralizedTime, utc: UtcTime) -> Self {
+                Self { time, utc }
+            }
+        }
+
+        fn event_time_default() -> GeneralizedTime {
+            "2024-01-01T00:00:00Z".parse::<GeneralizedTime>().unwrap()
+        }
+
+        fn event_utc_default() -> UtcTime {
+            "2024-01-01T11:00:00Z".parse::<UtcTime>().unwrap()
+        }                                                       "#
+);
+
+e2e_pdu!(
+    sequence_with_time_type_members,
+    r#"Event ::= SEQUENCE { day DATE DEFAULT "2024-01-01", at TIME-OF-DAY OPTIONAL, history SEQUENCE OF DATE-TIME }"#,
+    r#"
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 31))]
+        pub struct EventDay(pub VisibleString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 32))]
+        pub struct EventAt(pub VisibleString);
+
+        #[doc = " Anonymous SEQUENCE OF member "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 33), identifier = "DATE-TIME")]
+        pub struct AnonymousEventHistory(pub VisibleString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct EventHistory(pub SequenceOf<AnonymousEventHistory>);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Event {
+            #[rasn(default = "event_day_default")]
+            pub day: EventDay,
+            pub at: Option<EventAt>,
+            pub history: EventHistory,
+        }
+
+        impl Event {
+            pub fn new(day: EventDay, at: Option<EventAt>, history: EventHistory) -> Self {
+                Self { day, at, history }
+            }
+        }
+
+        fn event_day_default() -> EventDay {
+            EventDay(VisibleString::try_from("2024-01-01").unwrap())
+        }                                                       "#
+);
+
+e2e_pdu!(
+    time_values_of_referencing_types,
+    r#"
+          Stamp ::= GeneralizedTime
+          Validity ::= CHOICE { utcTime UTCTime, generalTime GeneralizedTime }
+          Record ::= SEQUENCE { created UTCTime, stamps SEQUENCE OF Stamp }
+          epoch Stamp ::= "19700101000000Z"
+          not-before Validity ::= utcTime : "240101120000+0100"
+          record Record ::= { created "491231235959Z", stamps { "20240101120000.5Z", "2024010112" } }
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            pub created: UtcTime,
+            pub stamps: SequenceOf<Stamp>,
+        }
+        impl Record {
+            pub fn new(created: UtcTime, stamps: SequenceOf<Stamp>) -> Self {
+                Self { created, stamps }
+            }
+        }
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate)]
+        pub struct Stamp(pub GeneralizedTime);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum Validity {
+            utcTime(UtcTime),
+            generalTime(GeneralizedTime),
+        }
+        lazy_static! {
+            pub static ref EPOCH: Stamp = Stamp("1970-01-01T00:00:00Z".parse().unwrap());
+        }
+        lazy_static! {
+            pub static ref NOT_BEFORE: Validity =
+                Validity::utcTime("2024-01-01T11:00:00Z".parse().unwrap());
+        }
+        lazy_static! {
+            pub static ref RECORD: Record = Record::new(
+                "2049-12-31T23:59:59Z".parse::<UtcTime>().unwrap(),
+                alloc::vec![
+                    Stamp("2024-01-01T12:00:00.500Z".parse().unwrap()),
+                    Stamp("2024-01-01T12:00:00Z".parse().unwrap())
+                ]
+            );
+        }
+    "#
+);
+
+e2e_pdu!(
+    non_cloneable_large_types,
+    rasn_compiler::prelude::RasnConfig {
+        non_cloneable_threshold: Some(16),
+        non_cloneable_types: std::collections::BTreeSet::from(["Label".into()]),
+        ..Default::default()
+    },
+    r#"
+        Entry ::= SEQUENCE { id INTEGER (0..255), flag BOOLEAN }
+        Entries ::= SEQUENCE (SIZE(1..1024)) OF Entry
+        Report ::= SEQUENCE { entries Entries }
+        Label ::= UTF8String
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Decode, Encode)]
+        #[rasn(delegate, size("1..=1024"))]
+        pub struct Entries(pub SequenceOf<Entry>);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Entry {
+            #[rasn(value("0..=255"))]
+            pub id: u8,
+            pub flag: bool,
+        }
+
+        impl Entry {
+            pub fn new(id: u8, flag: bool) -> Self {
+                Self { id, flag }
+            }
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode)]
+        #[rasn(delegate)]
+        pub struct Label(pub Utf8String);
+
+        #[derive(AsnType, Debug, Decode, Encode)]
+        #[rasn(automatic_tags)]
+        pub struct Report {
+            pub entries: Entries,
+        }
+
+        impl Report {
+            pub fn new(entries: Entries) -> Self {
+                Self { entries }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequence_with_codec_helpers,
+    rasn_compiler::prelude::RasnConfig {
+        generate_codec_helpers: Some(rasn_compiler::prelude::Codec::Der),
+        ..Default::default()
+    },
+    r#"Record ::= SEQUENCE { id INTEGER, inner SEQUENCE { flag BOOLEAN } }"#,
+    r#"
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct RecordInner {
+            pub flag: bool,
+        }
+
+        impl RecordInner {
+            pub fn new(flag: bool) -> Self {
+                Self { flag }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Record {
+            pub id: Integer,
+            pub inner: RecordInner,
+        }
+
+        impl Record {
+            pub fn new(id: Integer, inner: RecordInner) -> Self {
+                Self { id, inner }
+            }
+        }
+
+        impl Record {
+            pub fn to_der(&self) -> Result<alloc::vec::Vec<u8>, rasn::error::EncodeError> {
+                rasn::der::encode(self)
+            }
+
+            pub fn from_der(data: &[u8]) -> Result<Self, rasn::error::DecodeError> {
+                rasn::der::decode(data)
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequence_with_extension_presence,
+    rasn_compiler::prelude::RasnConfig {
+        generate_extension_presence: true,
+        ..Default::default()
+    },
+    r#"Record ::= SEQUENCE { id INTEGER, ..., flag BOOLEAN OPTIONAL, [[ name IA5String, note IA5String OPTIONAL ]], count INTEGER DEFAULT 3 }"#,
+    r#" #[doc = r" The extension additions that are present in a value, identified by their ASN.1 identifiers."]
+        #[doc = r" Extension addition groups are identified by their first component."]
+        #[derive(Debug, Clone, Default, PartialEq, Eq)]
+        pub struct ExtensionSet(pub alloc::vec::Vec<&'static str>);
+
+        impl ExtensionSet {
+            #[doc = r" Whether the extension addition with the given ASN.1 identifier is present."]
+            pub fn contains(&self, identifier: &str) -> bool {
+                self.0.iter().any(|present| *present == identifier)
+            }
+
+            #[doc = r" Whether no extension addition is present."]
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            #[doc = r" The ASN.1 identifiers of the present extension additions in order of their definition."]
+            pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+                self.0.iter().copied()
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct RecordExtGroupName {
+            pub name: Ia5String,
+            pub note: Option<Ia5String>,
+        }
+
+        impl RecordExtGroupName {
+            pub fn new(name: Ia5String, note: Option<Ia5String>) -> Self {
+                Self { name, note }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        pub struct Record {
+            pub id: Integer,
+            #[rasn(extension_addition)]
+            pub flag: Option<bool>,
+            #[rasn(extension_addition_group, identifier = "SEQUENCE")]
+            pub ext_group_name: Option<RecordExtGroupName>,
+            #[rasn(extension_addition, default = "record_count_default")]
+            pub count: Integer,
+        }
+
+        impl Record {
+            pub fn new(
+                id: Integer,
+                flag: Option<bool>,
+                ext_group_name: Option<RecordExtGroupName>,
+                count: Integer,
+            ) -> Self {
+                Self {
+                    id,
+                    flag,
+                    ext_group_name,
+                    count,
+                }
+            }
+        }
+
+        fn record_count_default() -> Integer {
+            Integer::from(3)
+        }
+
+        impl Record {
+            #[doc = r" Returns the extension additions that are present in the value."]
+            pub fn present_extensions(&self) -> ExtensionSet {
+                let mut present = alloc::vec::Vec::new();
+                if self.flag.is_some() {
+                    present.push("flag");
+                }
+                if self.ext_group_name.is_some() {
+                    present.push("name");
+                }
+                present.push("count");
+                ExtensionSet(present)
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequences_with_structural_conversions,
+    rasn_compiler::prelude::RasnConfig {
+        generate_structural_conversions: true,
+        ..Default::default()
+    },
+    r#"Base ::= SEQUENCE { id INTEGER, kind CHOICE { a BOOLEAN, b NULL } OPTIONAL }
+    Profile ::= SEQUENCE { id INTEGER, kind CHOICE { a BOOLEAN, b NULL } OPTIONAL }"#,
+    r#" #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum BaseKind {
+            a(bool),
+            b(()),
+        }
+
+        impl From<ProfileKind> for BaseKind {
+            fn from(value: ProfileKind) -> Self {
+                match value {
+                    ProfileKind::a(inner) => Self::a(inner),
+                    ProfileKind::b(inner) => Self::b(inner),
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Base {
+            pub id: Integer,
+            pub kind: Option<BaseKind>,
+        }
+
+        impl Base {
+            pub fn new(id: Integer, kind: Option<BaseKind>) -> Self {
+                Self { id, kind }
+            }
+        }
+
+        impl From<Profile> for Base {
+            fn from(value: Profile) -> Self {
+                Self {
+                    id: value.id,
+                    kind: value.kind.map(Into::into),
+                }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum ProfileKind {
+            a(bool),
+            b(()),
+        }
+
+        impl From<BaseKind> for ProfileKind {
+            fn from(value: BaseKind) -> Self {
+                match value {
+                    BaseKind::a(inner) => Self::a(inner),
+                    BaseKind::b(inner) => Self::b(inner),
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Profile {
+            pub id: Integer,
+            pub kind: Option<ProfileKind>,
+        }
+
+        impl Profile {
+            pub fn new(id: Integer, kind: Option<ProfileKind>) -> Self {
+                Self { id, kind }
+            }
+        }
+
+        impl From<Base> for Profile {
+            fn from(value: Base) -> Self {
+                Self {
+                    id: value.id,
+                    kind: value.kind.map(Into::into),
+                }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    sequence_with_root_components_after_extensions,
+    r#"Record ::= SEQUENCE { flag BOOLEAN OPTIONAL, ..., note IA5String OPTIONAL, ..., count INTEGER }"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        pub struct Record {
+            pub flag: Option<bool>,
+            pub count: Integer,
+            #[rasn(extension_addition)]
+            pub note: Option<Ia5String>,
+        }
+
+        impl Record {
+            pub fn new(flag: Option<bool>, count: Integer, note: Option<Ia5String>) -> Self {
+                Self { flag, count, note }
+            }
+        }
+    "#
+);
+
+#[test]
+fn decodes_root_only_value_into_extended_sequence() {
+    rasn_compiler_derive::asn1!(
+        r#"Version1 ::= SEQUENCE { flag BOOLEAN OPTIONAL, ..., ..., count INTEGER }
+        Version2 ::= SEQUENCE { flag BOOLEAN OPTIONAL, ..., [[ name IA5String ]], note IA5String OPTIONAL, ..., count INTEGER }"#
+    );
+    let encoded = rasn::uper::encode(&asn1::Version1::new(
+        Some(true),
+        rasn::types::Integer::from(7),
+    ))
+    .unwrap();
+    let decoded: asn1::Version2 = rasn::uper::decode(&encoded).unwrap();
+    assert_eq!(decoded.flag, Some(true));
+    assert_eq!(decoded.count, rasn::types::Integer::from(7));
+    assert_eq!(decoded.ext_group_name, None);
+    assert_eq!(decoded.note, None);
+}
+
+e2e_pdu!(
+    types_of_module_with_implied_extensibility,
+    r#"TestModule DEFINITIONS AUTOMATIC TAGS EXTENSIBILITY IMPLIED ::= BEGIN
+        Record ::= SEQUENCE { flag BOOLEAN, inner SET { id INTEGER } }
+        Extended ::= SEQUENCE { flag BOOLEAN, ..., id INTEGER OPTIONAL }
+        Color ::= ENUMERATED { red, green }
+        Alternatives ::= CHOICE { a BOOLEAN, b INTEGER }
+    END"#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        #[non_exhaustive]
+        pub enum Alternatives {
+            a(bool),
+            b(Integer),
+        }
+
+        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[rasn(enumerated)]
+        #[non_exhaustive]
+        pub enum Color {
+            red = 0,
+            green = 1,
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        pub struct Extended {
+            pub flag: bool,
+            #[rasn(extension_addition)]
+            pub id: Option<Integer>,
+        }
+
+        impl Extended {
+            pub fn new(flag: bool, id: Option<Integer>) -> Self {
+                Self { flag, id }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(set, automatic_tags)]
+        #[non_exhaustive]
+        pub struct RecordInner {
+            pub id: Integer,
+        }
+
+        impl RecordInner {
+            pub fn new(id: Integer) -> Self {
+                Self { id }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        pub struct Record {
+            pub flag: bool,
+            pub inner: RecordInner,
+        }
+
+        impl Record {
+            pub fn new(flag: bool, inner: RecordInner) -> Self {
+                Self { flag, inner }
+            }
+        }
+    "#
+);
+
+e2e_pdu!(
+    embedded_pdv,
+    r#" Pdv ::= EMBEDDED PDV
+        Message ::= SEQUENCE { id INTEGER, payload EMBEDDED PDV OPTIONAL }"#,
+    r#" #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct MessagePayloadIdentificationSyntaxes {
+            #[rasn(identifier = "abstract")]
+            pub r_abstract: ObjectIdentifier,
+            pub transfer: ObjectIdentifier,
+        }
+        impl MessagePayloadIdentificationSyntaxes {
+            pub fn new(r_abstract: ObjectIdentifier, transfer: ObjectIdentifier) -> Self {
+                Self {
+                    r_abstract,
+                    transfer,
+                }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct MessagePayloadIdentificationContextNegotiation {
+            #[rasn(identifier = "presentation-context-id")]
+            pub presentation_context_id: Integer,
+            #[rasn(identifier = "transfer-syntax")]
+            pub transfer_syntax: ObjectIdentifier,
+        }
+        impl MessagePayloadIdentificationContextNegotiation {
+            pub fn new(presentation_context_id: Integer, transfer_syntax: ObjectIdentifier) -> Self {
+                Self {
+                    presentation_context_id,
+                    transfer_syntax,
+                }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum MessagePayloadIdentification {
+            #[rasn(tag(context, 0))]
+            syntaxes(MessagePayloadIdentificationSyntaxes),
+            #[rasn(tag(context, 1))]
+            syntax(ObjectIdentifier),
+            #[rasn(tag(context, 2), identifier = "presentation-context-id")]
+            presentation_context_id(Integer),
+            #[rasn(tag(context, 3), identifier = "context-negotiation")]
+            context_negotiation(MessagePayloadIdentificationContextNegotiation),
+            #[rasn(tag(context, 4), identifier = "transfer-syntax")]
+            transfer_syntax(ObjectIdentifier),
+            #[rasn(tag(context, 5))]
+            fixed(()),
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(tag(universal, 11))]
+        pub struct MessagePayload {
+            #[rasn(tag(explicit(context, 0)))]
+            pub identification: MessagePayloadIdentification,
+            #[rasn(tag(context, 2), identifier = "data-value")]
+            pub data_value: OctetString,
+        }
+        impl MessagePayload {
+            pub fn new(identification: MessagePayloadIdentification, data_value: OctetString) -> Self {
+                Self {
+                    identification,
+                    data_value,
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct Message {
+            pub id: Integer,
+            pub payload: Option<MessagePayload>,
+        }
+        impl Message {
+            pub fn new(id: Integer, payload: Option<MessagePayload>) -> Self {
+                Self { id, payload }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct PdvIdentificationSyntaxes {
+            #[rasn(identifier = "abstract")]
+            pub r_abstract: ObjectIdentifier,
+            pub transfer: ObjectIdentifier,
+        }
+        impl PdvIdentificationSyntaxes {
+            pub fn new(r_abstract: ObjectIdentifier, transfer: ObjectIdentifier) -> Self {
+                Self {
+                    r_abstract,
+                    transfer,
+                }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        pub struct PdvIdentificationContextNegotiation {
+            #[rasn(identifier = "presentation-context-id")]
+            pub presentation_context_id: Integer,
+            #[rasn(identifier = "transfer-syntax")]
+            pub transfer_syntax: ObjectIdentifier,
+        }
+        impl PdvIdentificationContextNegotiation {
+            pub fn new(presentation_context_id: Integer, transfer_syntax: ObjectIdentifier) -> Self {
+                Self {
+                    presentation_context_id,
+                    transfer_syntax,
+                }
+            }
+        }
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum PdvIdentification {
+            #[rasn(tag(context, 0))]
+            syntaxes(PdvIdentificationSyntaxes),
+            #[rasn(tag(context, 1))]
+            syntax(ObjectIdentifier),
+            #[rasn(tag(context, 2), identifier = "presentation-context-id")]
+            presentation_context_id(Integer),
+            #[rasn(tag(context, 3), identifier = "context-negotiation")]
+            context_negotiation(PdvIdentificationContextNegotiation),
+            #[rasn(tag(context, 4), identifier = "transfer-syntax")]
+            transfer_syntax(ObjectIdentifier),
+            #[rasn(tag(context, 5))]
+            fixed(()),
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(tag(universal, 11))]
+        pub struct Pdv {
+            #[rasn(tag(explicit(context, 0)))]
+            pub identification: PdvIdentification,
+            #[rasn(tag(context, 2), identifier = "data-value")]
+            pub data_value: OctetString,
+        }
+        impl Pdv {
+            pub fn new(identification: PdvIdentification, data_value: OctetString) -> Self {
+                Self {
+                    identification,
+                    data_value,
+                }
+            }
+        }                                                           "#
+);
+
+e2e_pdu!(
+    external,
+    r#" Ext ::= EXTERNAL"#,
+    r#" #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(universal, 7))]
+        pub struct ExtDataValueDescriptor(pub GeneralString);
+
+        #[doc = " Inner type "]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        pub enum ExtEncoding {
+            #[rasn(tag(explicit(context, 0)), identifier = "single-ASN1-type")]
+            single_ASN1_type(Any),
+            #[rasn(tag(context, 1), identifier = "octet-aligned")]
+            octet_aligned(OctetString),
+            #[rasn(tag(context, 2))]
+            arbitrary(BitString),
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(tag(universal, 8))]
+        pub struct Ext {
+            #[rasn(identifier = "direct-reference")]
+            pub direct_reference: Option<ObjectIdentifier>,
+            #[rasn(identifier = "indirect-reference")]
+            pub indirect_reference: Option<Integer>,
+            #[rasn(identifier = "data-value-descriptor")]
+            pub data_value_descriptor: Option<ExtDataValueDescriptor>,
+            pub encoding: ExtEncoding,
+        }
+        impl Ext {
+            pub fn new(
+                direct_reference: Option<ObjectIdentifier>,
+                indirect_reference: Option<Integer>,
+                data_value_descriptor: Option<ExtDataValueDescriptor>,
+                encoding: ExtEncoding,
+            ) -> Self {
+                Self {
+                    direct_reference,
+                    indirect_reference,
+                    data_value_descriptor,
+                    encoding,
+                }
+            }
+        }                                                           "#
+);
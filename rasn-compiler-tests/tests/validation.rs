@@ -0,0 +1,98 @@
+// The bindings of `validation.asn1` are generated with `generate_validation` by the build script
+include!(concat!(env!("OUT_DIR"), "/validation.rs"));
+
+use rasn::types::{Ia5String, NumericString};
+use validation::*;
+
+fn code(code: &str) -> Code {
+    Code(Ia5String::try_from(code).unwrap())
+}
+
+fn violation(identifier: &'static str, constraint: &'static str) -> Result<(), ConstraintError> {
+    Err(ConstraintError {
+        identifier,
+        constraint,
+    })
+}
+
+#[test]
+fn accepts_valid_values() {
+    assert_eq!(code("ABCD").validate(), Ok(()));
+    assert_eq!(
+        Record::new(255, Some(code("A")), vec![-10, 0, 10]).validate(),
+        Ok(())
+    );
+    assert_eq!(Record::new(0, None, vec![0; 8]).validate(), Ok(()));
+}
+
+#[test]
+fn rejects_invalid_values() {
+    assert_eq!(code("").validate(), violation("Code", "(SIZE(1..4))"));
+    assert_eq!(code("ABCDE").validate(), violation("Code", "(SIZE(1..4))"));
+    assert_eq!(
+        code("Ab").validate(),
+        violation("Code", "(FROM(\"A\"..\"Z\"))")
+    );
+    assert_eq!(
+        Record::new(1, Some(code("a")), vec![0]).validate(),
+        violation("Code", "(FROM(\"A\"..\"Z\"))")
+    );
+    assert_eq!(
+        Record::new(1, None, vec![]).validate(),
+        violation("Record.values", "(SIZE(1..8))")
+    );
+    assert_eq!(
+        Record::new(1, None, vec![0; 9]).validate(),
+        violation("Record.values", "(SIZE(1..8))")
+    );
+    assert_eq!(
+        Record::new(1, None, vec![0, 11]).validate(),
+        violation("Record.values", "(-10..10)")
+    );
+}
+
+fn matches<T: Validate>(value: T) -> bool {
+    value.validate().is_ok()
+}
+
+#[test]
+fn matches_language_tags() {
+    let tag = |tag: &str| LanguageTag(tag.into());
+    assert!(matches(tag("en")));
+    assert!(matches(tag("en-GB-oxendict")));
+    assert!(!matches(tag("")));
+    assert!(!matches(tag("en-")));
+    assert_eq!(
+        tag("toolonglanguage").validate(),
+        violation(
+            "Language-Tag",
+            "(PATTERN \"[a-zA-Z]#(1,8)(-[a-zA-Z0-9]#(1,8))*\")"
+        )
+    );
+}
+
+#[test]
+fn matches_quantifiers() {
+    let digits = |digits: &str| ThreeDigits(NumericString::try_from(digits).unwrap());
+    assert!(matches(digits("123")));
+    assert!(!matches(digits("12")));
+    assert!(matches(RepeatedA("aaaa".into())));
+    assert!(!matches(RepeatedA("a".into())));
+    assert!(matches(BoundedA("aab".into())));
+    assert!(!matches(BoundedA("aaa".into())));
+    assert!(matches(Alternation("abcab".into())));
+    assert!(!matches(Alternation("abb".into())));
+    assert!(matches(NestedStar("aaa".into())));
+}
+
+#[test]
+fn matches_escapes_and_quadruples() {
+    assert!(matches(Escapes("ab1 .A".into())));
+    assert!(!matches(Escapes("ab1 .B".into())));
+    assert!(matches(EscapedDot(".".into())));
+    assert!(!matches(EscapedDot("a".into())));
+    assert!(matches(QuadrupleRange("x5".into())));
+    assert!(!matches(QuadrupleRange("xa".into())));
+    assert!(matches(DigitsAndDashes("12-3".into())));
+    assert!(!matches(DigitsAndDashes("12+3".into())));
+}
@@ -1,5 +1,5 @@
 #![cfg(feature = "cli")]
-use std::path::PathBuf;
+use std::{fs::read_to_string, path::PathBuf};
 
 use clap::{arg, command, Parser};
 use colored::Colorize;
@@ -27,6 +27,11 @@ struct CompilerArgs {
     ///  - "typescript": generates typescript type definitions
     #[arg(short, long, default_value = "rasn")]
     backend: String,
+
+    /// Check whether the generated bindings at the output path are up to date instead of writing them.
+    /// Exits with status code 1 if the bindings are missing or outdated
+    #[arg(long)]
+    check: bool,
 }
 
 pub fn main() {
@@ -59,21 +64,62 @@ pub fn main() {
         )
     }
 
+    if args.check {
+        let result = if args.backend == "typescript" {
+            Compiler::<TypescriptBackend, _>::new()
+                .add_asn_sources_by_path(modules.into_iter())
+                .set_output_path(args.output_path)
+                .continue_on_source_errors(true)
+                .check()
+        } else {
+            Compiler::<RasnBackend, _>::new()
+                .add_asn_sources_by_path(modules.into_iter())
+                .set_output_path(args.output_path)
+                .continue_on_source_errors(true)
+                .check()
+        };
+        match result {
+            Ok(true) => println!("{}", "Generated bindings are up to date.".green()),
+            Ok(false) => {
+                println!(
+                    "{}",
+                    "Generated bindings are outdated, please rerun the rasn compiler.".red()
+                );
+                std::process::exit(1)
+            }
+            Err(error) => {
+                println!(
+                    "{}\n{}",
+                    "Rasn compiler error:".red(),
+                    error.to_string().red()
+                );
+                std::process::exit(1)
+            }
+        }
+        return;
+    }
+
     let results = if args.backend == "typescript" {
         Compiler::<TypescriptBackend, _>::new()
             .add_asn_sources_by_path(modules.into_iter())
             .set_output_path(args.output_path)
+            .continue_on_source_errors(true)
             .compile()
     } else {
         Compiler::<RasnBackend, _>::new()
             .add_asn_sources_by_path(modules.into_iter())
             .set_output_path(args.output_path)
+            .continue_on_source_errors(true)
             .compile()
     };
 
     match results {
         Ok(warnings) => {
             for warning in warnings {
+                if let Some(diagnostic) = render_failed_source(&*warning) {
+                    println!("{diagnostic}");
+                    continue;
+                }
                 println!(
                     "{}\n{}",
                     "Rasn compiler warning:".yellow(),
@@ -90,3 +136,12 @@ pub fn main() {
         }
     }
 }
+
+/// Renders the parser error of a skipped ASN.1 module with a snippet of the module's source
+fn render_failed_source(warning: &(dyn std::error::Error + 'static)) -> Option<String> {
+    let failed = warning.downcast_ref::<FailedSource>()?;
+    let error = failed.error.downcast_ref::<LexerError>()?;
+    let path = failed.path.as_ref()?;
+    let source = read_to_string(path).ok()?;
+    Some(error.render_pretty(&path.to_string_lossy(), &source))
+}
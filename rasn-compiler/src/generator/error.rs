@@ -26,6 +26,7 @@ impl GeneratorError {
 pub enum GeneratorErrorType {
     Asn1TypeMismatch,
     EmptyChoiceType,
+    AmbiguousChoiceTags,
     MissingCustomSyntax,
     SyntaxMismatch,
     MissingClassKey,
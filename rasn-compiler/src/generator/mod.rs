@@ -41,6 +41,11 @@ pub trait Backend: Sized + Default {
         Ok(bindings.to_owned())
     }
 
+    /// Prepares the generation of bindings for the given top-level declarations of all modules.
+    /// Backends can use this hook to collect information across module boundaries
+    /// before [Backend::generate_module] is called for each module. The default implementation does nothing.
+    fn prepare(&mut self, _top_level_declarations: &[ToplevelDefinition]) {}
+
     /// Returns a reference to the backend's config
     fn config(&self) -> &Self::Config;
 
@@ -48,12 +53,14 @@ pub trait Backend: Sized + Default {
     fn from_config(config: Self::Config) -> Self;
 }
 
-pub struct GeneratedModule {
-    pub generated: Option<String>,
+/// Bindings of an ASN.1 module along with the warnings raised while generating them.
+/// Backends yield the bindings as a `String`, the rasn backend also as a `TokenStream`.
+pub struct GeneratedModule<T = String> {
+    pub generated: Option<T>,
     pub warnings: Vec<Box<dyn Error>>,
 }
 
-impl GeneratedModule {
+impl<T> GeneratedModule<T> {
     pub fn empty() -> Self {
         Self {
             generated: None,
@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::collections::BTreeMap;
 
@@ -8,8 +8,8 @@ use crate::intermediate::{
         ASN1Information, ClassLink, InformationObjectClass, InformationObjectFields,
         ObjectSetValue, ToplevelInformationDefinition,
     },
-    ASN1Type, ASN1Value, CharacterStringType, ToplevelDefinition, ToplevelTypeDefinition,
-    ToplevelValueDefinition,
+    ASN1Type, ASN1Value, AsnTag, TagClass, TaggingEnvironment, ToplevelDefinition,
+    ToplevelTypeDefinition, ToplevelValueDefinition,
 };
 
 use super::{information_object::InformationObjectClassField, template::*, Rasn};
@@ -45,7 +45,62 @@ impl Rasn {
                 if t.parameterization.is_some() {
                     return Ok(TokenStream::new());
                 }
-                match t.ty {
+                if t.is_alias {
+                    return self.generate_alias(t);
+                }
+                // Types such as EMBEDDED PDV are represented by the SEQUENCEs they are encoded as
+                if let Some(ty) = t.ty.associated_type() {
+                    let tag = t.tag.clone().or_else(|| {
+                        t.ty.universal_tag().map(|id| AsnTag {
+                            environment: TaggingEnvironment::Implicit,
+                            tag_class: TagClass::Universal,
+                            id,
+                        })
+                    });
+                    return self.generate_tld(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                        tag,
+                        ty,
+                        ..t
+                    }));
+                }
+                let visit_impl = if self.config.generate_visitor {
+                    self.format_visit_impl(&t)
+                } else {
+                    TokenStream::new()
+                };
+                let validate_impl = if self.config.generate_validation {
+                    self.format_validate_impl(&t)?
+                } else {
+                    TokenStream::new()
+                };
+                let display_impl = if self.config.generate_display {
+                    self.format_display_impl(&t)
+                } else {
+                    TokenStream::new()
+                };
+                let debug_impl = if self.config.generate_debug_notation {
+                    self.format_debug_impl(&t)
+                } else {
+                    TokenStream::new()
+                };
+                let arbitrary_impl = if self.config.generate_arbitrary {
+                    self.format_arbitrary_impl(&t)?
+                } else {
+                    TokenStream::new()
+                };
+                let conversion_impls = if self.config.generate_newtype_conversions {
+                    self.format_newtype_conversions(&t)?
+                } else {
+                    TokenStream::new()
+                };
+                let extension_presence_impl = if self.config.generate_extension_presence {
+                    self.format_extension_presence_impl(&t)
+                } else {
+                    TokenStream::new()
+                };
+                let structural_conversions = self.format_structural_conversions(&t);
+                let selection_impls = self.format_selection_conversions(&t);
+                let definition = match t.ty {
                     ASN1Type::Null => self.generate_null(t),
                     ASN1Type::Boolean(_) => self.generate_boolean(t),
                     ASN1Type::Integer(_) => self.generate_integer(t),
@@ -59,7 +114,8 @@ impl Rasn {
                     ASN1Type::ElsewhereDeclaredType(_) => self.generate_typealias(t),
                     ASN1Type::Choice(_) => self.generate_choice(t),
                     ASN1Type::OctetString(_) => self.generate_octet_string(t),
-                    ASN1Type::Time(_) => unimplemented!("rasn does not support TIME types yet!"),
+                    ASN1Type::Time(_) => self.generate_time(t),
+                    ASN1Type::ObjectIdentifierIri(_) => self.generate_oid_iri(t),
                     ASN1Type::Real(_) => Err(GeneratorError {
                         kind: GeneratorErrorType::NotYetInplemented,
                         details: "Real types are currently unsupported!".into(),
@@ -77,11 +133,33 @@ impl Rasn {
                             .into(),
                         top_level_declaration: None,
                     }),
+                }?;
+                Ok(quote! {
+                    #definition #visit_impl #validate_impl #display_impl #debug_impl #arbitrary_impl #conversion_impls #extension_presence_impl #structural_conversions #selection_impls
+                })
+            }
+            ToplevelDefinition::Value(v) => {
+                if v.parameterization.is_some() {
+                    return Ok(TokenStream::new());
                 }
+                if let Some(path) = self
+                    .config
+                    .external_types
+                    .get(v.associated_type.as_str().as_ref())
+                {
+                    let details = format!("Skipping value, since its type is mapped to {path}!");
+                    return Err(GeneratorError::new(
+                        Some(ToplevelDefinition::Value(v)),
+                        &details,
+                        GeneratorErrorType::NotYetInplemented,
+                    ));
+                }
+                self.generate_value(v)
             }
-            ToplevelDefinition::Value(v) => self.generate_value(v),
             ToplevelDefinition::Information(i) => match i.value {
-                ASN1Information::ObjectSet(_) => self.generate_information_object_set(i),
+                ASN1Information::ObjectSet(_) if i.parameterization.is_none() => {
+                    self.generate_information_object_set(i)
+                }
                 _ => Ok(TokenStream::new()),
             },
         }
@@ -106,9 +184,10 @@ impl Rasn {
                 ));
             }
             Ok(typealias_template(
-                self.format_comments(&tld.comments)?,
+                self.format_alias_comments(&tld.comments, &dec.constraints)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
-                self.to_rust_title_case(&dec.identifier),
+                self.format_type_reference(&dec.identifier)?,
                 self.join_annotations(annotations),
             ))
         } else {
@@ -120,6 +199,26 @@ impl Rasn {
         }
     }
 
+    /// Generates a plain type alias, which shares the implementations of the aliased type
+    pub(crate) fn generate_alias(
+        &self,
+        tld: ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        if let ASN1Type::ElsewhereDeclaredType(dec) = &tld.ty {
+            Ok(alias_template(
+                self.format_comments(&tld.comments)?,
+                self.to_rust_title_case(&tld.name),
+                self.format_type_reference(&dec.identifier)?,
+            ))
+        } else {
+            Err(GeneratorError::new(
+                Some(ToplevelDefinition::Type(tld)),
+                "Expected type alias top-level declaration",
+                GeneratorErrorType::Asn1TypeMismatch,
+            ))
+        }
+    }
+
     pub(crate) fn generate_integer_value(
         &self,
         tld: ToplevelValueDefinition,
@@ -174,11 +273,14 @@ impl Rasn {
                     &tld.ty,
                 ));
             }
+            let named_numbers = self.format_named_numbers(&name, int);
             Ok(integer_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
                 int.int_type().to_token_stream(),
+                named_numbers,
             ))
         } else {
             Err(GeneratorError::new(
@@ -207,11 +309,17 @@ impl Rasn {
                     &tld.ty,
                 ));
             }
-            Ok(bit_string_template(
+            let named_bits = self.format_named_bits(&name, bitstr);
+            let contained_type_access =
+                self.format_contained_type_access(&name, &bitstr.constraints, true)?;
+            let definition = bit_string_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
-            ))
+                named_bits,
+            );
+            Ok(quote!(#definition #contained_type_access))
         } else {
             Err(GeneratorError::new(
                 Some(ToplevelDefinition::Type(tld)),
@@ -239,11 +347,16 @@ impl Rasn {
                     &tld.ty,
                 ));
             }
-            Ok(octet_string_template(
+            let value_range_check = self.format_octet_string_value_range_check(&name, oct_str);
+            let contained_type_access =
+                self.format_contained_type_access(&name, &oct_str.constraints, false)?;
+            let definition = octet_string_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
-            ))
+            );
+            Ok(quote!(#definition #value_range_check #contained_type_access))
         } else {
             Err(GeneratorError::new(
                 Some(ToplevelDefinition::Type(tld)),
@@ -259,12 +372,22 @@ impl Rasn {
     ) -> Result<TokenStream, GeneratorError> {
         if let ASN1Type::CharacterString(ref char_str) = tld.ty {
             let name = self.to_rust_title_case(&tld.name);
-            let mut annotations = vec![
-                quote!(delegate),
-                self.format_range_annotations(true, &char_str.constraints)?,
-                self.format_alphabet_annotations(char_str.ty, &char_str.constraints)?,
-                self.format_tag(tld.tag.as_ref(), false),
-            ];
+            let mut annotations = vec![quote!(delegate)];
+            // The constraints of strings that are carried as raw octets do not apply to the octets
+            if self.string_carrier(char_str.ty).is_some() {
+                annotations.push(self.format_range_annotations(true, &char_str.constraints)?);
+                annotations
+                    .push(self.format_alphabet_annotations(char_str.ty, &char_str.constraints)?);
+            }
+            // String types that rasn does not represent natively are represented by their
+            // carrier type with the string type's universal tag
+            annotations.push(match tld.tag.as_ref() {
+                None if self.is_retagged_string(char_str.ty) => {
+                    let universal_tag = tld.ty.universal_tag().map(Literal::u64_unsuffixed);
+                    quote!(tag(universal, #universal_tag))
+                }
+                tag => self.format_tag(tag, false),
+            });
             if name.to_string() != tld.name {
                 annotations.push(self.format_identifier_annotation(
                     &tld.name,
@@ -272,12 +395,15 @@ impl Rasn {
                     &tld.ty,
                 ));
             }
-            Ok(char_string_template(
+            let pattern_constant = self.format_pattern_constant(&name, &char_str.constraints);
+            let definition = char_string_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.string_type(&char_str.ty)?,
                 self.join_annotations(annotations),
-            ))
+            );
+            Ok(quote!(#definition #pattern_constant))
         } else {
             Err(GeneratorError::new(
                 Some(ToplevelDefinition::Type(tld)),
@@ -300,6 +426,7 @@ impl Rasn {
         if let ASN1Type::Boolean(_) = tld.ty {
             Ok(boolean_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
             ))
@@ -345,6 +472,18 @@ impl Rasn {
                 assignment!(self, &ty.as_str(), b.to_token_stream())
             ),
             ASN1Value::LinkedIntValue { .. } => self.generate_integer_value(tld),
+            ASN1Value::Real(_) if ty.is_builtin_type() => call_template!(
+                self,
+                primitive_value_template,
+                tld,
+                quote!(f64),
+                self.value_to_tokens(&tld.value, None)?
+            ),
+            ASN1Value::Real(_) => Err(GeneratorError::new(
+                Some(ToplevelDefinition::Value(tld)),
+                "Real types are currently unsupported!",
+                GeneratorErrorType::NotYetInplemented,
+            )),
             ASN1Value::BitString(_) if ty.is_builtin_type() => call_template!(
                 self,
                 lazy_static_value_template,
@@ -394,27 +533,29 @@ impl Rasn {
                 self.to_rust_title_case(enumerated),
                 self.to_rust_enum_identifier(enumerable)
             ),
-            ASN1Value::Time(_) if ty.is_builtin_type() => match ty {
-                ASN1Type::GeneralizedTime(_) => call_template!(
-                    self,
-                    lazy_static_value_template,
-                    tld,
-                    quote!(GeneralizedTime),
-                    self.value_to_tokens(&tld.value, Some(&quote!(GeneralizedTime)))?
-                ),
-                ASN1Type::UTCTime(_) => call_template!(
-                    self,
-                    lazy_static_value_template,
-                    tld,
-                    quote!(UtcTime),
-                    self.value_to_tokens(&tld.value, Some(&quote!(UtcTime)))?
-                ),
-                _ => Err(GeneratorError::new(
-                    Some(ToplevelDefinition::Value(tld)),
-                    "Time value does not match expected type",
-                    GeneratorErrorType::Asn1TypeMismatch,
-                )),
-            },
+            ASN1Value::Time(_) | ASN1Value::LinkedTimeValue(_) if ty.is_builtin_type() => {
+                match ty {
+                    ASN1Type::GeneralizedTime(_) => call_template!(
+                        self,
+                        lazy_static_value_template,
+                        tld,
+                        quote!(GeneralizedTime),
+                        self.value_to_tokens(&tld.value, Some(&quote!(GeneralizedTime)))?
+                    ),
+                    ASN1Type::UTCTime(_) => call_template!(
+                        self,
+                        lazy_static_value_template,
+                        tld,
+                        quote!(UtcTime),
+                        self.value_to_tokens(&tld.value, Some(&quote!(UtcTime)))?
+                    ),
+                    _ => Err(GeneratorError::new(
+                        Some(ToplevelDefinition::Value(tld)),
+                        "Time value does not match expected type",
+                        GeneratorErrorType::Asn1TypeMismatch,
+                    )),
+                }
+            }
             ASN1Value::LinkedStructLikeValue(s) => {
                 let members = s
                     .iter()
@@ -466,25 +607,7 @@ impl Rasn {
                 self.value_to_tokens(&tld.value, None)?
             ),
             ASN1Value::LinkedCharStringValue(cs_ty, _) if ty.is_builtin_type() => {
-                let ty_ts = match cs_ty {
-                    CharacterStringType::NumericString => quote!(NumericString),
-                    CharacterStringType::VisibleString => quote!(VisibleString),
-                    CharacterStringType::IA5String => quote!(IA5String),
-                    CharacterStringType::UTF8String => quote!(UTF8String),
-                    CharacterStringType::BMPString => quote!(BMPString),
-                    CharacterStringType::PrintableString => quote!(PrintableString),
-                    CharacterStringType::GeneralString => quote!(GeneralString),
-                    CharacterStringType::GraphicString
-                    | CharacterStringType::TeletexString
-                    | CharacterStringType::VideotexString
-                    | CharacterStringType::UniversalString => {
-                        return Err(GeneratorError::new(
-                            None,
-                            &format!("{:?} values are currently unsupported", cs_ty),
-                            GeneratorErrorType::NotYetInplemented,
-                        ))
-                    }
-                };
+                let ty_ts = self.string_type(cs_ty)?;
                 call_template!(
                     self,
                     lazy_static_value_template,
@@ -518,6 +641,7 @@ impl Rasn {
             }
             ASN1Value::BitString(_)
             | ASN1Value::Time(_)
+            | ASN1Value::LinkedTimeValue(_)
             | ASN1Value::LinkedCharStringValue(_, _)
             | ASN1Value::ObjectIdentifier(_)
             | ASN1Value::LinkedArrayLikeValue(_)
@@ -544,6 +668,7 @@ impl Rasn {
         }
         Ok(any_template(
             self.format_comments(&tld.comments)?,
+            self.derives(&tld.name, &tld.ty),
             name,
             self.join_annotations(annotations),
         ))
@@ -565,6 +690,7 @@ impl Rasn {
             }
             Ok(generalized_time_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
             ))
@@ -593,6 +719,7 @@ impl Rasn {
             }
             Ok(utc_time_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
             ))
@@ -605,15 +732,94 @@ impl Rasn {
         }
     }
 
+    pub(crate) fn generate_time(
+        &self,
+        tld: ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        if let ASN1Type::Time(_) = &tld.ty {
+            let name = self.to_rust_title_case(&tld.name);
+            // TIME types are represented by visible strings with the TIME type's universal tag
+            let universal_tag = tld.ty.universal_tag().map(Literal::u64_unsuffixed);
+            let tag = match tld.tag.as_ref() {
+                Some(tag) => self.format_tag(Some(tag), false),
+                None => quote!(tag(universal, #universal_tag)),
+            };
+            let mut annotations = vec![quote!(delegate), tag];
+            if name.to_string() != tld.name {
+                annotations.push(self.format_identifier_annotation(
+                    &tld.name,
+                    &tld.comments,
+                    &tld.ty,
+                ));
+            }
+            Ok(time_template(
+                self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
+                name,
+                self.join_annotations(annotations),
+            ))
+        } else {
+            Err(GeneratorError::new(
+                Some(ToplevelDefinition::Type(tld)),
+                "Expected TIME top-level declaration",
+                GeneratorErrorType::Asn1TypeMismatch,
+            ))
+        }
+    }
+
+    pub(crate) fn generate_oid_iri(
+        &self,
+        tld: ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        if let ASN1Type::ObjectIdentifierIri(_) = &tld.ty {
+            let name = self.to_rust_title_case(&tld.name);
+            // IRI types are represented by UTF8 strings with the IRI type's universal tag
+            let universal_tag = tld.ty.universal_tag().map(Literal::u64_unsuffixed);
+            let tag = match tld.tag.as_ref() {
+                Some(tag) => self.format_tag(Some(tag), false),
+                None => quote!(tag(universal, #universal_tag)),
+            };
+            let mut annotations = vec![quote!(delegate), tag];
+            if name.to_string() != tld.name {
+                annotations.push(self.format_identifier_annotation(
+                    &tld.name,
+                    &tld.comments,
+                    &tld.ty,
+                ));
+            }
+            Ok(oid_iri_template(
+                self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
+                name,
+                self.join_annotations(annotations),
+            ))
+        } else {
+            Err(GeneratorError::new(
+                Some(ToplevelDefinition::Type(tld)),
+                "Expected OID-IRI top-level declaration",
+                GeneratorErrorType::Asn1TypeMismatch,
+            ))
+        }
+    }
+
     pub(crate) fn generate_oid(
         &self,
         tld: ToplevelTypeDefinition,
     ) -> Result<TokenStream, GeneratorError> {
         if let ASN1Type::ObjectIdentifier(oid) = &tld.ty {
             let name = self.to_rust_title_case(&tld.name);
+            // RELATIVE-OID types are represented by their contents octets
+            // with the RELATIVE-OID type's universal tag
+            let tag = match (tld.tag.as_ref(), oid.is_relative) {
+                (None, true) => {
+                    let universal_tag = tld.ty.universal_tag().map(Literal::u64_unsuffixed);
+                    quote!(tag(universal, #universal_tag))
+                }
+                (tag, _) => self.format_tag(tag, false),
+            };
             let mut annotations = vec![
                 quote!(delegate),
-                self.format_tag(tld.tag.as_ref(), false),
+                tag,
                 self.format_range_annotations(false, &oid.constraints)?,
             ];
             if name.to_string() != tld.name {
@@ -623,8 +829,14 @@ impl Rasn {
                     &tld.ty,
                 ));
             }
-            Ok(oid_template(
+            let template = if oid.is_relative {
+                relative_oid_template
+            } else {
+                oid_template
+            };
+            Ok(template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
             ))
@@ -653,6 +865,7 @@ impl Rasn {
             }
             Ok(null_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 self.join_annotations(annotations),
             ))
@@ -689,6 +902,7 @@ impl Rasn {
             }
             Ok(enumerated_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name,
                 extensible,
                 self.format_enum_members(enumerated),
@@ -709,7 +923,8 @@ impl Rasn {
     ) -> Result<TokenStream, GeneratorError> {
         if let ASN1Type::Choice(ref choice) = tld.ty {
             let name = self.to_rust_title_case(&tld.name);
-            let inner_options = self.format_nested_choice_options(choice, &name.to_string())?;
+            let inner_options =
+                self.format_nested_choice_options(choice, &name.to_string(), &tld.index)?;
             let extensible = choice
                 .extensible
                 .map(|_| {
@@ -717,7 +932,21 @@ impl Rasn {
                     #[non_exhaustive]}
                 })
                 .unwrap_or_default();
-            let mut annotations = vec![quote!(choice), self.format_tag(tld.tag.as_ref(), true)];
+            // Without automatic tagging, rasn tells the alternatives apart by their own tags
+            let automatic_tags = self.has_automatic_tagging(&tld);
+            if !automatic_tags {
+                if let Err(details) = self.check_distinct_choice_tags(choice) {
+                    return Err(GeneratorError::new(
+                        Some(ToplevelDefinition::Type(tld)),
+                        &details,
+                        GeneratorErrorType::AmbiguousChoiceTags,
+                    ));
+                }
+            }
+            let mut annotations = vec![
+                quote!(choice),
+                self.format_tag(tld.tag.as_ref(), automatic_tags),
+            ];
             if name.to_string() != tld.name {
                 annotations.push(self.format_identifier_annotation(
                     &tld.name,
@@ -725,11 +954,17 @@ impl Rasn {
                     &tld.ty,
                 ));
             }
+            let feature_gates = self.format_extension_feature_gates(
+                &tld.name,
+                choice.extensible,
+                choice.options.iter().map(|o| &o.name),
+            );
             Ok(choice_template(
                 self.format_comments(&tld.comments)?,
+                self.derives(&tld.name, &tld.ty),
                 name.clone(),
                 extensible,
-                self.format_choice_options(choice, &name.to_string())?,
+                self.format_choice_options(choice, &name.to_string(), &feature_gates)?,
                 inner_options,
                 self.join_annotations(annotations),
             ))
@@ -756,17 +991,31 @@ impl Rasn {
                         #[non_exhaustive]}
                     })
                     .unwrap_or_default();
-                let set_annotation = if let ASN1Type::Set(_) = tld.ty {
-                    quote!(set)
-                } else {
-                    TokenStream::new()
+                // rasn cannot derive `Decode` for SETs without components. Since an empty SET
+                // is encoded like an empty SEQUENCE, it is annotated with the universal SET tag instead.
+                let (set_annotation, tag) = match tld.ty {
+                    ASN1Type::Set(_) if seq.members.is_empty() => (
+                        TokenStream::new(),
+                        Some(tld.tag.clone().unwrap_or(AsnTag {
+                            environment: TaggingEnvironment::Implicit,
+                            tag_class: TagClass::Universal,
+                            id: 17,
+                        })),
+                    ),
+                    ASN1Type::Set(_) => (quote!(set), tld.tag.clone()),
+                    _ => (TokenStream::new(), tld.tag.clone()),
                 };
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    seq.extensible,
+                    seq.members.iter().map(|m| &m.name),
+                );
                 let class_fields = if self.config.opaque_open_types {
                     TokenStream::new()
                 } else {
-                    seq.members.iter().fold(
+                    seq.members.iter().zip(&feature_gates).fold(
                     TokenStream::new(),
-                    |mut acc, m| {
+                    |mut acc, (m, feature_gate)| {
                         [
                             m.constraints.clone(),
                             m.ty.constraints().map_or(vec![], |c| c.to_vec())
@@ -775,10 +1024,14 @@ impl Rasn {
                         .iter()
                         .for_each(|c| {
                             if let (Constraint::TableConstraint(t), ASN1Type::InformationObjectFieldReference(iofr)) = (c, &m.ty) {
+                                if t.linked_fields.iter().any(|l| l.level != 1) {
+                                    // Components of enclosing types are not accessible from the generated struct
+                                    return;
+                                }
                                 let decode_fn = format_ident!("decode_{}", self.to_rust_snake_case(&m.name));
-                                let open_field_name = self.to_rust_snake_case(&m.name);
-                                let identifier = t.linked_fields.iter().map(|l|
-                                    self.to_rust_snake_case(&l.field_name)
+                                let open_field_name = self.to_rust_field_case(&m.name);
+                                let identifier = t.linked_fields.iter().flat_map(|l|
+                                    [&l.field_name].into_iter().chain(&l.component_path).map(|c| self.to_rust_field_case(c))
                                 );
                                 let field_name = iofr.field_path.last().unwrap().identifier().replace('&', "");
                                 if field_name.starts_with(|initial: char| initial.is_lowercase()) {
@@ -793,6 +1046,7 @@ impl Rasn {
                                 let input = m.is_optional.then(|| quote!(self. #open_field_name .as_ref())).unwrap_or(quote!(Some(&self. #open_field_name)));
                                 acc.append_all(quote! {
 
+                                    #feature_gate
                                     impl #name {
                                         pub fn #decode_fn<D: Decoder>(&self, decoder: &mut D) -> Result<#field_enum_name, D::Error> {
                                             #field_enum_name ::decode(decoder, #input, &self. #(#identifier).*)
@@ -805,8 +1059,13 @@ impl Rasn {
                     })
                 };
                 let (declaration, name_types) =
-                    self.format_sequence_or_set_members(seq, &name.to_string())?;
-                let mut annotations = vec![set_annotation, self.format_tag(tld.tag.as_ref(), true)];
+                    self.format_sequence_or_set_members(seq, &name.to_string(), &feature_gates)?;
+                // rasn does not support automatic tags for structs with explicitly tagged fields
+                let automatic_tags = seq.members.iter().all(|m| m.tag.is_none());
+                let mut annotations = vec![
+                    set_annotation,
+                    self.format_tag(tag.as_ref(), automatic_tags),
+                ];
                 if name.to_string() != tld.name {
                     annotations.push(self.format_identifier_annotation(
                         &tld.name,
@@ -816,13 +1075,14 @@ impl Rasn {
                 }
                 Ok(sequence_or_set_template(
                     self.format_comments(&tld.comments)?,
+                    self.derives(&tld.name, &tld.ty),
                     name.clone(),
                     extensible,
                     declaration,
-                    self.format_nested_sequence_members(seq, &name.to_string())?,
+                    self.format_nested_sequence_members(seq, &name.to_string(), &tld.index)?,
                     self.join_annotations(annotations),
                     self.format_default_methods(&seq.members, &name.to_string())?,
-                    self.format_new_impl(&name, name_types),
+                    self.format_new_impl(&name, name_types, &feature_gates),
                     class_fields,
                 ))
             }
@@ -850,6 +1110,7 @@ impl Rasn {
             }
         };
         let name = self.to_rust_title_case(&tld.name);
+        let anonymous_name = String::from(INNER_ARRAY_LIKE_PREFIX) + &name.to_string();
         let anonymous_item = match seq_or_set_of.element_type.as_ref() {
             ASN1Type::ElsewhereDeclaredType(_) => None,
             n => Some(
@@ -859,17 +1120,19 @@ impl Rasn {
                         " Anonymous {} OF member ",
                         if is_set_of { "SET" } else { "SEQUENCE" }
                     ),
-                    name: String::from(INNER_ARRAY_LIKE_PREFIX) + &name.to_string(),
+                    name: anonymous_name.clone(),
                     ty: n.clone(),
                     tag: None,
-                    index: None,
+                    index: tld.index.clone(),
+                    selection: None,
+                    is_alias: false,
                 }))?,
             ),
         }
         .unwrap_or_default();
         let member_type = match seq_or_set_of.element_type.as_ref() {
-            ASN1Type::ElsewhereDeclaredType(d) => self.to_rust_title_case(&d.identifier),
-            _ => format_ident!("Anonymous{}", &name.to_string()).to_token_stream(),
+            ASN1Type::ElsewhereDeclaredType(d) => self.format_type_reference(&d.identifier)?,
+            _ => self.to_rust_title_case(&anonymous_name),
         };
         let mut annotations = vec![
             quote!(delegate),
@@ -882,6 +1145,7 @@ impl Rasn {
         Ok(sequence_or_set_of_template(
             is_set_of,
             self.format_comments(&tld.comments)?,
+            self.derives(&tld.name, &tld.ty),
             name,
             anonymous_item,
             member_type,
@@ -911,11 +1175,14 @@ impl Rasn {
                 .values
                 .iter()
                 .map(|v| match v {
-                    ObjectSetValue::Reference(r) => Err(GeneratorError::new(
-                        None,
-                        &format!("Could not resolve reference of Information Object Set {r}"),
-                        GeneratorErrorType::MissingClassKey,
-                    )),
+                    ObjectSetValue::Reference(r)
+                    | ObjectSetValue::ParameterizedReference { identifier: r, .. } => {
+                        Err(GeneratorError::new(
+                            None,
+                            &format!("Could not resolve reference of Information Object Set {r}"),
+                            GeneratorErrorType::MissingClassKey,
+                        ))
+                    }
                     ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(_)) => {
                         Err(GeneratorError::new(
                             Some(ToplevelDefinition::Information(tld.clone())),
@@ -973,7 +1240,13 @@ impl Rasn {
             let mut field_enums = vec![];
             for (field_name, fields) in choices.iter() {
                 let field_enum_name = format_ident!("{name}_{}", field_name.replace('&', ""));
-                let (mut ids, mut inner_types) = (vec![], vec![]);
+                let (mut ids, mut inner_types, mut arbitrary_values) = (vec![], vec![], vec![]);
+                let cloneable = fields.iter().all(|(_, ty)| self.is_cloneable_type(ty));
+                let derives = if cloneable {
+                    quote!(Debug, Clone, PartialEq)
+                } else {
+                    quote!(Debug)
+                };
                 for (index, (id, ty)) in fields.iter().enumerate() {
                     let identifier_value = match id {
                         ASN1Value::LinkedElsewhereDefinedValue {
@@ -1007,7 +1280,18 @@ impl Rasn {
                         }
                         _ => self.value_to_tokens(id, Some(&class_unique_id_type_name))?,
                     };
-                    let type_id = self.type_to_tokens(ty).unwrap_or(quote!(Option<()>));
+                    let type_id = self.type_to_tokens(ty);
+                    let arbitrary_value = match ty {
+                        _ if !self.config.generate_arbitrary || type_id.is_err() => {
+                            quote!(u.arbitrary()?)
+                        }
+                        ASN1Type::Integer(int) => self.format_arbitrary_delegate_integer(int)?,
+                        ASN1Type::ElsewhereDeclaredType(_)
+                        | ASN1Type::SequenceOf(_)
+                        | ASN1Type::SetOf(_) => quote!(u.arbitrary()?),
+                        _ => self.format_arbitrary_builtin(ty, &[])?,
+                    };
+                    let type_id = type_id.unwrap_or(quote!(Option<()>));
                     let variant_name = match id {
                         ASN1Value::LinkedElsewhereDefinedValue {
                             identifier: ref_id, ..
@@ -1017,9 +1301,30 @@ impl Rasn {
                         } => self.to_rust_title_case(ref_id),
                         _ => format_ident!("{field_enum_name}_{index}").to_token_stream(),
                     };
-                    if ty.constraints().map_or(true, |c| c.is_empty()) {
+                    if self.needs_unnesting(ty) {
+                        let inner_name = format!("Inner_{field_enum_name}_{index}");
+                        inner_types.push(self.generate_tld(ToplevelDefinition::Type(
+                            ToplevelTypeDefinition {
+                                parameterization: None,
+                                comments: " Inner type ".into(),
+                                name: inner_name.clone(),
+                                ty: ty.clone(),
+                                tag: None,
+                                index: tld.index.clone(),
+                                selection: None,
+                                is_alias: false,
+                            },
+                        ))?);
+                        ids.push((
+                            variant_name,
+                            self.to_rust_title_case(&inner_name),
+                            identifier_value,
+                        ));
+                        arbitrary_values.push(quote!(u.arbitrary()?));
+                    } else if ty.constraints().map_or(true, |c| c.is_empty()) {
                         ids.push((variant_name, type_id, identifier_value));
                         inner_types.push(TokenStream::new());
+                        arbitrary_values.push(arbitrary_value);
                     } else {
                         let (signed_range, character_string_type) = match ty {
                             ASN1Type::CharacterString(c) => (false, Some(c.ty)),
@@ -1052,13 +1357,23 @@ impl Rasn {
                             alphabet_constraints,
                             quote!(delegate),
                         ]);
+                        let arbitrary_impl = if self.config.generate_arbitrary {
+                            self.format_arbitrary_template(
+                                delegate_id.clone(),
+                                quote!(Ok(Self(#arbitrary_value))),
+                            )
+                        } else {
+                            TokenStream::new()
+                        };
                         ids.push((variant_name, delegate_id.clone(), identifier_value));
                         inner_types.push(quote! {
-                            #[derive(Debug, Clone, PartialEq, AsnType, Decode, Encode)]
+                            #[derive(#derives, AsnType, Decode, Encode)]
                             #annotations
                             pub struct #delegate_id (pub #type_id);
 
+                            #arbitrary_impl
                         });
+                        arbitrary_values.push(quote!(u.arbitrary()?));
                     }
                 }
 
@@ -1079,10 +1394,34 @@ impl Rasn {
                 quote!((Self::#variant_name (inner), i) if i == &#identifier_value =>inner.encode(encoder),)
             });
 
+                // Generated values are members of the object set
+                let arbitrary_impl = if self.config.generate_arbitrary && !ids.is_empty() {
+                    let arbitrary_arms = ids.iter().zip(arbitrary_values).enumerate().map(
+                        |(index, ((variant_name, _, _), value))| {
+                            let index = Literal::usize_unsuffixed(index);
+                            quote!(#index => Self::#variant_name(#value),)
+                        },
+                    );
+                    let max_index = Literal::usize_unsuffixed(ids.len() - 1);
+                    self.format_arbitrary_template(
+                        field_enum_name.to_token_stream(),
+                        quote! {
+                            Ok(match u.int_in_range(0..=#max_index)? {
+                                #(#arbitrary_arms)*
+                                _ => unreachable!(),
+                            })
+                        },
+                    )
+                } else {
+                    TokenStream::new()
+                };
+
                 field_enums.push(quote! {
                 #(#inner_types)*
 
-                #[derive(Debug, Clone, PartialEq)]
+                #arbitrary_impl
+
+                #[derive(#derives)]
                 pub enum #field_enum_name {
                     #(#variants)*
                 }
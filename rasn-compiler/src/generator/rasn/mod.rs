@@ -1,4 +1,5 @@
 use std::{
+    collections::{BTreeMap, BTreeSet},
     env,
     error::Error,
     io::{self, Write},
@@ -17,6 +18,7 @@ use wasm_bindgen::prelude::*;
 use super::{error::GeneratorError, Backend, GeneratedModule};
 
 mod builder;
+mod pattern;
 mod template;
 mod utils;
 
@@ -25,6 +27,11 @@ mod utils;
 /// the `rasn` framework for rust.
 pub struct Rasn {
     config: Config,
+    /// Names of the types that do not derive the optional traits, see [Config::non_cloneable_threshold]
+    non_cloneable: BTreeSet<String>,
+    /// Rust names of the generated types that are structurally identical to a generated type,
+    /// see [Config::generate_structural_conversions]
+    compatible_types: BTreeMap<String, BTreeSet<String>>,
 }
 
 #[cfg_attr(target_family = "wasm", wasm_bindgen)]
@@ -46,16 +53,243 @@ pub struct Config {
     /// is set to `true` , the compiler will import the entire module using
     /// the wildcard `*` for each module that the input ASN.1 module imports from.
     pub default_wildcard_imports: bool,
+    /// If `generate_visitor` is set to `true`, the compiler will generate a `Visit` trait
+    /// for every module and implement it for all generated types. The trait's `visit` method
+    /// walks a decoded value depth-first and calls a visitor closure with the identifier and
+    /// value of every field, chosen alternative, and collection element that it encounters.
+    pub generate_visitor: bool,
+    /// If `generate_validation` is set to `true`, the compiler will generate a `Validate` trait
+    /// and a `ConstraintError` type for every module and implement the trait for all generated types.
+    /// The trait's `validate` method checks the size, value range, permitted alphabet, and `PATTERN`
+    /// constraints of a value and its components at runtime, so that values can be verified before encoding.
+    pub generate_validation: bool,
+    /// If `generate_display` is set to `true`, the compiler will implement `Display` and `FromStr`
+    /// for enumerations and integers with named numbers, converting values to and from their
+    /// original ASN.1 identifiers. Unnamed integer values are displayed and parsed as numbers.
+    pub generate_display: bool,
+    /// If `generate_arbitrary` is set to `true`, the compiler will implement `arbitrary::Arbitrary`
+    /// for all generated types, so that they can be used as fuzzing inputs. Generated values
+    /// satisfy the size, value range, and permitted alphabet constraints of their types.
+    /// The implementations are only compiled with the `arbitrary` cargo feature of the consuming crate.
+    pub generate_arbitrary: bool,
+    /// If `generate_newtype_conversions` is set to `true`, the compiler will implement `Deref`,
+    /// `AsRef`, and `From` for newtypes that wrap a primitive type, such as constrained integers
+    /// and strings. If `generate_validation` is set as well, `TryFrom` is implemented instead
+    /// of `From`, so that converted values are checked against the newtype's constraints.
+    pub generate_newtype_conversions: bool,
+    /// If `generate_oid_names` is set to `true`, the compiler will generate an `oid_name` function
+    /// for every module that defines named `OBJECT IDENTIFIER` values. The function looks up the
+    /// ASN.1 identifier of an object identifier, e.g. for logging the algorithms of a certificate.
+    pub generate_oid_names: bool,
+    /// If `generate_debug_notation` is set to `true`, the compiler will implement `Debug` for all
+    /// generated types instead of deriving it. The implementations print values in ASN.1 value
+    /// notation using the original identifiers, e.g. `{ id 1, name "a", kind inner : { flag TRUE } }`,
+    /// so that decoded values can be compared with the ASN.1 specification.
+    pub generate_debug_notation: bool,
+    /// If `generate_extension_presence` is set to `true`, the compiler generates an `ExtensionSet` type
+    /// for every module and a `present_extensions` method for all extensible `SEQUENCE` and `SET` types
+    /// with extension additions. The method returns the ASN.1 identifiers of the extension additions
+    /// that are present in a value, e.g. to determine the version of the specification that a peer uses.
+    pub generate_extension_presence: bool,
+    /// If `generate_structural_conversions` is set to `true`, the compiler implements `From` in both
+    /// directions for all pairs of distinctly named types of the same module that are structurally
+    /// identical, i.e. whose definitions, including constraints and tags, match. This allows to convert
+    /// between a base type and profile-specific copies of it. Inner types are converted accordingly.
+    pub generate_structural_conversions: bool,
+    /// Maps extension additions to cargo features. Keys identify an extension addition of a
+    /// `SEQUENCE`, `SET`, or `CHOICE` type as `Type-Name.component-name`, where extension
+    /// addition groups are identified by their first component. The generated field or variant
+    /// of a mapped extension addition and of all subsequent extension additions of the type
+    /// is only compiled if the feature is enabled.
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub extension_features: BTreeMap<String, String>,
+    /// If `non_cloneable_threshold` is set, the compiler omits the optional derives of `SEQUENCE`,
+    /// `SET`, and `CHOICE` types with more components than the threshold, and of `SEQUENCE OF`
+    /// and `SET OF` types whose maximum size exceeds the threshold or is unconstrained.
+    /// The optional derives are `Clone` and `PartialEq`, as well as `PartialOrd`, `Eq`, `Ord`,
+    /// and `Hash`, which depend on them. `AsnType`, `Decode`, and `Encode` are always derived,
+    /// as is `Debug` unless [Config::generate_debug_notation] is set.
+    /// Types that contain or reference a type without optional derives omit them as well.
+    /// Types that derive `Copy`, i.e. `BOOLEAN`, `NULL`, and `ENUMERATED` types, keep their derives.
+    /// _Components with a `DEFAULT` value require their type to implement `PartialEq`._
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub non_cloneable_threshold: Option<usize>,
+    /// ASN.1 names of types whose optional derives are omitted regardless of their size.
+    /// See [Config::non_cloneable_threshold] for the optional derives.
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub non_cloneable_types: BTreeSet<String>,
+    /// Maps ASN.1 type names to paths of existing rust types, e.g. `"Timestamp"` to
+    /// `"crate::time::Timestamp"`. The compiler does not generate bindings for mapped types
+    /// and references the configured path wherever a mapped type is referenced. Mapped types
+    /// are still used to link the remaining definitions. Values of mapped types are skipped.
+    /// _The existing rust types need to implement the traits that the referencing types derive._
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub external_types: BTreeMap<String, String>,
+    /// The version of the `rasn` framework that the generated bindings are compiled against.
+    /// The compiler only emits `#[rasn(...)]` annotations that the selected version supports.
+    pub rasn_version: RasnVersion,
+    /// The casing of the rust identifiers that the compiler derives from ASN.1 identifiers.
+    /// Regardless of the casing, `#[rasn(identifier = "...")]` annotations carry the original
+    /// ASN.1 identifiers, so that encodings that include identifiers are not affected.
+    pub casing: CasingStrategy,
+    /// If `generate_codec_helpers` is set, the compiler generates `to_<codec>` and `from_<codec>`
+    /// methods for every top-level type, e.g. `to_der` and `from_der`, which wrap the `encode` and
+    /// `decode` functions of the selected codec. Inner types and parameterized types are skipped.
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub generate_codec_helpers: Option<Codec>,
+    /// Lints that the compiler allows with an `#[allow(...)]` attribute on every generated module,
+    /// e.g. `dead_code` or `clippy::all`. By default, the lints that the naming of the generated
+    /// items and unused imports trip are allowed.
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub allowed_lints: BTreeSet<String>,
+    /// The rust representation of the legacy restricted character string types `TeletexString`,
+    /// `VideotexString`, `GraphicString`, `GeneralString`, and `UniversalString`, as well as
+    /// `ObjectDescriptor`, which is a `GraphicString`.
+    pub legacy_strings: LegacyStringRepresentation,
+}
+
+/// The lints that are allowed on generated modules by default, see [Config::allowed_lints]
+pub const DEFAULT_ALLOWED_LINTS: [&str; 4] = [
+    "non_camel_case_types",
+    "non_snake_case",
+    "non_upper_case_globals",
+    "unused",
+];
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// The `rasn` versions whose annotation dialects the [Rasn] backend can target
+pub enum RasnVersion {
+    /// `rasn` 0.12 and 0.13, which do not support the `identifier` annotation.
+    /// Bindings for this version do not preserve the original ASN.1 identifiers of types,
+    /// fields, and enumerals, so JER and XER encodings use the rust identifiers instead.
+    V0_13,
+    /// `rasn` 0.14 and later
+    #[default]
+    V0_14,
+}
+
+impl RasnVersion {
+    /// Whether the version supports `#[rasn(identifier = "...")]` annotations
+    pub(crate) fn supports_identifier_annotation(&self) -> bool {
+        *self >= RasnVersion::V0_14
+    }
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// The casing of the rust identifiers of types, fields, enumerals, alternatives, and constants.
+/// Hyphens in ASN.1 identifiers are always replaced by underscores, and identifiers that
+/// collide with rust keywords are always prefixed.
+pub enum CasingStrategy {
+    /// Types in `PascalCase`, fields in `snake_case`, and constants in `SCREAMING_SNAKE_CASE`.
+    /// Enumerals and alternatives keep their ASN.1 spelling.
+    #[default]
+    RustIdiomatic,
+    /// All identifiers keep their ASN.1 spelling.
+    PreserveOriginal,
+    /// Constants in `SCREAMING_SNAKE_CASE`, all other identifiers keep their ASN.1 spelling.
+    ScreamingSnakeConstants,
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// The rust representations of the legacy restricted character string types,
+/// see [Config::legacy_strings]
+pub enum LegacyStringRepresentation {
+    /// `TeletexString`, `GeneralString`, and `UniversalString` are represented by the
+    /// corresponding string types of `rasn`. `GraphicString` and `ObjectDescriptor` are
+    /// represented by delegates over `GeneralString`, whose character set covers the graphic
+    /// characters, and `VideotexString` by delegates over `OctetString`, each with the universal
+    /// tag of the represented type.
+    #[default]
+    Native,
+    /// All legacy string types are represented by delegates over `OctetString` with the universal
+    /// tag of the represented type, which carry the raw contents octets of the string without
+    /// interpreting any character set or escape sequences. Values are encoded as UTF-8 octets,
+    /// or as UCS-4 octets for `UniversalString` values.
+    Octets,
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The `rasn` codecs that generated convenience methods can bind to, see [Config::generate_codec_helpers]
+pub enum Codec {
+    /// Aligned Packed Encoding Rules
+    Aper,
+    /// Basic Encoding Rules
+    Ber,
+    /// Canonical Encoding Rules
+    Cer,
+    /// Canonical Octet Encoding Rules
+    Coer,
+    /// Distinguished Encoding Rules
+    Der,
+    /// JSON Encoding Rules, which encode values as strings instead of bytes
+    Jer,
+    /// Octet Encoding Rules
+    Oer,
+    /// Unaligned Packed Encoding Rules
+    Uper,
+}
+
+impl Codec {
+    /// The name of the codec's module in `rasn`
+    pub(crate) fn module_name(&self) -> &'static str {
+        match self {
+            Codec::Aper => "aper",
+            Codec::Ber => "ber",
+            Codec::Cer => "cer",
+            Codec::Coer => "coer",
+            Codec::Der => "der",
+            Codec::Jer => "jer",
+            Codec::Oer => "oer",
+            Codec::Uper => "uper",
+        }
+    }
 }
 
 #[cfg(target_family = "wasm")]
 #[wasm_bindgen]
 impl Config {
     #[wasm_bindgen(constructor)]
-    pub fn new(opaque_open_types: bool, default_wildcard_imports: bool) -> Self {
+    pub fn new(
+        opaque_open_types: bool,
+        default_wildcard_imports: bool,
+        generate_visitor: bool,
+        generate_validation: bool,
+        generate_display: bool,
+        generate_arbitrary: bool,
+        generate_newtype_conversions: bool,
+        generate_oid_names: bool,
+        generate_debug_notation: bool,
+        generate_extension_presence: bool,
+        generate_structural_conversions: bool,
+        rasn_version: RasnVersion,
+        casing: CasingStrategy,
+        legacy_strings: LegacyStringRepresentation,
+    ) -> Self {
         Self {
             opaque_open_types,
             default_wildcard_imports,
+            generate_visitor,
+            generate_validation,
+            generate_display,
+            generate_arbitrary,
+            generate_newtype_conversions,
+            generate_oid_names,
+            generate_debug_notation,
+            generate_extension_presence,
+            generate_structural_conversions,
+            extension_features: BTreeMap::new(),
+            non_cloneable_threshold: None,
+            non_cloneable_types: BTreeSet::new(),
+            external_types: BTreeMap::new(),
+            rasn_version,
+            casing,
+            generate_codec_helpers: None,
+            allowed_lints: DEFAULT_ALLOWED_LINTS.map(String::from).into(),
+            legacy_strings,
         }
     }
 }
@@ -65,27 +299,46 @@ impl Default for Config {
         Self {
             opaque_open_types: true,
             default_wildcard_imports: false,
+            generate_visitor: false,
+            generate_validation: false,
+            generate_display: false,
+            generate_arbitrary: false,
+            generate_newtype_conversions: false,
+            generate_oid_names: false,
+            generate_debug_notation: false,
+            generate_extension_presence: false,
+            generate_structural_conversions: false,
+            extension_features: BTreeMap::new(),
+            non_cloneable_threshold: None,
+            non_cloneable_types: BTreeSet::new(),
+            external_types: BTreeMap::new(),
+            rasn_version: RasnVersion::default(),
+            casing: CasingStrategy::default(),
+            generate_codec_helpers: None,
+            allowed_lints: DEFAULT_ALLOWED_LINTS.map(String::from).into(),
+            legacy_strings: LegacyStringRepresentation::default(),
         }
     }
 }
 
-impl Backend for Rasn {
-    type Config = Config;
-
-    const FILE_EXTENSION: &'static str = ".rs";
-
-    fn from_config(config: Self::Config) -> Self {
-        Self { config }
+impl Rasn {
+    /// Maps an ASN.1 type to an existing rust type, see [Config::external_types]
+    pub(crate) fn map_type(&mut self, asn1_name: String, rust_path: String) {
+        self.config.external_types.insert(asn1_name, rust_path);
     }
 
-    fn config(&self) -> &Self::Config {
-        &self.config
+    /// Allows additional lints on the generated modules, see [Config::allowed_lints]
+    pub(crate) fn allow_lints(&mut self, lints: &[&str]) {
+        self.config
+            .allowed_lints
+            .extend(lints.iter().map(|lint| lint.to_string()));
     }
 
-    fn generate_module(
+    /// Generates the bindings of an ASN.1 module as a `TokenStream`, see [Backend::generate_module]
+    pub(crate) fn generate_module_tokens(
         &self,
         tlds: Vec<ToplevelDefinition>,
-    ) -> Result<GeneratedModule, GeneratorError> {
+    ) -> Result<GeneratedModule<TokenStream>, GeneratorError> {
         if let Some((module_ref, _)) = tlds.first().and_then(|tld| tld.get_index().cloned()) {
             let module = module_ref.borrow();
             let name = self.to_rust_snake_case(&module.name);
@@ -94,7 +347,11 @@ impl Backend for Rasn {
                     self.to_rust_snake_case(&import.global_module_reference.module_reference);
                 let mut usages = Some(vec![]);
                 'imports: for usage in &import.types {
-                    if usage.contains("{}") || usage.chars().all(|c| c.is_uppercase() || c == '-') {
+                    if self.config.external_types.contains_key(usage) {
+                        continue;
+                    } else if usage.contains("{}")
+                        || usage.chars().all(|c| c.is_uppercase() || c == '-')
+                    {
                         usages = None;
                         break 'imports;
                     } else if usage.starts_with(|c: char| c.is_lowercase()) {
@@ -114,11 +371,52 @@ impl Backend for Rasn {
                 };
                 quote!(use super:: #module::{ #(#used_imports),* };)
             });
+            let visitor = if self.config.generate_visitor {
+                self.format_visit_trait(&module.imports)
+            } else {
+                TokenStream::new()
+            };
+            let validation = if self.config.generate_validation {
+                let with_pattern_matcher = tlds.iter().any(|tld| {
+                    matches!(tld, ToplevelDefinition::Type(t) if self.has_pattern_checks(&t.ty, &[]))
+                });
+                self.format_validate_trait(&module.imports, with_pattern_matcher)
+            } else {
+                TokenStream::new()
+            };
+            let display = if self.config.generate_display {
+                self.format_parse_identifier_error()
+            } else {
+                TokenStream::new()
+            };
+            let extension_set = if self.config.generate_extension_presence {
+                self.format_extension_set()
+            } else {
+                TokenStream::new()
+            };
+            let oid_names = if self.config.generate_oid_names {
+                self.format_oid_name_lookup(&tlds)
+            } else {
+                TokenStream::new()
+            };
+            let allowed_lints = self.format_allowed_lints();
             let (pdus, warnings): (Vec<TokenStream>, Vec<Box<dyn Error>>) =
-                tlds.into_iter().fold((vec![], vec![]), |mut acc, tld| {
+                tlds.into_iter()
+                    .filter(|tld| {
+                        !matches!(tld, ToplevelDefinition::Type(t) if self.config.external_types.contains_key(&t.name))
+                    })
+                    .fold((vec![], vec![]), |mut acc, tld| {
+                    let codec_helpers = match (self.config.generate_codec_helpers, &tld) {
+                        (Some(codec), ToplevelDefinition::Type(t))
+                            if t.parameterization.is_none() && !t.is_alias =>
+                        {
+                            self.format_codec_helpers_impl(t, codec)
+                        }
+                        _ => TokenStream::new(),
+                    };
                     match self.generate_tld(tld) {
                         Ok(s) => {
-                            acc.0.push(s);
+                            acc.0.push(quote!(#s #codec_helpers));
                             acc
                         }
                         Err(e) => {
@@ -129,23 +427,97 @@ impl Backend for Rasn {
                 });
             Ok(GeneratedModule {
                 generated: Some(quote! {
-                #[allow(non_camel_case_types, non_snake_case, non_upper_case_globals, unused)]
-                pub mod #name {
-                    extern crate alloc;
+                    #allowed_lints
+                    pub mod #name {
+                        extern crate alloc;
 
-                    use core::borrow::Borrow;
-                    use rasn::prelude::*;
-                    use lazy_static::lazy_static;
+                        use core::borrow::Borrow;
+                        use rasn::prelude::*;
+                        use lazy_static::lazy_static;
 
-                    #(#imports)*
+                        #(#imports)*
 
-                    #(#pdus)*
-                }
-            }.to_string()), warnings})
+                        #visitor
+
+                        #validation
+
+                        #display
+
+                        #extension_set
+
+                        #oid_names
+
+                        #(#pdus)*
+                    }
+                }),
+                warnings,
+            })
         } else {
             Ok(GeneratedModule::empty())
         }
     }
+}
+
+impl Backend for Rasn {
+    type Config = Config;
+
+    const FILE_EXTENSION: &'static str = ".rs";
+
+    fn from_config(config: Self::Config) -> Self {
+        Self {
+            config,
+            non_cloneable: BTreeSet::new(),
+            compatible_types: BTreeMap::new(),
+        }
+    }
+
+    fn prepare(&mut self, top_level_declarations: &[ToplevelDefinition]) {
+        self.compatible_types.clear();
+        if self.config.generate_structural_conversions {
+            self.collect_compatible_types(top_level_declarations);
+        }
+        self.non_cloneable.clear();
+        if self.config.non_cloneable_threshold.is_none()
+            && self.config.non_cloneable_types.is_empty()
+        {
+            return;
+        }
+        // Iterate until no further type loses its optional derives,
+        // since types referencing a non-cloneable type are not cloneable either
+        loop {
+            let non_cloneable = top_level_declarations
+                .iter()
+                .filter_map(|tld| match tld {
+                    ToplevelDefinition::Type(t)
+                        if !self.non_cloneable.contains(&t.name)
+                            && !self.is_cloneable(&t.name, &t.ty) =>
+                    {
+                        Some(t.name.clone())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if non_cloneable.is_empty() {
+                break;
+            }
+            self.non_cloneable.extend(non_cloneable);
+        }
+    }
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn generate_module(
+        &self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        self.generate_module_tokens(tlds)
+            .map(|module| GeneratedModule {
+                generated: module.generated.map(|tokens| tokens.to_string()),
+                warnings: module.warnings,
+            })
+    }
 
     fn format_bindings(bindings: &str) -> Result<String, Box<dyn Error>> {
         let mut rustfmt = PathBuf::from(env::var("CARGO_HOME")?);
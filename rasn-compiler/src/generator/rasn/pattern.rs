@@ -0,0 +1,384 @@
+//! The `pattern` module compiles the regular expressions of ASN.1 `PATTERN` constraints into
+//! programs of a small matching automaton. The generated `Validate` implementations run these
+//! programs on the code points of a string, so that the bindings do not depend on a regex engine.
+//! *See Rec. ITU-T X.680 (02/2021) Annex A*
+
+/// Maximum number of instructions of a compiled pattern.
+/// Patterns with large repetition counts are not checked, since their programs would bloat the bindings.
+const MAX_INSTRUCTIONS: usize = 1024;
+
+/// An instruction of a compiled pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Instruction {
+    /// Consumes a character whose code point lies in one of the ranges,
+    /// or in none of the ranges if the class is negated.
+    Class {
+        ranges: Vec<(u32, u32)>,
+        negated: bool,
+    },
+    /// Continues at both instructions.
+    Split(usize, usize),
+    /// Continues at the instruction.
+    Jump(usize),
+    /// Accepts the string if all of its characters have been consumed.
+    Match,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Class {
+        ranges: Vec<(u32, u32)>,
+        negated: bool,
+    },
+    Concatenation(Vec<Node>),
+    Alternation(Vec<Node>),
+    Repetition {
+        node: Box<Node>,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+/// Compiles the regular expression of a `PATTERN` constraint.
+/// Returns a description of the problem if the expression is malformed
+/// or uses notation that is not supported, i.e. `\b`, `\N{...}`, and `\p{...}`.
+pub(crate) fn compile(pattern: &str) -> Result<Vec<Instruction>, String> {
+    let mut parser = Parser {
+        input: pattern.chars().collect(),
+        position: 0,
+    };
+    let node = parser.alternation()?;
+    if let Some(c) = parser.peek() {
+        return Err(format!(
+            "Unexpected character '{c}' at position {}",
+            parser.position
+        ));
+    }
+    let mut program = vec![];
+    emit(&node, &mut program)?;
+    program.push(Instruction::Match);
+    Ok(program)
+}
+
+fn emit(node: &Node, program: &mut Vec<Instruction>) -> Result<(), String> {
+    match node {
+        Node::Class { ranges, negated } => program.push(Instruction::Class {
+            ranges: ranges.clone(),
+            negated: *negated,
+        }),
+        Node::Concatenation(nodes) => {
+            for node in nodes {
+                emit(node, program)?;
+            }
+        }
+        Node::Alternation(alternatives) => {
+            let mut jumps = vec![];
+            for (index, alternative) in alternatives.iter().enumerate() {
+                if index + 1 == alternatives.len() {
+                    emit(alternative, program)?;
+                } else {
+                    let split = program.len();
+                    program.push(Instruction::Split(split + 1, 0));
+                    emit(alternative, program)?;
+                    jumps.push(program.len());
+                    program.push(Instruction::Jump(0));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+            }
+            for jump in jumps {
+                program[jump] = Instruction::Jump(program.len());
+            }
+        }
+        Node::Repetition { node, min, max } => {
+            for _ in 0..*min {
+                emit(node, program)?;
+            }
+            match max {
+                None => {
+                    let split = program.len();
+                    program.push(Instruction::Split(split + 1, 0));
+                    emit(node, program)?;
+                    program.push(Instruction::Jump(split));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+                Some(max) => {
+                    let mut splits = vec![];
+                    for _ in *min..*max {
+                        splits.push(program.len());
+                        program.push(Instruction::Split(0, 0));
+                        emit(node, program)?;
+                    }
+                    for split in splits {
+                        program[split] = Instruction::Split(split + 1, program.len());
+                    }
+                }
+            }
+        }
+    }
+    if program.len() > MAX_INSTRUCTIONS {
+        Err(format!(
+            "Pattern exceeds the limit of {MAX_INSTRUCTIONS} instructions"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+struct Parser {
+    input: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.input.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<char, String> {
+        let c = self
+            .peek()
+            .ok_or_else(|| "Unexpected end of pattern".to_string())?;
+        self.position += 1;
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next()? {
+            c if c == expected => Ok(()),
+            c => Err(format!(
+                "Expected '{expected}' but found '{c}' at position {}",
+                self.position - 1
+            )),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node, String> {
+        let mut alternatives = vec![self.concatenation()?];
+        while self.peek() == Some('|') {
+            self.position += 1;
+            alternatives.push(self.concatenation()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.remove(0)
+        } else {
+            Node::Alternation(alternatives)
+        })
+    }
+
+    fn concatenation(&mut self) -> Result<Node, String> {
+        let mut nodes = vec![];
+        while !matches!(self.peek(), None | Some('|') | Some(')')) {
+            let mut node = self.atom()?;
+            while let Some((min, max)) = self.quantifier()? {
+                node = Node::Repetition {
+                    node: Box::new(node),
+                    min,
+                    max,
+                };
+            }
+            nodes.push(node);
+        }
+        Ok(Node::Concatenation(nodes))
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        match self.next()? {
+            '(' => {
+                let node = self.alternation()?;
+                self.expect(')')?;
+                Ok(node)
+            }
+            '[' => self.class(),
+            '.' => Ok(Node::Class {
+                ranges: vec![],
+                negated: true,
+            }),
+            '\\' => self.escape().map(|ranges| Node::Class {
+                ranges,
+                negated: false,
+            }),
+            '{' => self.quadruple().map(literal),
+            c @ ('*' | '+' | '?' | '#') => Err(format!(
+                "Quantifier '{c}' at position {} does not follow an expression",
+                self.position - 1
+            )),
+            c => Ok(literal(c as u32)),
+        }
+    }
+
+    /// Parses the quantifier of an expression as its minimum and maximum number of repetitions.
+    fn quantifier(&mut self) -> Result<Option<(usize, Option<usize>)>, String> {
+        let quantifier = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('#') => {
+                self.position += 1;
+                if self.peek() == Some('(') {
+                    self.position += 1;
+                    let min = self.number()?;
+                    let quantifier = if self.peek() == Some(',') {
+                        self.position += 1;
+                        (min.unwrap_or(0), self.number()?)
+                    } else {
+                        let exact =
+                            min.ok_or_else(|| "Expected a number of repetitions".to_string())?;
+                        (exact, Some(exact))
+                    };
+                    self.expect(')')?;
+                    if quantifier.1.is_some_and(|max| max < quantifier.0) {
+                        return Err("Minimum number of repetitions exceeds maximum".into());
+                    }
+                    return Ok(Some(quantifier));
+                }
+                let exact = self
+                    .number()?
+                    .ok_or_else(|| "Expected a number of repetitions".to_string())?;
+                return Ok(Some((exact, Some(exact))));
+            }
+            _ => return Ok(None),
+        };
+        self.position += 1;
+        Ok(Some(quantifier))
+    }
+
+    fn number(&mut self) -> Result<Option<usize>, String> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Ok(None);
+        }
+        self.input[start..self.position]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .map(Some)
+            .map_err(|_| "Number of repetitions is too large".into())
+    }
+
+    fn class(&mut self) -> Result<Node, String> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.position += 1;
+        }
+        let mut ranges = vec![];
+        while self.peek() != Some(']') {
+            let from = match self.next()? {
+                '\\' => {
+                    let escaped = self.escape()?;
+                    match escaped[..] {
+                        [(from, to)] if from == to => from,
+                        _ => {
+                            ranges.extend(escaped);
+                            continue;
+                        }
+                    }
+                }
+                '{' => self.quadruple()?,
+                c => c as u32,
+            };
+            if self.peek() == Some('-') && self.input.get(self.position + 1) != Some(&']') {
+                self.position += 1;
+                let to = match self.next()? {
+                    '\\' => match self.escape()?[..] {
+                        [(from, to)] if from == to => from,
+                        _ => return Err("Character ranges must end in a single character".into()),
+                    },
+                    '{' => self.quadruple()?,
+                    c => c as u32,
+                };
+                if to < from {
+                    return Err("Character range ends before it starts".into());
+                }
+                ranges.push((from, to));
+            } else {
+                ranges.push((from, from));
+            }
+        }
+        self.position += 1;
+        Ok(Node::Class { ranges, negated })
+    }
+
+    /// Parses an escape sequence as the code point ranges that it denotes.
+    fn escape(&mut self) -> Result<Vec<(u32, u32)>, String> {
+        Ok(match self.next()? {
+            'd' => vec![('0' as u32, '9' as u32)],
+            'w' => vec![
+                ('0' as u32, '9' as u32),
+                ('A' as u32, 'Z' as u32),
+                ('a' as u32, 'z' as u32),
+            ],
+            's' => vec![(9, 13), (32, 32)],
+            't' => vec![(9, 9)],
+            'n' => vec![(10, 10)],
+            'r' => vec![(13, 13)],
+            c @ ('b' | 'N' | 'p') => {
+                return Err(format!("Escape sequence '\\{c}' is not supported"));
+            }
+            c => vec![(c as u32, c as u32)],
+        })
+    }
+
+    /// Parses the quadruple notation `{group, plane, row, cell}` of an ISO/IEC 10646 character.
+    /// The opening brace has already been consumed.
+    fn quadruple(&mut self) -> Result<u32, String> {
+        let mut code_point = 0;
+        for (index, limit) in [128, 256, 256, 256].into_iter().enumerate() {
+            while self.peek() == Some(' ') {
+                self.position += 1;
+            }
+            let number = self
+                .number()?
+                .filter(|n| *n < limit)
+                .ok_or_else(|| "Malformed quadruple".to_string())?;
+            code_point = code_point << 8 | number as u32;
+            while self.peek() == Some(' ') {
+                self.position += 1;
+            }
+            self.expect(if index == 3 { '}' } else { ',' })?;
+        }
+        Ok(code_point)
+    }
+}
+
+fn literal(code_point: u32) -> Node {
+    Node::Class {
+        ranges: vec![(code_point, code_point)],
+        negated: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_literals_and_classes() {
+        assert_eq!(
+            compile("a[^b-d]").unwrap(),
+            vec![
+                Instruction::Class {
+                    ranges: vec![(97, 97)],
+                    negated: false
+                },
+                Instruction::Class {
+                    ranges: vec![(98, 100)],
+                    negated: true
+                },
+                Instruction::Match
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_patterns() {
+        assert!(compile("(ab").is_err());
+        assert!(compile("*a").is_err());
+        assert!(compile("[b-a]").is_err());
+        assert!(compile("a#(3,2)").is_err());
+        assert!(compile("\\N{latinCapitalLetterA}").is_err());
+        assert!(compile("a#(2000)").is_err());
+    }
+}
@@ -1,20 +1,51 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
+/// The optional derives of a generated type
+#[derive(Debug, Clone, Copy)]
+pub struct Derives {
+    /// Whether the type derives `Clone`, `PartialEq`, and the traits that depend on them,
+    /// see [super::Config::non_cloneable_threshold]
+    pub cloneable: bool,
+    /// Whether the type derives `Debug`, see [super::Config::generate_debug_notation]
+    pub debug: bool,
+}
+
+/// Formats the `derive` attribute of a generated type.
+/// If the type is not `cloneable`, the optional derives `Clone` and `PartialEq`
+/// are omitted, together with the `dependent_derives` that require them.
+fn derive_attribute(derives: Derives, dependent_derives: TokenStream) -> TokenStream {
+    let debug = derives.debug.then(|| quote!(Debug,));
+    if derives.cloneable {
+        quote!(#[derive(AsnType, #debug Clone, Decode, Encode, PartialEq #dependent_derives)])
+    } else {
+        quote!(#[derive(AsnType, #debug Decode, Encode)])
+    }
+}
+
 pub fn typealias_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     alias: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name (pub #alias);
     }
 }
 
+pub fn alias_template(comments: TokenStream, name: TokenStream, alias: TokenStream) -> TokenStream {
+    quote! {
+        #comments
+        pub type #name = #alias;
+    }
+}
+
 pub fn integer_value_template(
     comments: TokenStream,
     name: Ident,
@@ -43,27 +74,33 @@ pub fn lazy_static_value_template(
 
 pub fn integer_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
     integer_type: TokenStream,
+    named_numbers: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, quote!(, PartialOrd, Eq, Ord, Hash));
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #derive
         #annotations
         pub struct #name (pub #integer_type);
 
+        #named_numbers
     }
 }
 
 pub fn generalized_time_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name(pub GeneralizedTime);
     }
@@ -71,38 +108,62 @@ pub fn generalized_time_template(
 
 pub fn utc_time_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name(pub UtcTime);
     }
 }
 
+pub fn time_template(
+    comments: TokenStream,
+    derives: Derives,
+    name: TokenStream,
+    annotations: TokenStream,
+) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
+    quote! {
+        #comments
+        #derive
+        #annotations
+        pub struct #name(pub VisibleString);
+    }
+}
+
 pub fn bit_string_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
+    named_bits: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name(pub BitString);
+
+        #named_bits
     }
 }
 
 pub fn octet_string_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name(pub OctetString);
     }
@@ -110,13 +171,15 @@ pub fn octet_string_template(
 
 pub fn char_string_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     string_type: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name(pub #string_type);
     }
@@ -124,12 +187,14 @@ pub fn char_string_template(
 
 pub fn boolean_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let debug = derives.debug.then(|| quote!(Debug,));
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq)]
+        #[derive(AsnType, #debug Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #annotations
         pub struct #name(pub bool);
     }
@@ -161,12 +226,14 @@ pub fn enum_value_template(
 
 pub fn null_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let debug = derives.debug.then(|| quote!(Debug,));
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq)]
+        #[derive(AsnType, #debug Clone, Copy, Decode, Encode, PartialEq)]
         #annotations
         pub struct #name(());
     }
@@ -174,40 +241,76 @@ pub fn null_template(
 
 pub fn any_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
-        pub struct #name(Any);
+        pub struct #name(pub Any);
     }
 }
 
 pub fn oid_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         pub struct #name(pub ObjectIdentifier);
     }
 }
 
+pub fn oid_iri_template(
+    comments: TokenStream,
+    derives: Derives,
+    name: TokenStream,
+    annotations: TokenStream,
+) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
+    quote! {
+        #comments
+        #derive
+        #annotations
+        pub struct #name(pub Utf8String);
+    }
+}
+
+pub fn relative_oid_template(
+    comments: TokenStream,
+    derives: Derives,
+    name: TokenStream,
+    annotations: TokenStream,
+) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
+    quote! {
+        #comments
+        #derive
+        #annotations
+        pub struct #name(pub OctetString);
+    }
+}
+
 pub fn enumerated_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     extensible: TokenStream,
     enum_members: TokenStream,
     annotations: TokenStream,
 ) -> TokenStream {
+    let debug = derives.debug.then(|| quote!(Debug,));
     quote! {
         #comments
-        #[derive(AsnType, Debug, Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[derive(AsnType, #debug Clone, Copy, Decode, Encode, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #annotations
         #extensible
         pub enum #name {
@@ -235,6 +338,7 @@ pub fn sequence_or_set_value_template(
 #[allow(clippy::too_many_arguments)]
 pub fn sequence_or_set_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     extensible: TokenStream,
     members: TokenStream,
@@ -244,10 +348,11 @@ pub fn sequence_or_set_template(
     new_impl: TokenStream,
     class_fields: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #(#nested_members)*
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         #extensible
         pub struct #name {
@@ -265,6 +370,7 @@ pub fn sequence_or_set_template(
 pub fn sequence_or_set_of_template(
     is_set_of: bool,
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     anonymous_item: TokenStream,
     member_type: TokenStream,
@@ -273,10 +379,11 @@ pub fn sequence_or_set_of_template(
     let generic_type = is_set_of
         .then(|| quote!(SetOf))
         .unwrap_or(quote!(SequenceOf));
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
             #anonymous_item
             #comments
-            #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+            #derive
             #annotations
             pub struct #name(pub #generic_type<#member_type>);
     }
@@ -312,16 +419,18 @@ pub fn const_choice_value_template(
 
 pub fn choice_template(
     comments: TokenStream,
+    derives: Derives,
     name: TokenStream,
     extensible: TokenStream,
     options: TokenStream,
     nested_options: Vec<TokenStream>,
     annotations: TokenStream,
 ) -> TokenStream {
+    let derive = derive_attribute(derives, TokenStream::new());
     quote! {
         #(#nested_options)*
         #comments
-        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #derive
         #annotations
         #extensible
         pub enum #name {
@@ -1,5 +1,6 @@
-use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc, str::FromStr};
 
+use chrono::{SecondsFormat, Utc};
 use proc_macro2::{Ident, Literal, Punct, Spacing, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use utils::types::SequenceOrSetOf;
@@ -7,12 +8,20 @@ use utils::types::SequenceOrSetOf;
 use crate::{
     common::INTERNAL_NESTED_TYPE_NAME_PREFIX,
     intermediate::{
-        constraints::Constraint,
+        constraints::{
+            Constraint, ContentConstraint, ElementOrSetOperation, ElementSet, PatternConstraint,
+            SetOperation, SetOperator, SubtypeElement,
+        },
         encoding_rules::per_visible::{
-            per_visible_range_constraints, CharsetSubset, PerVisibleAlphabetConstraints,
+            is_range_visible, per_visible_range_constraints, CharsetSubset,
+            PerVisibleAlphabetConstraints,
         },
         information_object::{InformationObjectClass, InformationObjectField},
-        types::{Choice, ChoiceOption, Enumerated, SequenceOrSet, SequenceOrSetMember},
+        types::{
+            BitString, Choice, ChoiceOption, Enumerated, GeneralizedTime, Integer,
+            ObjectIdentifier, OctetString, OctetStringValueRange, SequenceOrSet,
+            SequenceOrSetMember, UTCTime,
+        },
         ASN1Type, ASN1Value, AsnTag, CharacterStringType, IntegerType, TagClass,
         TaggingEnvironment, ToplevelDefinition, ToplevelTypeDefinition,
     },
@@ -34,7 +43,301 @@ pub(crate) use error;
 
 use self::types::{CharacterString, Constrainable};
 
-use super::*;
+use super::{
+    pattern::{self, Instruction},
+    template::Derives,
+    *,
+};
+
+/// Merges characters into sorted ranges of consecutive code points.
+fn code_point_ranges(characters: impl Iterator<Item = char>) -> Vec<(u32, u32)> {
+    let mut code_points = characters.map(u32::from).collect::<Vec<u32>>();
+    code_points.sort_unstable();
+    code_points.dedup();
+    let mut ranges = Vec::<(u32, u32)>::new();
+    for code_point in code_points {
+        match ranges.last_mut() {
+            Some((_, to)) if *to + 1 == code_point => *to = code_point,
+            _ => ranges.push((code_point, code_point)),
+        }
+    }
+    ranges
+}
+
+/// Formats the bounds of a range constraint in ASN.1 notation.
+fn format_asn1_range(min: Option<i128>, max: Option<i128>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) if min == max => min.to_string(),
+        (min, max) => format!(
+            "{}..{}",
+            min.map_or(String::from("MIN"), |min| min.to_string()),
+            max.map_or(String::from("MAX"), |max| max.to_string())
+        ),
+    }
+}
+
+/// Returns the condition that a value of an OCTET STRING satisfies its value range constraint,
+/// preceded by the binding of the value's octets, along with the constraint in ASN.1 notation.
+/// Values of the same length as the endpoints are compared octet by octet, which corresponds
+/// to comparing them as unsigned integers.
+fn octet_string_value_range_condition(
+    octet_string: &OctetString,
+    value: &TokenStream,
+) -> Option<(TokenStream, String)> {
+    let OctetStringValueRange { min, max } = octet_string.value_range()?;
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    let hex = |octets: &Option<Vec<u8>>, unbounded: &str| {
+        octets.as_ref().map_or(String::from(unbounded), |octets| {
+            format!(
+                "'{}'H",
+                octets
+                    .iter()
+                    .map(|o| format!("{o:02X}"))
+                    .collect::<String>()
+            )
+        })
+    };
+    let description = format!("({}..{})", hex(&min, "MIN"), hex(&max, "MAX"));
+    let octets = |octets: Vec<u8>| octets.into_iter().map(Literal::u8_unsuffixed);
+    let conditions = [
+        min.map(octets)
+            .map(|min| quote!(octets >= &[#(#min),*][..])),
+        max.map(octets)
+            .map(|max| quote!(octets <= &[#(#max),*][..])),
+    ]
+    .into_iter()
+    .flatten();
+    Some((
+        quote!(let octets: &[u8] = #value; #(#conditions)&&*),
+        description,
+    ))
+}
+
+/// Returns the expression of an integer value that is compared in a constraint check,
+/// along with a function that formats the integers that the value is compared to.
+fn integer_comparands(
+    unbounded_integer: bool,
+    value: &TokenStream,
+) -> (TokenStream, fn(i128) -> TokenStream) {
+    if unbounded_integer {
+        (quote!(*#value), |b| {
+            let b = Literal::i128_suffixed(b);
+            quote!(Integer::from(#b))
+        })
+    } else {
+        (quote!(i128::from(*#value)), |b| {
+            Literal::i128_unsuffixed(b).to_token_stream()
+        })
+    }
+}
+
+/// Returns the integer ranges that a constraint excludes from `ALL` values, e.g. `(ALL EXCEPT 1..5)`.
+/// Only exclusions of single integers, integer ranges, and their unions are considered,
+/// as long as neither the exclusion nor its elements are extensible.
+fn excluded_integer_ranges(constraint: &Constraint) -> Option<Vec<(Option<i128>, Option<i128>)>> {
+    fn integer(value: &ASN1Value) -> Option<i128> {
+        match value {
+            ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => Some(*i),
+            _ => None,
+        }
+    }
+    fn collect(
+        set: &ElementOrSetOperation,
+        ranges: &mut Vec<(Option<i128>, Option<i128>)>,
+    ) -> bool {
+        match set {
+            ElementOrSetOperation::Element(e) => collect_element(e, ranges),
+            ElementOrSetOperation::SetOperation(s) => collect_union(s, ranges),
+        }
+    }
+    fn collect_union(set: &SetOperation, ranges: &mut Vec<(Option<i128>, Option<i128>)>) -> bool {
+        set.operator == SetOperator::Union
+            && collect_element(&set.base, ranges)
+            && collect(&set.operant, ranges)
+    }
+    fn collect_element(
+        element: &SubtypeElement,
+        ranges: &mut Vec<(Option<i128>, Option<i128>)>,
+    ) -> bool {
+        match element {
+            SubtypeElement::SingleValue {
+                value,
+                extensible: false,
+            } => integer(value)
+                .map(|i| ranges.push((Some(i), Some(i))))
+                .is_some(),
+            SubtypeElement::ValueRange {
+                min,
+                max,
+                extensible: false,
+            } => match (min.as_ref().map(integer), max.as_ref().map(integer)) {
+                (Some(None), _) | (_, Some(None)) => false,
+                (min, max) => {
+                    ranges.push((min.flatten(), max.flatten()));
+                    true
+                }
+            },
+            SubtypeElement::NestedSet(s) => collect_union(s, ranges),
+            _ => false,
+        }
+    }
+    match constraint {
+        Constraint::SubtypeConstraint(ElementSet {
+            set:
+                ElementOrSetOperation::SetOperation(SetOperation {
+                    base: SubtypeElement::All,
+                    operator: SetOperator::Except,
+                    operant,
+                }),
+            extensible: false,
+        }) => {
+            let mut ranges = vec![];
+            collect(operant, &mut ranges).then_some(ranges)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the enumerals that the constraints of a reference to an `ENUMERATED` type permit
+/// as pairs of the name of the `ENUMERATED` type and the name of the enumeral.
+/// Only single enumerals, their unions, and value sets of enumerals are considered.
+fn permitted_enumerals(constraints: &[Constraint]) -> Vec<(&String, &String)> {
+    fn collect<'a>(set: &'a ElementOrSetOperation, enumerals: &mut Vec<(&'a String, &'a String)>) {
+        match set {
+            ElementOrSetOperation::Element(e) => collect_element(e, enumerals),
+            ElementOrSetOperation::SetOperation(s) if s.operator == SetOperator::Union => {
+                collect_element(&s.base, enumerals);
+                collect(&s.operant, enumerals);
+            }
+            ElementOrSetOperation::SetOperation(_) => (),
+        }
+    }
+    fn collect_element<'a>(
+        element: &'a SubtypeElement,
+        enumerals: &mut Vec<(&'a String, &'a String)>,
+    ) {
+        match element {
+            SubtypeElement::SingleValue {
+                value:
+                    ASN1Value::EnumeratedValue {
+                        enumerated,
+                        enumerable,
+                    },
+                ..
+            } => enumerals.push((enumerated, enumerable)),
+            SubtypeElement::NestedSet(s) if s.operator == SetOperator::Union => {
+                collect_element(&s.base, enumerals);
+                collect(&s.operant, enumerals);
+            }
+            // e.g. `Color (Warm)` with the value set `Warm Color ::= { red | green }`
+            SubtypeElement::ContainedSubtype { subtype, .. } => {
+                for c in subtype.constraints().into_iter().flatten() {
+                    if let Constraint::SubtypeConstraint(c) = c {
+                        collect(&c.set, enumerals);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    let mut enumerals = vec![];
+    for constraint in constraints {
+        if let Constraint::SubtypeConstraint(c) = constraint {
+            collect(&c.set, &mut enumerals);
+        }
+    }
+    enumerals
+}
+
+/// Returns the `PATTERN` constraints that the values of a character string have to match,
+/// together with the extensibility of their element sets. Only patterns that are intersected
+/// with the remaining elements of their element set are considered.
+fn pattern_constraints(constraints: &[Constraint]) -> Vec<(&PatternConstraint, bool)> {
+    fn collect<'a>(
+        set: &'a ElementOrSetOperation,
+        patterns: &mut Vec<&'a PatternConstraint>,
+    ) -> bool {
+        match set {
+            ElementOrSetOperation::Element(e) => {
+                collect_element(e, patterns);
+                true
+            }
+            ElementOrSetOperation::SetOperation(s) => {
+                s.operator == SetOperator::Intersection && {
+                    collect_element(&s.base, patterns);
+                    collect(&s.operant, patterns)
+                }
+            }
+        }
+    }
+    fn collect_element<'a>(element: &'a SubtypeElement, patterns: &mut Vec<&'a PatternConstraint>) {
+        match element {
+            SubtypeElement::PatternConstraint(pattern) => patterns.push(pattern),
+            SubtypeElement::NestedSet(s) if s.operator == SetOperator::Intersection => {
+                let mut nested = vec![];
+                collect_element(&s.base, &mut nested);
+                if collect(&s.operant, &mut nested) {
+                    patterns.append(&mut nested);
+                }
+            }
+            _ => (),
+        }
+    }
+    let mut patterns = vec![];
+    for constraint in constraints {
+        if let Constraint::SubtypeConstraint(c) = constraint {
+            let mut intersected = vec![];
+            if collect(&c.set, &mut intersected) {
+                patterns.extend(intersected.into_iter().map(|p| (p, c.extensible)));
+            }
+        }
+    }
+    patterns
+}
+
+/// Returns the `PATTERN` constraints of non-extensible element sets that can be compiled,
+/// together with their compiled programs.
+fn checked_patterns(constraints: &[Constraint]) -> Vec<(&PatternConstraint, Vec<Instruction>)> {
+    pattern_constraints(constraints)
+        .into_iter()
+        .filter(|(_, extensible)| !extensible)
+        .filter_map(|(p, _)| {
+            pattern::compile(&p.pattern)
+                .ok()
+                .map(|program| (p, program))
+        })
+        .collect()
+}
+
+/// Formats the instructions of a compiled pattern as elements of a `PatternInstruction` slice.
+fn format_pattern_program(program: &[Instruction]) -> Vec<TokenStream> {
+    program
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Class { ranges, negated } => {
+                let ranges = ranges.iter().map(|(from, to)| {
+                    let (from, to) = (Literal::u32_unsuffixed(*from), Literal::u32_unsuffixed(*to));
+                    quote!((#from, #to))
+                });
+                quote!(PatternInstruction::Class(&[#(#ranges),*], #negated))
+            }
+            Instruction::Split(first, second) => {
+                let (first, second) = (
+                    Literal::usize_unsuffixed(*first),
+                    Literal::usize_unsuffixed(*second),
+                );
+                quote!(PatternInstruction::Split(#first, #second))
+            }
+            Instruction::Jump(target) => {
+                let target = Literal::usize_unsuffixed(*target);
+                quote!(PatternInstruction::Jump(#target))
+            }
+            Instruction::Match => quote!(PatternInstruction::Match),
+        })
+        .collect()
+}
 
 impl IntegerType {
     fn to_token_stream(self) -> TokenStream {
@@ -68,14 +371,93 @@ macro_rules! assert_eq_ignore_ws {
 }
 
 impl Rasn {
+    /// Formats a reference to the type with the given ASN.1 name. Types that are mapped
+    /// to existing rust types are referenced by their configured path.
+    pub(crate) fn format_type_reference(
+        &self,
+        identifier: &str,
+    ) -> Result<TokenStream, GeneratorError> {
+        match self.config.external_types.get(identifier) {
+            Some(path) => TokenStream::from_str(path).map_err(|_| {
+                error!(
+                    Unidentified,
+                    "Type {identifier} is mapped to {path}, which is not a valid rust path!"
+                )
+            }),
+            None => Ok(self.to_rust_title_case(identifier)),
+        }
+    }
+
     pub(crate) fn inner_name(&self, name: &str, parent_name: &str) -> Ident {
+        let separator = match self.config.casing {
+            CasingStrategy::RustIdiomatic => "",
+            _ => "_",
+        };
         format_ident!(
-            "{}{}",
+            "{}{separator}{}",
             parent_name,
             self.to_rust_title_case(name).to_string()
         )
     }
 
+    /// Returns the optional derives of the rust representation of a top-level type.
+    /// ### Params
+    /// * `name` - ASN.1 name of the type
+    /// * `ty` - the type's definition
+    pub(crate) fn derives(&self, name: &str, ty: &ASN1Type) -> Derives {
+        Derives {
+            cloneable: self.is_cloneable(name, ty),
+            debug: !self.config.generate_debug_notation,
+        }
+    }
+
+    /// Checks whether the rust representation of a top-level type derives the optional traits,
+    /// see [super::Config::non_cloneable_threshold].
+    /// ### Params
+    /// * `name` - ASN.1 name of the type
+    /// * `ty` - the type's definition
+    pub(crate) fn is_cloneable(&self, name: &str, ty: &ASN1Type) -> bool {
+        match ty {
+            ASN1Type::Boolean(_) | ASN1Type::Null | ASN1Type::Real(_) | ASN1Type::Enumerated(_) => {
+                true
+            }
+            _ => {
+                !self.config.non_cloneable_types.contains(name)
+                    && !self.non_cloneable.contains(name)
+                    && self.is_cloneable_type(ty)
+            }
+        }
+    }
+
+    /// Checks whether the rust representation of a type and of all of its components
+    /// derive the optional traits, see [super::Config::non_cloneable_threshold].
+    pub(crate) fn is_cloneable_type(&self, ty: &ASN1Type) -> bool {
+        let exceeds_threshold = |count: Option<usize>| {
+            self.config
+                .non_cloneable_threshold
+                .is_some_and(|threshold| count.is_none_or(|c| c > threshold))
+        };
+        match ty {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                !exceeds_threshold(Some(s.members.len()))
+                    && s.members.iter().all(|m| self.is_cloneable_type(&m.ty))
+            }
+            ASN1Type::Choice(c) => {
+                !exceeds_threshold(Some(c.options.len()))
+                    && c.options.iter().all(|o| self.is_cloneable_type(&o.ty))
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                let max_size = per_visible_range_constraints(false, &s.constraints)
+                    .ok()
+                    .filter(|c| c.is_size_constraint() && !c.is_extensible())
+                    .and_then(|c| c.max::<usize>());
+                !exceeds_threshold(max_size) && self.is_cloneable_type(&s.element_type)
+            }
+            ASN1Type::ElsewhereDeclaredType(e) => !self.non_cloneable.contains(&e.identifier),
+            _ => true,
+        }
+    }
+
     pub(crate) fn int_type_token(
         &self,
         opt_min: Option<i128>,
@@ -117,13 +499,46 @@ impl Rasn {
         }
     }
 
+    /// Formats the comments of a type alias. If the alias restricts an `ENUMERATED` type
+    /// to a subset of its enumerals, the comments list the permitted enumerals.
+    /// Inner subtyping constraints, which the generated bindings do not enforce,
+    /// are documented in ASN.1 notation.
+    pub(crate) fn format_alias_comments(
+        &self,
+        comments: &str,
+        constraints: &[Constraint],
+    ) -> Result<TokenStream, GeneratorError> {
+        let mut lines = vec![];
+        if !comments.is_empty() {
+            lines.push(comments.to_owned());
+        }
+        let enumerals = permitted_enumerals(constraints);
+        if !enumerals.is_empty() {
+            let permitted = enumerals
+                .iter()
+                .map(|(_, enumeral)| enumeral.as_str())
+                .collect::<Vec<_>>()
+                .join(" | ");
+            lines.push(format!(" Permitted values: {permitted}"));
+        }
+        lines.extend(
+            constraints
+                .iter()
+                .filter(|c| c.has_inner_subtyping())
+                .map(|c| format!(" Inner subtyping: {}", c.to_asn1_string())),
+        );
+        self.format_comments(&lines.join("\n"))
+    }
+
     pub(crate) fn format_identifier_annotation(
         &self,
         name: &str,
         comments: &str,
         ty: &ASN1Type,
     ) -> TokenStream {
-        if comments == " Inner type "
+        if !self.config.rasn_version.supports_identifier_annotation() {
+            TokenStream::new()
+        } else if comments == " Inner type "
             || comments.starts_with(" Anonymous ")
             || name.starts_with("ext_group_")
         {
@@ -139,7 +554,7 @@ impl Rasn {
         signed: bool,
         constraints: &Vec<Constraint>,
     ) -> Result<TokenStream, GeneratorError> {
-        if constraints.is_empty() {
+        if !constraints.iter().any(is_range_visible) {
             return Ok(TokenStream::new());
         }
         let per_constraints = per_visible_range_constraints(signed, constraints)?;
@@ -207,56 +622,77 @@ impl Rasn {
         if constraints.is_empty() {
             return Ok(TokenStream::new());
         }
-        let mut permitted_alphabet = PerVisibleAlphabetConstraints::default_for(string_type);
+        let mut permitted_alphabet = PerVisibleAlphabetConstraints::default();
         for c in constraints {
             if let Some(mut p) = PerVisibleAlphabetConstraints::try_new(c, string_type)? {
                 permitted_alphabet += &mut p
             }
         }
-        permitted_alphabet.finalize();
-        let alphabet_unicode = permitted_alphabet
+        // rasn reads single characters only from one-byte strings, and splits ranges at the first `..`
+        let alphabet = permitted_alphabet
             .charset_subsets()
-            .iter()
-            .map(|subset| match subset {
-                CharsetSubset::Single(c) => format!("{}", c.escape_unicode()),
-                CharsetSubset::Range { from, to } => format!(
-                    "{}..{}",
-                    from.map_or(String::from(""), |c| format!("{}", c.escape_unicode())),
-                    to.map_or(String::from(""), |c| format!("{}", c.escape_unicode()))
-                ),
+            .into_iter()
+            .flat_map(|subset| match subset {
+                CharsetSubset::Single(c) if c.is_ascii() => vec![c.to_string()],
+                CharsetSubset::Single(c) => vec![format!("{c}..={c}")],
+                CharsetSubset::Range {
+                    from: Some('.'),
+                    to: Some(to),
+                } => vec![String::from("."), format!("/..={to}")],
+                CharsetSubset::Range { from, to } => vec![format!(
+                    "{}..={}",
+                    from.map_or(String::new(), String::from),
+                    to.map_or(String::new(), String::from)
+                )],
             })
-            .collect::<Vec<String>>()
-            .join(", ");
-        Ok(if alphabet_unicode.is_empty() {
+            .map(|subset| Literal::string(&subset))
+            .collect::<Vec<_>>();
+        Ok(if alphabet.is_empty() {
             TokenStream::new()
         } else {
-            quote!(from(#alphabet_unicode))
+            quote!(from(#(#alphabet),*))
         })
     }
 
+    /// Formats the variants of an enumeration. `rasn` derives the PER enumeration index of
+    /// a variant from its position, so the root enumerals and the extension additions are
+    /// each formatted in ascending order of their numbers.
+    /// *See Rec. ITU-T X.691 (02/2021) §14.3*
     pub(crate) fn format_enum_members(&self, enumerated: &Enumerated) -> TokenStream {
-        let first_extension_index = enumerated.extensible;
-        let enumerals = enumerated.members.iter().enumerate().map(|(i, e)| {
-            let name = self.to_rust_enum_identifier(&e.name);
-            let index = Literal::i128_unsuffixed(e.index);
-            let extension_annotation = if i >= first_extension_index.unwrap_or(usize::MAX) {
-                quote!(extension_addition)
-            } else {
-                TokenStream::new()
-            };
-            let identifier_annotation = if name != e.name {
-                let name = &e.name;
-                quote!(identifier = #name)
-            } else {
-                TokenStream::new()
-            };
-            let annotations =
-                self.join_annotations(vec![extension_annotation, identifier_annotation]);
-            quote!(
-                #annotations
-                #name = #index,
-            )
-        });
+        let (root, additions) = enumerated
+            .members
+            .split_at(enumerated.extensible.unwrap_or(enumerated.members.len()));
+        let mut root = root
+            .iter()
+            .map(|e| (e, TokenStream::new()))
+            .collect::<Vec<_>>();
+        let mut additions = additions
+            .iter()
+            .map(|e| (e, quote!(extension_addition)))
+            .collect::<Vec<_>>();
+        root.sort_by_key(|(e, _)| e.index);
+        additions.sort_by_key(|(e, _)| e.index);
+        let enumerals = root
+            .into_iter()
+            .chain(additions)
+            .map(|(e, extension_annotation)| {
+                let name = self.to_rust_enum_identifier(&e.name);
+                let index = Literal::i128_unsuffixed(e.index);
+                let identifier_annotation = if name != e.name
+                    && self.config.rasn_version.supports_identifier_annotation()
+                {
+                    let name = &e.name;
+                    quote!(identifier = #name)
+                } else {
+                    TokenStream::new()
+                };
+                let annotations =
+                    self.join_annotations(vec![extension_annotation, identifier_annotation]);
+                quote!(
+                    #annotations
+                    #name = #index,
+                )
+            });
         quote!(#(#enumerals)*)
     }
 
@@ -289,28 +725,35 @@ impl Rasn {
         &self,
         sequence_or_set: &SequenceOrSet,
         parent_name: &String,
+        feature_gates: &[TokenStream],
     ) -> Result<(TokenStream, Vec<NameType>), GeneratorError> {
         let first_extension_index = sequence_or_set.extensible;
-        sequence_or_set.members.iter().enumerate().try_fold(
-            (TokenStream::new(), Vec::new()),
-            |mut acc, (i, m)| {
-                let extension_annotation = if i >= first_extension_index.unwrap_or(usize::MAX)
-                    && m.name.starts_with("ext_group_")
-                {
-                    quote!(extension_addition_group)
-                } else if i >= first_extension_index.unwrap_or(usize::MAX) {
-                    quote!(extension_addition)
-                } else {
-                    TokenStream::new()
-                };
-                self.format_sequence_member(m, parent_name, extension_annotation)
-                    .map(|(declaration, name_type)| {
-                        acc.0.append_all([declaration, quote!(, )]);
-                        acc.1.push(name_type);
-                        acc
-                    })
-            },
-        )
+        sequence_or_set
+            .members
+            .iter()
+            .zip(feature_gates)
+            .enumerate()
+            .try_fold(
+                (TokenStream::new(), Vec::new()),
+                |mut acc, (i, (m, feature_gate))| {
+                    let extension_annotation = if i >= first_extension_index.unwrap_or(usize::MAX)
+                        && m.name.starts_with("ext_group_")
+                    {
+                        quote!(extension_addition_group)
+                    } else if i >= first_extension_index.unwrap_or(usize::MAX) {
+                        quote!(extension_addition)
+                    } else {
+                        TokenStream::new()
+                    };
+                    self.format_sequence_member(m, parent_name, extension_annotation)
+                        .map(|(declaration, name_type)| {
+                            acc.0
+                                .append_all([feature_gate.clone(), declaration, quote!(, )]);
+                            acc.1.push(name_type);
+                            acc
+                        })
+                },
+            )
     }
 
     pub(crate) fn format_sequence_member(
@@ -319,7 +762,7 @@ impl Rasn {
         parent_name: &String,
         extension_annotation: TokenStream,
     ) -> Result<(TokenStream, NameType), GeneratorError> {
-        let name = self.to_rust_snake_case(&member.name);
+        let name = self.to_rust_field_case(&member.name);
         let (mut all_constraints, mut formatted_type_name) =
             self.constraints_and_type_name(&member.ty, &member.name, parent_name)?;
         all_constraints.append(&mut member.constraints.clone());
@@ -372,13 +815,15 @@ impl Rasn {
         &self,
         choice: &Choice,
         parent_name: &String,
+        feature_gates: &[TokenStream],
     ) -> Result<TokenStream, GeneratorError> {
         let first_extension_index = choice.extensible;
         let options = choice
             .options
             .iter()
+            .zip(feature_gates)
             .enumerate()
-            .map(|(i, o)| {
+            .map(|(i, (o, feature_gate))| {
                 let extension_annotation = if i >= first_extension_index.unwrap_or(usize::MAX)
                     && o.name.starts_with("ext_group_")
                 {
@@ -390,6 +835,7 @@ impl Rasn {
                 };
                 let name = self.to_rust_enum_identifier(&o.name);
                 self.format_choice_option(name, o, parent_name, extension_annotation)
+                    .map(|option| quote!(#feature_gate #option))
             })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(quote!(#(#options)*))
@@ -452,24 +898,30 @@ impl Rasn {
                 )
             }
             ASN1Type::Real(_) => (vec![], quote!(f64)),
-            ASN1Type::ObjectIdentifier(o) => (o.constraints.clone(), quote!(ObjectIdentifier)),
+            ASN1Type::ObjectIdentifier(o) if !o.is_relative => {
+                (o.constraints.clone(), quote!(ObjectIdentifier))
+            }
             ASN1Type::BitString(b) => (b.constraints.clone(), quote!(BitString)),
             ASN1Type::OctetString(o) => (o.constraints.clone(), quote!(OctetString)),
             ASN1Type::GeneralizedTime(o) => (o.constraints.clone(), quote!(GeneralizedTime)),
             ASN1Type::UTCTime(o) => (o.constraints.clone(), quote!(UtcTime)),
-            ASN1Type::Time(_) => {
-                return Err(GeneratorError {
-                    details: "rasn does not support TIME types yet!".into(),
-                    top_level_declaration: None,
-                    kind: GeneratorErrorType::NotYetInplemented,
-                })
+            ASN1Type::CharacterString(c) if !self.is_retagged_string(c.ty) => {
+                (c.constraints.clone(), self.string_type(&c.ty)?)
             }
-            ASN1Type::CharacterString(c) => (c.constraints.clone(), self.string_type(&c.ty)?),
             ASN1Type::Enumerated(_)
             | ASN1Type::Choice(_)
             | ASN1Type::Sequence(_)
             | ASN1Type::SetOf(_)
-            | ASN1Type::Set(_) => (vec![], self.inner_name(name, parent_name).to_token_stream()),
+            | ASN1Type::Set(_)
+            | ASN1Type::Time(_)
+            | ASN1Type::ObjectIdentifierIri(_)
+            | ASN1Type::ObjectIdentifier(_)
+            | ASN1Type::CharacterString(_)
+            | ASN1Type::EmbeddedPdv
+            | ASN1Type::External => (vec![], self.inner_name(name, parent_name).to_token_stream()),
+            ASN1Type::SequenceOf(_) if self.needs_unnesting(ty) => {
+                (vec![], self.inner_name(name, parent_name).to_token_stream())
+            }
             ASN1Type::SequenceOf(s) => {
                 let (_, inner_type) =
                     self.constraints_and_type_name(&s.element_type, name, parent_name)?;
@@ -477,11 +929,9 @@ impl Rasn {
             }
             ASN1Type::ElsewhereDeclaredType(e) => (
                 e.constraints.clone(),
-                self.to_rust_title_case(&e.identifier).to_token_stream(),
+                self.format_type_reference(&e.identifier)?,
             ),
-            ASN1Type::InformationObjectFieldReference(_)
-            | ASN1Type::EmbeddedPdv
-            | ASN1Type::External => (vec![], quote!(Any)),
+            ASN1Type::InformationObjectFieldReference(_) => (vec![], quote!(Any)),
             ASN1Type::ChoiceSelectionType(_) => unreachable!(),
         })
     }
@@ -490,33 +940,55 @@ impl Rasn {
         &self,
         c_type: &CharacterStringType,
     ) -> Result<TokenStream, GeneratorError> {
-        match c_type {
-            CharacterStringType::NumericString => Ok(quote!(NumericString)),
-            CharacterStringType::VisibleString => Ok(quote!(VisibleString)),
-            CharacterStringType::IA5String => Ok(quote!(Ia5String)),
-            CharacterStringType::TeletexString => Ok(quote!(TeletexString)),
-            CharacterStringType::VideotexString => Err(GeneratorError {
-                kind: GeneratorErrorType::NotYetInplemented,
-                details: "VideotexString is currently unsupported!".into(),
-                top_level_declaration: None,
-            }),
-            CharacterStringType::GraphicString => Err(GeneratorError {
-                kind: GeneratorErrorType::NotYetInplemented,
-                details: "GraphicString is currently unsupported!".into(),
-                top_level_declaration: None,
-            }),
-            CharacterStringType::GeneralString => Ok(quote!(GeneralString)),
-            CharacterStringType::UniversalString => Err(GeneratorError {
-                kind: GeneratorErrorType::NotYetInplemented,
-                details: "UniversalString is currently unsupported!".into(),
-                top_level_declaration: None,
-            }),
-            CharacterStringType::UTF8String => Ok(quote!(Utf8String)),
-            CharacterStringType::BMPString => Ok(quote!(BmpString)),
-            CharacterStringType::PrintableString => Ok(quote!(PrintableString)),
+        match self.string_carrier(*c_type) {
+            None => Ok(quote!(OctetString)),
+            Some(CharacterStringType::NumericString) => Ok(quote!(NumericString)),
+            Some(CharacterStringType::VisibleString) => Ok(quote!(VisibleString)),
+            Some(CharacterStringType::IA5String) => Ok(quote!(Ia5String)),
+            Some(CharacterStringType::TeletexString) => Ok(quote!(TeletexString)),
+            Some(CharacterStringType::GeneralString) => Ok(quote!(GeneralString)),
+            Some(CharacterStringType::UniversalString) => Ok(quote!(UniversalString)),
+            Some(CharacterStringType::UTF8String) => Ok(quote!(Utf8String)),
+            Some(CharacterStringType::BMPString) => Ok(quote!(BmpString)),
+            Some(CharacterStringType::PrintableString) => Ok(quote!(PrintableString)),
+            Some(carrier) => Err(error!(
+                NotYetInplemented,
+                "{:?} is currently unsupported!", carrier
+            )),
+        }
+    }
+
+    /// Returns the character string type whose `rasn` representation carries the values of
+    /// `string_type`, or `None` if the values are carried as raw octets in an `OctetString`.
+    /// See [Config::legacy_strings].
+    pub(crate) fn string_carrier(
+        &self,
+        string_type: CharacterStringType,
+    ) -> Option<CharacterStringType> {
+        match (self.config.legacy_strings, string_type) {
+            (
+                LegacyStringRepresentation::Octets,
+                CharacterStringType::TeletexString
+                | CharacterStringType::GraphicString
+                | CharacterStringType::GeneralString
+                | CharacterStringType::UniversalString
+                | CharacterStringType::ObjectDescriptor,
+            )
+            | (_, CharacterStringType::VideotexString) => None,
+            (_, CharacterStringType::GraphicString | CharacterStringType::ObjectDescriptor) => {
+                Some(CharacterStringType::GeneralString)
+            }
+            (_, string_type) => Some(string_type),
         }
     }
 
+    /// Whether values of `string_type` are carried by a representation that does not carry
+    /// the universal tag of `string_type`, so that the type is represented by a delegate
+    /// with the universal tag of `string_type`.
+    pub(crate) fn is_retagged_string(&self, string_type: CharacterStringType) -> bool {
+        self.string_carrier(string_type) != Some(string_type)
+    }
+
     pub(crate) fn join_annotations(&self, elements: Vec<TokenStream>) -> TokenStream {
         let mut not_empty_exprs = elements.into_iter().filter(|ts| !ts.is_empty());
         if let Some(mut annotations) = not_empty_exprs.next() {
@@ -546,11 +1018,30 @@ impl Rasn {
         let mut output = TokenStream::new();
         for member in members {
             if let Some(value) = member.default_value.as_ref() {
-                let val = self.value_to_tokens(
+                let mut val = self.value_to_tokens(
                     value,
                     Some(&self.to_rust_title_case(&self.type_to_tokens(&member.ty)?.to_string())),
                 )?;
-                let ty = self.type_to_tokens(&member.ty)?;
+                let mut ty = self.type_to_tokens(&member.ty)?;
+                let is_delegate = match &member.ty {
+                    ASN1Type::CharacterString(c) => self.is_retagged_string(c.ty),
+                    ASN1Type::Time(_)
+                    | ASN1Type::ObjectIdentifierIri(_)
+                    | ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                        is_relative: true, ..
+                    }) => true,
+                    _ => false,
+                };
+                if is_delegate {
+                    // TIME, IRI, RELATIVE-OID and retagged string members are represented by
+                    // inner delegate types
+                    let inner = self.inner_name(
+                        &member.name,
+                        &self.to_rust_title_case(parent_name).to_string(),
+                    );
+                    val = quote!(#inner(#val));
+                    ty = inner.to_token_stream();
+                }
                 let method_name =
                     TokenStream::from_str(&self.default_method_name(parent_name, &member.name))?;
                 output.append_all(quote! {
@@ -588,23 +1079,19 @@ impl Rasn {
                 let inner = self.type_to_tokens(&so.element_type)?;
                 Ok(quote!(SequenceOf<#inner>))
             }
-            ASN1Type::ObjectIdentifier(_) => Err(error!(
-                NotYetInplemented,
-                "Object Identifier values are currently unsupported!"
-            )),
+            ASN1Type::ObjectIdentifier(o) if o.is_relative => Ok(quote!(OctetString)),
+            ASN1Type::ObjectIdentifier(_) => Ok(quote!(ObjectIdentifier)),
             ASN1Type::Set(_) => Err(error!(
                 NotYetInplemented,
                 "Set values are currently unsupported!"
             )),
-            ASN1Type::ElsewhereDeclaredType(e) => Ok(self.to_rust_title_case(&e.identifier)),
+            ASN1Type::ElsewhereDeclaredType(e) => self.format_type_reference(&e.identifier),
             ASN1Type::InformationObjectFieldReference(_) => Err(error!(
                 NotYetInplemented,
                 "Information Object field reference values are currently unsupported!"
             )),
-            ASN1Type::Time(_) => Err(error!(
-                NotYetInplemented,
-                "Time values are currently unsupported!"
-            )),
+            ASN1Type::Time(_) => Ok(quote!(VisibleString)),
+            ASN1Type::ObjectIdentifierIri(_) => Ok(quote!(Utf8String)),
             ASN1Type::GeneralizedTime(_) => Ok(quote!(GeneralizedTime)),
             ASN1Type::UTCTime(_) => Ok(quote!(UtcTime)),
             ASN1Type::EmbeddedPdv | ASN1Type::External => Ok(quote!(Any)),
@@ -622,10 +1109,6 @@ impl Rasn {
         type_name: Option<&TokenStream>,
     ) -> Result<TokenStream, GeneratorError> {
         match value {
-            ASN1Value::All => Err(error!(
-                NotYetInplemented,
-                "rasn does not support ALL values."
-            )),
             ASN1Value::Null => Ok(quote!(())),
             ASN1Value::Choice {
                 type_name: tn,
@@ -666,6 +1149,14 @@ impl Rasn {
                 Unidentified,
                 "Unexpectedly encountered unlinked struct-like ASN1 value!"
             )),
+            ASN1Value::ParameterizedValueReference { identifier, .. } => Err(error!(
+                Unidentified,
+                "Unexpectedly encountered unresolved parameterized value reference {identifier}!"
+            )),
+            ASN1Value::Arithmetic { .. } => Err(error!(
+                Unidentified,
+                "Unexpectedly encountered unevaluated arithmetic expression {:?}!", value
+            )),
             ASN1Value::LinkedStructLikeValue(fields) => {
                 if let Some(ty_n) = type_name {
                     let tokenized_fields = fields
@@ -685,7 +1176,15 @@ impl Rasn {
             ASN1Value::Boolean(b) => Ok(b.to_token_stream()),
             ASN1Value::Integer(i) => Ok(Literal::i128_unsuffixed(*i).to_token_stream()),
             ASN1Value::String(s) => Ok(s.to_token_stream()),
+            ASN1Value::Real(r) if r.is_infinite() && r.is_sign_positive() => {
+                Ok(quote!(f64::INFINITY))
+            }
+            ASN1Value::Real(r) if r.is_infinite() => Ok(quote!(f64::NEG_INFINITY)),
             ASN1Value::Real(r) => Ok(r.to_token_stream()),
+            // An empty array literal would leave the item type of the iterator undetermined
+            ASN1Value::BitString(b) if b.is_empty() => {
+                Ok(quote!(core::iter::empty::<bool>().collect()))
+            }
             ASN1Value::BitString(b) => {
                 let bits = b.iter().map(|bit| bit.to_token_stream());
                 Ok(quote!([#(#bits),*].into_iter().collect()))
@@ -706,12 +1205,36 @@ impl Rasn {
                 let arcs = oid
                     .0
                     .iter()
-                    .filter_map(|arc| arc.number.map(|id| id.to_token_stream()));
+                    .filter_map(|arc| arc.number.map(Literal::u128_unsuffixed));
                 Ok(quote!(Oid::const_new(&[#(#arcs),*]).to_owned()))
             }
-            ASN1Value::Time(t) => match type_name {
-                Some(time_type) => Ok(quote!(#t.parse::<#time_type>().unwrap())),
-                None => Ok(quote!(#t.parse::<_>().unwrap())),
+            ASN1Value::LinkedTimeValue(t) => {
+                let time = t.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+                match type_name.map(ToString::to_string).as_deref() {
+                    Some("GeneralizedTime") => {
+                        Ok(quote!(#time.parse::<GeneralizedTime>().unwrap()))
+                    }
+                    Some("UtcTime") => Ok(quote!(#time.parse::<UtcTime>().unwrap())),
+                    // The time type of values of referencing types is inferred from the context
+                    _ => Ok(quote!(#time.parse().unwrap())),
+                }
+            }
+            ASN1Value::Time(t) => match type_name.map(ToString::to_string).as_deref() {
+                Some("GeneralizedTime") => {
+                    let time = GeneralizedTime::parse_value(t)?
+                        .to_rfc3339_opts(SecondsFormat::AutoSi, true);
+                    Ok(quote!(#time.parse::<GeneralizedTime>().unwrap()))
+                }
+                Some("UtcTime") => {
+                    let time = UTCTime::parse_value(t)?
+                        .with_timezone(&Utc)
+                        .to_rfc3339_opts(SecondsFormat::AutoSi, true);
+                    Ok(quote!(#time.parse::<UtcTime>().unwrap()))
+                }
+                _ => match type_name {
+                    Some(time_type) => Ok(quote!(#t.parse::<#time_type>().unwrap())),
+                    None => Ok(quote!(#t.parse::<_>().unwrap())),
+                },
             },
             ASN1Value::LinkedArrayLikeValue(seq) => {
                 let elems = seq
@@ -748,32 +1271,68 @@ impl Rasn {
             }
             ASN1Value::LinkedCharStringValue(string_type, value) => {
                 let val = value.to_token_stream();
-                match string_type {
-                    CharacterStringType::NumericString => {
+                match self.string_carrier(*string_type) {
+                    None => {
+                        let bytes = match string_type {
+                            CharacterStringType::UniversalString => value
+                                .chars()
+                                .flat_map(|c| u32::from(c).to_be_bytes())
+                                .collect(),
+                            _ => value.as_bytes().to_vec(),
+                        }
+                        .into_iter()
+                        .map(Literal::u8_unsuffixed);
+                        Ok(quote!(<OctetString as From<&'static [u8]>>::from(&[#(#bytes),*])))
+                    }
+                    Some(CharacterStringType::NumericString) => {
                         Ok(quote!(NumericString::try_from(#val).unwrap()))
                     }
-                    CharacterStringType::VisibleString => {
+                    Some(CharacterStringType::VisibleString) => {
                         Ok(quote!(VisibleString::try_from(#val).unwrap()))
                     }
-                    CharacterStringType::IA5String => {
+                    Some(CharacterStringType::IA5String) => {
                         Ok(quote!(Ia5String::try_from(#val).unwrap()))
                     }
-                    CharacterStringType::UTF8String => Ok(quote!(String::from(#val))),
-                    CharacterStringType::BMPString => {
+                    Some(CharacterStringType::UTF8String) => Ok(quote!(String::from(#val))),
+                    Some(CharacterStringType::BMPString) => {
                         Ok(quote!(BmpString::try_from(#val).unwrap()))
                     }
-                    CharacterStringType::PrintableString => {
+                    Some(CharacterStringType::PrintableString) => {
                         Ok(quote!(PrintableString::try_from(#val).unwrap()))
                     }
-                    CharacterStringType::GeneralString => {
+                    Some(CharacterStringType::GeneralString) if value.is_ascii() => {
                         Ok(quote!(GeneralString::try_from(String::from(#val)).unwrap()))
                     }
-                    CharacterStringType::VideotexString
-                    | CharacterStringType::GraphicString
-                    | CharacterStringType::UniversalString
-                    | CharacterStringType::TeletexString => Err(GeneratorError::new(
+                    // GeneralString carries the Latin-1 Supplement as single octets
+                    Some(CharacterStringType::GeneralString) => {
+                        let bytes = value
+                            .chars()
+                            .map(|c| match c {
+                                '\u{0}'..='\u{7f}' | '\u{a1}'..='\u{ff}' => {
+                                    Some(Literal::u8_unsuffixed(c as u8))
+                                }
+                                _ => None,
+                            })
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(|| {
+                                error!(
+                                    NotYetInplemented,
+                                    "The {:?} value \"{}\" contains characters that GeneralString cannot carry!",
+                                    string_type,
+                                    value
+                                )
+                            })?;
+                        Ok(quote!(GeneralString::from_bytes(&[#(#bytes),*]).unwrap()))
+                    }
+                    Some(CharacterStringType::UniversalString) => {
+                        Ok(quote!(UniversalString::from(String::from(#val))))
+                    }
+                    Some(CharacterStringType::TeletexString) => {
+                        Ok(quote!(TeletexString::from(#val.as_bytes().to_vec())))
+                    }
+                    Some(carrier) => Err(GeneratorError::new(
                         None,
-                        &format!("{:?} values are currently unsupported!", string_type),
+                        &format!("{:?} values are currently unsupported!", carrier),
                         GeneratorErrorType::NotYetInplemented,
                     )),
                 }
@@ -785,6 +1344,7 @@ impl Rasn {
         &self,
         sequence_or_set: &SequenceOrSet,
         parent_name: &String,
+        parent_index: &Option<(Rc<RefCell<ModuleReference>>, usize)>,
     ) -> Result<Vec<TokenStream>, GeneratorError> {
         sequence_or_set
             .members
@@ -797,20 +1357,36 @@ impl Rasn {
                     name: self.inner_name(&m.name, parent_name).to_string(),
                     ty: m.ty.clone(),
                     tag: None,
-                    index: None,
+                    index: parent_index.clone(),
+                    selection: None,
+                    is_alias: false,
                 }))
             })
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Returns whether a component of the given type is represented by a generated inner type.
+    /// `SET OF` components are always represented by inner types, while `SEQUENCE OF` components
+    /// are only represented by inner types if their items are represented by generated types.
     pub(crate) fn needs_unnesting(&self, ty: &ASN1Type) -> bool {
         match ty {
+            // TIME, IRI, RELATIVE-OID and retagged string types are wrapped in delegates with their
+            // universal tags,
+            // EMBEDDED PDV and EXTERNAL types are represented by their associated types
             ASN1Type::Enumerated(_)
             | ASN1Type::Choice(_)
             | ASN1Type::Sequence(_)
-            | ASN1Type::Set(_) => true,
-            ASN1Type::SequenceOf(SequenceOrSetOf { element_type, .. })
-            | ASN1Type::SetOf(SequenceOrSetOf { element_type, .. }) => {
+            | ASN1Type::Set(_)
+            | ASN1Type::SetOf(_)
+            | ASN1Type::Time(_)
+            | ASN1Type::ObjectIdentifierIri(_)
+            | ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                is_relative: true, ..
+            })
+            | ASN1Type::EmbeddedPdv
+            | ASN1Type::External => true,
+            ASN1Type::CharacterString(c) => self.is_retagged_string(c.ty),
+            ASN1Type::SequenceOf(SequenceOrSetOf { element_type, .. }) => {
                 self.needs_unnesting(element_type)
             }
             _ => false,
@@ -821,20 +1397,12 @@ impl Rasn {
         &self,
         choice: &Choice,
         parent_name: &String,
+        parent_index: &Option<(Rc<RefCell<ModuleReference>>, usize)>,
     ) -> Result<Vec<TokenStream>, GeneratorError> {
         choice
             .options
             .iter()
-            .filter(|m| {
-                matches!(
-                    m.ty,
-                    ASN1Type::Enumerated(_)
-                        | ASN1Type::Choice(_)
-                        | ASN1Type::Sequence(_)
-                        | ASN1Type::SequenceOf(_)
-                        | ASN1Type::Set(_)
-                )
-            })
+            .filter(|m| self.needs_unnesting(&m.ty))
             .map(|m| {
                 self.generate_tld(ToplevelDefinition::Type(ToplevelTypeDefinition {
                     parameterization: None,
@@ -842,23 +1410,72 @@ impl Rasn {
                     name: self.inner_name(&m.name, parent_name).to_string(),
                     ty: m.ty.clone(),
                     tag: None,
-                    index: None,
+                    index: parent_index.clone(),
+                    selection: None,
+                    is_alias: false,
                 }))
             })
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Inner types do not belong to a module themselves and inherit the
+    /// tagging environment of their parent's module instead.
+    pub(crate) fn has_automatic_tagging(&self, tld: &ToplevelTypeDefinition) -> bool {
+        match &tld.index {
+            Some((module, _)) => {
+                module.borrow().tagging_environment == TaggingEnvironment::Automatic
+            }
+            None => true,
+        }
+    }
+
+    /// Checks that the alternatives of a `CHOICE` can be distinguished by their tags,
+    /// as required by Rec. ITU-T X.680 (02/2021) § 29.2. Untagged alternatives are
+    /// compared using the `UNIVERSAL` tags of their types, if these are known.
+    pub(crate) fn check_distinct_choice_tags(&self, choice: &Choice) -> Result<(), String> {
+        let mut tags = Vec::<(TagClass, u64, &String)>::new();
+        for option in &choice.options {
+            let tag = match &option.tag {
+                Some(tag) => Some((tag.tag_class, tag.id)),
+                None => option
+                    .ty
+                    .universal_tag()
+                    .map(|id| (TagClass::Universal, id)),
+            };
+            if let Some((class, id)) = tag {
+                if let Some((_, _, other)) = tags.iter().find(|(c, i, _)| *c == class && *i == id) {
+                    return Err(format!(
+                        "Alternatives {other} and {} of CHOICE share the same tag!",
+                        option.name
+                    ));
+                }
+                tags.push((class, id, &option.name));
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn format_new_impl(
         &self,
         name: &TokenStream,
         name_types: Vec<NameType>,
+        feature_gates: &[TokenStream],
     ) -> TokenStream {
-        let args = name_types.iter().map(|nt| {
-            let name = &nt.name;
-            let ty = &nt.typ;
-            quote!(#name: #ty)
-        });
-        let instance = name_types.iter().map(|nt| &nt.name);
+        let args = name_types
+            .iter()
+            .zip(feature_gates)
+            .map(|(nt, feature_gate)| {
+                let name = &nt.name;
+                let ty = &nt.typ;
+                quote!(#feature_gate #name: #ty)
+            });
+        let instance = name_types
+            .iter()
+            .zip(feature_gates)
+            .map(|(nt, feature_gate)| {
+                let name = &nt.name;
+                quote!(#feature_gate #name)
+            });
         quote! {
             impl #name {
                 pub fn new(#(#args),*) -> Self {
@@ -868,58 +1485,2223 @@ impl Rasn {
         }
     }
 
-    pub(crate) fn format_sequence_or_set_of_item_type(
+    /// Formats a `cfg` attribute for every component of a `SEQUENCE`, `SET`, or `CHOICE` type.
+    /// An extension addition that is mapped to a cargo feature in the `extension_features`
+    /// configuration is gated behind that feature together with all subsequent extension additions.
+    /// Builds without the feature thus only lack trailing extension additions, which keeps the
+    /// positions of the remaining extension additions consistent with the ASN.1 definition.
+    pub(crate) fn format_extension_feature_gates<'a>(
         &self,
-        ty: &ASN1Type,
-        first_item: Option<&ASN1Value>,
-    ) -> Result<TokenStream, GeneratorError> {
-        if ty.is_builtin_type() {
-            match ty {
-                ASN1Type::Null => Ok(quote!(())),
-                ASN1Type::Boolean(_) => Ok(quote!(bool)),
-                ASN1Type::Integer(_) => {
-                    match first_item {
-                        Some(ASN1Value::LinkedIntValue { integer_type, .. }) => {
-                            Ok(integer_type.to_token_stream())
+        type_name: &str,
+        first_extension_index: Option<usize>,
+        component_names: impl Iterator<Item = &'a String>,
+    ) -> Vec<TokenStream> {
+        let mut features = Vec::<&String>::new();
+        component_names
+            .enumerate()
+            .map(|(i, name)| {
+                if i >= first_extension_index.unwrap_or(usize::MAX) {
+                    let identifier = name.strip_prefix("ext_group_").unwrap_or(name);
+                    if let Some(feature) = self
+                        .config
+                        .extension_features
+                        .get(&format!("{type_name}.{identifier}"))
+                    {
+                        if !features.contains(&feature) {
+                            features.push(feature);
                         }
-                        _ => Ok(quote!(Integer)), // best effort
                     }
                 }
-                ASN1Type::BitString(_) => Ok(quote!(BitString)),
-                ASN1Type::OctetString(_) => Ok(quote!(OctetString)),
-                ASN1Type::GeneralizedTime(_) => Ok(quote!(GeneralizedTime)),
-                ASN1Type::UTCTime(_) => Ok(quote!(UtcTime)),
-                ASN1Type::ObjectIdentifier(_) => Ok(quote!(ObjectIdentifier)),
-                ASN1Type::CharacterString(cs) => match cs.ty {
-                    CharacterStringType::NumericString => Ok(quote!(NumericString)),
-                    CharacterStringType::VisibleString => Ok(quote!(VisibleString)),
-                    CharacterStringType::IA5String => Ok(quote!(IA5String)),
-                    CharacterStringType::UTF8String => Ok(quote!(UTF8String)),
-                    CharacterStringType::BMPString => Ok(quote!(BMPString)),
-                    CharacterStringType::PrintableString => Ok(quote!(PrintableString)),
-                    CharacterStringType::GeneralString => Ok(quote!(GeneralString)),
-                    CharacterStringType::VideotexString
-                    | CharacterStringType::GraphicString
-                    | CharacterStringType::UniversalString
-                    | CharacterStringType::TeletexString => Err(GeneratorError::new(
-                        None,
-                        &format!("{:?} values are currently unsupported!", cs.ty),
-                        GeneratorErrorType::NotYetInplemented,
-                    )),
-                },
-                _ => Ok(self.to_rust_title_case(&ty.as_str())),
+                match features.as_slice() {
+                    [] => TokenStream::new(),
+                    [feature] => quote!(#[cfg(feature = #feature)]),
+                    _ => quote!(#[cfg(all(#(feature = #features),*))]),
+                }
+            })
+            .collect()
+    }
+
+    /// Formats the definition of a module's `Visit` trait.
+    /// The `Visit` traits of imported modules are brought into scope
+    /// anonymously, so that imported types can be visited as well.
+    pub(crate) fn format_visit_trait(&self, imports: &[Import]) -> TokenStream {
+        let imported_traits = imports.iter().map(|import| {
+            let module = self.to_rust_snake_case(&import.global_module_reference.module_reference);
+            quote!(use super:: #module::Visit as _;)
+        });
+        quote! {
+            #(#imported_traits)*
+
+            /// Walks the components of a decoded value depth-first.
+            pub trait Visit {
+                /// Calls `visitor` with the identifier and value of every field,
+                /// chosen alternative, and collection element of `self`.
+                fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn core::any::Any));
             }
-        } else {
-            Ok(self.to_rust_title_case(&ty.as_str()))
         }
     }
 
-    /// Resolves the custom syntax declared in an information object class' WITH SYNTAX clause
-    pub(crate) fn resolve_standard_syntax(
-        &self,
-        class: &InformationObjectClass,
-        application: &[InformationObjectField],
-    ) -> Result<(ASN1Value, Vec<(usize, ASN1Type)>), GeneratorError> {
+    pub(crate) fn format_visit_impl(&self, tld: &ToplevelTypeDefinition) -> TokenStream {
+        let name = self.to_rust_title_case(&tld.name);
+        let body = match &tld.ty {
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    seq.extensible,
+                    seq.members.iter().map(|m| &m.name),
+                );
+                let members = seq
+                    .members
+                    .iter()
+                    .zip(feature_gates)
+                    .map(|(m, feature_gate)| {
+                        let field = self.to_rust_field_case(&m.name);
+                        let visit = self.format_visit_component(&m.ty, &m.name, quote!(value));
+                        if (m.is_optional && m.default_value.is_none())
+                            || m.name.starts_with("ext_group_")
+                        {
+                            quote!(#feature_gate if let Some(value) = &self.#field { #visit })
+                        } else {
+                            quote!(#feature_gate { let value = &self.#field; #visit })
+                        }
+                    });
+                quote!(#(#members)*)
+            }
+            ASN1Type::Choice(choice) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    choice.extensible,
+                    choice.options.iter().map(|o| &o.name),
+                );
+                let options = choice
+                    .options
+                    .iter()
+                    .zip(feature_gates)
+                    .map(|(o, feature_gate)| {
+                        let option = self.to_rust_enum_identifier(&o.name);
+                        let visit = self.format_visit_component(&o.ty, &o.name, quote!(value));
+                        quote!(#feature_gate Self::#option(value) => { #visit })
+                    });
+                quote!(match self { #(#options)* })
+            }
+            ASN1Type::SequenceOf(seq_of) | ASN1Type::SetOf(seq_of) => {
+                let visit =
+                    self.format_visit_component(&seq_of.element_type, &tld.name, quote!(value));
+                quote!(for value in self.0.iter() { #visit })
+            }
+            ASN1Type::ElsewhereDeclaredType(_) => quote!(self.0.visit(visitor);),
+            _ => TokenStream::new(),
+        };
+        quote! {
+            impl Visit for #name {
+                fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn core::any::Any)) {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Formats the visit of a single component. Components of generated types are
+    /// visited recursively, while builtin types only descend into collection elements.
+    fn format_visit_component(
+        &self,
+        ty: &ASN1Type,
+        identifier: &str,
+        value: TokenStream,
+    ) -> TokenStream {
+        let descend = match ty {
+            ASN1Type::ElsewhereDeclaredType(_)
+            | ASN1Type::Enumerated(_)
+            | ASN1Type::Choice(_)
+            | ASN1Type::Sequence(_)
+            | ASN1Type::Set(_)
+            | ASN1Type::SetOf(_) => quote!(#value.visit(visitor);),
+            ASN1Type::SequenceOf(_) if self.needs_unnesting(ty) => quote!(#value.visit(visitor);),
+            ASN1Type::SequenceOf(seq_of) => {
+                let visit =
+                    self.format_visit_component(&seq_of.element_type, identifier, quote!(item));
+                quote!(for item in #value.iter() { #visit })
+            }
+            _ => TokenStream::new(),
+        };
+        quote! {
+            visitor(#identifier, #value);
+            #descend
+        }
+    }
+
+    /// Formats the definitions of a module's `Validate` trait and `ConstraintError` type.
+    /// The `Validate` traits of imported modules are brought into scope anonymously,
+    /// and their constraint errors are converted into the module's own `ConstraintError`.
+    pub(crate) fn format_validate_trait(
+        &self,
+        imports: &[Import],
+        with_pattern_matcher: bool,
+    ) -> TokenStream {
+        let imported_traits = imports.iter().map(|import| {
+            let module = self.to_rust_snake_case(&import.global_module_reference.module_reference);
+            quote! {
+                use super:: #module::Validate as _;
+
+                impl From<super:: #module::ConstraintError> for ConstraintError {
+                    fn from(error: super:: #module::ConstraintError) -> Self {
+                        Self {
+                            identifier: error.identifier,
+                            constraint: error.constraint,
+                        }
+                    }
+                }
+            }
+        });
+        let pattern_matcher = if with_pattern_matcher {
+            self.format_pattern_matcher()
+        } else {
+            TokenStream::new()
+        };
+        quote! {
+            #(#imported_traits)*
+
+            /// A violated ASN.1 constraint.
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct ConstraintError {
+                /// ASN.1 identifier of the constrained type or component.
+                pub identifier: &'static str,
+                /// The violated constraint in ASN.1 notation.
+                pub constraint: &'static str,
+            }
+
+            impl core::fmt::Display for ConstraintError {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{} violates constraint {}", self.identifier, self.constraint)
+                }
+            }
+
+            /// Checks the constraints of a value at runtime.
+            pub trait Validate {
+                /// Returns the first violated constraint of `self` or its components.
+                fn validate(&self) -> Result<(), ConstraintError>;
+            }
+
+            #pattern_matcher
+        }
+    }
+
+    /// Formats the pattern matcher that the `Validate` implementations use to check `PATTERN` constraints.
+    /// A pattern is compiled into instructions of a nondeterministic automaton, which is simulated on
+    /// all code points of a string at once, so that the matching time is linear in the string's length.
+    fn format_pattern_matcher(&self) -> TokenStream {
+        quote! {
+            /// An instruction of a compiled `PATTERN` constraint.
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub enum PatternInstruction {
+                /// Consumes a character whose code point lies in one of the ranges,
+                /// or in none of the ranges if the class is negated.
+                Class(&'static [(u32, u32)], bool),
+                /// Continues at both instructions.
+                Split(usize, usize),
+                /// Continues at the instruction.
+                Jump(usize),
+                /// Accepts the string if all of its characters have been consumed.
+                Match,
+            }
+
+            /// Returns `true` if the code points of a string match a compiled `PATTERN` constraint.
+            pub fn matches_pattern(program: &[PatternInstruction], characters: impl Iterator<Item = u32>) -> bool {
+                fn follow(program: &[PatternInstruction], threads: &mut alloc::vec::Vec<usize>, pc: usize) {
+                    if threads.contains(&pc) {
+                        return;
+                    }
+                    threads.push(pc);
+                    match program[pc] {
+                        PatternInstruction::Split(first, second) => {
+                            follow(program, threads, first);
+                            follow(program, threads, second);
+                        }
+                        PatternInstruction::Jump(target) => follow(program, threads, target),
+                        _ => (),
+                    }
+                }
+                let mut threads = alloc::vec::Vec::new();
+                follow(program, &mut threads, 0);
+                for character in characters {
+                    let mut next = alloc::vec::Vec::new();
+                    for pc in threads {
+                        if let PatternInstruction::Class(ranges, negated) = program[pc] {
+                            if ranges.iter().any(|(from, to)| (*from..=*to).contains(&character)) != negated {
+                                follow(program, &mut next, pc + 1);
+                            }
+                        }
+                    }
+                    threads = next;
+                }
+                threads.iter().any(|pc| program[*pc] == PatternInstruction::Match)
+            }
+        }
+    }
+
+    pub(crate) fn format_validate_impl(
+        &self,
+        tld: &ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        let name = self.to_rust_title_case(&tld.name);
+        let body = match &tld.ty {
+            ASN1Type::Integer(int) => self.format_builtin_checks(
+                &tld.ty,
+                &[],
+                int.int_type().is_unbounded(),
+                &tld.name,
+                quote!(value),
+            )?,
+            ASN1Type::BitString(_) | ASN1Type::OctetString(_) | ASN1Type::CharacterString(_) => {
+                self.format_builtin_checks(&tld.ty, &[], false, &tld.name, quote!(value))?
+            }
+            // Items of top-level collections are always represented by generated types
+            ASN1Type::SequenceOf(_) | ASN1Type::SetOf(_) => {
+                let checks =
+                    self.format_builtin_checks(&tld.ty, &[], false, &tld.name, quote!(value))?;
+                quote!(#checks for item in value.iter() { item.validate()?; })
+            }
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    seq.extensible,
+                    seq.members.iter().map(|m| &m.name),
+                );
+                let members = seq
+                    .members
+                    .iter()
+                    .zip(feature_gates)
+                    .map(|(m, feature_gate)| {
+                        let field = self.to_rust_field_case(&m.name);
+                        let identifier = format!("{}.{}", tld.name, m.name);
+                        let checks = self.format_component_checks(
+                            &m.ty,
+                            &m.constraints,
+                            &identifier,
+                            quote!(value),
+                        )?;
+                        Ok(if checks.is_empty() {
+                            checks
+                        } else if (m.is_optional && m.default_value.is_none())
+                            || m.name.starts_with("ext_group_")
+                        {
+                            quote!(#feature_gate if let Some(value) = &self.#field { #checks })
+                        } else {
+                            quote!(#feature_gate { let value = &self.#field; #checks })
+                        })
+                    })
+                    .collect::<Result<Vec<_>, GeneratorError>>()?;
+                quote!(#(#members)*)
+            }
+            ASN1Type::Choice(choice) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    choice.extensible,
+                    choice.options.iter().map(|o| &o.name),
+                );
+                let options = choice
+                    .options
+                    .iter()
+                    .zip(feature_gates)
+                    .map(|(o, feature_gate)| {
+                        let option = self.to_rust_enum_identifier(&o.name);
+                        let identifier = format!("{}.{}", tld.name, o.name);
+                        let checks = self.format_component_checks(
+                            &o.ty,
+                            &o.constraints,
+                            &identifier,
+                            quote!(value),
+                        )?;
+                        Ok(quote!(#feature_gate Self::#option(value) => { #checks }))
+                    })
+                    .collect::<Result<Vec<_>, GeneratorError>>()?;
+                quote!(match self { #(#options)* })
+            }
+            ASN1Type::ElsewhereDeclaredType(dec) => {
+                let enumeral_check = self.format_enumeral_check(
+                    &dec.identifier,
+                    &dec.constraints,
+                    &tld.name,
+                    &quote!(self.0),
+                );
+                quote!(self.0.validate()?; #enumeral_check)
+            }
+            _ => TokenStream::new(),
+        };
+        let body = match &tld.ty {
+            ASN1Type::Integer(_)
+            | ASN1Type::BitString(_)
+            | ASN1Type::OctetString(_)
+            | ASN1Type::CharacterString(_)
+            | ASN1Type::SequenceOf(_)
+            | ASN1Type::SetOf(_)
+                if !body.is_empty() =>
+            {
+                quote!(let value = &self.0; #body)
+            }
+            _ => body,
+        };
+        Ok(quote! {
+            impl Validate for #name {
+                fn validate(&self) -> Result<(), ConstraintError> {
+                    #body
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    /// Formats the constraint checks of a single component. Components of generated types
+    /// are validated recursively, while the constraints of builtin types are checked in place.
+    fn format_component_checks(
+        &self,
+        ty: &ASN1Type,
+        constraints: &[Constraint],
+        identifier: &str,
+        value: TokenStream,
+    ) -> Result<TokenStream, GeneratorError> {
+        match ty {
+            ASN1Type::ElsewhereDeclaredType(dec) => {
+                let mut all_constraints = dec.constraints.clone();
+                all_constraints.extend_from_slice(constraints);
+                let enumeral_check = self.format_enumeral_check(
+                    &dec.identifier,
+                    &all_constraints,
+                    identifier,
+                    &value,
+                );
+                Ok(quote!(#value.validate()?; #enumeral_check))
+            }
+            ASN1Type::Enumerated(_)
+            | ASN1Type::Choice(_)
+            | ASN1Type::Sequence(_)
+            | ASN1Type::Set(_)
+            | ASN1Type::SetOf(_) => Ok(quote!(#value.validate()?;)),
+            ASN1Type::SequenceOf(_) if self.needs_unnesting(ty) => Ok(quote!(#value.validate()?;)),
+            ASN1Type::Integer(int) => {
+                let per_constraints = per_visible_range_constraints(true, &int.constraints)?;
+                let unbounded = self.int_type_token(
+                    per_constraints.min(),
+                    per_constraints.max(),
+                    per_constraints.is_extensible(),
+                ) == "Integer";
+                self.format_builtin_checks(ty, constraints, unbounded, identifier, value)
+            }
+            ASN1Type::SequenceOf(seq_of) => {
+                let mut checks =
+                    self.format_builtin_checks(ty, constraints, false, identifier, value.clone())?;
+                let item_checks = self.format_component_checks(
+                    &seq_of.element_type,
+                    &[],
+                    identifier,
+                    quote!(item),
+                )?;
+                if !item_checks.is_empty() {
+                    checks.append_all(quote!(for item in #value.iter() { #item_checks }));
+                }
+                Ok(checks)
+            }
+            _ => self.format_builtin_checks(ty, constraints, false, identifier, value),
+        }
+    }
+
+    /// Formats the check of a constraint that restricts a reference to an `ENUMERATED` type
+    /// to a subset of its enumerals. Enumerals of indirectly referenced `ENUMERATED` types
+    /// are not checked, since their values are wrapped in the intermediate types.
+    fn format_enumeral_check(
+        &self,
+        type_name: &str,
+        constraints: &[Constraint],
+        identifier: &str,
+        value: &TokenStream,
+    ) -> TokenStream {
+        let enumerals = permitted_enumerals(constraints)
+            .into_iter()
+            .filter(|(enumerated, _)| *enumerated == type_name)
+            .map(|(_, enumeral)| enumeral)
+            .collect::<Vec<_>>();
+        if enumerals.is_empty() {
+            return TokenStream::new();
+        }
+        let ty = self.to_rust_title_case(type_name);
+        let variants = enumerals.iter().map(|e| self.to_rust_enum_identifier(e));
+        let description = format!(
+            "({})",
+            enumerals
+                .iter()
+                .map(|e| e.as_str())
+                .collect::<Vec<_>>()
+                .join(" | ")
+        );
+        quote! {
+            if !matches!(#value, #(#ty::#variants)|*) {
+                return Err(ConstraintError { identifier: #identifier, constraint: #description });
+            }
+        }
+    }
+
+    /// Formats the checks of the PER-visible size, value range, and permitted alphabet constraints
+    /// of a value of a builtin type. Extensible constraints are not checked, since values outside
+    /// of the extension root are valid as well.
+    fn format_builtin_checks(
+        &self,
+        ty: &ASN1Type,
+        additional_constraints: &[Constraint],
+        unbounded_integer: bool,
+        identifier: &str,
+        value: TokenStream,
+    ) -> Result<TokenStream, GeneratorError> {
+        let mut constraints = match ty {
+            ASN1Type::Integer(i) => i.constraints.clone(),
+            ASN1Type::BitString(b) => b.constraints.clone(),
+            ASN1Type::OctetString(o) => o.constraints.clone(),
+            ASN1Type::CharacterString(c) => c.constraints.clone(),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => s.constraints().clone(),
+            _ => return Ok(TokenStream::new()),
+        };
+        constraints.extend_from_slice(additional_constraints);
+        let mut checks = TokenStream::new();
+        if !constraints.is_empty() {
+            let per_constraints =
+                per_visible_range_constraints(matches!(ty, ASN1Type::Integer(_)), &constraints)?;
+            let (min, max) = (per_constraints.min::<i128>(), per_constraints.max::<i128>());
+            if !per_constraints.is_extensible() && (min.is_some() || max.is_some()) {
+                if per_constraints.is_size_constraint() {
+                    if let Some(length) = self.format_length(ty, &value) {
+                        let description = format!("(SIZE({}))", format_asn1_range(min, max));
+                        let conditions = [
+                            min.filter(|min| *min > 0).map(|min| {
+                                let min = Literal::usize_unsuffixed(min as usize);
+                                quote!(length < #min)
+                            }),
+                            max.map(|max| {
+                                let max = Literal::usize_unsuffixed(max as usize);
+                                quote!(length > #max)
+                            }),
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>();
+                        if !conditions.is_empty() {
+                            checks.append_all(quote! {
+                                let length = #length;
+                                if #(#conditions)||* {
+                                    return Err(ConstraintError { identifier: #identifier, constraint: #description });
+                                }
+                            });
+                        }
+                    }
+                } else if matches!(ty, ASN1Type::Integer(_)) {
+                    let description = format!("({})", format_asn1_range(min, max));
+                    let (integer, bound) = integer_comparands(unbounded_integer, &value);
+                    let conditions = [
+                        min.map(|min| {
+                            let min = bound(min);
+                            quote!(#integer < #min)
+                        }),
+                        max.map(|max| {
+                            let max = bound(max);
+                            quote!(#integer > #max)
+                        }),
+                    ]
+                    .into_iter()
+                    .flatten();
+                    checks.append_all(quote! {
+                        if #(#conditions)||* {
+                            return Err(ConstraintError { identifier: #identifier, constraint: #description });
+                        }
+                    });
+                }
+            }
+        }
+        if matches!(ty, ASN1Type::Integer(_)) {
+            checks.append_all(self.format_exclusion_checks(
+                &constraints,
+                unbounded_integer,
+                identifier,
+                &value,
+            ));
+        }
+        if let ASN1Type::CharacterString(c_string) = ty {
+            checks.append_all(self.format_alphabet_check(
+                c_string.ty,
+                &constraints,
+                identifier,
+                &value,
+            )?);
+        }
+        if let ASN1Type::CharacterString(c_string) = ty {
+            checks.append_all(self.format_pattern_checks(
+                c_string.ty,
+                &constraints,
+                identifier,
+                &value,
+            ));
+        }
+        if let ASN1Type::OctetString(_) = ty {
+            if let Some((condition, description)) =
+                octet_string_value_range_condition(&OctetString { constraints }, &value)
+            {
+                checks.append_all(quote! {
+                    if !{ #condition } {
+                        return Err(ConstraintError { identifier: #identifier, constraint: #description });
+                    }
+                });
+            }
+        }
+        Ok(checks)
+    }
+
+    /// Formats the checks of constraints that exclude integers from `ALL` values.
+    /// Exclusions are not PER-visible, so that they do not result in annotations and
+    /// are only enforced by the `Validate` trait. Extensible exclusions are not checked.
+    fn format_exclusion_checks(
+        &self,
+        constraints: &[Constraint],
+        unbounded_integer: bool,
+        identifier: &str,
+        value: &TokenStream,
+    ) -> TokenStream {
+        let (integer, bound) = integer_comparands(unbounded_integer, value);
+        let checks = constraints
+            .iter()
+            .filter_map(excluded_integer_ranges)
+            .filter(|ranges| !ranges.is_empty())
+            .map(|ranges| {
+                let excluded = ranges
+                    .iter()
+                    .map(|(min, max)| format_asn1_range(*min, *max))
+                    .collect::<Vec<_>>();
+                let description = if excluded.len() == 1 {
+                    format!("(ALL EXCEPT {})", excluded[0])
+                } else {
+                    format!("(ALL EXCEPT ({}))", excluded.join(" | "))
+                };
+                let conditions = ranges.iter().map(|(min, max)| match (min, max) {
+                    (Some(min), Some(max)) if min == max => {
+                        let value = bound(*min);
+                        quote!(#integer == #value)
+                    }
+                    (Some(min), Some(max)) => {
+                        let (min, max) = (bound(*min), bound(*max));
+                        quote!((#min..=#max).contains(&#integer))
+                    }
+                    (Some(min), None) => {
+                        let min = bound(*min);
+                        quote!(#integer >= #min)
+                    }
+                    (None, Some(max)) => {
+                        let max = bound(*max);
+                        quote!(#integer <= #max)
+                    }
+                    (None, None) => quote!(true),
+                });
+                quote! {
+                    if #(#conditions)||* {
+                        return Err(ConstraintError { identifier: #identifier, constraint: #description });
+                    }
+                }
+            });
+        quote!(#(#checks)*)
+    }
+
+    fn format_alphabet_check(
+        &self,
+        string_type: CharacterStringType,
+        constraints: &[Constraint],
+        identifier: &str,
+        value: &TokenStream,
+    ) -> Result<TokenStream, GeneratorError> {
+        let Some(characters) = self.format_characters(string_type, value) else {
+            return Ok(TokenStream::new());
+        };
+        let ranges = self.permitted_code_point_ranges(string_type, constraints)?;
+        if ranges.is_empty() {
+            return Ok(TokenStream::new());
+        }
+        let patterns = ranges.iter().map(|(from, to)| {
+            let (from_literal, to_literal) =
+                (Literal::u32_unsuffixed(*from), Literal::u32_unsuffixed(*to));
+            if from == to {
+                quote!(#from_literal)
+            } else {
+                quote!(#from_literal..=#to_literal)
+            }
+        });
+        let quoted = |code_point: u32| {
+            format!(
+                "\"{}\"",
+                char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER)
+            )
+        };
+        let description = format!(
+            "(FROM({}))",
+            ranges
+                .iter()
+                .map(|(from, to)| if from == to {
+                    quoted(*from)
+                } else {
+                    format!("{}..{}", quoted(*from), quoted(*to))
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        );
+        Ok(quote! {
+            if !#characters.all(|c| matches!(c, #(#patterns)|*)) {
+                return Err(ConstraintError { identifier: #identifier, constraint: #description });
+            }
+        })
+    }
+
+    /// Formats the checks of the `PATTERN` constraints of a character string.
+    /// Patterns of extensible element sets are not checked, and neither are patterns
+    /// that use regular expression notation that the compiler does not support.
+    fn format_pattern_checks(
+        &self,
+        string_type: CharacterStringType,
+        constraints: &[Constraint],
+        identifier: &str,
+        value: &TokenStream,
+    ) -> TokenStream {
+        let Some(characters) = self.format_characters(string_type, value) else {
+            return TokenStream::new();
+        };
+        let checks = checked_patterns(constraints)
+            .into_iter()
+            .map(|(pattern, program)| {
+                let description = format!("(PATTERN \"{}\")", pattern.pattern.replace('"', "\"\""));
+                let program = format_pattern_program(&program);
+                quote! {
+                    if !matches_pattern(&[#(#program),*], #characters) {
+                        return Err(ConstraintError { identifier: #identifier, constraint: #description });
+                    }
+                }
+            });
+        quote!(#(#checks)*)
+    }
+
+    /// Returns `true` if the `Validate` implementation of a type checks `PATTERN` constraints,
+    /// so that the module of the type needs to define the pattern matcher.
+    pub(crate) fn has_pattern_checks(&self, ty: &ASN1Type, constraints: &[Constraint]) -> bool {
+        match ty {
+            ASN1Type::CharacterString(c_string) => {
+                let mut all_constraints = c_string.constraints.clone();
+                all_constraints.extend_from_slice(constraints);
+                self.format_characters(c_string.ty, &TokenStream::new())
+                    .is_some()
+                    && !checked_patterns(&all_constraints).is_empty()
+            }
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => seq
+                .members
+                .iter()
+                .any(|m| self.has_pattern_checks(&m.ty, &m.constraints)),
+            ASN1Type::Choice(choice) => choice
+                .options
+                .iter()
+                .any(|o| self.has_pattern_checks(&o.ty, &o.constraints)),
+            ASN1Type::SequenceOf(seq_of) | ASN1Type::SetOf(seq_of) => {
+                self.has_pattern_checks(&seq_of.element_type, &[])
+            }
+            _ => false,
+        }
+    }
+
+    /// Formats an associated constant with the regular expression of a character string's
+    /// `PATTERN` constraint. Character strings that are constrained by more than one pattern
+    /// do not get a constant.
+    pub(crate) fn format_pattern_constant(
+        &self,
+        name: &TokenStream,
+        constraints: &[Constraint],
+    ) -> TokenStream {
+        match pattern_constraints(constraints)[..] {
+            [(pattern, _)] => {
+                let regex = &pattern.pattern;
+                quote! {
+                    impl #name {
+                        /// Regular expression of the `PATTERN` constraint, in the notation of Rec. ITU-T X.680 Annex A.
+                        pub const PATTERN: &'static str = #regex;
+                    }
+                }
+            }
+            _ => TokenStream::new(),
+        }
+    }
+
+    /// Returns the code point ranges of the PER-visible permitted alphabet constraints of a
+    /// character string. The ranges are empty if the alphabet is not constrained.
+    fn permitted_code_point_ranges(
+        &self,
+        string_type: CharacterStringType,
+        constraints: &[Constraint],
+    ) -> Result<Vec<(u32, u32)>, GeneratorError> {
+        let mut permitted_alphabet = PerVisibleAlphabetConstraints::default();
+        for c in constraints {
+            if let Some(mut p) = PerVisibleAlphabetConstraints::try_new(c, string_type)? {
+                permitted_alphabet += &mut p
+            }
+        }
+        Ok(permitted_alphabet.code_point_ranges().to_vec())
+    }
+
+    /// Formats an expression for the length of a value that is subject to a size constraint.
+    fn format_length(&self, ty: &ASN1Type, value: &TokenStream) -> Option<TokenStream> {
+        match ty {
+            ASN1Type::CharacterString(c_string) => match self.string_carrier(c_string.ty) {
+                None if c_string.ty == CharacterStringType::UniversalString => {
+                    Some(quote!(#value.len() / 4))
+                }
+                Some(CharacterStringType::UTF8String | CharacterStringType::UniversalString) => {
+                    Some(quote!(#value.chars().count()))
+                }
+                Some(CharacterStringType::IA5String | CharacterStringType::VisibleString) => {
+                    Some(quote!(#value.as_iso646_bytes().len()))
+                }
+                Some(CharacterStringType::PrintableString | CharacterStringType::NumericString) => {
+                    Some(quote!(#value.as_bytes().len()))
+                }
+                None
+                | Some(CharacterStringType::GeneralString | CharacterStringType::TeletexString) => {
+                    Some(quote!(#value.len()))
+                }
+                Some(CharacterStringType::BMPString) => Some(quote!(#value.to_bytes().len() / 2)),
+                _ => None,
+            },
+            ASN1Type::BitString(_)
+            | ASN1Type::OctetString(_)
+            | ASN1Type::SequenceOf(_)
+            | ASN1Type::SetOf(_) => Some(quote!(#value.len())),
+            _ => None,
+        }
+    }
+
+    /// Formats an iterator over the code points of a character string value.
+    fn format_characters(
+        &self,
+        string_type: CharacterStringType,
+        value: &TokenStream,
+    ) -> Option<TokenStream> {
+        match self.string_carrier(string_type) {
+            None if string_type == CharacterStringType::UniversalString => Some(quote!(#value
+                .chunks(4)
+                .map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]])))),
+            Some(CharacterStringType::UTF8String | CharacterStringType::UniversalString) => {
+                Some(quote!(#value.chars().map(u32::from)))
+            }
+            Some(CharacterStringType::IA5String | CharacterStringType::VisibleString) => {
+                Some(quote!(#value.as_iso646_bytes().iter().map(|b| u32::from(*b))))
+            }
+            Some(CharacterStringType::PrintableString | CharacterStringType::NumericString) => {
+                Some(quote!(#value.as_bytes().iter().map(|b| u32::from(*b))))
+            }
+            None
+            | Some(CharacterStringType::GeneralString | CharacterStringType::TeletexString) => {
+                Some(quote!(#value.iter().map(|b| u32::from(*b))))
+            }
+            Some(CharacterStringType::BMPString) => Some(
+                quote!(#value.to_bytes().chunks(2).map(|c| u32::from(c[0]) << 8 | u32::from(c[1]))),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Formats the `#[allow(...)]` attribute of a generated module.
+    /// Lints that cannot be tokenized are skipped.
+    pub(crate) fn format_allowed_lints(&self) -> TokenStream {
+        let lints = self
+            .config
+            .allowed_lints
+            .iter()
+            .filter_map(|lint| TokenStream::from_str(lint).ok())
+            .filter(|lint| !lint.is_empty())
+            .collect::<Vec<_>>();
+        if lints.is_empty() {
+            TokenStream::new()
+        } else {
+            quote!(#[allow(#(#lints),*)])
+        }
+    }
+
+    /// Formats the definition of the error type that the `FromStr` implementations
+    /// of a module's enumerations and named integers return for unknown identifiers.
+    pub(crate) fn format_parse_identifier_error(&self) -> TokenStream {
+        quote! {
+            /// An unknown ASN.1 identifier.
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct ParseIdentifierError {
+                /// ASN.1 identifier of the parsed type.
+                pub type_name: &'static str,
+                /// The input that does not match any identifier of the type.
+                pub input: alloc::string::String,
+            }
+
+            impl core::fmt::Display for ParseIdentifierError {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{} is not an identifier of {}", self.input, self.type_name)
+                }
+            }
+        }
+    }
+
+    /// Formats the definition of the type that the `present_extensions` methods
+    /// of a module's extensible types return.
+    pub(crate) fn format_extension_set(&self) -> TokenStream {
+        quote! {
+            /// The extension additions that are present in a value, identified by their ASN.1 identifiers.
+            /// Extension addition groups are identified by their first component.
+            #[derive(Debug, Clone, Default, PartialEq, Eq)]
+            pub struct ExtensionSet(pub alloc::vec::Vec<&'static str>);
+
+            impl ExtensionSet {
+                /// Whether the extension addition with the given ASN.1 identifier is present.
+                pub fn contains(&self, identifier: &str) -> bool {
+                    self.0.iter().any(|present| *present == identifier)
+                }
+
+                /// Whether no extension addition is present.
+                pub fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+
+                /// The ASN.1 identifiers of the present extension additions in order of their definition.
+                pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+                    self.0.iter().copied()
+                }
+            }
+        }
+    }
+
+    /// Formats a `present_extensions` method for extensible `SEQUENCE` and `SET` types with
+    /// extension additions. Optional extension additions and extension addition groups are
+    /// present if their fields are `Some`. Mandatory extension additions and extension additions
+    /// with a `DEFAULT` value always hold a value after decoding and are thus always present.
+    pub(crate) fn format_extension_presence_impl(
+        &self,
+        tld: &ToplevelTypeDefinition,
+    ) -> TokenStream {
+        let (ASN1Type::Sequence(seq) | ASN1Type::Set(seq)) = &tld.ty else {
+            return TokenStream::new();
+        };
+        let Some(first_extension_index) = seq.extensible.filter(|index| *index < seq.members.len())
+        else {
+            return TokenStream::new();
+        };
+        let name = self.to_rust_title_case(&tld.name);
+        let feature_gates = self.format_extension_feature_gates(
+            &tld.name,
+            seq.extensible,
+            seq.members.iter().map(|m| &m.name),
+        );
+        let additions = seq
+            .members
+            .iter()
+            .zip(feature_gates)
+            .skip(first_extension_index)
+            .map(|(m, feature_gate)| {
+                let field = self.to_rust_field_case(&m.name);
+                let identifier = m.name.strip_prefix("ext_group_").unwrap_or(&m.name);
+                if (m.is_optional && m.default_value.is_none()) || m.name.starts_with("ext_group_")
+                {
+                    quote! {
+                        #feature_gate
+                        if self.#field.is_some() {
+                            present.push(#identifier);
+                        }
+                    }
+                } else {
+                    quote! {
+                        #feature_gate
+                        present.push(#identifier);
+                    }
+                }
+            });
+        quote! {
+            impl #name {
+                /// Returns the extension additions that are present in the value.
+                pub fn present_extensions(&self) -> ExtensionSet {
+                    let mut present = alloc::vec::Vec::new();
+                    #(#additions)*
+                    ExtensionSet(present)
+                }
+            }
+        }
+    }
+
+    /// Collects the pairs of distinctly named, structurally identical top-level types of each module,
+    /// see [super::Config::generate_structural_conversions].
+    pub(crate) fn collect_compatible_types(&mut self, tlds: &[ToplevelDefinition]) {
+        let mut modules = BTreeMap::<String, Vec<&ToplevelTypeDefinition>>::new();
+        for tld in tlds {
+            if let ToplevelDefinition::Type(t) = tld {
+                if t.parameterization.is_none() {
+                    let module = t
+                        .index
+                        .as_ref()
+                        .map(|(m, _)| m.borrow().name.clone())
+                        .unwrap_or_default();
+                    modules.entry(module).or_default().push(t);
+                }
+            }
+        }
+        for types in modules.values() {
+            for (i, a) in types.iter().enumerate() {
+                for b in &types[i + 1..] {
+                    if a.name != b.name && a.is_structurally_equal(b) {
+                        self.add_compatible_types(&a.name, &b.name, &a.ty);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Registers two structurally identical types of the given definition as compatible,
+    /// together with their corresponding generated inner types. Types whose components
+    /// are gated behind different cargo features are skipped.
+    fn add_compatible_types(&mut self, a: &str, b: &str, ty: &ASN1Type) {
+        let gates = |name| match ty {
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => self.format_extension_feature_gates(
+                name,
+                seq.extensible,
+                seq.members.iter().map(|m| &m.name),
+            ),
+            ASN1Type::Choice(choice) => self.format_extension_feature_gates(
+                name,
+                choice.extensible,
+                choice.options.iter().map(|o| &o.name),
+            ),
+            _ => vec![],
+        };
+        if gates(a)
+            .iter()
+            .map(ToString::to_string)
+            .ne(gates(b).iter().map(ToString::to_string))
+        {
+            return;
+        }
+        let a = self.to_rust_title_case(a).to_string();
+        let b = self.to_rust_title_case(b).to_string();
+        let inner_types: Vec<(String, String, &ASN1Type)> = match ty {
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => seq
+                .members
+                .iter()
+                .filter(|m| self.needs_unnesting(&m.ty))
+                .map(|m| {
+                    (
+                        self.inner_name(&m.name, &a).to_string(),
+                        self.inner_name(&m.name, &b).to_string(),
+                        &m.ty,
+                    )
+                })
+                .collect(),
+            ASN1Type::Choice(choice) => choice
+                .options
+                .iter()
+                .filter(|o| self.needs_unnesting(&o.ty))
+                .map(|o| {
+                    (
+                        self.inner_name(&o.name, &a).to_string(),
+                        self.inner_name(&o.name, &b).to_string(),
+                        &o.ty,
+                    )
+                })
+                .collect(),
+            ASN1Type::SequenceOf(seq_of) | ASN1Type::SetOf(seq_of)
+                if !matches!(*seq_of.element_type, ASN1Type::ElsewhereDeclaredType(_)) =>
+            {
+                vec![(
+                    String::from(builder::INNER_ARRAY_LIKE_PREFIX) + &a,
+                    String::from(builder::INNER_ARRAY_LIKE_PREFIX) + &b,
+                    &seq_of.element_type,
+                )]
+            }
+            _ => vec![],
+        };
+        for (inner_a, inner_b, inner_ty) in inner_types {
+            self.add_compatible_types(&inner_a, &inner_b, inner_ty);
+        }
+        self.compatible_types
+            .entry(a.clone())
+            .or_default()
+            .insert(b.clone());
+        self.compatible_types.entry(b).or_default().insert(a);
+    }
+
+    /// Formats `From` implementations that convert the structurally identical types of
+    /// a type into the type, see [super::Config::generate_structural_conversions].
+    /// Components that are represented by generated inner types are converted recursively.
+    pub(crate) fn format_structural_conversions(
+        &self,
+        tld: &ToplevelTypeDefinition,
+    ) -> TokenStream {
+        let name = self.to_rust_title_case(&tld.name);
+        let Some(compatible_types) = self.compatible_types.get(&name.to_string()) else {
+            return TokenStream::new();
+        };
+        let impls = compatible_types.iter().map(|other| {
+            let other = format_ident!("{other}");
+            let body = match &tld.ty {
+                ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => {
+                    let feature_gates = self.format_extension_feature_gates(
+                        &tld.name,
+                        seq.extensible,
+                        seq.members.iter().map(|m| &m.name),
+                    );
+                    let fields = seq
+                        .members
+                        .iter()
+                        .zip(feature_gates)
+                        .map(|(m, feature_gate)| {
+                            let field = self.to_rust_field_case(&m.name);
+                            if !self.needs_unnesting(&m.ty) {
+                                quote!(#feature_gate #field: value.#field)
+                            } else if (m.is_optional && m.default_value.is_none())
+                                || m.name.starts_with("ext_group_")
+                            {
+                                quote!(#feature_gate #field: value.#field.map(Into::into))
+                            } else {
+                                quote!(#feature_gate #field: value.#field.into())
+                            }
+                        });
+                    quote!(Self { #(#fields),* })
+                }
+                ASN1Type::Choice(choice) => {
+                    let feature_gates = self.format_extension_feature_gates(
+                        &tld.name,
+                        choice.extensible,
+                        choice.options.iter().map(|o| &o.name),
+                    );
+                    let options = choice.options.iter().zip(feature_gates).map(
+                        |(o, feature_gate)| {
+                            let option = self.to_rust_enum_identifier(&o.name);
+                            let inner = if self.needs_unnesting(&o.ty) {
+                                quote!(inner.into())
+                            } else {
+                                quote!(inner)
+                            };
+                            quote!(#feature_gate #other::#option(inner) => Self::#option(#inner),)
+                        },
+                    );
+                    quote!(match value { #(#options)* })
+                }
+                ASN1Type::Enumerated(enumerated) => {
+                    let enumerals = enumerated
+                        .members
+                        .iter()
+                        .map(|e| self.to_rust_enum_identifier(&e.name));
+                    quote!(match value { #(#other::#enumerals => Self::#enumerals,)* })
+                }
+                ASN1Type::SequenceOf(seq_of) | ASN1Type::SetOf(seq_of)
+                    if !matches!(*seq_of.element_type, ASN1Type::ElsewhereDeclaredType(_)) =>
+                {
+                    quote!(Self(value.0.into_iter().map(Into::into).collect()))
+                }
+                _ => quote!(Self(value.0)),
+            };
+            quote! {
+                impl From<#other> for #name {
+                    fn from(value: #other) -> Self {
+                        #body
+                    }
+                }
+            }
+        });
+        quote!(#(#impls)*)
+    }
+
+    /// Formats a function that looks up the ASN.1 identifier of the named `OBJECT IDENTIFIER`
+    /// values of a module. Values whose arcs are not all numbered are skipped, and values that
+    /// share their arcs with a preceding value are only reachable by the preceding value's name.
+    pub(crate) fn format_oid_name_lookup(&self, tlds: &[ToplevelDefinition]) -> TokenStream {
+        let mut arms: Vec<(Vec<u32>, &str)> = vec![];
+        for tld in tlds {
+            let ToplevelDefinition::Value(value) = tld else {
+                continue;
+            };
+            let oid = match &value.value {
+                ASN1Value::ObjectIdentifier(oid) => oid,
+                ASN1Value::LinkedNestedValue { value: inner, .. } => match &**inner {
+                    ASN1Value::ObjectIdentifier(oid) => oid,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let Some(arcs) = oid
+                .0
+                .iter()
+                .map(|arc| arc.number.and_then(|n| u32::try_from(n).ok()))
+                .collect::<Option<Vec<u32>>>()
+            else {
+                continue;
+            };
+            if !arms.iter().any(|(a, _)| *a == arcs) {
+                arms.push((arcs, &value.name));
+            }
+        }
+        if arms.is_empty() {
+            return TokenStream::new();
+        }
+        let (arcs, names): (Vec<_>, Vec<_>) = arms
+            .into_iter()
+            .map(|(arcs, name)| {
+                let arcs = arcs.into_iter().map(Literal::u32_unsuffixed);
+                (quote!([#(#arcs),*]), name)
+            })
+            .unzip();
+        quote! {
+            /// Returns the ASN.1 identifier of an object identifier defined in this module.
+            pub fn oid_name(oid: &Oid) -> Option<&'static str> {
+                match &**oid {
+                    #(#arcs => Some(#names),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Formats `Display` and `FromStr` implementations that convert enumerations and named
+    /// integers to and from their original ASN.1 identifiers. Integer values without an
+    /// identifier are displayed and parsed in their numeric form.
+    pub(crate) fn format_display_impl(&self, tld: &ToplevelTypeDefinition) -> TokenStream {
+        let (display, from_str) = match &tld.ty {
+            ASN1Type::Enumerated(enumerated) => {
+                let (variants, identifiers): (Vec<_>, Vec<_>) = enumerated
+                    .members
+                    .iter()
+                    .map(|e| (self.to_rust_enum_identifier(&e.name), &e.name))
+                    .unzip();
+                (
+                    quote!(f.write_str(match self { #(Self::#variants => #identifiers,)* })),
+                    quote!(match s { #(#identifiers => Ok(Self::#variants),)* _ => Err(error()) }),
+                )
+            }
+            ASN1Type::Integer(
+                int @ Integer {
+                    distinguished_values: Some(distinguished_values),
+                    ..
+                },
+            ) if !distinguished_values.is_empty() => {
+                let int_type = int.int_type();
+                // Named values outside of the range of the rust type would not compile
+                let (identifiers, values): (Vec<_>, Vec<_>) = distinguished_values
+                    .iter()
+                    .filter(|d| int_type.can_represent(d.value))
+                    .map(|d| (&d.name, d.value))
+                    .unzip();
+                let (value, patterns, named_integers) = if int_type.is_unbounded() {
+                    (
+                        quote!(i128::try_from(&self.0).ok()),
+                        values
+                            .iter()
+                            .map(|v| {
+                                let v = Literal::i128_unsuffixed(*v);
+                                quote!(Some(#v))
+                            })
+                            .collect::<Vec<_>>(),
+                        values
+                            .iter()
+                            .map(|v| {
+                                let v = Literal::i128_suffixed(*v);
+                                quote!(Integer::from(#v))
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                } else {
+                    let literals = values
+                        .iter()
+                        .map(|v| Literal::i128_unsuffixed(*v).to_token_stream())
+                        .collect::<Vec<_>>();
+                    (quote!(self.0), literals.clone(), literals)
+                };
+                (
+                    quote! {
+                        match #value {
+                            #(#patterns => f.write_str(#identifiers),)*
+                            _ => write!(f, "{}", self.0),
+                        }
+                    },
+                    quote! {
+                        match s {
+                            #(#identifiers => Ok(Self(#named_integers)),)*
+                            _ => s.parse().map(Self).map_err(|_| error()),
+                        }
+                    },
+                )
+            }
+            _ => return TokenStream::new(),
+        };
+        let name = self.to_rust_title_case(&tld.name);
+        let type_name = &tld.name;
+        quote! {
+            impl core::fmt::Display for #name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #display
+                }
+            }
+
+            impl core::str::FromStr for #name {
+                type Err = ParseIdentifierError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let error = || ParseIdentifierError {
+                        type_name: #type_name,
+                        input: s.into(),
+                    };
+                    #from_str
+                }
+            }
+        }
+    }
+
+    /// Formats `to_<codec>` and `from_<codec>` methods that wrap the `encode` and `decode`
+    /// functions of the given codec. JER methods take and return strings instead of bytes.
+    pub(crate) fn format_codec_helpers_impl(
+        &self,
+        tld: &ToplevelTypeDefinition,
+        codec: Codec,
+    ) -> TokenStream {
+        let name = self.to_rust_title_case(&tld.name);
+        let module = format_ident!("{}", codec.module_name());
+        let encode = format_ident!("to_{}", codec.module_name());
+        let decode = format_ident!("from_{}", codec.module_name());
+        let (encoded, data) = match codec {
+            Codec::Jer => (quote!(alloc::string::String), quote!(&str)),
+            _ => (quote!(alloc::vec::Vec<u8>), quote!(&[u8])),
+        };
+        quote! {
+            impl #name {
+                pub fn #encode(&self) -> Result<#encoded, rasn::error::EncodeError> {
+                    rasn::#module::encode(self)
+                }
+
+                pub fn #decode(data: #data) -> Result<Self, rasn::error::DecodeError> {
+                    rasn::#module::decode(data)
+                }
+            }
+        }
+    }
+
+    /// Formats a `Debug` implementation that prints values in ASN.1 value notation,
+    /// using the original identifiers of components, alternatives, and enumerals.
+    /// Components of generated types are printed by their own `Debug` implementation.
+    pub(crate) fn format_debug_impl(&self, tld: &ToplevelTypeDefinition) -> TokenStream {
+        let name = self.to_rust_title_case(&tld.name);
+        let body = match &tld.ty {
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    seq.extensible,
+                    seq.members.iter().map(|m| &m.name),
+                );
+                let members = self.format_debug_members(&seq.members, quote!(self), feature_gates);
+                quote! {
+                    let mut separator = "";
+                    f.write_str("{")?;
+                    #(#members)*
+                    f.write_str(" }")
+                }
+            }
+            ASN1Type::Choice(choice) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    choice.extensible,
+                    choice.options.iter().map(|o| &o.name),
+                );
+                let options = choice
+                    .options
+                    .iter()
+                    .zip(feature_gates)
+                    .map(|(o, feature_gate)| {
+                        let option = self.to_rust_enum_identifier(&o.name);
+                        let identifier = format!("{} : ", o.name);
+                        let value = self.format_debug_value(&o.ty, quote!(value));
+                        quote!(#feature_gate Self::#option(value) => { f.write_str(#identifier)?; #value })
+                    });
+                quote! {
+                    match self { #(#options)* }
+                    Ok(())
+                }
+            }
+            ASN1Type::Enumerated(enumerated) => {
+                let (variants, identifiers): (Vec<_>, Vec<_>) = enumerated
+                    .members
+                    .iter()
+                    .map(|e| (self.to_rust_enum_identifier(&e.name), &e.name))
+                    .unzip();
+                quote!(f.write_str(match self { #(Self::#variants => #identifiers,)* }))
+            }
+            ASN1Type::SequenceOf(_) | ASN1Type::SetOf(_) => {
+                // Items are either references or anonymous types, which implement `Debug` themselves
+                let items =
+                    self.format_debug_items(quote!(self.0), quote!(write!(f, "{:?}", item)?;));
+                quote!(#items Ok(()))
+            }
+            ty => {
+                let value = self.format_debug_value(ty, quote!(value));
+                quote! {
+                    let value = &self.0;
+                    #value
+                    Ok(())
+                }
+            }
+        };
+        quote! {
+            impl core::fmt::Debug for #name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Formats the `identifier value` pairs of the components of a `SEQUENCE` or `SET`.
+    /// Absent optional components are omitted, and the components of extension addition
+    /// groups are printed as components of the parent.
+    fn format_debug_members(
+        &self,
+        members: &[SequenceOrSetMember],
+        parent: TokenStream,
+        feature_gates: Vec<TokenStream>,
+    ) -> Vec<TokenStream> {
+        members
+            .iter()
+            .zip(feature_gates)
+            .map(|(m, feature_gate)| {
+                let field = self.to_rust_field_case(&m.name);
+                match &m.ty {
+                    ASN1Type::Sequence(group) if m.name.starts_with("ext_group_") => {
+                        let members = self.format_debug_members(
+                            &group.members,
+                            quote!(group),
+                            vec![TokenStream::new(); group.members.len()],
+                        );
+                        quote!(#feature_gate if let Some(group) = &#parent.#field { #(#members)* })
+                    }
+                    ty => {
+                        let identifier = format!(" {} ", m.name);
+                        let value = self.format_debug_value(ty, quote!(value));
+                        let member = quote! {
+                            f.write_str(separator)?;
+                            f.write_str(#identifier)?;
+                            #value
+                            separator = ",";
+                        };
+                        if m.is_optional && m.default_value.is_none() {
+                            quote!(#feature_gate if let Some(value) = &#parent.#field { #member })
+                        } else {
+                            quote!(#feature_gate { let value = &#parent.#field; #member })
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Formats the statements that print a value of a builtin type in ASN.1 value notation.
+    /// Values of generated types and of types without a dedicated notation are printed
+    /// by their `Debug` implementation.
+    fn format_debug_value(&self, ty: &ASN1Type, value: TokenStream) -> TokenStream {
+        match ty {
+            ASN1Type::Null => quote!(f.write_str("NULL")?;),
+            ASN1Type::Boolean(_) => quote!(f.write_str(if *#value { "TRUE" } else { "FALSE" })?;),
+            ASN1Type::BitString(_) => quote! {
+                f.write_str("'")?;
+                for bit in #value.iter() {
+                    f.write_str(if *bit { "1" } else { "0" })?;
+                }
+                f.write_str("'B")?;
+            },
+            ASN1Type::OctetString(_) => quote! {
+                f.write_str("'")?;
+                for byte in #value.iter() {
+                    write!(f, "{byte:02X}")?;
+                }
+                f.write_str("'H")?;
+            },
+            ASN1Type::ObjectIdentifier(o) if !o.is_relative => quote! {
+                f.write_str("{")?;
+                for arc in #value.iter() {
+                    write!(f, " {arc}")?;
+                }
+                f.write_str(" }")?;
+            },
+            ASN1Type::CharacterString(CharacterString {
+                ty: CharacterStringType::IA5String | CharacterStringType::VisibleString,
+                ..
+            })
+            | ASN1Type::Time(_)
+            | ASN1Type::ObjectIdentifierIri(_) => quote!(write!(f, "\"{}\"", #value)?;),
+            ASN1Type::CharacterString(CharacterString {
+                ty: CharacterStringType::NumericString | CharacterStringType::PrintableString,
+                ..
+            }) => {
+                quote!(write!(f, "{:?}", core::str::from_utf8(#value.as_bytes()).unwrap_or_default())?;)
+            }
+            ASN1Type::GeneralizedTime(_) => {
+                quote!(write!(f, "\"{}\"", #value.naive_utc().format("%Y%m%d%H%M%S%.fZ"))?;)
+            }
+            ASN1Type::UTCTime(_) => quote!(write!(f, "\"{}\"", #value.format("%y%m%d%H%M%SZ"))?;),
+            ASN1Type::SequenceOf(seq_of) if !self.needs_unnesting(ty) => {
+                let item = self.format_debug_value(&seq_of.element_type, quote!(item));
+                self.format_debug_items(value, item)
+            }
+            _ => quote!(write!(f, "{:?}", #value)?;),
+        }
+    }
+
+    /// Formats the statements that print the items of a collection as `{ item, ... }`.
+    fn format_debug_items(&self, collection: TokenStream, item: TokenStream) -> TokenStream {
+        quote! {
+            f.write_str("{")?;
+            for (index, item) in #collection.iter().enumerate() {
+                f.write_str(if index == 0 { " " } else { ", " })?;
+                #item
+            }
+            f.write_str(" }")?;
+        }
+    }
+
+    /// Formats a method that checks whether the value of an OCTET STRING newtype lies within
+    /// the value range constraint of the type. The value range is not PER-visible and
+    /// cannot be expressed by a `rasn` annotation, so that it is only checked at runtime.
+    pub(crate) fn format_octet_string_value_range_check(
+        &self,
+        name: &TokenStream,
+        octet_string: &OctetString,
+    ) -> TokenStream {
+        let Some((condition, description)) =
+            octet_string_value_range_condition(octet_string, &quote!(&self.0))
+        else {
+            return TokenStream::new();
+        };
+        let doc = format!(" Checks whether the value lies within the value range {description}.");
+        quote! {
+            impl #name {
+                #[doc = #doc]
+                pub fn is_in_value_range(&self) -> bool {
+                    #condition
+                }
+            }
+        }
+    }
+
+    /// Formats methods that decode the value that a contents constraint places in a `BIT STRING`
+    /// or `OCTET STRING`, and that encode a value of the contained type as a new string.
+    /// The encoding rules are passed in, since the contents are encoded with the encoding
+    /// rules of the enclosing value unless the constraint specifies them with `ENCODED BY`.
+    /// Contained types that are neither references nor unconstrained built-in types are skipped,
+    /// since they have no rust representation of their own.
+    /// *As defined in Rec. ITU-T X.682 (02/2021) §11*
+    pub(crate) fn format_contained_type_access(
+        &self,
+        name: &TokenStream,
+        constraints: &[Constraint],
+        is_bit_string: bool,
+    ) -> Result<TokenStream, GeneratorError> {
+        let Some((constraint, contained)) = constraints.iter().find_map(|c| match c {
+            Constraint::ContentConstraint(
+                ContentConstraint::Containing(ty)
+                | ContentConstraint::ContainingEncodedBy { containing: ty, .. },
+            ) => Some((c, ty)),
+            _ => None,
+        }) else {
+            return Ok(TokenStream::new());
+        };
+        let contained_type = match contained {
+            ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier,
+                constraints,
+            }) if constraints.is_empty() => self.format_type_reference(identifier)?,
+            ty if !self.needs_unnesting(ty) && ty.constraints().into_iter().all(Vec::is_empty) => {
+                match self.type_to_tokens(ty) {
+                    Ok(tokens) => tokens,
+                    Err(_) => return Ok(TokenStream::new()),
+                }
+            }
+            _ => return Ok(TokenStream::new()),
+        };
+        let (contents, from_contents, kind) = if is_bit_string {
+            (
+                quote!(self.0.as_raw_slice()),
+                quote!(BitString::from_vec(contents)),
+                "bits",
+            )
+        } else {
+            (
+                quote!(&self.0),
+                quote!(OctetString::from(contents)),
+                "octets",
+            )
+        };
+        let notation = constraint.to_asn1_string();
+        let decode_doc =
+            format!(" Decodes the value that the {kind} contain as specified by `{notation}`.");
+        let encode_doc = format!(" Encodes a value as the {kind} that `{notation}` specifies.");
+        Ok(quote! {
+            impl #name {
+                #[doc = #decode_doc]
+                pub fn decode_contained(&self, codec: rasn::Codec) -> Result<#contained_type, rasn::error::DecodeError> {
+                    codec.decode_from_binary(#contents)
+                }
+
+                #[doc = #encode_doc]
+                pub fn encode_contained(value: &#contained_type, codec: rasn::Codec) -> Result<Self, rasn::error::EncodeError> {
+                    codec.encode_to_binary(value).map(|contents| Self(#from_contents))
+                }
+            }
+        })
+    }
+
+    /// Formats an associated constant for every named number of an `INTEGER`.
+    /// Unbounded integers cannot be constructed in a constant context, so their named
+    /// numbers are formatted as associated functions that return the named value instead.
+    /// Named numbers outside of the range of the rust type are skipped.
+    pub(crate) fn format_named_numbers(&self, name: &TokenStream, int: &Integer) -> TokenStream {
+        let named_numbers = match &int.distinguished_values {
+            Some(named_numbers) if !named_numbers.is_empty() => named_numbers,
+            _ => return TokenStream::new(),
+        };
+        let int_type = int.int_type();
+        let items = named_numbers
+            .iter()
+            .filter(|number| int_type.can_represent(number.value))
+            .map(|number| {
+                if int_type.is_unbounded() {
+                    let constructor = self.to_rust_snake_case(&number.name);
+                    let value = Literal::i128_suffixed(number.value);
+                    quote! {
+                        pub fn #constructor() -> Self {
+                            Self(Integer::from(#value))
+                        }
+                    }
+                } else {
+                    let constant = self.to_rust_screaming_snake_case(&number.name);
+                    let value = Literal::i128_unsuffixed(number.value);
+                    quote!(pub const #constant: Self = Self(#value);)
+                }
+            });
+        quote! {
+            impl #name {
+                #(#items)*
+            }
+        }
+    }
+
+    /// Formats an associated constant with the index of every named bit of a `BIT STRING`,
+    /// as well as methods that get and set the named bits.
+    /// Setting a bit beyond the current length of the bit string extends it with zero bits.
+    pub(crate) fn format_named_bits(
+        &self,
+        name: &TokenStream,
+        bit_string: &BitString,
+    ) -> TokenStream {
+        let named_bits = match &bit_string.distinguished_values {
+            Some(named_bits) if !named_bits.is_empty() => named_bits,
+            _ => return TokenStream::new(),
+        };
+        let accessors = named_bits.iter().map(|bit| {
+            let index_name = self.to_rust_screaming_snake_case(&bit.name);
+            let index = Literal::i128_unsuffixed(bit.value);
+            let getter = self.to_rust_snake_case(&bit.name);
+            let setter = format_ident!("set_{}", getter);
+            quote! {
+                pub const #index_name: usize = #index;
+
+                pub fn #getter(&self) -> bool {
+                    self.0.get(Self::#index_name).is_some_and(|bit| *bit)
+                }
+
+                pub fn #setter(&mut self, value: bool) {
+                    if self.0.len() <= Self::#index_name {
+                        self.0.resize(Self::#index_name + 1, false);
+                    }
+                    self.0.set(Self::#index_name, value);
+                }
+            }
+        });
+        quote! {
+            impl #name {
+                #(#accessors)*
+            }
+        }
+    }
+
+    /// Formats `Deref`, `AsRef`, and conversion implementations for newtypes that wrap
+    /// a primitive type. If validation is generated as well, the conversion from the inner
+    /// type is a `TryFrom` implementation that checks the newtype's constraints.
+    pub(crate) fn format_newtype_conversions(
+        &self,
+        tld: &ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        let inner = match &tld.ty {
+            ASN1Type::Boolean(_)
+            | ASN1Type::Integer(_)
+            | ASN1Type::Real(_)
+            | ASN1Type::BitString(_)
+            | ASN1Type::OctetString(_)
+            | ASN1Type::CharacterString(_)
+            | ASN1Type::ObjectIdentifier(_)
+            | ASN1Type::GeneralizedTime(_)
+            | ASN1Type::UTCTime(_)
+            | ASN1Type::Time(_)
+            | ASN1Type::ObjectIdentifierIri(_) => self.type_to_tokens(&tld.ty)?,
+            _ => return Ok(TokenStream::new()),
+        };
+        let name = self.to_rust_title_case(&tld.name);
+        let conversion = if self.config.generate_validation {
+            quote! {
+                impl TryFrom<#inner> for #name {
+                    type Error = ConstraintError;
+
+                    fn try_from(value: #inner) -> Result<Self, Self::Error> {
+                        let value = Self(value);
+                        value.validate()?;
+                        Ok(value)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl From<#inner> for #name {
+                    fn from(value: #inner) -> Self {
+                        Self(value)
+                    }
+                }
+            }
+        };
+        Ok(quote! {
+            impl core::ops::Deref for #name {
+                type Target = #inner;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl AsRef<#inner> for #name {
+                fn as_ref(&self) -> &#inner {
+                    &self.0
+                }
+            }
+
+            #conversion
+        })
+    }
+
+    /// Formats conversions between a type that is assigned a selection type and the CHOICE
+    /// it is selected from. Values of the type are wrapped into the selected alternative, while
+    /// values of the CHOICE are only converted if they hold the selected alternative.
+    /// Types that are represented by a structure of their own are not converted, since the
+    /// alternatives of the CHOICE hold an inner type of the same structure instead.
+    pub(crate) fn format_selection_conversions(&self, tld: &ToplevelTypeDefinition) -> TokenStream {
+        let Some(selection) = &tld.selection else {
+            return TokenStream::new();
+        };
+        if !matches!(
+            tld.ty,
+            ASN1Type::Null
+                | ASN1Type::Boolean(_)
+                | ASN1Type::Integer(_)
+                | ASN1Type::Real(_)
+                | ASN1Type::BitString(_)
+                | ASN1Type::OctetString(_)
+                | ASN1Type::CharacterString(_)
+                | ASN1Type::ObjectIdentifier(_)
+                | ASN1Type::GeneralizedTime(_)
+                | ASN1Type::UTCTime(_)
+                | ASN1Type::Time(_)
+                | ASN1Type::ObjectIdentifierIri(_)
+                | ASN1Type::ElsewhereDeclaredType(_)
+                | ASN1Type::InformationObjectFieldReference(_)
+                | ASN1Type::EmbeddedPdv
+                | ASN1Type::External
+        ) {
+            return TokenStream::new();
+        }
+        let name = self.to_rust_title_case(&tld.name);
+        let choice = self.to_rust_title_case(&selection.choice_name);
+        let option = self.to_rust_enum_identifier(&selection.selected_option);
+        quote! {
+            impl From<#name> for #choice {
+                fn from(value: #name) -> Self {
+                    #choice::#option(value.0)
+                }
+            }
+
+            impl TryFrom<#choice> for #name {
+                type Error = #choice;
+
+                fn try_from(value: #choice) -> Result<Self, Self::Error> {
+                    match value {
+                        #choice::#option(inner) => Ok(Self(inner)),
+                        _ => Err(value),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Formats an `arbitrary::Arbitrary` implementation that generates values within the
+    /// PER-visible size, value range, and permitted alphabet constraints of a type.
+    /// The implementation is only compiled with the `arbitrary` feature of the consuming crate.
+    pub(crate) fn format_arbitrary_impl(
+        &self,
+        tld: &ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        let name = self.to_rust_title_case(&tld.name);
+        let body = match &tld.ty {
+            ASN1Type::Integer(int) => {
+                let value = self.format_arbitrary_delegate_integer(int)?;
+                quote!(Ok(Self(#value)))
+            }
+            ASN1Type::Enumerated(enumerated) => {
+                let variants = enumerated
+                    .members
+                    .iter()
+                    .map(|e| self.to_rust_enum_identifier(&e.name));
+                quote!(Ok(*u.choose(&[#(Self::#variants),*])?))
+            }
+            // Items of top-level collections are always represented by generated types
+            ASN1Type::SequenceOf(seq_of) => {
+                let length = self.format_arbitrary_length(seq_of.constraints())?;
+                quote! {
+                    let length = #length;
+                    let mut items = SequenceOf::new();
+                    for _ in 0..length {
+                        items.push(u.arbitrary()?);
+                    }
+                    Ok(Self(items))
+                }
+            }
+            ASN1Type::SetOf(set_of) => {
+                let length = self.format_arbitrary_length(set_of.constraints())?;
+                quote! {
+                    let length = #length;
+                    let mut items = SetOf::new();
+                    for _ in 0..length {
+                        if !items.insert(u.arbitrary()?) {
+                            return Err(arbitrary::Error::IncorrectFormat);
+                        }
+                    }
+                    Ok(Self(items))
+                }
+            }
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    seq.extensible,
+                    seq.members.iter().map(|m| &m.name),
+                );
+                let members = seq
+                    .members
+                    .iter()
+                    .zip(feature_gates)
+                    .map(|(m, feature_gate)| {
+                        let field = self.to_rust_field_case(&m.name);
+                        let value = self.format_arbitrary_component(&m.ty, &m.constraints)?;
+                        Ok(
+                            if (m.is_optional && m.default_value.is_none())
+                                || m.name.starts_with("ext_group_")
+                            {
+                                quote!(#feature_gate #field: if u.arbitrary()? { Some(#value) } else { None })
+                            } else {
+                                quote!(#feature_gate #field: #value)
+                            },
+                        )
+                    })
+                    .collect::<Result<Vec<_>, GeneratorError>>()?;
+                quote!(Ok(Self { #(#members),* }))
+            }
+            ASN1Type::Choice(choice) => {
+                let feature_gates = self.format_extension_feature_gates(
+                    &tld.name,
+                    choice.extensible,
+                    choice.options.iter().map(|o| &o.name),
+                );
+                // Feature-gated options always form a suffix of the options,
+                // so that the indices of the compiled options are consecutive
+                let ungated_count = feature_gates.iter().filter(|g| g.is_empty()).count();
+                let gated_counts = feature_gates
+                    .iter()
+                    .filter(|g| !g.is_empty())
+                    .map(|feature_gate| quote!(#feature_gate { count += 1; }))
+                    .collect::<Vec<_>>();
+                let options = choice
+                    .options
+                    .iter()
+                    .zip(feature_gates)
+                    .enumerate()
+                    .map(|(index, (o, feature_gate))| {
+                        let option = self.to_rust_enum_identifier(&o.name);
+                        let value = self.format_arbitrary_component(&o.ty, &o.constraints)?;
+                        let index = Literal::usize_unsuffixed(index);
+                        Ok(quote!(#feature_gate #index => Self::#option(#value),))
+                    })
+                    .collect::<Result<Vec<_>, GeneratorError>>()?;
+                let count = Literal::usize_unsuffixed(ungated_count);
+                let count = if gated_counts.is_empty() {
+                    quote!(let count = #count;)
+                } else {
+                    quote!(let mut count = #count; #(#gated_counts)*)
+                };
+                quote! {
+                    #count
+                    Ok(match u.int_in_range(0..=count - 1)? {
+                        #(#options)*
+                        _ => unreachable!(),
+                    })
+                }
+            }
+            ASN1Type::ElsewhereDeclaredType(_) => quote!(Ok(Self(u.arbitrary()?))),
+            ASN1Type::Null => quote!(Ok(Self(()))),
+            ASN1Type::Boolean(_)
+            | ASN1Type::Real(_)
+            | ASN1Type::BitString(_)
+            | ASN1Type::OctetString(_)
+            | ASN1Type::CharacterString(_)
+            | ASN1Type::ObjectIdentifier(_)
+            | ASN1Type::GeneralizedTime(_)
+            | ASN1Type::UTCTime(_)
+            | ASN1Type::Time(_)
+            | ASN1Type::ObjectIdentifierIri(_)
+            | ASN1Type::InformationObjectFieldReference(_)
+            | ASN1Type::EmbeddedPdv
+            | ASN1Type::External => {
+                let value = self.format_arbitrary_builtin(&tld.ty, &[])?;
+                quote!(Ok(Self(#value)))
+            }
+            ASN1Type::ChoiceSelectionType(_) => return Ok(TokenStream::new()),
+        };
+        Ok(self.format_arbitrary_template(name.to_token_stream(), body))
+    }
+
+    /// Wraps the body of an `arbitrary` method in a feature-gated `Arbitrary` implementation.
+    pub(crate) fn format_arbitrary_template(
+        &self,
+        name: TokenStream,
+        body: TokenStream,
+    ) -> TokenStream {
+        quote! {
+            #[cfg(feature = "arbitrary")]
+            impl<'a> arbitrary::Arbitrary<'a> for #name {
+                fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Formats an expression that generates an arbitrary value of a single component.
+    /// Components of generated types delegate to their own `Arbitrary` implementation,
+    /// while values of builtin types are generated in place.
+    pub(crate) fn format_arbitrary_component(
+        &self,
+        ty: &ASN1Type,
+        constraints: &[Constraint],
+    ) -> Result<TokenStream, GeneratorError> {
+        match ty {
+            ASN1Type::ElsewhereDeclaredType(_)
+            | ASN1Type::Enumerated(_)
+            | ASN1Type::Choice(_)
+            | ASN1Type::Sequence(_)
+            | ASN1Type::Set(_)
+            | ASN1Type::SetOf(_) => Ok(quote!(u.arbitrary()?)),
+            ASN1Type::SequenceOf(_) if self.needs_unnesting(ty) => Ok(quote!(u.arbitrary()?)),
+            ASN1Type::Integer(int) => {
+                let per_constraints = per_visible_range_constraints(true, &int.constraints)?;
+                let int_type = self.int_type_token(
+                    per_constraints.min(),
+                    per_constraints.max(),
+                    per_constraints.is_extensible(),
+                );
+                let unbounded = int_type == "Integer";
+                let mut all_constraints = int.constraints.clone();
+                all_constraints.extend_from_slice(constraints);
+                let per_constraints = per_visible_range_constraints(true, &all_constraints)?;
+                Ok(self.format_arbitrary_integer(
+                    int_type.to_token_stream(),
+                    unbounded,
+                    per_constraints.min(),
+                    per_constraints.max(),
+                ))
+            }
+            ASN1Type::SequenceOf(seq_of) => {
+                let mut all_constraints = seq_of.constraints().clone();
+                all_constraints.extend_from_slice(constraints);
+                let length = self.format_arbitrary_length(&all_constraints)?;
+                let item = self.format_arbitrary_component(&seq_of.element_type, &[])?;
+                Ok(quote!({
+                    let length = #length;
+                    let mut items = SequenceOf::new();
+                    for _ in 0..length {
+                        items.push(#item);
+                    }
+                    items
+                }))
+            }
+            _ => self.format_arbitrary_builtin(ty, constraints),
+        }
+    }
+
+    /// Formats an expression that generates an arbitrary value of a builtin type.
+    /// Open types are represented by arbitrary bytes, since their encoding depends on the codec.
+    pub(crate) fn format_arbitrary_builtin(
+        &self,
+        ty: &ASN1Type,
+        additional_constraints: &[Constraint],
+    ) -> Result<TokenStream, GeneratorError> {
+        let with_additional = |constraints: &Vec<Constraint>| {
+            let mut all_constraints = constraints.clone();
+            all_constraints.extend_from_slice(additional_constraints);
+            all_constraints
+        };
+        Ok(match ty {
+            ASN1Type::Null => quote!(()),
+            ASN1Type::Boolean(_) => quote!(u.arbitrary::<bool>()?),
+            ASN1Type::Real(_) => quote!(u.arbitrary::<f64>()?),
+            ASN1Type::BitString(b) => {
+                let length = self.format_arbitrary_length(&with_additional(&b.constraints))?;
+                quote!({
+                    let length = #length;
+                    (0..length)
+                        .map(|_| u.arbitrary::<bool>())
+                        .collect::<arbitrary::Result<BitString>>()?
+                })
+            }
+            ASN1Type::OctetString(o) => {
+                let length = self.format_arbitrary_length(&with_additional(&o.constraints))?;
+                quote!({
+                    let length = #length;
+                    OctetString::from(u.bytes(length)?.to_vec())
+                })
+            }
+            ASN1Type::CharacterString(c_string) => {
+                self.format_arbitrary_string(c_string.ty, &with_additional(&c_string.constraints))?
+            }
+            ASN1Type::Time(_) => {
+                self.format_arbitrary_string(CharacterStringType::VisibleString, &[])?
+            }
+            ASN1Type::ObjectIdentifierIri(_) => {
+                self.format_arbitrary_string(CharacterStringType::UTF8String, &[])?
+            }
+            ASN1Type::ObjectIdentifier(o) if o.is_relative => {
+                quote!(OctetString::from(u.arbitrary::<alloc::vec::Vec<u8>>()?))
+            }
+            ASN1Type::ObjectIdentifier(_) => quote!({
+                let mut arcs = alloc::vec![u.int_in_range(0..=2)?, u.int_in_range(0..=39)?];
+                for _ in 0..u.arbitrary_len::<u32>()? {
+                    arcs.push(u.arbitrary()?);
+                }
+                ObjectIdentifier::new(arcs).ok_or(arbitrary::Error::IncorrectFormat)?
+            }),
+            // UTCTime represents the years 1950 to 2049
+            ASN1Type::UTCTime(_) => quote!(UtcTime::from_timestamp(
+                u.int_in_range(-631152000..=2524607999)?,
+                0
+            )
+            .ok_or(arbitrary::Error::IncorrectFormat)?),
+            ASN1Type::GeneralizedTime(_) => quote!(GeneralizedTime::from(
+                UtcTime::from_timestamp(u.int_in_range(0..=253402300799)?, 0)
+                    .ok_or(arbitrary::Error::IncorrectFormat)?
+            )),
+            ASN1Type::InformationObjectFieldReference(_)
+            | ASN1Type::EmbeddedPdv
+            | ASN1Type::External => quote!(Any::new(u.arbitrary()?)),
+            _ => quote!(u.arbitrary()?),
+        })
+    }
+
+    /// Formats an expression that generates an arbitrary value of the rust integer type that
+    /// represents `int` in delegate structs, i.e. the type determined by `Integer::int_type`.
+    pub(crate) fn format_arbitrary_delegate_integer(
+        &self,
+        int: &Integer,
+    ) -> Result<TokenStream, GeneratorError> {
+        let int_type = int.int_type();
+        let per_constraints = per_visible_range_constraints(true, &int.constraints)?;
+        // Bounds outside of the range of the rust type would not compile
+        Ok(self.format_arbitrary_integer(
+            int_type.to_token_stream(),
+            int_type.is_unbounded(),
+            per_constraints
+                .min()
+                .filter(|min| int_type.can_represent(*min)),
+            per_constraints
+                .max()
+                .filter(|max| int_type.can_represent(*max)),
+        ))
+    }
+
+    /// Formats an expression that generates an arbitrary integer within the given bounds.
+    /// Unbounded integers without explicit bounds are generated within the range of `i64`.
+    fn format_arbitrary_integer(
+        &self,
+        int_type: TokenStream,
+        unbounded: bool,
+        min: Option<i128>,
+        max: Option<i128>,
+    ) -> TokenStream {
+        if unbounded {
+            let min = min.unwrap_or(i64::MIN.into());
+            let max = max.unwrap_or(i64::MAX.into()).max(min);
+            let (min, max) = (Literal::i128_unsuffixed(min), Literal::i128_unsuffixed(max));
+            quote!(Integer::from(u.int_in_range::<i128>(#min..=#max)?))
+        } else {
+            let min = min.map_or(quote!(#int_type::MIN), |min| {
+                Literal::i128_unsuffixed(min).to_token_stream()
+            });
+            let max = max.map_or(quote!(#int_type::MAX), |max| {
+                Literal::i128_unsuffixed(max).to_token_stream()
+            });
+            quote!(u.int_in_range::<#int_type>(#min..=#max)?)
+        }
+    }
+
+    /// Formats an expression for the length of an arbitrary value that satisfies
+    /// the PER-visible size constraint among `constraints`.
+    fn format_arbitrary_length(
+        &self,
+        constraints: &[Constraint],
+    ) -> Result<TokenStream, GeneratorError> {
+        let per_constraints = per_visible_range_constraints(false, &constraints.to_vec())?;
+        let (min, max) = if per_constraints.is_size_constraint() {
+            (
+                per_constraints.min::<usize>(),
+                per_constraints.max::<usize>(),
+            )
+        } else {
+            (None, None)
+        };
+        Ok(match (min.filter(|min| *min > 0), max) {
+            (min, Some(max)) => {
+                let (min, max) = (
+                    Literal::usize_unsuffixed(min.unwrap_or_default()),
+                    Literal::usize_unsuffixed(max),
+                );
+                quote!(u.int_in_range::<usize>(#min..=#max)?)
+            }
+            (Some(min), None) => {
+                let min = Literal::usize_unsuffixed(min);
+                quote!(#min + u.arbitrary_len::<u8>()?)
+            }
+            (None, None) => quote!(u.arbitrary_len::<u8>()?),
+        })
+    }
+
+    /// Formats an expression that generates an arbitrary character string. Characters are
+    /// drawn from the permitted alphabet or, if the alphabet is not constrained, from the
+    /// character set of the string type. Values of the legacy string types other than
+    /// `UniversalString` are restricted to ASCII characters.
+    fn format_arbitrary_string(
+        &self,
+        string_type: CharacterStringType,
+        constraints: &[Constraint],
+    ) -> Result<TokenStream, GeneratorError> {
+        let length = self.format_arbitrary_length(constraints)?;
+        let mut ranges = self.permitted_code_point_ranges(string_type, constraints)?;
+        if ranges.is_empty() {
+            ranges = match string_type {
+                CharacterStringType::UTF8String | CharacterStringType::UniversalString => vec![],
+                CharacterStringType::TeletexString
+                | CharacterStringType::VideotexString
+                | CharacterStringType::GraphicString
+                | CharacterStringType::GeneralString
+                | CharacterStringType::ObjectDescriptor => vec![(0, 127)],
+                _ => code_point_ranges(string_type.character_set().into_values()),
+            };
+        }
+        let literals = |(from, to): &(u32, u32)| {
+            (Literal::u32_unsuffixed(*from), Literal::u32_unsuffixed(*to))
+        };
+        let code_point = match ranges.as_slice() {
+            [] => None,
+            [range] => {
+                let (from, to) = literals(range);
+                Some(quote!(u.int_in_range(#from..=#to)?))
+            }
+            _ => {
+                let (from, to): (Vec<_>, Vec<_>) = ranges.iter().map(literals).unzip();
+                Some(quote!({
+                    let (from, to) = *u.choose(&[#((#from, #to)),*])?;
+                    u.int_in_range(from..=to)?
+                }))
+            }
+        };
+        let character = match code_point {
+            Some(code_point) => {
+                quote!(char::from_u32(#code_point).ok_or(arbitrary::Error::IncorrectFormat)?)
+            }
+            None => quote!(u.arbitrary::<char>()?),
+        };
+        let string = match self.string_carrier(string_type) {
+            None if string_type == CharacterStringType::UniversalString => {
+                quote!(OctetString::from(
+                    string
+                        .chars()
+                        .flat_map(|c| u32::from(c).to_be_bytes())
+                        .collect::<alloc::vec::Vec<u8>>()
+                ))
+            }
+            None => quote!(OctetString::from(string.into_bytes())),
+            Some(CharacterStringType::UTF8String) => quote!(string),
+            Some(CharacterStringType::UniversalString) => quote!(UniversalString::from(string)),
+            Some(CharacterStringType::TeletexString) => {
+                quote!(TeletexString::from(string.into_bytes()))
+            }
+            _ => {
+                let ty = self.string_type(&string_type)?;
+                quote!(#ty::try_from(string).map_err(|_| arbitrary::Error::IncorrectFormat)?)
+            }
+        };
+        Ok(quote!({
+            let length = #length;
+            let mut string = alloc::string::String::new();
+            for _ in 0..length {
+                string.push(#character);
+            }
+            #string
+        }))
+    }
+
+    pub(crate) fn format_sequence_or_set_of_item_type(
+        &self,
+        ty: &ASN1Type,
+        first_item: Option<&ASN1Value>,
+    ) -> Result<TokenStream, GeneratorError> {
+        if ty.is_builtin_type() {
+            match ty {
+                ASN1Type::Null => Ok(quote!(())),
+                ASN1Type::Boolean(_) => Ok(quote!(bool)),
+                ASN1Type::Integer(_) => {
+                    match first_item {
+                        Some(ASN1Value::LinkedIntValue { integer_type, .. }) => {
+                            Ok(integer_type.to_token_stream())
+                        }
+                        _ => Ok(quote!(Integer)), // best effort
+                    }
+                }
+                ASN1Type::BitString(_) => Ok(quote!(BitString)),
+                ASN1Type::OctetString(_) => Ok(quote!(OctetString)),
+                ASN1Type::GeneralizedTime(_) => Ok(quote!(GeneralizedTime)),
+                ASN1Type::UTCTime(_) => Ok(quote!(UtcTime)),
+                ASN1Type::ObjectIdentifier(o) if o.is_relative => Ok(quote!(OctetString)),
+                ASN1Type::ObjectIdentifier(_) => Ok(quote!(ObjectIdentifier)),
+                ASN1Type::CharacterString(cs) => self.string_type(&cs.ty),
+                ASN1Type::Time(_) => Ok(quote!(VisibleString)),
+                ASN1Type::ObjectIdentifierIri(_) => Ok(quote!(Utf8String)),
+                _ => Ok(self.to_rust_title_case(&ty.as_str())),
+            }
+        } else {
+            Ok(self.to_rust_title_case(&ty.as_str()))
+        }
+    }
+
+    /// Resolves the custom syntax declared in an information object class' WITH SYNTAX clause
+    pub(crate) fn resolve_standard_syntax(
+        &self,
+        class: &InformationObjectClass,
+        application: &[InformationObjectField],
+    ) -> Result<(ASN1Value, Vec<(usize, ASN1Type)>), GeneratorError> {
         let mut key = None;
         let mut field_index_map = Vec::<(usize, ASN1Type)>::new();
 
@@ -934,30 +3716,28 @@ impl Rasn {
                 ..Default::default()
             })?;
 
-        let mut appl_iter = application.iter().enumerate();
-        'syntax_matching: for class_field in &class.fields {
-            if let Some((index, field)) = appl_iter.next() {
-                if class_field.identifier.identifier() == field.identifier() {
-                    match field {
-                        InformationObjectField::TypeField(f) => {
-                            field_index_map.push((index, f.ty.clone()));
-                        }
-                        InformationObjectField::FixedValueField(f) => {
-                            if index == key_index {
-                                key = Some(f.value.clone());
-                            }
-                        }
-                        InformationObjectField::ObjectSetField(_) => todo!(),
-                    }
-                } else if !class_field.is_optional {
+        // Fields are indexed by their position in the class, since objects may omit optional fields
+        let mut appl_iter = application.iter().peekable();
+        for (index, class_field) in class.fields.iter().enumerate() {
+            match appl_iter
+                .next_if(|field| class_field.identifier.identifier() == field.identifier())
+            {
+                Some(InformationObjectField::TypeField(f)) => {
+                    field_index_map.push((index, f.ty.clone()));
+                }
+                Some(InformationObjectField::FixedValueField(f)) if index == key_index => {
+                    key = Some(f.value.clone());
+                }
+                Some(InformationObjectField::FixedValueField(_)) => (),
+                Some(InformationObjectField::ObjectSetField(_)) => (),
+                None if !class_field.is_optional && appl_iter.peek().is_some() => {
                     return Err(GeneratorError {
                         top_level_declaration: None,
                         details: "Syntax mismatch while resolving information object.".to_string(),
                         kind: GeneratorErrorType::SyntaxMismatch,
                     });
-                } else {
-                    continue 'syntax_matching;
                 }
+                None => (),
             }
         }
         field_index_map.sort_by(|&(a, _), &(b, _)| a.cmp(&b));
@@ -972,11 +3752,14 @@ impl Rasn {
         }
     }
 
-    const RUST_KEYWORDS: [&'static str; 38] = [
+    const RUST_KEYWORDS: [&'static str; 51] = [
         "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
         "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
         "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
         "true", "type", "unsafe", "use", "where", "while",
+        // reserved for future use
+        "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+        "unsized", "virtual", "yield",
     ];
 
     pub(crate) fn to_rust_snake_case(&self, input: &str) -> Ident {
@@ -1002,13 +3785,42 @@ impl Rasn {
         Ident::new(&name, Span::call_site())
     }
 
+    /// Formats a field identifier according to the configured [CasingStrategy]
+    pub(crate) fn to_rust_field_case(&self, input: &str) -> Ident {
+        match self.config.casing {
+            CasingStrategy::RustIdiomatic => self.to_rust_snake_case(input),
+            _ => Ident::new(&Self::preserve_case(input, "r_"), Span::call_site()),
+        }
+    }
+
+    /// Formats a constant identifier according to the configured [CasingStrategy]
     pub(crate) fn to_rust_const_case(&self, input: &str) -> Ident {
+        match self.config.casing {
+            CasingStrategy::PreserveOriginal => {
+                Ident::new(&Self::preserve_case(input, "R_"), Span::call_site())
+            }
+            _ => self.to_rust_screaming_snake_case(input),
+        }
+    }
+
+    pub(crate) fn to_rust_screaming_snake_case(&self, input: &str) -> Ident {
         Ident::new(
             &self.to_rust_snake_case(input).to_string().to_uppercase(),
             Span::call_site(),
         )
     }
 
+    /// Keeps the spelling of an ASN.1 identifier, only replacing hyphens
+    /// and prefixing identifiers that collide with rust keywords
+    fn preserve_case(input: &str, keyword_prefix: &str) -> String {
+        let input = input.replace('-', "_");
+        if Self::RUST_KEYWORDS.contains(&input.as_str()) {
+            String::from(keyword_prefix) + &input
+        } else {
+            input
+        }
+    }
+
     pub(crate) fn to_rust_enum_identifier(&self, input: &str) -> Ident {
         let mut formatted = format_ident!("{}", input.replace('-', "_"));
         if Self::RUST_KEYWORDS.contains(&input) {
@@ -1017,7 +3829,11 @@ impl Rasn {
         formatted
     }
 
+    /// Formats a type identifier according to the configured [CasingStrategy]
     pub(crate) fn to_rust_title_case(&self, input: &str) -> TokenStream {
+        if self.config.casing != CasingStrategy::RustIdiomatic {
+            return TokenStream::from_str(&Self::preserve_case(input, "R_")).unwrap();
+        }
         let mut input = input.replace('-', "_");
         let input = input.drain(..).fold(String::new(), |mut acc, c| {
             if acc.is_empty() && c.is_lowercase() {
@@ -1082,12 +3898,17 @@ impl ASN1Type {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use quote::quote;
 
-    use crate::intermediate::{
-        constraints::ElementSet,
-        types::{Boolean, Enumeral, Integer},
-        AsnTag,
+    use crate::{
+        generator::rasn::RasnVersion,
+        intermediate::{
+            constraints::ElementSet,
+            types::{Boolean, Enumeral, EnumeralNumbering, Integer},
+            AsnTag,
+        },
     };
 
     use super::*;
@@ -1175,6 +3996,7 @@ mod tests {
                         ]
                     },
                     &"Parent".into(),
+                    &[TokenStream::new(), TokenStream::new()],
                 )
                 .unwrap()
                 .0
@@ -1199,17 +4021,20 @@ mod tests {
                         Enumeral {
                             name: "test-option-1".into(),
                             description: Some("optional comment".into()),
-                            index: 0
+                            index: 0,
+                            numbering: EnumeralNumbering::Explicit,
                         },
                         Enumeral {
                             name: "test-option-2".into(),
                             description: Some("another optional comment".into()),
-                            index: 2
+                            index: 2,
+                            numbering: EnumeralNumbering::Explicit,
                         },
                         Enumeral {
                             name: "test-option-3".into(),
                             description: None,
-                            index: 5
+                            index: 5,
+                            numbering: EnumeralNumbering::Explicit,
                         }
                     ],
                     extensible: Some(2),
@@ -1265,6 +4090,7 @@ mod tests {
                     ]
                 },
                 &"Parent".into(),
+                &[TokenStream::new(), TokenStream::new()],
             )
             .unwrap()
             .to_string(),
@@ -1276,6 +4102,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_extension_feature_gates() {
+        let mut generator = Rasn::default();
+        generator.config.extension_features = BTreeMap::from([
+            ("Parent.member-2".to_string(), "v2".to_string()),
+            ("Parent.member-3".to_string(), "v3".to_string()),
+        ]);
+        let names = ["member-0", "member-1", "ext_group_member-2", "member-3"].map(String::from);
+        assert_eq!(
+            generator
+                .format_extension_feature_gates("Parent", Some(1), names.iter())
+                .iter()
+                .map(|gate| gate.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                String::new(),
+                String::new(),
+                quote!(#[cfg(feature = "v2")]).to_string(),
+                quote!(#[cfg(all(feature = "v2", feature = "v3"))]).to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_distinct_choice_tags() {
+        let generator = Rasn::default();
+        let option = |name: &str, tag: Option<AsnTag>, ty: ASN1Type| ChoiceOption {
+            name: name.into(),
+            tag,
+            ty,
+            constraints: vec![],
+        };
+        let integer = || {
+            ASN1Type::Integer(Integer {
+                distinguished_values: None,
+                constraints: vec![],
+            })
+        };
+        let choice = |options| Choice {
+            extensible: None,
+            constraints: vec![],
+            options,
+        };
+        assert!(generator
+            .check_distinct_choice_tags(&choice(vec![
+                option("a", None, integer()),
+                option(
+                    "b",
+                    None,
+                    ASN1Type::Boolean(Boolean {
+                        constraints: vec![]
+                    })
+                ),
+                option(
+                    "c",
+                    Some(AsnTag {
+                        environment: TaggingEnvironment::Explicit,
+                        tag_class: TagClass::ContextSpecific,
+                        id: 2
+                    }),
+                    integer()
+                ),
+            ]))
+            .is_ok());
+        assert_eq!(
+            generator.check_distinct_choice_tags(&choice(vec![
+                option("a", None, integer()),
+                option("b", None, integer()),
+            ])),
+            Err("Alternatives a and b of CHOICE share the same tag!".to_string())
+        );
+    }
+
     #[test]
     fn formats_linked_value() {
         let generator = Rasn::default();
@@ -1295,6 +4194,26 @@ mod tests {
         )
     }
 
+    #[test]
+    fn formats_special_real_values() {
+        let generator = Rasn::default();
+
+        assert_eq!(
+            generator
+                .value_to_tokens(&ASN1Value::Real(f64::INFINITY), None)
+                .unwrap()
+                .to_string(),
+            quote!(f64::INFINITY).to_string()
+        );
+        assert_eq!(
+            generator
+                .value_to_tokens(&ASN1Value::Real(f64::NEG_INFINITY), None)
+                .unwrap()
+                .to_string(),
+            quote!(f64::NEG_INFINITY).to_string()
+        );
+    }
+
     #[test]
     fn formats_identifier_annotation() {
         let generator = Rasn::default();
@@ -1341,6 +4260,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn omits_identifier_annotation_for_rasn_0_13() {
+        let mut generator = Rasn::default();
+        generator.config.rasn_version = RasnVersion::V0_13;
+
+        assert!(generator
+            .format_identifier_annotation(
+                "original-name",
+                "",
+                &ASN1Type::Boolean(Boolean::default())
+            )
+            .is_empty());
+    }
+
     #[test]
     fn converts_to_snake_case() {
         let generator = Rasn::default();
@@ -1354,4 +4287,96 @@ mod tests {
         assert_eq!(generator.to_rust_snake_case("struct"), "r_struct");
         assert_eq!(generator.to_rust_snake_case("STRUCT"), "r_struct");
     }
+
+    #[test]
+    fn formats_identifiers_by_casing_strategy() {
+        let mut generator = Rasn::default();
+        let names = |generator: &Rasn| {
+            (
+                generator.to_rust_title_case("My-Type").to_string(),
+                generator.to_rust_field_case("fieldName").to_string(),
+                generator.to_rust_const_case("max-Value").to_string(),
+                generator.to_rust_enum_identifier("red-ish").to_string(),
+            )
+        };
+
+        assert_eq!(
+            names(&generator),
+            (
+                "MyType".into(),
+                "field_name".into(),
+                "MAX__VALUE".into(),
+                "red_ish".into()
+            )
+        );
+        generator.config.casing = CasingStrategy::PreserveOriginal;
+        assert_eq!(
+            names(&generator),
+            (
+                "My_Type".into(),
+                "fieldName".into(),
+                "max_Value".into(),
+                "red_ish".into()
+            )
+        );
+        generator.config.casing = CasingStrategy::ScreamingSnakeConstants;
+        assert_eq!(
+            names(&generator),
+            (
+                "My_Type".into(),
+                "fieldName".into(),
+                "MAX__VALUE".into(),
+                "red_ish".into()
+            )
+        );
+        assert_eq!(generator.to_rust_field_case("type"), "r_type");
+    }
+
+    #[test]
+    fn formats_codec_helpers() {
+        let tld = ToplevelTypeDefinition {
+            parameterization: None,
+            comments: String::new(),
+            name: "My-Pdu".into(),
+            ty: ASN1Type::Null,
+            tag: None,
+            index: None,
+            selection: None,
+            is_alias: false,
+        };
+        assert_eq!(
+            Rasn::default()
+                .format_codec_helpers_impl(&tld, Codec::Der)
+                .to_string(),
+            quote! {
+                impl MyPdu {
+                    pub fn to_der(&self) -> Result<alloc::vec::Vec<u8>, rasn::error::EncodeError> {
+                        rasn::der::encode(self)
+                    }
+
+                    pub fn from_der(data: &[u8]) -> Result<Self, rasn::error::DecodeError> {
+                        rasn::der::decode(data)
+                    }
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            Rasn::default()
+                .format_codec_helpers_impl(&tld, Codec::Jer)
+                .to_string(),
+            quote! {
+                impl MyPdu {
+                    pub fn to_jer(&self) -> Result<alloc::string::String, rasn::error::EncodeError> {
+                        rasn::jer::encode(self)
+                    }
+
+                    pub fn from_jer(data: &str) -> Result<Self, rasn::error::DecodeError> {
+                        rasn::jer::decode(data)
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
 }
@@ -119,7 +119,6 @@ impl Backend for Typescript {
                     }
                     ASN1Type::ElsewhereDeclaredType(_) => self.generate_typealias(t),
                     ASN1Type::Choice(_) => self.generate_choice(t),
-                    ASN1Type::Time(_) => unimplemented!("rasn does not support TIME types yet!"),
                     ASN1Type::Real(_) => self.generate_number_like(t),
                     ASN1Type::InformationObjectFieldReference(_)
                     | ASN1Type::EmbeddedPdv
@@ -128,7 +127,9 @@ impl Backend for Typescript {
                     ASN1Type::ObjectIdentifier(_)
                     | ASN1Type::GeneralizedTime(_)
                     | ASN1Type::CharacterString(_)
-                    | ASN1Type::UTCTime(_) => self.generate_string_like(t),
+                    | ASN1Type::UTCTime(_)
+                    | ASN1Type::Time(_)
+                    | ASN1Type::ObjectIdentifierIri(_) => self.generate_string_like(t),
                     ASN1Type::ChoiceSelectionType(_) => Err(GeneratorError {
                         kind: GeneratorErrorType::Asn1TypeMismatch,
                         details: "Choice selection type should have been resolved at this point!"
@@ -137,7 +138,12 @@ impl Backend for Typescript {
                     }),
                 }
             }
-            ToplevelDefinition::Value(v) => self.generate_value(v),
+            ToplevelDefinition::Value(v) => {
+                if v.parameterization.is_some() {
+                    return Ok(String::new());
+                }
+                self.generate_value(v)
+            }
             _ => Ok(String::new()),
         }
     }
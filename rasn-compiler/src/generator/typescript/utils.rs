@@ -25,6 +25,7 @@ pub fn type_to_tokens(ty: &ASN1Type) -> String {
         | ASN1Type::OctetString(_)
         | ASN1Type::CharacterString(_)
         | ASN1Type::Time(_)
+        | ASN1Type::ObjectIdentifierIri(_)
         | ASN1Type::UTCTime(_)
         | ASN1Type::GeneralizedTime(_)
         | ASN1Type::ObjectIdentifier(_) => String::from("string"),
@@ -100,6 +101,18 @@ pub fn value_to_tokens(value: &ASN1Value) -> Result<String, GeneratorError> {
             details: "Unexpectedly encountered unlinked struct-like ASN1 value!".into(),
             ..Default::default()
         }),
+        ASN1Value::ParameterizedValueReference { identifier, .. } => Err(GeneratorError {
+            details: format!(
+                "Unexpectedly encountered unresolved parameterized value reference {identifier}!"
+            ),
+            ..Default::default()
+        }),
+        ASN1Value::Arithmetic { .. } => Err(GeneratorError {
+            details: format!(
+                "Unexpectedly encountered unevaluated arithmetic expression {value:?}!"
+            ),
+            ..Default::default()
+        }),
         ASN1Value::LinkedStructLikeValue(fields) => fields
             .iter()
             .try_fold(String::from("{"), |mut acc, (field, _, val)| {
@@ -116,6 +129,10 @@ pub fn value_to_tokens(value: &ASN1Value) -> Result<String, GeneratorError> {
         ASN1Value::Boolean(b) => Ok(String::from(if *b { "true" } else { "false" })),
         ASN1Value::Integer(i) => Ok(i.to_string()),
         ASN1Value::String(s) => Ok(format!(r#""{s}""#)),
+        ASN1Value::Real(r) if r.is_infinite() && r.is_sign_positive() => {
+            Ok(String::from("Infinity"))
+        }
+        ASN1Value::Real(r) if r.is_infinite() => Ok(String::from("-Infinity")),
         ASN1Value::Real(r) => Ok(r.to_string()),
         ASN1Value::BitString(b) => {
             let value = b.chunks(8).fold(String::new(), |mut value, bits| {
@@ -169,6 +186,7 @@ pub fn value_to_tokens(value: &ASN1Value) -> Result<String, GeneratorError> {
                 s + "\""
             }),
         ASN1Value::Time(_) => todo!(),
+        ASN1Value::LinkedTimeValue(t) => Ok(format!("\"{}\"", t.format("%Y%m%d%H%M%S%.f%z"))),
         ASN1Value::LinkedArrayLikeValue(seq) => seq
             .iter()
             .try_fold(String::from("["), |mut acc, v| {
@@ -191,7 +209,6 @@ pub fn value_to_tokens(value: &ASN1Value) -> Result<String, GeneratorError> {
             value,
         } => Ok(value.to_string()),
         ASN1Value::LinkedCharStringValue(_, value) => Ok(format!(r#""{value}""#)),
-        ASN1Value::All => todo!(),
     }
 }
 
@@ -3,6 +3,7 @@ use internal_macros::EnumDebug;
 use std::error::Error;
 
 use super::{
+    encoding_rules::per_visible::PerVisibleRangeConstraints,
     error::{GrammarError, GrammarErrorType},
     information_object::{InformationObjectFields, ObjectSet},
     ASN1Type, ASN1Value, IntegerType,
@@ -37,23 +38,13 @@ impl Constraint {
     /// Returns the type of integer that should be used in a representation when applying the Constraint
     /// ### Example
     pub fn integer_constraints(&self) -> IntegerType {
-        let (mut min, mut max, mut is_extensible) = (i128::MAX, i128::MIN, false);
-        if let Ok((cmin, cmax, extensible)) = self.unpack_as_value_range() {
-            is_extensible = is_extensible || extensible;
-            if let Some(ASN1Value::Integer(i)) = cmin {
-                min = (*i).min(min);
-            };
-            if let Some(ASN1Value::Integer(i)) = cmax {
-                max = (*i).max(max);
-            };
-        } else if let Ok((val, extensible)) = self.unpack_as_strict_value() {
-            is_extensible = is_extensible || extensible;
-            if let ASN1Value::Integer(i) = val {
-                min = (*i).min(min);
-                max = (*i).max(max);
-            };
+        let Ok(range) = PerVisibleRangeConstraints::try_from(self) else {
+            return IntegerType::Unbounded;
         };
-        if min > max || is_extensible {
+        let (Some(min), Some(max)) = (range.min::<i128>(), range.max::<i128>()) else {
+            return IntegerType::Unbounded;
+        };
+        if range.is_extensible() || range.is_size_constraint() {
             IntegerType::Unbounded
         } else if min >= 0 {
             match max {
@@ -114,6 +105,30 @@ impl Constraint {
             kind: GrammarErrorType::UnpackingError,
         })
     }
+
+    /// Returns `true` if the constraint constrains the components of a structured type,
+    /// i.e. if its element set contains a `WITH COMPONENT` or `WITH COMPONENTS` constraint.
+    pub fn has_inner_subtyping(&self) -> bool {
+        fn in_element(element: &SubtypeElement) -> bool {
+            match element {
+                SubtypeElement::SingleTypeConstraint(_)
+                | SubtypeElement::MultipleTypeConstraints(_)
+                | SubtypeElement::ComponentTypeConstraint(_) => true,
+                SubtypeElement::NestedSet(s) => in_element(&s.base) || in_set(&s.operant),
+                _ => false,
+            }
+        }
+        fn in_set(set: &ElementOrSetOperation) -> bool {
+            match set {
+                ElementOrSetOperation::Element(e) => in_element(e),
+                ElementOrSetOperation::SetOperation(s) => in_element(&s.base) || in_set(&s.operant),
+            }
+        }
+        match self {
+            Constraint::SubtypeConstraint(set) => in_set(&set.set),
+            _ => false,
+        }
+    }
 }
 
 #[cfg_attr(test, derive(EnumDebug))]
@@ -300,15 +315,20 @@ impl From<(ObjectSet, Option<Vec<RelationalConstraint>>)> for TableConstraint {
 pub struct RelationalConstraint {
     pub field_name: String,
     /// The level is null if the field is in the outermost object set of the declaration.
-    /// The level is 1-n counting from the innermost object set of the declaration
+    /// The level is 1-n counting from the innermost object set of the declaration.
+    /// The linker resolves null levels, so that linked levels always count from the innermost level.
     pub level: usize,
+    /// Components nested in the field, e.g. `messageId` in `@header.messageId`
+    pub component_path: Vec<String>,
 }
 
-impl From<(usize, &str)> for RelationalConstraint {
-    fn from(value: (usize, &str)) -> Self {
+impl From<(usize, Vec<&str>)> for RelationalConstraint {
+    fn from(value: (usize, Vec<&str>)) -> Self {
+        let mut components = value.1.into_iter().map(String::from);
         Self {
-            field_name: value.1.into(),
+            field_name: components.next().unwrap_or_default(),
             level: value.0,
+            component_path: components.collect(),
         }
     }
 }
@@ -711,6 +731,9 @@ pub enum MidnightSettings {
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
 pub enum SubtypeElement {
+    /// The set of all values of the constrained type, which only occurs
+    /// on its own or as the base of an exclusion, e.g. `(ALL EXCEPT 0)`.
+    All,
     SingleValue {
         value: ASN1Value,
         extensible: bool,
@@ -729,8 +752,15 @@ pub enum SubtypeElement {
     TypeConstraint(ASN1Type),
     SingleTypeConstraint(InnerTypeConstraint),
     MultipleTypeConstraints(InnerTypeConstraint),
+    /// A constraint on the component type of a `SEQUENCE OF` or `SET OF`, e.g. `(WITH COMPONENT (0..7))`.
+    /// Component constraints that consist of a `WITH COMPONENTS` constraint only are represented
+    /// as [SubtypeElement::MultipleTypeConstraints].
+    ComponentTypeConstraint(Vec<Constraint>),
     PatternConstraint(PatternConstraint),
     UserDefinedConstraint(UserDefinedConstraint),
+    /// A set operation that forms the base of another set operation, but binds tighter
+    /// than it, e.g. a parenthesized union that is intersected with further elements.
+    NestedSet(Box<SetOperation>),
     PropertySettings(PropertySettings), // DurationRange
                                         // TimePointRange
                                         // RecurrenceRange
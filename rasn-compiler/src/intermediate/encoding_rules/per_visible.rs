@@ -1,10 +1,12 @@
 use crate::intermediate::{
-    constraints::{Constraint, ElementOrSetOperation, SetOperation, SetOperator, SubtypeElement},
+    constraints::{
+        Constraint, ElementOrSetOperation, ElementSet, SetOperation, SetOperator, SubtypeElement,
+    },
     error::{GrammarError, GrammarErrorType},
-    types::{Choice, Enumerated},
-    ASN1Type, ASN1Value, CharacterStringType,
+    types::{CharacterString, Choice, Enumerated},
+    ASN1Type, ASN1Value, CharacterStringType, PRINTABLE_STRING_CHARSET,
 };
-use std::{collections::BTreeMap, ops::AddAssign};
+use std::ops::AddAssign;
 
 pub fn to_per_visible(
     constraints: Vec<Constraint>,
@@ -27,7 +29,7 @@ pub fn to_per_visible(
         character_string_type
             .map(|c| {
                 constraints.iter().try_fold(
-                    PerVisibleAlphabetConstraints::default_for(c),
+                    PerVisibleAlphabetConstraints::default(),
                     |mut acc, curr| {
                         if let Some(mut constraints) =
                             PerVisibleAlphabetConstraints::try_new(curr, c)?
@@ -42,10 +44,11 @@ pub fn to_per_visible(
     ))
 }
 
-trait PerVisible {
+pub(crate) trait PerVisible {
     fn per_visible(&self) -> bool;
 }
 
+/// A single character or a range of consecutive characters of a permitted alphabet.
 #[derive(Debug, PartialEq)]
 pub enum CharsetSubset {
     Single(char),
@@ -55,170 +58,315 @@ pub enum CharsetSubset {
     },
 }
 
-#[derive(Debug, PartialEq)]
+/// The effective permitted alphabet of a character string type,
+/// i.e. the characters that its PER-visible constraints permit.
+#[derive(Debug, Default, PartialEq)]
 pub struct PerVisibleAlphabetConstraints {
-    string_type: CharacterStringType,
-    character_by_index: BTreeMap<usize, char>,
-    index_by_character: Option<BTreeMap<char, usize>>,
-    charset_subsets: Vec<CharsetSubset>,
+    /// Sorted, disjoint, and non-adjacent ranges of the permitted code points,
+    /// or `None` if the alphabet is not constrained.
+    code_points: Option<Vec<(u32, u32)>>,
 }
 
 impl PerVisibleAlphabetConstraints {
+    /// Returns the permitted alphabet of a constraint, or `None` if the constraint does not restrict
+    /// the alphabet in a PER-visible way. Only permitted alphabet constraints and contained subtypes
+    /// of constrained character string types restrict the alphabet, and only if they are not extensible.
+    /// *See Rec. ITU-T X.691 (02/2021) §10.3*
     pub fn try_new(
         constraint: &Constraint,
         string_type: CharacterStringType,
     ) -> Result<Option<Self>, GrammarError> {
-        match constraint {
-            Constraint::SubtypeConstraint(c) => match &c.set {
-                ElementOrSetOperation::Element(e) => Self::from_subtype_elem(Some(e), string_type),
-                ElementOrSetOperation::SetOperation(s) => Self::from_subtype_elem(
-                    fold_constraint_set(s, Some(&string_type.character_set()))?.as_ref(),
-                    string_type,
-                ),
-            },
-            _ => Ok(None),
+        let code_points = match constraint {
+            Constraint::SubtypeConstraint(c) if !c.extensible => {
+                visible_alphabet(&c.set, string_type)?
+            }
+            _ => None,
+        };
+        match code_points {
+            Some(code_points) if code_points.is_empty() => Err(GrammarError {
+                details: format!("Permitted alphabet of {constraint:?} is empty"),
+                kind: GrammarErrorType::UnpackingError,
+            }),
+            code_points => Ok(code_points.map(|code_points| Self {
+                code_points: Some(code_points),
+            })),
         }
     }
 
-    fn from_subtype_elem(
-        element: Option<&SubtypeElement>,
-        string_type: CharacterStringType,
-    ) -> Result<Option<Self>, GrammarError> {
-        match element {
-            None => Ok(None),
-            Some(SubtypeElement::PermittedAlphabet(elem_or_set)) => match &**elem_or_set {
-                ElementOrSetOperation::Element(e) => Self::from_subtype_elem(Some(e), string_type),
-                ElementOrSetOperation::SetOperation(s) => Self::from_subtype_elem(
-                    fold_constraint_set(s, Some(&string_type.character_set()))?.as_ref(),
-                    string_type,
-                ),
-            },
-            Some(SubtypeElement::SingleValue { value, extensible }) => match (value, extensible) {
-                (ASN1Value::String(s), false) => {
-                    let mut char_subset = s
-                        .clone()
-                        .chars()
-                        .map(|c| find_char_index(&string_type.character_set(), c).map(|i| (i, c)))
-                        .collect::<Result<Vec<(usize, char)>, _>>()?;
-                    char_subset.sort_by(|(a, _), (b, _)| a.cmp(b));
-                    Ok(Some(PerVisibleAlphabetConstraints {
-                        string_type,
-                        character_by_index: char_subset
-                            .iter()
-                            .map(|(_, c)| *c)
-                            .enumerate()
-                            .collect(),
-                        index_by_character: None,
-                        charset_subsets: s.chars().map(CharsetSubset::Single).collect(),
-                    }))
-                }
-                _ => Ok(None),
-            },
-            Some(SubtypeElement::ValueRange {
-                min,
-                max,
-                extensible,
-            }) => {
-                let char_set = string_type.character_set();
-                if *extensible {
-                    return Ok(None);
-                }
-                let (lower, upper) = match (min, max) {
-                    (Some(ASN1Value::String(min)), Some(ASN1Value::String(max))) => (
-                        find_string_index(min, &char_set)?,
-                        find_string_index(max, &char_set)?,
-                    ),
-                    (None, Some(ASN1Value::String(max))) => (0, find_string_index(max, &char_set)?),
-                    (Some(ASN1Value::String(min)), None) => {
-                        (find_string_index(min, &char_set)?, char_set.len() - 1)
-                    }
-                    _ => (0, char_set.len() - 1),
-                };
-                if lower > upper {
-                    return Err(GrammarError {
-                    details: format!("Invalid range for permitted alphabet: Charset {:?}; Range: {lower}..={upper}", char_set),
-                    kind: GrammarErrorType::UnpackingError
-                  });
-                }
-                Ok(Some(PerVisibleAlphabetConstraints {
-                    string_type,
-                    character_by_index: char_set
-                        .iter()
-                        .filter_map(|(i, c)| (lower..=upper).contains(i).then_some(*c))
-                        .enumerate()
-                        .collect(),
-                    index_by_character: None,
-                    charset_subsets: vec![CharsetSubset::Range {
-                        from: char_set.get(&lower).copied(),
-                        to: char_set.get(&upper).copied(),
-                    }],
-                }))
-            }
-            Some(SubtypeElement::ContainedSubtype {
-                subtype,
-                extensible: _,
-            }) => {
-                if let ASN1Type::CharacterString(c_string) = subtype {
-                    let mut permitted_alphabet =
-                        PerVisibleAlphabetConstraints::default_for(string_type);
-                    for c in &c_string.constraints {
-                        PerVisibleAlphabetConstraints::try_new(c, c_string.ty)?
-                            .map(|mut p| permitted_alphabet += &mut p);
+    /// Returns the ranges of the permitted code points in ascending order.
+    /// The ranges are empty if the alphabet is not constrained.
+    pub fn code_point_ranges(&self) -> &[(u32, u32)] {
+        self.code_points.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the permitted characters in ascending order of their code points.
+    pub fn permitted_characters(&self) -> impl Iterator<Item = char> + '_ {
+        self.code_point_ranges()
+            .iter()
+            .flat_map(|(from, to)| (*from..=*to).filter_map(char::from_u32))
+    }
+
+    /// Returns the permitted alphabet as single characters and ranges of consecutive characters.
+    pub fn charset_subsets(&self) -> Vec<CharsetSubset> {
+        self.code_point_ranges()
+            .iter()
+            .filter_map(|(from, to)| {
+                let (from, to) = (char::from_u32(*from)?, char::from_u32(*to)?);
+                Some(if from == to {
+                    CharsetSubset::Single(from)
+                } else {
+                    CharsetSubset::Range {
+                        from: Some(from),
+                        to: Some(to),
                     }
-                    Ok(Some(permitted_alphabet))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Constraints that are applied one after another restrict the alphabet
+/// to the characters that all of them permit.
+impl AddAssign<&mut PerVisibleAlphabetConstraints> for PerVisibleAlphabetConstraints {
+    fn add_assign(&mut self, rhs: &mut PerVisibleAlphabetConstraints) {
+        self.code_points = match (self.code_points.take(), rhs.code_points.take()) {
+            (Some(lhs), Some(rhs)) => Some(intersection(&lhs, &rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        }
+    }
+}
+
+/// Returns the PER-visible permitted alphabet of an element set, or `None` if the element set does
+/// not restrict the alphabet. Following X.691 §10.3.21, the parts of an intersection that are not
+/// PER-visible are ignored, a union with a part that is not PER-visible is not PER-visible itself,
+/// and `EXCEPT` clauses are ignored altogether.
+fn visible_alphabet(
+    set: &ElementOrSetOperation,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    match set {
+        ElementOrSetOperation::Element(e) => visible_element_alphabet(e, string_type),
+        ElementOrSetOperation::SetOperation(s) => visible_operation_alphabet(s, string_type),
+    }
+}
+
+fn visible_operation_alphabet(
+    set: &SetOperation,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    let base = visible_element_alphabet(&set.base, string_type)?;
+    Ok(match set.operator {
+        SetOperator::Except => base,
+        SetOperator::Intersection => match (base, visible_alphabet(&set.operant, string_type)?) {
+            (Some(base), Some(operant)) => Some(intersection(&base, &operant)),
+            (base, operant) => base.or(operant),
+        },
+        SetOperator::Union => match (base, visible_alphabet(&set.operant, string_type)?) {
+            (Some(base), Some(operant)) => Some(union(&base, &operant)),
+            _ => None,
+        },
+    })
+}
+
+fn visible_element_alphabet(
+    element: &SubtypeElement,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    match element {
+        SubtypeElement::PermittedAlphabet(set) => alphabet(set, string_type),
+        SubtypeElement::ContainedSubtype {
+            subtype: ASN1Type::CharacterString(c_string),
+            extensible: false,
+        } => effective_alphabet(c_string, string_type),
+        SubtypeElement::NestedSet(s) => visible_operation_alphabet(s, string_type),
+        _ => Ok(None),
+    }
+}
+
+/// Returns the effective permitted alphabet of a character string type, restricted to the
+/// character set of `string_type`, or `None` if the alphabet of the type is not constrained.
+fn effective_alphabet(
+    c_string: &CharacterString,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    let mut permitted_alphabet = PerVisibleAlphabetConstraints::default();
+    for c in &c_string.constraints {
+        if let Some(mut p) = PerVisibleAlphabetConstraints::try_new(c, c_string.ty)? {
+            permitted_alphabet += &mut p;
+        }
+    }
+    Ok(permitted_alphabet
+        .code_points
+        .map(|code_points| intersection(&code_points, &character_set(string_type))))
+}
+
+/// Returns the characters of the value set of a permitted alphabet constraint, or `None`
+/// if the value set is extensible or contains elements that do not denote characters.
+fn alphabet(
+    set: &ElementOrSetOperation,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    match set {
+        ElementOrSetOperation::Element(e) => element_alphabet(e, string_type),
+        ElementOrSetOperation::SetOperation(s) => operation_alphabet(s, string_type),
+    }
+}
+
+fn operation_alphabet(
+    set: &SetOperation,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    let (Some(base), Some(operant)) = (
+        element_alphabet(&set.base, string_type)?,
+        alphabet(&set.operant, string_type)?,
+    ) else {
+        return Ok(None);
+    };
+    Ok(Some(match set.operator {
+        SetOperator::Union => union(&base, &operant),
+        SetOperator::Intersection => intersection(&base, &operant),
+        SetOperator::Except => difference(&base, &operant),
+    }))
+}
+
+fn element_alphabet(
+    element: &SubtypeElement,
+    string_type: CharacterStringType,
+) -> Result<Option<Vec<(u32, u32)>>, GrammarError> {
+    let permitted = character_set(string_type);
+    let not_in_character_set = |c: char| GrammarError {
+        details: format!("Character {c:?} is not in the character set of {string_type:?}"),
+        kind: GrammarErrorType::UnpackingError,
+    };
+    match element {
+        SubtypeElement::SingleValue {
+            value: ASN1Value::String(s),
+            extensible: false,
+        } => s
+            .chars()
+            .map(|c| {
+                let code_point = u32::from(c);
+                if intersection(&[(code_point, code_point)], &permitted).is_empty() {
+                    Err(not_in_character_set(c))
                 } else {
-                    Ok(None)
+                    Ok((code_point, code_point))
                 }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|code_points| Some(normalize(code_points))),
+        SubtypeElement::ValueRange {
+            min,
+            max,
+            extensible: false,
+        } => {
+            let bound = |value: &Option<ASN1Value>, default: u32| match value {
+                None => Ok(Some(default)),
+                Some(ASN1Value::String(s)) => match s.chars().collect::<Vec<_>>()[..] {
+                    [c] => Ok(Some(u32::from(c))),
+                    _ => Err(GrammarError {
+                        details: format!(
+                            "Bound {s:?} of a character range is not a single character"
+                        ),
+                        kind: GrammarErrorType::UnpackingError,
+                    }),
+                },
+                _ => Ok(None),
+            };
+            let (Some(lower), Some(upper)) = (
+                bound(min, permitted.first().map_or(0, |r| r.0))?,
+                bound(max, permitted.last().map_or(0, |r| r.1))?,
+            ) else {
+                return Ok(None);
+            };
+            if lower > upper {
+                return Err(GrammarError {
+                    details: format!("Invalid range for permitted alphabet: {min:?}..{max:?}"),
+                    kind: GrammarErrorType::UnpackingError,
+                });
             }
-            _ => Ok(None),
+            Ok(Some(intersection(&[(lower, upper)], &permitted)))
         }
+        SubtypeElement::ContainedSubtype {
+            subtype: ASN1Type::CharacterString(c_string),
+            extensible: false,
+        } => Ok(Some(
+            effective_alphabet(c_string, string_type)?
+                .unwrap_or_else(|| intersection(&character_set(c_string.ty), &permitted)),
+        )),
+        SubtypeElement::NestedSet(s) => operation_alphabet(s, string_type),
+        _ => Ok(None),
     }
+}
 
-    pub fn charset_subsets(&self) -> &Vec<CharsetSubset> {
-        &self.charset_subsets
-    }
-
-    pub fn finalize(&mut self) {
-        self.index_by_character = Some(
-            self.character_by_index
+/// Returns the ranges of the code points of the characters of a character string type.
+fn character_set(string_type: CharacterStringType) -> Vec<(u32, u32)> {
+    match string_type {
+        CharacterStringType::NumericString => vec![(0x20, 0x20), (0x30, 0x39)],
+        CharacterStringType::PrintableString => normalize(
+            PRINTABLE_STRING_CHARSET
                 .iter()
-                .map(|(i, c)| (*c, *i))
+                .map(|c| (u32::from(*c), u32::from(*c)))
                 .collect(),
-        )
+        ),
+        CharacterStringType::VisibleString => vec![(0x20, 0x7E)],
+        CharacterStringType::IA5String => vec![(0, 0x7F)],
+        CharacterStringType::BMPString => vec![(0, 0xD7FF), (0xE000, 0xFFFF)],
+        _ => vec![(0, 0xD7FF), (0xE000, 0x10FFFF)],
     }
+}
 
-    pub fn default_for(string_type: CharacterStringType) -> Self {
-        Self {
-            character_by_index: BTreeMap::new(),
-            string_type,
-            index_by_character: None,
-            charset_subsets: vec![],
+/// Sorts inclusive ranges, drops the empty ones, and merges the ones that overlap or are adjacent.
+fn normalize<T: Copy + Ord + num::CheckedAdd + num::One>(mut ranges: Vec<(T, T)>) -> Vec<(T, T)> {
+    ranges.retain(|(from, to)| from <= to);
+    ranges.sort_unstable();
+    let mut merged: Vec<(T, T)> = vec![];
+    for (from, to) in ranges {
+        match merged.last_mut() {
+            Some((_, last)) if last.checked_add(&T::one()).is_none_or(|next| from <= next) => {
+                *last = (*last).max(to)
+            }
+            _ => merged.push((from, to)),
         }
     }
+    merged
 }
 
-fn find_string_index(
-    value: &String,
-    char_set: &BTreeMap<usize, char>,
-) -> Result<usize, GrammarError> {
-    let as_char = value.chars().next().unwrap();
-    find_char_index(char_set, as_char)
+fn union<T: Copy + Ord + num::CheckedAdd + num::One>(
+    lhs: &[(T, T)],
+    rhs: &[(T, T)],
+) -> Vec<(T, T)> {
+    normalize(lhs.iter().chain(rhs).copied().collect())
 }
 
-fn find_char_index(char_set: &BTreeMap<usize, char>, as_char: char) -> Result<usize, GrammarError> {
-    char_set
-        .iter()
-        .find_map(|(i, c)| (as_char == *c).then_some(*i))
-        .ok_or(GrammarError {
-            details: format!("Character {as_char} is not in char set: {:?}", char_set),
-            kind: GrammarErrorType::UnpackingError,
-        })
+fn intersection<T: Copy + Ord + num::CheckedAdd + num::One>(
+    lhs: &[(T, T)],
+    rhs: &[(T, T)],
+) -> Vec<(T, T)> {
+    normalize(
+        lhs.iter()
+            .flat_map(|(l_from, l_to)| {
+                rhs.iter().filter_map(move |(r_from, r_to)| {
+                    let (from, to) = (*l_from.max(r_from), *l_to.min(r_to));
+                    (from <= to).then_some((from, to))
+                })
+            })
+            .collect(),
+    )
 }
 
-impl AddAssign<&mut PerVisibleAlphabetConstraints> for PerVisibleAlphabetConstraints {
-    fn add_assign(&mut self, rhs: &mut PerVisibleAlphabetConstraints) {
-        self.character_by_index.append(&mut rhs.character_by_index)
+fn difference(lhs: &[(u32, u32)], rhs: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut complement = vec![];
+    let mut next = 0;
+    for (from, to) in normalize(rhs.to_vec()) {
+        if from > next {
+            complement.push((next, from - 1));
+        }
+        next = to + 1;
+    }
+    if next <= 0x10FFFF {
+        complement.push((next, 0x10FFFF));
     }
+    intersection(lhs, &complement)
 }
 
 #[derive(Default)]
@@ -295,83 +443,204 @@ impl TryFrom<&Constraint> for PerVisibleRangeConstraints {
     type Error = GrammarError;
 
     fn try_from(value: &Constraint) -> Result<PerVisibleRangeConstraints, Self::Error> {
-        match value {
-            Constraint::SubtypeConstraint(c) => {
-                let mut per_visible: PerVisibleRangeConstraints = match &c.set {
-                    ElementOrSetOperation::Element(e) => Some(e).try_into(),
-                    ElementOrSetOperation::SetOperation(s) => {
-                        fold_constraint_set(s, None)?.as_ref().try_into()
-                    }
-                }?;
-                if let (PerVisibleRangeConstraints { min, max, .. }, true) =
-                    (&mut per_visible, c.extensible)
-                {
-                    if min.or(*max).is_some() {
-                        per_visible.extensible = true;
-                    }
-                }
-                Ok(per_visible)
-            }
-            _ => Ok(Self::default()),
-        }
+        EffectiveConstraint::of_constraint(value).map_or(
+            Ok(Self::default()),
+            EffectiveConstraint::into_range_constraints,
+        )
     }
 }
 
 impl TryFrom<Option<&SubtypeElement>> for PerVisibleRangeConstraints {
     type Error = GrammarError;
     fn try_from(value: Option<&SubtypeElement>) -> Result<PerVisibleRangeConstraints, Self::Error> {
-        match value {
-            Some(SubtypeElement::PermittedAlphabet(_)) | None => Ok(Self::default()),
-            Some(SubtypeElement::SingleValue { value, extensible }) => {
-                let val = value.unwrap_as_integer().ok();
-                Ok(Self {
-                    min: val,
-                    max: val,
+        value.and_then(EffectiveConstraint::of_element).map_or(
+            Ok(Self::default()),
+            EffectiveConstraint::into_range_constraints,
+        )
+    }
+}
+
+/// The effective constraint of an element set on the values and on the sizes of a type.
+/// Dimensions that are not restricted are `None`, and the bounds `i128::MIN` and `i128::MAX`
+/// of a range stand for `MIN` and `MAX`.
+#[derive(Debug, Default)]
+struct EffectiveConstraint {
+    /// Sorted, disjoint, and non-adjacent ranges of the permitted values
+    values: Option<Vec<(i128, i128)>>,
+    /// Sorted, disjoint, and non-adjacent ranges of the permitted sizes
+    sizes: Option<Vec<(i128, i128)>>,
+    extensible: bool,
+}
+
+impl EffectiveConstraint {
+    fn of_constraint(constraint: &Constraint) -> Option<Self> {
+        match constraint {
+            Constraint::SubtypeConstraint(c) => Self::of_set(&c.set).map(|mut effective| {
+                effective.extensible |=
+                    c.extensible && (effective.values.is_some() || effective.sizes.is_some());
+                effective
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the PER-visible effective constraint of an element set,
+    /// or `None` if the element set is not PER-visible.
+    /// ### Reference in X691
+    /// >* _10.3.21 If a constraint that is PER-visible is part of an INTERSECTION construction,
+    /// > then the resulting constraint is PER-visible, and consists of the INTERSECTION of
+    /// > all PER-visible parts (with the non-PER-visible parts ignored).
+    /// > If a constraint which is not PER-visible is part of a UNION construction,
+    /// > then the resulting constraint is not PER-visible.
+    /// > If a constraint has an EXCEPT clause, the EXCEPT and the following value set is completely ignored,
+    /// > whether the value set following the EXCEPT is PER-visible or not._
+    fn of_set(set: &ElementOrSetOperation) -> Option<Self> {
+        match set {
+            ElementOrSetOperation::Element(e) => Self::of_element(e),
+            ElementOrSetOperation::SetOperation(s) => Self::of_operation(s),
+        }
+    }
+
+    fn of_operation(set: &SetOperation) -> Option<Self> {
+        let base = Self::of_element(&set.base);
+        // In `1, ..., 0 | 2..MAX`, the operations after the extension marker
+        // are extension additions that do not contribute to the root
+        if matches!(
+            set.base,
+            SubtypeElement::SingleValue {
+                extensible: true,
+                ..
+            } | SubtypeElement::ValueRange {
+                extensible: true,
+                ..
+            }
+        ) {
+            return base;
+        }
+        match set.operator {
+            SetOperator::Except => base,
+            SetOperator::Intersection => match (base, Self::of_set(&set.operant)) {
+                (Some(base), Some(operant)) => Some(base.intersect(operant)),
+                (base, operant) => base.or(operant),
+            },
+            SetOperator::Union => Some(base?.unite(Self::of_set(&set.operant)?)),
+        }
+    }
+
+    fn of_element(element: &SubtypeElement) -> Option<Self> {
+        match element {
+            SubtypeElement::All => Some(Self::default()),
+            SubtypeElement::SingleValue { value, extensible } => {
+                let value = value.unwrap_as_integer().ok()?;
+                Some(Self {
+                    values: Some(vec![(value, value)]),
+                    sizes: None,
                     extensible: *extensible,
-                    is_size_constraint: false,
                 })
             }
-            Some(SubtypeElement::ValueRange {
+            SubtypeElement::ValueRange {
                 min,
                 max,
                 extensible,
-            }) => Ok(Self {
-                min: min.as_ref().and_then(|i| i.unwrap_as_integer().ok()),
-                max: max.as_ref().and_then(|i| i.unwrap_as_integer().ok()),
-                extensible: *extensible,
-                is_size_constraint: false,
-            }),
-            Some(SubtypeElement::SizeConstraint(s)) => match &**s {
-                ElementOrSetOperation::Element(e) => <Option<&SubtypeElement> as TryInto<
-                    PerVisibleRangeConstraints,
-                >>::try_into(Some(e))
-                .map(|mut c| {
-                    c.is_size_constraint = true;
-                    c
-                }),
-                ElementOrSetOperation::SetOperation(s) => {
-                    <Option<&SubtypeElement> as TryInto<PerVisibleRangeConstraints>>::try_into(
-                        fold_constraint_set(s, None)?.as_ref(),
-                    )
-                    .map(|mut c| {
-                        c.is_size_constraint = true;
-                        c
-                    })
-                }
-            },
-            Some(SubtypeElement::ContainedSubtype {
+            } => {
+                let bound = |value: &Option<ASN1Value>, unbounded| {
+                    value
+                        .as_ref()
+                        .and_then(|v| v.unwrap_as_integer().ok())
+                        .unwrap_or(unbounded)
+                };
+                Some(Self {
+                    values: Some(normalize(vec![(
+                        bound(min, i128::MIN),
+                        bound(max, i128::MAX),
+                    )])),
+                    sizes: None,
+                    extensible: *extensible,
+                })
+            }
+            // A size constraint restricts the sizes of a type even if its bounds are unknown
+            SubtypeElement::SizeConstraint(s) => {
+                let size = Self::of_set(s).unwrap_or_default();
+                Some(Self {
+                    values: None,
+                    sizes: Some(match size.values {
+                        Some(sizes) => intersection(&sizes, &[(0, i128::MAX)]),
+                        None => vec![(0, i128::MAX)],
+                    }),
+                    extensible: size.extensible,
+                })
+            }
+            SubtypeElement::ContainedSubtype {
                 subtype,
-                extensible: _,
-            }) => per_visible_range_constraints(
-                matches!(subtype, ASN1Type::Integer(_)),
-                subtype.constraints().unwrap_or(&mut vec![]),
-            ),
-            x => {
-                println!("{x:?}");
-                unreachable!()
+                extensible,
+            } => {
+                let mut contained = Self::default();
+                for c in subtype.constraints().into_iter().flatten() {
+                    if let Some(effective) = Self::of_constraint(c) {
+                        contained = contained.intersect(effective);
+                    }
+                }
+                contained.extensible |= *extensible;
+                Some(contained)
             }
+            SubtypeElement::NestedSet(s) => Self::of_operation(s),
+            _ => None,
+        }
+    }
+
+    fn intersect(self, rhs: Self) -> Self {
+        let intersect = |lhs: Option<Vec<_>>, rhs: Option<Vec<_>>| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(intersection(&lhs, &rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        };
+        Self {
+            values: intersect(self.values, rhs.values),
+            sizes: intersect(self.sizes, rhs.sizes),
+            extensible: self.extensible || rhs.extensible,
+        }
+    }
+
+    fn unite(self, rhs: Self) -> Self {
+        let unite = |lhs: Option<Vec<_>>, rhs: Option<Vec<_>>| Some(union(&lhs?, &rhs?));
+        Self {
+            values: unite(self.values, rhs.values),
+            sizes: unite(self.sizes, rhs.sizes),
+            extensible: self.extensible || rhs.extensible,
         }
     }
+
+    /// Returns the bounds of the effective constraint. The sizes of a type take
+    /// precedence over its values, since only one of them is restricted in practice.
+    fn into_range_constraints(self) -> Result<PerVisibleRangeConstraints, GrammarError> {
+        let (ranges, is_size_constraint) = match (self.sizes, self.values) {
+            (Some(sizes), _) => (Some(sizes), true),
+            (None, values) => (values, false),
+        };
+        let (min, max) = match ranges {
+            None => (None, None),
+            Some(ranges) => match (ranges.first(), ranges.last()) {
+                (Some((min, _)), Some((_, max))) => (
+                    (*min != i128::MIN).then_some(*min),
+                    (*max != i128::MAX).then_some(*max),
+                ),
+                _ => {
+                    return Err(GrammarError {
+                        details: format!(
+                            "The constraint permits no {}",
+                            if is_size_constraint { "size" } else { "value" }
+                        ),
+                        kind: GrammarErrorType::UnpackingError,
+                    })
+                }
+            },
+        };
+        Ok(PerVisibleRangeConstraints {
+            min,
+            max,
+            extensible: self.extensible,
+            is_size_constraint,
+        })
+    }
 }
 
 impl PerVisible for Constraint {
@@ -388,7 +657,7 @@ impl PerVisible for ElementOrSetOperation {
         match self {
             ElementOrSetOperation::Element(e) => e.per_visible(),
             ElementOrSetOperation::SetOperation(o) => {
-                o.operant.per_visible() || o.operant.per_visible()
+                o.base.per_visible() || o.operant.per_visible()
             }
         }
     }
@@ -397,6 +666,11 @@ impl PerVisible for ElementOrSetOperation {
 impl PerVisible for SubtypeElement {
     fn per_visible(&self) -> bool {
         match self {
+            // Constraints of ENUMERATED types do not affect their PER encoding
+            SubtypeElement::SingleValue {
+                value: ASN1Value::EnumeratedValue { .. },
+                extensible: _,
+            } => false,
             SubtypeElement::SingleValue {
                 value: _,
                 extensible: _,
@@ -414,6 +688,7 @@ impl PerVisible for SubtypeElement {
             } => true,
             SubtypeElement::PermittedAlphabet(p) => p.per_visible(),
             SubtypeElement::SizeConstraint(s) => s.per_visible(),
+            SubtypeElement::NestedSet(s) => s.base.per_visible() || s.operant.per_visible(),
             _ => false,
         }
     }
@@ -428,1047 +703,520 @@ pub fn per_visible_range_constraints(
     } else {
         PerVisibleRangeConstraints::default_unsigned()
     };
-    for c in constraint_list.iter().filter(|c| c.per_visible()) {
+    for c in constraint_list.iter().filter(|c| is_range_visible(c)) {
         constraints += c.try_into()?
     }
     Ok(constraints)
 }
 
-/// 10.3.21	If a constraint that is PER-visible is part of an INTERSECTION construction,
-/// then the resulting constraint is PER-visible, and consists of the INTERSECTION of
-/// all PER-visible parts (with the non-PER-visible parts ignored).
-/// If a constraint which is not PER-visible is part of a UNION construction,
-/// then the resulting constraint is not PER-visible.
-/// If a constraint has an EXCEPT clause, the EXCEPT and the following value set is completely ignored,
-/// whether the value set following the EXCEPT is PER-visible or not.
-fn fold_constraint_set(
-    set: &SetOperation,
-    char_set: Option<&BTreeMap<usize, char>>,
-) -> Result<Option<SubtypeElement>, GrammarError> {
-    let folded_operant = match &*set.operant {
-        ElementOrSetOperation::Element(e) => e.per_visible().then(|| e.clone()),
-        ElementOrSetOperation::SetOperation(s) => fold_constraint_set(s, char_set)?,
-    };
-    match (&set.base, &folded_operant) {
-        (base, Some(SubtypeElement::PermittedAlphabet(elem_or_set)))
-        | (SubtypeElement::PermittedAlphabet(elem_or_set), Some(base))
-        | (base, Some(SubtypeElement::SizeConstraint(elem_or_set)))
-        | (SubtypeElement::SizeConstraint(elem_or_set), Some(base)) => {
-            return fold_constraint_set(
-                &SetOperation {
-                    base: base.clone(),
-                    operator: set.operator.clone(),
-                    operant: elem_or_set.clone(),
-                },
-                char_set,
-            )
-        }
-        (
-            SubtypeElement::ContainedSubtype {
-                subtype: _,
-                extensible: _,
-            },
-            None,
-        )
-        | (
-            SubtypeElement::ContainedSubtype {
-                subtype: _,
-                extensible: _,
-            },
-            Some(SubtypeElement::ContainedSubtype {
-                subtype: _,
-                extensible: _,
-            }),
-        ) => return Ok(None),
-        (
-            SubtypeElement::ContainedSubtype {
-                subtype: _,
-                extensible: _,
-            },
-            Some(c),
+/// Returns whether a constraint restricts the values or the sizes of a type in a PER-visible way.
+/// Permitted alphabet constraints are PER-visible, but only restrict the characters of a string.
+pub fn is_range_visible(constraint: &Constraint) -> bool {
+    constraint.per_visible()
+        && !matches!(
+            constraint,
+            Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(_)),
+                ..
+            })
         )
-        | (
-            c,
-            Some(SubtypeElement::ContainedSubtype {
-                subtype: _,
-                extensible: _,
-            }),
-        ) => return Ok(Some(c.clone())),
-        (SubtypeElement::PermittedAlphabet(elem_or_set), None)
-        | (SubtypeElement::SizeConstraint(elem_or_set), None) => {
-            return match &**elem_or_set {
-                ElementOrSetOperation::Element(e) => Ok(Some(e.clone())),
-                ElementOrSetOperation::SetOperation(s) => fold_constraint_set(s, char_set),
-            }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intermediate::{constraints::*, *};
+
+    use super::*;
+
+    fn permitted_alphabet(set: ElementOrSetOperation) -> Constraint {
+        Constraint::SubtypeConstraint(ElementSet {
+            extensible: false,
+            set: ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(Box::new(set))),
+        })
+    }
+
+    fn character_range(min: Option<&str>, max: Option<&str>) -> SubtypeElement {
+        SubtypeElement::ValueRange {
+            min: min.map(|m| ASN1Value::String(m.to_owned())),
+            max: max.map(|m| ASN1Value::String(m.to_owned())),
+            extensible: false,
         }
-        _ => (),
     }
 
-    match set.operator {
-        SetOperator::Intersection => match (&set.base, &folded_operant) {
-            (b, _) if !b.per_visible() => Ok(None),
-            (b, None) => Ok(Some(b.clone())),
-            (b, Some(f)) if !f.per_visible() => Ok(Some(b.clone())),
-            (
-                SubtypeElement::SingleValue {
-                    value: v1,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::SingleValue {
-                    value: v2,
-                    extensible: x2,
-                }),
-            ) => match (v1, v2, char_set.is_some()) {
-                (ASN1Value::Integer(_), ASN1Value::String(_), false)
-                | (ASN1Value::String(_), ASN1Value::Integer(_), true) => Ok(Some(set.base.clone())),
-                (ASN1Value::String(_), ASN1Value::Integer(_), false)
-                | (ASN1Value::Integer(_), ASN1Value::String(_), true) => Ok(folded_operant),
-                (ASN1Value::Integer(i1), ASN1Value::Integer(i2), _) => {
-                    if *i1 != *i2 {
-                        Err(GrammarError {
-                            details: format!(
-                                "Empty intersection result for {:?} and {:?}",
-                                v1,
-                                ASN1Value::Integer(*i2)
-                            ),
-                            kind: GrammarErrorType::UnpackingError,
-                        })
-                    } else {
-                        Ok(Some(SubtypeElement::SingleValue {
-                            value: ASN1Value::Integer(*i2),
-                            extensible: *x1 || *x2,
-                        }))
-                    }
-                }
-                (ASN1Value::String(s1), ASN1Value::String(s2), _) => {
-                    if *x1 || *x2 {
-                        Ok(None)
-                    } else {
-                        let permitted: String = s2.chars().filter(|c| s1.contains(*c)).collect();
-                        if permitted.is_empty() {
-                            return Err(GrammarError {
-                                details: format!(
-                                    "Empty intersection result for {:?} and {:?}",
-                                    v1,
-                                    ASN1Value::String(s2.clone())
-                                ),
-                                kind: GrammarErrorType::UnpackingError,
-                            });
-                        }
-                        Ok(Some(SubtypeElement::SingleValue {
-                            value: ASN1Value::String(permitted),
-                            extensible: false,
-                        }))
-                    }
-                }
-                (v1, v2, _) => Err(GrammarError {
-                    details: format!("Unsupported operation for ASN1Values {:?} and {:?}", v1, v2),
-                    kind: GrammarErrorType::UnpackingError,
-                }),
-            },
-            (
-                SubtypeElement::SingleValue {
-                    value,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::ValueRange {
-                    min,
-                    max,
-                    extensible: x2,
-                }),
-            ) => intersect_single_and_range(value, min.as_ref(), max.as_ref(), *x1, *x2, char_set),
-            (
-                SubtypeElement::ValueRange {
-                    min,
-                    max,
-                    extensible: x2,
-                },
-                Some(SubtypeElement::SingleValue {
-                    value,
-                    extensible: x1,
-                }),
-            ) => intersect_single_and_range(value, min.as_ref(), max.as_ref(), *x1, *x2, char_set),
-            (
-                _,
-                Some(SubtypeElement::SingleValue {
-                    value: v,
-                    extensible: x,
-                }),
-            ) => Ok(Some(SubtypeElement::SingleValue {
-                value: v.clone(),
-                extensible: *x,
-            })),
-            (
-                SubtypeElement::ValueRange {
-                    min: min1,
-                    max: max1,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::ValueRange {
-                    min: min2,
-                    max: max2,
-                    extensible: x2,
-                }),
-            ) => {
-                match (min1, max1, &min2, &max2) {
-                    (Some(ASN1Value::Integer(_)), _, Some(ASN1Value::String(_)), _)
-                    | (_, Some(ASN1Value::Integer(_)), Some(ASN1Value::String(_)), _)
-                    | (Some(ASN1Value::Integer(_)), _, _, Some(ASN1Value::String(_)))
-                    | (_, Some(ASN1Value::Integer(_)), _, Some(ASN1Value::String(_))) => {
-                        return if char_set.is_none() {
-                            Ok(Some(set.base.clone()))
-                        } else if !x2 {
-                            Ok(folded_operant.clone())
-                        } else {
-                            Ok(None)
-                        }
-                    }
-                    (Some(ASN1Value::String(_)), _, Some(ASN1Value::Integer(_)), _)
-                    | (_, Some(ASN1Value::String(_)), Some(ASN1Value::Integer(_)), _)
-                    | (Some(ASN1Value::String(_)), _, _, Some(ASN1Value::Integer(_)))
-                    | (_, Some(ASN1Value::String(_)), _, Some(ASN1Value::Integer(_))) => {
-                        return if char_set.is_none() {
-                            Ok(folded_operant)
-                        } else if !x1 {
-                            Ok(Some(set.base.clone()))
-                        } else {
-                            Ok(None)
-                        }
-                    }
-                    _ => (),
-                };
-                let min = compare_optional_asn1values(min1.as_ref(), min2.as_ref(), |m1, m2| {
-                    m1.max(m2, char_set)
-                })?;
-                let max = compare_optional_asn1values(max1.as_ref(), max2.as_ref(), |m1, m2| {
-                    m1.min(m2, char_set)
-                })?;
-                Ok(Some(SubtypeElement::ValueRange {
-                    min,
-                    max,
-                    extensible: *x1 || *x2,
-                }))
-            }
-            _ => unreachable!(),
-        },
-        SetOperator::Union => match (&set.base, folded_operant) {
-            (b, _) if !b.per_visible() => Ok(None),
-            (_, None) => Ok(None),
-            (_, Some(f)) if !f.per_visible() => Ok(None),
-            (
-                SubtypeElement::SingleValue {
-                    value: v1,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::SingleValue {
-                    value: v2,
-                    extensible: x2,
-                }),
-            ) => match (v1, &v2) {
-                (ASN1Value::String(_), ASN1Value::Integer(_))
-                | (ASN1Value::Integer(_), ASN1Value::String(_)) => Ok(None),
-                (ASN1Value::Integer(v1_int), ASN1Value::Integer(v2_int)) => {
-                    Ok(Some(SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(*v2_int.min(v1_int))),
-                        max: Some(ASN1Value::Integer(*v2_int.max(v1_int))),
-                        extensible: *x1 || x2,
-                    }))
-                }
-                (ASN1Value::String(v1_str), ASN1Value::String(v2_str)) => {
-                    let mut v2_clone = v2_str.clone();
-                    v2_clone.extend(v1_str.chars().filter(|c| !v2_str.contains(*c)));
-                    Ok(Some(SubtypeElement::SingleValue {
-                        value: ASN1Value::String(v2_clone),
-                        extensible: *x1 || x2,
-                    }))
-                }
-                _ => Err(GrammarError {
-                    details: format!("Unsupported operation for ASN1Values {:?} and {:?}", v1, v2),
-                    kind: GrammarErrorType::UnpackingError,
-                }),
-            },
-            (
-                SubtypeElement::ValueRange {
-                    min,
-                    max,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::SingleValue {
-                    value: v,
-                    extensible: x2,
-                }),
-            ) => union_single_and_range(&v, min.as_ref(), char_set, max.as_ref(), *x1, x2),
-            (
-                SubtypeElement::SingleValue {
-                    value: v,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::ValueRange {
-                    min,
-                    max,
-                    extensible: x2,
-                }),
-            ) => union_single_and_range(v, min.as_ref(), char_set, max.as_ref(), *x1, x2),
-            (
-                SubtypeElement::ValueRange {
-                    min: min1,
-                    max: max1,
-                    extensible: x1,
-                },
-                Some(SubtypeElement::ValueRange {
-                    min: min2,
-                    max: max2,
-                    extensible: x2,
-                }),
-            ) => {
-                match (min1, max1, &min2, &max2) {
-                    (Some(ASN1Value::Integer(_)), _, Some(ASN1Value::String(_)), _)
-                    | (Some(ASN1Value::String(_)), _, Some(ASN1Value::Integer(_)), _)
-                    | (_, Some(ASN1Value::Integer(_)), Some(ASN1Value::String(_)), _)
-                    | (_, Some(ASN1Value::String(_)), Some(ASN1Value::Integer(_)), _)
-                    | (Some(ASN1Value::Integer(_)), _, _, Some(ASN1Value::String(_)))
-                    | (Some(ASN1Value::String(_)), _, _, Some(ASN1Value::Integer(_)))
-                    | (_, Some(ASN1Value::Integer(_)), _, Some(ASN1Value::String(_)))
-                    | (_, Some(ASN1Value::String(_)), _, Some(ASN1Value::Integer(_))) => {
-                        return Ok(None)
-                    }
-                    _ => (),
-                };
-                let min = compare_optional_asn1values(min1.as_ref(), min2.as_ref(), |m1, m2| {
-                    m1.min(m2, char_set)
-                })?;
-                let max = compare_optional_asn1values(max1.as_ref(), max2.as_ref(), |m1, m2| {
-                    m1.max(m2, char_set)
-                })?;
-                Ok(Some(SubtypeElement::ValueRange {
-                    min,
-                    max,
-                    extensible: *x1 || x2,
-                }))
-            }
-            _ => unreachable!(),
-        },
-        SetOperator::Except => {
-            if set.base.per_visible() {
-                Ok(Some(set.base.clone()))
-            } else {
-                Ok(None)
-            }
+    fn characters(value: &str) -> SubtypeElement {
+        SubtypeElement::SingleValue {
+            value: ASN1Value::String(value.to_owned()),
+            extensible: false,
         }
     }
-}
-
-fn intersect_single_and_range(
-    value: &ASN1Value,
-    min: Option<&ASN1Value>,
-    max: Option<&ASN1Value>,
-    x1: bool,
-    x2: bool,
-    char_set: Option<&BTreeMap<usize, char>>,
-) -> Result<Option<SubtypeElement>, GrammarError> {
-    match (value, min, max, x1 || x2, char_set) {
-        (ASN1Value::Integer(_), _, Some(ASN1Value::String(_)), _, Some(_))
-        | (ASN1Value::Integer(_), Some(ASN1Value::String(_)), _, _, Some(_)) => {
-            if x2 {
-                Ok(None)
-            } else {
-                Ok(Some(SubtypeElement::ValueRange {
-                    min: min.cloned(),
-                    max: max.cloned(),
-                    extensible: false,
-                }))
-            }
-        }
-        (ASN1Value::String(_), Some(ASN1Value::Integer(_)), _, _, Some(_))
-        | (ASN1Value::String(_), _, Some(ASN1Value::Integer(_)), _, Some(_)) => {
-            if x1 {
-                Ok(None)
-            } else {
-                Ok(Some(SubtypeElement::SingleValue {
-                    value: value.clone(),
-                    extensible: false,
-                }))
-            }
-        }
-        (ASN1Value::Integer(_), _, Some(ASN1Value::String(_)), _, None)
-        | (ASN1Value::Integer(_), Some(ASN1Value::String(_)), _, _, None) => {
-            Ok(Some(SubtypeElement::SingleValue {
-                value: value.clone(),
-                extensible: x1,
-            }))
-        }
-        (ASN1Value::String(_), Some(ASN1Value::Integer(_)), _, _, None)
-        | (ASN1Value::String(_), _, Some(ASN1Value::Integer(_)), _, None) => {
-            Ok(Some(SubtypeElement::ValueRange {
-                min: min.cloned(),
-                max: max.cloned(),
-                extensible: x2,
-            }))
-        }
-        (ASN1Value::Integer(v), _, _, extensible, _) => Ok(Some(SubtypeElement::SingleValue {
-            value: ASN1Value::Integer(*v),
-            extensible,
-        })),
-        (_, _, _, true, _) => Ok(None),
-        (ASN1Value::String(s1), _, _, _, Some(chars)) => {
-            let indices = s1
-                .chars()
-                .map(|c| find_char_index(chars, c).map(|i| (c, i)))
-                .collect::<Result<Vec<(char, usize)>, _>>()?;
-            let s_min = indices
-                .iter()
-                .min_by(|(_, a), (_, b)| a.cmp(b))
-                .map(|(c, _)| ASN1Value::String(format!("{c}")));
-            let s_max = indices
-                .iter()
-                .max_by(|(_, a), (_, b)| a.cmp(b))
-                .map(|(c, _)| ASN1Value::String(format!("{c}")));
-            Ok(Some(SubtypeElement::ValueRange {
-                min: compare_optional_asn1values(s_min.as_ref(), min, |a, b| a.max(b, char_set))?,
-                max: compare_optional_asn1values(s_max.as_ref(), max, |a, b| a.min(b, char_set))?,
-                extensible: false,
-            }))
-        }
-        _ => Err(GrammarError {
-            details: format!(
-                "Unsupported operation for ASN1Values {:?} and {:?}..{:?}",
-                value, min, max
-            ),
-            kind: GrammarErrorType::UnpackingError,
-        }),
-    }
-}
-
-fn union_single_and_range(
-    v: &ASN1Value,
-    min: Option<&ASN1Value>,
-    char_set: Option<&BTreeMap<usize, char>>,
-    max: Option<&ASN1Value>,
-    x1: bool,
-    x2: bool,
-) -> Result<Option<SubtypeElement>, GrammarError> {
-    match (v, min, max, x1 || x2, char_set) {
-        (ASN1Value::Integer(_), _, Some(ASN1Value::String(_)), _, _)
-        | (ASN1Value::Integer(_), Some(ASN1Value::String(_)), _, _, _)
-        | (ASN1Value::String(_), Some(ASN1Value::Integer(_)), _, _, _)
-        | (ASN1Value::String(_), _, Some(ASN1Value::Integer(_)), _, _) => Ok(None),
-        (ASN1Value::Integer(_), _, _, extensible, _) => Ok(Some(SubtypeElement::ValueRange {
-            min: compare_optional_asn1values(Some(v), min, |a, b| a.min(b, char_set))?,
-            max: compare_optional_asn1values(Some(v), max, |a, b| a.max(b, char_set))?,
-            extensible,
-        })),
-        (_, _, _, true, _) => Ok(None),
-        (ASN1Value::String(s1), _, _, _, Some(chars)) => {
-            let indices = s1
-                .chars()
-                .map(|c| find_char_index(chars, c).map(|i| (c, i)))
-                .collect::<Result<Vec<(char, usize)>, _>>()?;
-            let s_min = indices
-                .iter()
-                .min_by(|(_, a), (_, b)| a.cmp(b))
-                .map(|(c, _)| ASN1Value::String(format!("{c}")));
-            let s_max = indices
-                .iter()
-                .max_by(|(_, a), (_, b)| a.cmp(b))
-                .map(|(c, _)| ASN1Value::String(format!("{c}")));
-            Ok(Some(SubtypeElement::ValueRange {
-                min: compare_optional_asn1values(s_min.as_ref(), min, |a, b| a.min(b, char_set))?,
-                max: compare_optional_asn1values(s_max.as_ref(), max, |a, b| a.max(b, char_set))?,
-                extensible: false,
-            }))
-        }
-        _ => Err(GrammarError {
-            details: format!(
-                "Unsupported operation for values {:?} and {:?}..{:?}",
-                v, min, max
-            ),
-            kind: GrammarErrorType::UnpackingError,
-        }),
-    }
-}
-
-fn compare_optional_asn1values(
-    first: Option<&ASN1Value>,
-    second: Option<&ASN1Value>,
-    predicate: impl Fn(&ASN1Value, &ASN1Value) -> Result<ASN1Value, GrammarError>,
-) -> Result<Option<ASN1Value>, GrammarError> {
-    match (first, second) {
-        (Some(f), Some(s)) => Ok(Some(predicate(f, s)?)),
-        (None, Some(s)) => Ok(Some(s.clone())),
-        (Some(f), None) => Ok(Some(f.clone())),
-        _ => Ok(None),
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::intermediate::{constraints::*, *};
-
-    use super::*;
 
     #[test]
     fn initializes_per_visible_alphabet_from_single_value() {
+        let alphabet = PerVisibleAlphabetConstraints::try_new(
+            &permitted_alphabet(ElementOrSetOperation::Element(characters("ABCDEF"))),
+            CharacterStringType::UTF8String,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(alphabet.code_point_ranges(), &[(0x41, 0x46)]);
         assert_eq!(
-            PerVisibleAlphabetConstraints::try_new(
-                &Constraint::SubtypeConstraint(ElementSet {
-                    extensible: false,
-                    set: ElementOrSetOperation::Element(SubtypeElement::SingleValue {
-                        value: ASN1Value::String("ABCDEF".to_owned()),
-                        extensible: false
-                    })
-                }),
-                CharacterStringType::UTF8String
-            )
-            .unwrap()
-            .unwrap(),
-            PerVisibleAlphabetConstraints {
-                string_type: CharacterStringType::UTF8String,
-                character_by_index: [(0, 'A'), (1, 'B'), (2, 'C'), (3, 'D'), (4, 'E'), (5, 'F')]
-                    .into_iter()
-                    .collect(),
-                index_by_character: None,
-                charset_subsets: vec![
-                    CharsetSubset::Single('A'),
-                    CharsetSubset::Single('B'),
-                    CharsetSubset::Single('C'),
-                    CharsetSubset::Single('D'),
-                    CharsetSubset::Single('E'),
-                    CharsetSubset::Single('F')
-                ]
-            }
+            alphabet.charset_subsets(),
+            vec![CharsetSubset::Range {
+                from: Some('A'),
+                to: Some('F')
+            }]
         );
+        let alphabet = PerVisibleAlphabetConstraints::try_new(
+            &permitted_alphabet(ElementOrSetOperation::Element(characters("13"))),
+            CharacterStringType::NumericString,
+        )
+        .unwrap()
+        .unwrap();
         assert_eq!(
-            PerVisibleAlphabetConstraints::try_new(
-                &Constraint::SubtypeConstraint(ElementSet {
-                    extensible: false,
-                    set: ElementOrSetOperation::Element(SubtypeElement::SingleValue {
-                        value: ASN1Value::String("132".to_owned()),
-                        extensible: false
-                    })
-                }),
-                CharacterStringType::NumericString
-            )
-            .unwrap()
-            .unwrap(),
-            PerVisibleAlphabetConstraints {
-                string_type: CharacterStringType::NumericString,
-                character_by_index: [(0, '1'), (2, '3'), (1, '2')].into_iter().collect(),
-                index_by_character: None,
-                charset_subsets: vec![
-                    CharsetSubset::Single('1'),
-                    CharsetSubset::Single('3'),
-                    CharsetSubset::Single('2')
-                ]
-            }
+            alphabet.charset_subsets(),
+            vec![CharsetSubset::Single('1'), CharsetSubset::Single('3')]
+        );
+        assert_eq!(alphabet.permitted_characters().collect::<String>(), "13");
+        assert!(PerVisibleAlphabetConstraints::try_new(
+            &permitted_alphabet(ElementOrSetOperation::Element(characters("1A"))),
+            CharacterStringType::NumericString,
         )
+        .is_err());
     }
 
     #[test]
     fn initializes_per_visible_alphabet_from_range_value() {
         assert_eq!(
             PerVisibleAlphabetConstraints::try_new(
-                &Constraint::SubtypeConstraint(ElementSet {
-                    extensible: false,
-                    set: ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("A".to_owned())),
-                        max: Some(ASN1Value::String("F".to_owned())),
-                        extensible: false
-                    })
-                }),
-                CharacterStringType::UTF8String
+                &permitted_alphabet(ElementOrSetOperation::Element(character_range(
+                    Some("A"),
+                    Some("F")
+                ))),
+                CharacterStringType::UTF8String,
             )
             .unwrap()
-            .unwrap(),
-            PerVisibleAlphabetConstraints {
-                string_type: CharacterStringType::UTF8String,
-                character_by_index: [(0, 'A'), (1, 'B'), (2, 'C'), (3, 'D'), (4, 'E'), (5, 'F')]
-                    .into_iter()
-                    .collect(),
-                index_by_character: None,
-                charset_subsets: vec![CharsetSubset::Range {
-                    from: Some('A'),
-                    to: Some('F')
-                }]
-            }
+            .unwrap()
+            .charset_subsets(),
+            vec![CharsetSubset::Range {
+                from: Some('A'),
+                to: Some('F')
+            }]
         );
         assert_eq!(
             PerVisibleAlphabetConstraints::try_new(
-                &Constraint::SubtypeConstraint(ElementSet {
-                    extensible: false,
-                    set: ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                        min: None,
-                        max: Some(ASN1Value::String("3".to_owned())),
-                        extensible: false
-                    })
-                }),
-                CharacterStringType::NumericString
+                &permitted_alphabet(ElementOrSetOperation::Element(character_range(
+                    None,
+                    Some("3")
+                ))),
+                CharacterStringType::NumericString,
             )
             .unwrap()
-            .unwrap(),
-            PerVisibleAlphabetConstraints {
-                string_type: CharacterStringType::NumericString,
-                character_by_index: [(0, ' '), (1, '0'), (2, '1'), (3, '2'), (4, '3')]
-                    .into_iter()
-                    .collect(),
-                index_by_character: None,
-                charset_subsets: vec![CharsetSubset::Range {
-                    from: Some(' '),
+            .unwrap()
+            .charset_subsets(),
+            vec![
+                CharsetSubset::Single(' '),
+                CharsetSubset::Range {
+                    from: Some('0'),
                     to: Some('3')
-                }]
-            }
+                }
+            ]
         )
     }
 
     #[test]
-    fn folds_single_value_alphabet_constraints() {
-        assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::SingleValue {
-                        value: ASN1Value::String("ABC".into()),
-                        extensible: false
-                    },
-                    operator: SetOperator::Intersection,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::String("CDE".into()),
-                            extensible: false
-                        }
-                    ))
-                },
-                Some(&CharacterStringType::IA5String.character_set())
-            )
-            .unwrap()
-            .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::String("C".into()),
-                extensible: false
-            }
-        );
-        assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::SingleValue {
-                        value: ASN1Value::String("ABC".into()),
-                        extensible: false
-                    },
+    fn initializes_per_visible_alphabet_from_set_operations() {
+        // FROM ("A".."Z" | "-" | "a".."c" EXCEPT "b")
+        let alphabet = PerVisibleAlphabetConstraints::try_new(
+            &permitted_alphabet(ElementOrSetOperation::SetOperation(SetOperation {
+                base: character_range(Some("A"), Some("Z")),
+                operator: SetOperator::Union,
+                operant: Box::new(ElementOrSetOperation::SetOperation(SetOperation {
+                    base: characters("-"),
                     operator: SetOperator::Union,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::String("CDE".into()),
-                            extensible: false
-                        }
-                    ))
-                },
-                Some(&CharacterStringType::IA5String.character_set())
-            )
-            .unwrap()
-            .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::String("CDEAB".into()),
-                extensible: false
-            }
+                    operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::NestedSet(
+                        Box::new(SetOperation {
+                            base: character_range(Some("a"), Some("c")),
+                            operator: SetOperator::Except,
+                            operant: Box::new(ElementOrSetOperation::Element(characters("b"))),
+                        }),
+                    ))),
+                })),
+            })),
+            CharacterStringType::IA5String,
         )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            alphabet.code_point_ranges(),
+            &[(0x2D, 0x2D), (0x41, 0x5A), (0x61, 0x61), (0x63, 0x63)]
+        );
     }
 
     #[test]
-    fn folds_range_value_alphabet_constraints() {
+    fn initializes_per_visible_alphabet_from_contained_subtype() {
+        let digits = ASN1Type::CharacterString(CharacterString {
+            constraints: vec![permitted_alphabet(ElementOrSetOperation::Element(
+                character_range(Some("0"), Some("9")),
+            ))],
+            ty: CharacterStringType::IA5String,
+        });
+        // FROM (Digits | "-")
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("A".into())),
-                        max: Some(ASN1Value::String("C".into())),
+            PerVisibleAlphabetConstraints::try_new(
+                &permitted_alphabet(ElementOrSetOperation::SetOperation(SetOperation {
+                    base: SubtypeElement::ContainedSubtype {
+                        subtype: digits.clone(),
                         extensible: false
                     },
-                    operator: SetOperator::Intersection,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::String("CDE".into()),
-                            extensible: false
-                        }
-                    ))
-                },
-                Some(&CharacterStringType::PrintableString.character_set())
+                    operator: SetOperator::Union,
+                    operant: Box::new(ElementOrSetOperation::Element(characters("-"))),
+                })),
+                CharacterStringType::IA5String,
             )
             .unwrap()
-            .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::String("C".into())),
-                max: Some(ASN1Value::String("C".into())),
-                extensible: false
-            }
+            .unwrap()
+            .code_point_ranges(),
+            &[(0x2D, 0x2D), (0x30, 0x39)]
         );
+        // (Digits)
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("A".into())),
-                        max: Some(ASN1Value::String("C".into())),
+            PerVisibleAlphabetConstraints::try_new(
+                &Constraint::SubtypeConstraint(ElementSet {
+                    extensible: false,
+                    set: ElementOrSetOperation::Element(SubtypeElement::ContainedSubtype {
+                        subtype: digits,
                         extensible: false
-                    },
-                    operator: SetOperator::Union,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::String("CDE".into()),
-                            extensible: false
-                        }
-                    ))
-                },
-                Some(&CharacterStringType::PrintableString.character_set())
+                    }),
+                }),
+                CharacterStringType::IA5String,
             )
             .unwrap()
-            .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::String("A".into())),
-                max: Some(ASN1Value::String("E".into())),
-                extensible: false
-            }
-        )
+            .unwrap()
+            .code_point_ranges(),
+            &[(0x30, 0x39)]
+        );
     }
 
     #[test]
-    fn folds_range_values_alphabet_constraints() {
+    fn ignores_alphabets_that_are_not_per_visible() {
+        // (FROM ("A".."Z") | SIZE (1))
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("A".into())),
-                        max: Some(ASN1Value::String("C".into())),
-                        extensible: false
-                    },
-                    operator: SetOperator::Intersection,
-                    operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("C".into())),
-                        max: Some(ASN1Value::String("E".into())),
-                        extensible: false
-                    }))
-                },
-                Some(&CharacterStringType::VisibleString.character_set())
+            PerVisibleAlphabetConstraints::try_new(
+                &Constraint::SubtypeConstraint(ElementSet {
+                    extensible: false,
+                    set: ElementOrSetOperation::SetOperation(SetOperation {
+                        base: SubtypeElement::PermittedAlphabet(Box::new(
+                            ElementOrSetOperation::Element(character_range(Some("A"), Some("Z")))
+                        )),
+                        operator: SetOperator::Union,
+                        operant: Box::new(ElementOrSetOperation::Element(
+                            SubtypeElement::SizeConstraint(Box::new(
+                                ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                                    value: ASN1Value::Integer(1),
+                                    extensible: false
+                                })
+                            ))
+                        )),
+                    }),
+                }),
+                CharacterStringType::IA5String,
             )
-            .unwrap()
             .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::String("C".into())),
-                max: Some(ASN1Value::String("C".into())),
-                extensible: false
-            }
+            None
         );
+        // (FROM ("A".."Z", ...))
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("A".into())),
-                        max: Some(ASN1Value::String("C".into())),
-                        extensible: false
-                    },
-                    operator: SetOperator::Union,
-                    operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::String("C".into())),
-                        max: Some(ASN1Value::String("E".into())),
-                        extensible: false
-                    }))
-                },
-                Some(&CharacterStringType::PrintableString.character_set())
+            PerVisibleAlphabetConstraints::try_new(
+                &Constraint::SubtypeConstraint(ElementSet {
+                    extensible: true,
+                    set: ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(
+                        Box::new(ElementOrSetOperation::Element(character_range(
+                            Some("A"),
+                            Some("Z")
+                        )))
+                    )),
+                }),
+                CharacterStringType::IA5String,
             )
-            .unwrap()
             .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::String("A".into())),
-                max: Some(ASN1Value::String("E".into())),
-                extensible: false
-            }
+            None
+        );
+    }
+
+    #[test]
+    fn intersects_consecutive_per_visible_alphabets() {
+        let mut alphabet = PerVisibleAlphabetConstraints::try_new(
+            &permitted_alphabet(ElementOrSetOperation::Element(character_range(
+                Some("A"),
+                Some("Z"),
+            ))),
+            CharacterStringType::IA5String,
+        )
+        .unwrap()
+        .unwrap();
+        alphabet += &mut PerVisibleAlphabetConstraints::try_new(
+            &permitted_alphabet(ElementOrSetOperation::Element(characters("XYZ012"))),
+            CharacterStringType::IA5String,
         )
+        .unwrap()
+        .unwrap();
+        assert_eq!(alphabet.permitted_characters().collect::<String>(), "XYZ");
+    }
+
+    fn range(min: i128, max: i128) -> SubtypeElement {
+        SubtypeElement::ValueRange {
+            min: Some(ASN1Value::Integer(min)),
+            max: Some(ASN1Value::Integer(max)),
+            extensible: false,
+        }
+    }
+
+    fn single(value: ASN1Value) -> SubtypeElement {
+        SubtypeElement::SingleValue {
+            value,
+            extensible: false,
+        }
+    }
+
+    fn operation(
+        base: SubtypeElement,
+        operator: SetOperator,
+        operant: SubtypeElement,
+    ) -> ElementOrSetOperation {
+        ElementOrSetOperation::SetOperation(SetOperation {
+            base,
+            operator,
+            operant: Box::new(ElementOrSetOperation::Element(operant)),
+        })
+    }
+
+    fn nested(set: ElementOrSetOperation) -> SubtypeElement {
+        match set {
+            ElementOrSetOperation::SetOperation(s) => SubtypeElement::NestedSet(Box::new(s)),
+            ElementOrSetOperation::Element(e) => e,
+        }
+    }
+
+    fn range_constraints(
+        set: ElementOrSetOperation,
+    ) -> Result<(Option<i128>, Option<i128>, bool), GrammarError> {
+        PerVisibleRangeConstraints::try_from(&Constraint::SubtypeConstraint(ElementSet {
+            set,
+            extensible: false,
+        }))
+        .map(|c| (c.min(), c.max(), c.is_extensible()))
     }
 
     #[test]
-    fn folds_single_value_numeric_constraints() {
+    fn computes_range_of_numeric_set_operations() {
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::SingleValue {
+            range_constraints(ElementOrSetOperation::SetOperation(SetOperation {
+                base: single(ASN1Value::Integer(4)),
+                operator: SetOperator::Intersection,
+                operant: Box::new(ElementOrSetOperation::Element(
+                    SubtypeElement::SingleValue {
                         value: ASN1Value::Integer(4),
-                        extensible: false
-                    },
-                    operator: SetOperator::Intersection,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::Integer(4),
-                            extensible: true
-                        }
-                    ))
-                },
-                None
-            )
-            .unwrap()
+                        extensible: true
+                    }
+                )),
+            }))
             .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::Integer(4),
-                extensible: true
-            }
+            (Some(4), Some(4), true)
         );
-    }
-
-    #[test]
-    fn folds_range_value_integer_constraints() {
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(-1)),
-                        max: Some(ASN1Value::Integer(3)),
-                        extensible: false
-                    },
-                    operator: SetOperator::Intersection,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::Integer(2),
-                            extensible: false
-                        }
-                    ))
-                },
-                None
-            )
-            .unwrap()
+            range_constraints(operation(
+                range(-1, 3),
+                SetOperator::Intersection,
+                single(ASN1Value::Integer(2))
+            ))
             .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::Integer(2),
-                extensible: false
-            }
+            (Some(2), Some(2), false)
         );
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(-1)),
-                        max: Some(ASN1Value::Integer(5)),
-                        extensible: false
-                    },
-                    operator: SetOperator::Union,
-                    operant: Box::new(ElementOrSetOperation::Element(
-                        SubtypeElement::SingleValue {
-                            value: ASN1Value::Integer(-3),
-                            extensible: false
-                        }
-                    ))
-                },
-                None
-            )
-            .unwrap()
+            range_constraints(operation(
+                range(-1, 5),
+                SetOperator::Union,
+                single(ASN1Value::Integer(-3))
+            ))
             .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-3)),
-                max: Some(ASN1Value::Integer(5)),
-                extensible: false
-            }
-        )
-    }
-
-    #[test]
-    fn folds_range_values_numeric_constraints() {
+            (Some(-3), Some(5), false)
+        );
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(-2)),
-                        max: Some(ASN1Value::Integer(3)),
-                        extensible: false
-                    },
-                    operator: SetOperator::Intersection,
-                    operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(-5)),
-                        max: Some(ASN1Value::Integer(1)),
-                        extensible: false
-                    }))
-                },
-                None
-            )
-            .unwrap()
+            range_constraints(operation(
+                range(-2, 3),
+                SetOperator::Intersection,
+                range(-5, 1)
+            ))
             .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(1)),
-                extensible: false
-            }
+            (Some(-2), Some(1), false)
         );
         assert_eq!(
-            fold_constraint_set(
-                &SetOperation {
-                    base: SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(-2)),
-                        max: Some(ASN1Value::Integer(3)),
-                        extensible: false
-                    },
-                    operator: SetOperator::Union,
-                    operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                        min: Some(ASN1Value::Integer(-1)),
-                        max: Some(ASN1Value::Integer(5)),
-                        extensible: false
-                    }))
-                },
-                None
-            )
-            .unwrap()
-            .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(5)),
-                extensible: false
-            }
-        )
+            range_constraints(operation(range(-2, 3), SetOperator::Union, range(-1, 5))).unwrap(),
+            (Some(-2), Some(5), false)
+        );
     }
 
     #[test]
-    fn folds_single_value_mixed_constraints() {
-        let set_op = |op: SetOperator| SetOperation {
-            base: SubtypeElement::SingleValue {
-                value: ASN1Value::Integer(4),
-                extensible: false,
-            },
-            operator: op,
-            operant: Box::new(ElementOrSetOperation::Element(
-                SubtypeElement::SingleValue {
-                    value: ASN1Value::String("abc".into()),
-                    extensible: false,
-                },
-            )),
-        };
+    fn computes_range_of_nested_set_operations() {
+        // (1..10 ^ 5..20) | 30..40
         assert_eq!(
-            fold_constraint_set(&set_op(SetOperator::Intersection), None)
-                .unwrap()
-                .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::Integer(4),
-                extensible: false
-            }
+            range_constraints(operation(
+                nested(operation(
+                    range(1, 10),
+                    SetOperator::Intersection,
+                    range(5, 20)
+                )),
+                SetOperator::Union,
+                range(30, 40)
+            ))
+            .unwrap(),
+            (Some(5), Some(40), false)
         );
+        // (1..10 EXCEPT 3) ^ 5..20
         assert_eq!(
-            fold_constraint_set(
-                &set_op(SetOperator::Intersection),
-                Some(&CharacterStringType::IA5String.character_set())
-            )
-            .unwrap()
+            range_constraints(operation(
+                nested(operation(
+                    range(1, 10),
+                    SetOperator::Except,
+                    single(ASN1Value::Integer(3))
+                )),
+                SetOperator::Intersection,
+                range(5, 20)
+            ))
             .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::String("abc".into()),
-                extensible: false
-            }
+            (Some(5), Some(10), false)
         );
+        // 1..4 | (6 ^ 8..9)
         assert_eq!(
-            fold_constraint_set(&set_op(SetOperator::Union), None).unwrap(),
-            None
+            range_constraints(operation(
+                range(1, 4),
+                SetOperator::Union,
+                nested(operation(
+                    single(ASN1Value::Integer(6)),
+                    SetOperator::Intersection,
+                    range(8, 9)
+                ))
+            ))
+            .unwrap(),
+            (Some(1), Some(4), false)
         );
+        // ALL EXCEPT 1
         assert_eq!(
-            fold_constraint_set(
-                &set_op(SetOperator::Union),
-                Some(&CharacterStringType::IA5String.character_set())
-            )
+            range_constraints(operation(
+                SubtypeElement::All,
+                SetOperator::Except,
+                single(ASN1Value::Integer(1))
+            ))
             .unwrap(),
-            None
+            (None, None, false)
         );
     }
 
     #[test]
-    fn folds_range_value_mixed_constraints() {
-        let set_op = |op| SetOperation {
-            base: SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-1)),
-                max: Some(ASN1Value::Integer(3)),
-                extensible: false,
-            },
-            operator: op,
-            operant: Box::new(ElementOrSetOperation::Element(
-                SubtypeElement::SingleValue {
-                    value: ASN1Value::String("ABC".into()),
-                    extensible: false,
-                },
-            )),
-        };
+    fn computes_range_of_unbounded_set_operations() {
+        // MIN..10 | 20..MAX
         assert_eq!(
-            fold_constraint_set(
-                &set_op(SetOperator::Intersection),
-                Some(&CharacterStringType::PrintableString.character_set())
-            )
-            .unwrap()
+            range_constraints(operation(
+                SubtypeElement::ValueRange {
+                    min: None,
+                    max: Some(ASN1Value::Integer(10)),
+                    extensible: false
+                },
+                SetOperator::Union,
+                SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::Integer(20)),
+                    max: None,
+                    extensible: false
+                }
+            ))
             .unwrap(),
-            SubtypeElement::SingleValue {
-                value: ASN1Value::String("ABC".into()),
-                extensible: false,
-            }
+            (None, None, false)
         );
+        // MIN..10 ^ 0..MAX
         assert_eq!(
-            fold_constraint_set(
-                &set_op(SetOperator::Union),
-                Some(&CharacterStringType::PrintableString.character_set())
-            )
+            range_constraints(operation(
+                SubtypeElement::ValueRange {
+                    min: None,
+                    max: Some(ASN1Value::Integer(10)),
+                    extensible: false
+                },
+                SetOperator::Intersection,
+                SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::Integer(0)),
+                    max: None,
+                    extensible: false
+                }
+            ))
             .unwrap(),
-            None
+            (Some(0), Some(10), false)
         );
-        assert_eq!(
-            fold_constraint_set(&set_op(SetOperator::Intersection), None)
-                .unwrap()
+    }
+
+    #[test]
+    fn ignores_set_operands_that_are_not_per_visible() {
+        for base in [single(ASN1Value::Integer(4)), range(-1, 3)] {
+            let expected = match &base {
+                SubtypeElement::ValueRange { .. } => (Some(-1), Some(3), false),
+                _ => (Some(4), Some(4), false),
+            };
+            assert_eq!(
+                range_constraints(operation(
+                    base.clone(),
+                    SetOperator::Intersection,
+                    single(ASN1Value::String("abc".into()))
+                ))
                 .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-1)),
-                max: Some(ASN1Value::Integer(3)),
-                extensible: false,
-            }
-        );
-        assert_eq!(
-            fold_constraint_set(&set_op(SetOperator::Union), None).unwrap(),
-            None
-        );
+                expected
+            );
+            assert_eq!(
+                range_constraints(operation(
+                    base,
+                    SetOperator::Union,
+                    single(ASN1Value::String("abc".into()))
+                ))
+                .unwrap(),
+                (None, None, false)
+            );
+        }
     }
 
     #[test]
-    fn folds_range_values_mixed_constraints() {
-        let set_op = |op| SetOperation {
-            base: SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(3)),
-                extensible: false,
-            },
-            operator: op,
-            operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::ValueRange {
-                min: Some(ASN1Value::String("A".into())),
-                max: Some(ASN1Value::String("C".into())),
-                extensible: false,
-            })),
+    fn rejects_set_operations_without_values() {
+        assert!(range_constraints(operation(
+            range(1, 10),
+            SetOperator::Intersection,
+            range(20, 30)
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn keeps_size_constraint_of_size_constraint_set() {
+        let size = |min, max, extensible| {
+            SubtypeElement::SizeConstraint(Box::new(ElementOrSetOperation::Element(
+                SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::Integer(min)),
+                    max: Some(ASN1Value::Integer(max)),
+                    extensible,
+                },
+            )))
         };
-        assert_eq!(
-            fold_constraint_set(
-                &set_op(SetOperator::Intersection),
-                Some(&CharacterStringType::PrintableString.character_set())
-            )
-            .unwrap()
-            .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::String("A".into())),
-                max: Some(ASN1Value::String("C".into())),
-                extensible: false,
-            }
-        );
-        assert_eq!(
-            fold_constraint_set(&set_op(SetOperator::Intersection), None)
-                .unwrap()
-                .unwrap(),
-            SubtypeElement::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(3)),
+        // SIZE(1..4, ...) INTERSECTION SIZE(2..8)
+        let constraints = per_visible_range_constraints(
+            false,
+            &vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::SetOperation(SetOperation {
+                    base: size(1, 4, true),
+                    operator: SetOperator::Intersection,
+                    operant: Box::new(ElementOrSetOperation::Element(size(2, 8, false))),
+                }),
                 extensible: false,
-            }
-        );
-        assert_eq!(
-            fold_constraint_set(
-                &set_op(SetOperator::Union),
-                Some(&CharacterStringType::PrintableString.character_set())
-            )
-            .unwrap(),
-            None
-        );
-        assert_eq!(
-            fold_constraint_set(&set_op(SetOperator::Union), None).unwrap(),
-            None
-        );
+            })],
+        )
+        .unwrap();
+        assert!(constraints.is_size_constraint());
+        assert!(constraints.is_extensible());
+        assert_eq!(constraints.min::<i128>(), Some(2));
+        assert_eq!(constraints.max::<i128>(), Some(4));
     }
 }
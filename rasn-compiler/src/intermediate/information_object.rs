@@ -340,12 +340,96 @@ impl
     }
 }
 
+impl InformationObjectClass {
+    /// Returns the definition of a built-in information object class. Specifications
+    /// use the classes `TYPE-IDENTIFIER` (X.681 Annex A) and `ABSTRACT-SYNTAX` (X.681 Annex B)
+    /// without declaring them:
+    /// ```ignore
+    /// TYPE-IDENTIFIER ::= CLASS {
+    ///     &id OBJECT IDENTIFIER UNIQUE,
+    ///     &Type
+    /// }
+    /// WITH SYNTAX { &Type IDENTIFIED BY &id }
+    ///
+    /// ABSTRACT-SYNTAX ::= CLASS {
+    ///     &id OBJECT IDENTIFIER UNIQUE,
+    ///     &Type,
+    ///     &property BIT STRING { handles-invalid-encodings(0) } DEFAULT {}
+    /// }
+    /// WITH SYNTAX { &Type IDENTIFIED BY &id [HAS PROPERTY &property] }
+    /// ```
+    pub fn built_in(name: &str) -> Option<Self> {
+        let mut fields = vec![
+            InformationObjectClassField {
+                identifier: ObjectFieldIdentifier::SingleValue("&id".into()),
+                ty: Some(ASN1Type::ObjectIdentifier(types::ObjectIdentifier {
+                    is_relative: false,
+                    constraints: vec![],
+                })),
+                is_optional: false,
+                default: None,
+                is_unique: true,
+            },
+            InformationObjectClassField {
+                identifier: ObjectFieldIdentifier::MultipleValue("&Type".into()),
+                ty: None,
+                is_optional: false,
+                default: None,
+                is_unique: false,
+            },
+        ];
+        let mut expressions = vec![
+            SyntaxExpression::Required(SyntaxToken::from(ObjectFieldIdentifier::MultipleValue(
+                "&Type".into(),
+            ))),
+            SyntaxExpression::Required(SyntaxToken::from("IDENTIFIED")),
+            SyntaxExpression::Required(SyntaxToken::from("BY")),
+            SyntaxExpression::Required(SyntaxToken::from(ObjectFieldIdentifier::SingleValue(
+                "&id".into(),
+            ))),
+        ];
+        match name {
+            TYPE_IDENTIFIER => (),
+            ABSTRACT_SYNTAX => {
+                let property = ObjectFieldIdentifier::SingleValue("&property".into());
+                fields.push(InformationObjectClassField {
+                    identifier: property.clone(),
+                    ty: Some(ASN1Type::BitString(types::BitString {
+                        constraints: vec![],
+                        distinguished_values: Some(vec![DistinguishedValue {
+                            name: "handles-invalid-encodings".into(),
+                            value: 0,
+                        }]),
+                    })),
+                    is_optional: false,
+                    default: Some(InformationObjectField::from((
+                        property.clone(),
+                        ASN1Value::BitString(vec![]),
+                    ))),
+                    is_unique: false,
+                });
+                expressions.push(SyntaxExpression::Optional(vec![
+                    SyntaxExpression::Required(SyntaxToken::from("HAS")),
+                    SyntaxExpression::Required(SyntaxToken::from("PROPERTY")),
+                    SyntaxExpression::Required(SyntaxToken::from(property)),
+                ]));
+            }
+            _ => return None,
+        }
+        Some(Self {
+            fields,
+            syntax: Some(InformationObjectSyntax { expressions }),
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct InformationObjectClassField {
     pub identifier: ObjectFieldIdentifier,
     pub ty: Option<ASN1Type>,
     pub is_optional: bool,
-    pub default: Option<ASN1Value>,
+    /// The field that objects of the class implicitly specify if they omit the field
+    pub default: Option<InformationObjectField>,
     pub is_unique: bool,
 }
 
@@ -367,12 +451,21 @@ impl
             Option<ASN1Value>,
         ),
     ) -> Self {
+        let default = match (&value.0, value.4) {
+            (ObjectFieldIdentifier::SingleValue(identifier), Some(value)) => {
+                Some(InformationObjectField::FixedValueField(FixedValueField {
+                    identifier: identifier.clone(),
+                    value,
+                }))
+            }
+            _ => None,
+        };
         Self {
             identifier: value.0,
             ty: value.1,
             is_unique: value.2.is_some(),
-            is_optional: value.3.is_some() || value.4.is_some(),
-            default: value.4,
+            is_optional: value.3.is_some() || default.is_some(),
+            default,
         }
     }
 }
@@ -394,6 +487,21 @@ impl ObjectFieldIdentifier {
     }
 }
 
+impl From<(ObjectFieldIdentifier, ASN1Type)> for InformationObjectClassField {
+    fn from(value: (ObjectFieldIdentifier, ASN1Type)) -> Self {
+        Self {
+            default: Some(InformationObjectField::TypeField(TypeField {
+                identifier: value.0.identifier().clone(),
+                ty: value.1,
+            })),
+            identifier: value.0,
+            ty: None,
+            is_optional: true,
+            is_unique: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct InformationObject {
     pub class_name: String,
@@ -413,6 +521,11 @@ pub enum InformationObjectFields {
 #[derive(Clone, PartialEq)]
 pub enum ObjectSetValue {
     Reference(String),
+    /// A reference to a parameterized object set with its actual parameters, e.g. `BaseIEs { {ie-c} }`
+    ParameterizedReference {
+        identifier: String,
+        parameters: Vec<Parameter>,
+    },
     Inline(InformationObjectFields),
 }
 
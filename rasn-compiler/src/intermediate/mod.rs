@@ -10,6 +10,7 @@ pub mod constraints;
 pub mod encoding_rules;
 pub mod error;
 pub mod information_object;
+mod notation;
 pub mod parameterization;
 pub mod types;
 pub mod utils;
@@ -17,9 +18,12 @@ pub mod utils;
 use std::{borrow::Cow, cell::RefCell, collections::BTreeMap, ops::Add, rc::Rc};
 
 use crate::common::INTERNAL_IO_FIELD_REF_TYPE_NAME_PREFIX;
-use constraints::Constraint;
+use chrono::{DateTime, FixedOffset};
+use constraints::{Constraint, Parameter};
 use error::{GrammarError, GrammarErrorType};
-use information_object::{InformationObjectFieldReference, ToplevelInformationDefinition};
+use information_object::{
+    InformationObjectFieldReference, ObjectFieldIdentifier, ToplevelInformationDefinition,
+};
 #[cfg(test)]
 use internal_macros::EnumDebug;
 use parameterization::Parameterization;
@@ -56,6 +60,7 @@ pub const TELETEX_STRING: &str = "TeletexString";
 pub const VIDEOTEX_STRING: &str = "VideotexString";
 pub const GRAPHIC_STRING: &str = "GraphicString";
 pub const GENERAL_STRING: &str = "GeneralString";
+pub const OBJECT_DESCRIPTOR: &str = "ObjectDescriptor";
 pub const UNIVERSAL_STRING: &str = "UniversalString";
 pub const BMP_STRING: &str = "BMPString";
 pub const PRINTABLE_STRING: &str = "PrintableString";
@@ -92,6 +97,8 @@ pub const IMPORTS: &str = "IMPORTS";
 pub const EXPORTS: &str = "EXPORTS";
 pub const FROM: &str = "FROM";
 pub const INSTRUCTIONS: &str = "INSTRUCTIONS";
+pub const ENCODING_CONTROL: &str = "ENCODING-CONTROL";
+pub const MACRO: &str = "MACRO";
 pub const TAGS: &str = "TAGS";
 pub const EXTENSIBILITY_IMPLIED: &str = "EXTENSIBILITY IMPLIED";
 pub const WITH_SUCCESSORS: &str = "WITH SUCCESSORS";
@@ -128,6 +135,10 @@ pub const LESS_THAN: char = '<';
 pub const GREATER_THAN: char = '>';
 pub const PIPE: &str = "|";
 pub const CARET: &str = "^";
+pub const PLUS: char = '+';
+pub const HYPHEN_MINUS: char = '-';
+pub const ASTERISK: char = '*';
+pub const SOLIDUS: char = '/';
 
 pub const ASSIGN: &str = "::=";
 pub const RANGE: &str = "..";
@@ -158,7 +169,7 @@ pub const TIME: &str = "TIME";
 pub const TIME_OF_DAY: &str = "TIME-OF-DAY";
 pub const TYPE_IDENTIFIER: &str = "TYPE-IDENTIFIER";
 
-pub const ASN1_KEYWORDS: [&str; 63] = [
+pub const ASN1_KEYWORDS: [&str; 65] = [
     ABSTRACT_SYNTAX,
     BIT,
     CHARACTER,
@@ -173,6 +184,7 @@ pub const ASN1_KEYWORDS: [&str; 63] = [
     NOT_A_NUMBER,
     OBJECT,
     OCTET,
+    OBJECT_DESCRIPTOR,
     OID_IRI,
     PLUS_INFINITY,
     RELATIVE_OID,
@@ -221,6 +233,7 @@ pub const ASN1_KEYWORDS: [&str; 63] = [
     IMPORTS,
     FROM,
     INSTRUCTIONS,
+    ENCODING_CONTROL,
     TAGS,
 ];
 
@@ -401,6 +414,8 @@ pub struct ModuleReference {
     pub extensibility_environment: ExtensibilityEnvironment,
     pub imports: Vec<Import>,
     pub exports: Option<Exports>,
+    pub encoding_control: Vec<EncodingControl>,
+    pub macros: Vec<String>,
 }
 
 impl ModuleReference {
@@ -452,10 +467,21 @@ impl
             extensibility_environment,
             exports: value.3,
             imports: value.4.unwrap_or_default(),
+            encoding_control: vec![],
+            macros: vec![],
         }
     }
 }
 
+/// Represents an encoding control section as specified in
+/// Rec. ITU-T X.680 (02/2021) § 54.
+/// The encoding instructions of the section are kept verbatim, since they are not interpreted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodingControl {
+    pub encoding_reference: String,
+    pub instructions: String,
+}
+
 /// Represents an object identifier value as specified in
 /// Rec. ITU-T X.680 (02/2021) §32
 #[derive(Debug, Clone, PartialEq)]
@@ -597,6 +623,21 @@ impl ToplevelDefinition {
         }
     }
 
+    /// Inserts the extension marker that the `EXTENSIBILITY IMPLIED` clause of a module implies
+    /// at the end of every `SEQUENCE`, `SET`, `CHOICE`, and `ENUMERATED` type of a top-level
+    /// type definition that does not contain an extension marker yet, including nested types.
+    /// *As defined in Rec. ITU-T X.680 (02/2021) §13.4*
+    pub(crate) fn apply_extensibility_environment(
+        &mut self,
+        environment: &ExtensibilityEnvironment,
+    ) {
+        if let (ExtensibilityEnvironment::Implied, ToplevelDefinition::Type(ty)) =
+            (environment, self)
+        {
+            ty.ty.imply_extensibility();
+        }
+    }
+
     /// Returns the name of a top-level definition.
     /// ### Example
     /// ```
@@ -689,12 +730,24 @@ pub struct ToplevelTypeDefinition {
     pub ty: ASN1Type,
     pub parameterization: Option<Parameterization>,
     pub index: Option<(Rc<RefCell<ModuleReference>>, usize)>,
+    /// The CHOICE alternative that the type is selected from, if the type is assigned a selection type.
+    /// The linker replaces the selection type in `ty` with the type of the selected alternative.
+    pub selection: Option<ChoiceSelectionType>,
+    /// Whether the type is generated as a plain alias of the type referenced in `ty`.
+    /// Set for definitions that are merged with an identical definition of another module.
+    pub is_alias: bool,
 }
 
 impl ToplevelTypeDefinition {
     pub fn pdu(&self) -> &ASN1Type {
         &self.ty
     }
+
+    /// Whether two type definitions are structurally identical, i.e. whether both
+    /// their types (including constraints) and their tags match. Names are not compared.
+    pub fn is_structurally_equal(&self, other: &ToplevelTypeDefinition) -> bool {
+        self.ty == other.ty && self.tag == other.tag
+    }
 }
 
 impl From<(&str, ASN1Type)> for ToplevelTypeDefinition {
@@ -706,6 +759,8 @@ impl From<(&str, ASN1Type)> for ToplevelTypeDefinition {
             ty: value.1,
             parameterization: None,
             index: None,
+            selection: None,
+            is_alias: false,
         }
     }
 }
@@ -726,6 +781,10 @@ impl
             (Option<AsnTag>, ASN1Type),
         ),
     ) -> Self {
+        let selection = match &value.3 .1 {
+            ASN1Type::ChoiceSelectionType(selection) => Some(selection.clone()),
+            _ => None,
+        };
         Self {
             comments: value.0.join("\n"),
             name: value.1.into(),
@@ -733,6 +792,8 @@ impl
             ty: value.3 .1,
             tag: value.3 .0,
             index: None,
+            selection,
+            is_alias: false,
         }
     }
 }
@@ -763,6 +824,7 @@ pub enum ASN1Type {
     ElsewhereDeclaredType(DeclarationElsewhere),
     ChoiceSelectionType(ChoiceSelectionType),
     ObjectIdentifier(ObjectIdentifier),
+    ObjectIdentifierIri(ObjectIdentifierIri),
     InformationObjectFieldReference(InformationObjectFieldReference),
     EmbeddedPdv,
     External,
@@ -817,6 +879,10 @@ impl ASN1Type {
                 ty: CharacterStringType::GraphicString,
                 ..
             }) => Cow::Borrowed(GRAPHIC_STRING),
+            ASN1Type::CharacterString(CharacterString {
+                ty: CharacterStringType::ObjectDescriptor,
+                ..
+            }) => Cow::Borrowed(OBJECT_DESCRIPTOR),
             ASN1Type::CharacterString(CharacterString {
                 ty: CharacterStringType::NumericString,
                 ..
@@ -827,14 +893,21 @@ impl ASN1Type {
             ASN1Type::SequenceOf(_) => Cow::Borrowed(SEQUENCE_OF),
             ASN1Type::Set(_) => Cow::Borrowed(SET),
             ASN1Type::SetOf(_) => Cow::Borrowed(SET_OF),
-            ASN1Type::Time(_) => Cow::Borrowed(TIME),
+            ASN1Type::Time(t) => Cow::Borrowed(t.ty.as_str()),
             ASN1Type::GeneralizedTime(_) => Cow::Borrowed(GENERALIZED_TIME),
             ASN1Type::UTCTime(_) => Cow::Borrowed(UTC_TIME),
             ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere { identifier, .. }) => {
                 Cow::Borrowed(identifier)
             }
             ASN1Type::ChoiceSelectionType(_) => todo!(),
+            ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                is_relative: true, ..
+            }) => Cow::Borrowed(RELATIVE_OID),
             ASN1Type::ObjectIdentifier(_) => Cow::Borrowed(OBJECT_IDENTIFIER),
+            ASN1Type::ObjectIdentifierIri(ObjectIdentifierIri {
+                is_relative: true, ..
+            }) => Cow::Borrowed(RELATIVE_OID_IRI),
+            ASN1Type::ObjectIdentifierIri(_) => Cow::Borrowed(OID_IRI),
             ASN1Type::InformationObjectFieldReference(ifr) => Cow::Owned(format!(
                 "{INTERNAL_IO_FIELD_REF_TYPE_NAME_PREFIX}{}${}",
                 ifr.class,
@@ -845,6 +918,208 @@ impl ASN1Type {
         }
     }
 
+    /// Returns the number of the `UNIVERSAL` tag of a builtin type as specified in
+    /// Rec. ITU-T X.680 (02/2021) § 8.4. Returns `None` for types whose tag
+    /// depends on other definitions, such as references, open types, or `CHOICE`s.
+    pub fn universal_tag(&self) -> Option<u64> {
+        match self {
+            ASN1Type::Boolean(_) => Some(1),
+            ASN1Type::Integer(_) => Some(2),
+            ASN1Type::BitString(_) => Some(3),
+            ASN1Type::OctetString(_) => Some(4),
+            ASN1Type::Null => Some(5),
+            ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                is_relative: true, ..
+            }) => Some(13),
+            ASN1Type::ObjectIdentifier(_) => Some(6),
+            ASN1Type::ObjectIdentifierIri(ObjectIdentifierIri {
+                is_relative: true, ..
+            }) => Some(36),
+            ASN1Type::ObjectIdentifierIri(_) => Some(35),
+            ASN1Type::External => Some(8),
+            ASN1Type::Real(_) => Some(9),
+            ASN1Type::Enumerated(_) => Some(10),
+            ASN1Type::EmbeddedPdv => Some(11),
+            ASN1Type::Time(Time { ty, .. }) => Some(match ty {
+                TimeType::Time => 14,
+                TimeType::Date => 31,
+                TimeType::TimeOfDay => 32,
+                TimeType::DateTime => 33,
+                TimeType::Duration => 34,
+            }),
+            ASN1Type::Sequence(_) | ASN1Type::SequenceOf(_) => Some(16),
+            ASN1Type::Set(_) | ASN1Type::SetOf(_) => Some(17),
+            ASN1Type::UTCTime(_) => Some(23),
+            ASN1Type::GeneralizedTime(_) => Some(24),
+            ASN1Type::CharacterString(CharacterString { ty, .. }) => Some(match ty {
+                CharacterStringType::ObjectDescriptor => 7,
+                CharacterStringType::UTF8String => 12,
+                CharacterStringType::NumericString => 18,
+                CharacterStringType::PrintableString => 19,
+                CharacterStringType::TeletexString => 20,
+                CharacterStringType::VideotexString => 21,
+                CharacterStringType::IA5String => 22,
+                CharacterStringType::GraphicString => 25,
+                CharacterStringType::VisibleString => 26,
+                CharacterStringType::GeneralString => 27,
+                CharacterStringType::UniversalString => 28,
+                CharacterStringType::BMPString => 30,
+            }),
+            ASN1Type::Choice(_)
+            | ASN1Type::ElsewhereDeclaredType(_)
+            | ASN1Type::ChoiceSelectionType(_)
+            | ASN1Type::InformationObjectFieldReference(_) => None,
+        }
+    }
+
+    /// Returns the `SEQUENCE` type that values of a builtin type with an associated type
+    /// are encoded as, or `None` for all other types.
+    /// For `EMBEDDED PDV`, this is the associated type of Rec. ITU-T X.680 (02/2021) § 36.5
+    /// with the tags of its automatic tagging environment and without the absent
+    /// `data-value-descriptor` component.
+    /// For `EXTERNAL`, this is the sequence type of Rec. ITU-T X.690 (02/2021) § 8.18.1,
+    /// which is compatible with the associated type of Rec. ITU-T X.680 (02/2021) § 37.5.
+    pub fn associated_type(&self) -> Option<ASN1Type> {
+        let tag = |environment, id| {
+            Some(AsnTag {
+                environment,
+                tag_class: TagClass::ContextSpecific,
+                id,
+            })
+        };
+        let optional_member = |name: &str, tag, ty, is_optional| SequenceOrSetMember {
+            name: name.into(),
+            tag,
+            ty,
+            default_value: None,
+            is_optional,
+            constraints: vec![],
+        };
+        let member = |name: &str, tag, ty| optional_member(name, tag, ty, false);
+        let sequence = |members| {
+            ASN1Type::Sequence(SequenceOrSet {
+                components_of: vec![],
+                extensible: None,
+                constraints: vec![],
+                members,
+            })
+        };
+        let oid = || {
+            ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                constraints: vec![],
+                is_relative: false,
+            })
+        };
+        let integer = || ASN1Type::Integer(Integer::default());
+        let choice = |options: Vec<(&str, Option<AsnTag>, ASN1Type)>| {
+            ASN1Type::Choice(Choice {
+                extensible: None,
+                options: options
+                    .into_iter()
+                    .map(|(name, tag, ty)| ChoiceOption {
+                        name: name.into(),
+                        tag,
+                        ty,
+                        constraints: vec![],
+                    })
+                    .collect(),
+                constraints: vec![],
+            })
+        };
+        match self {
+            ASN1Type::EmbeddedPdv => {
+                let options = [
+                    (
+                        "syntaxes",
+                        sequence(vec![
+                            member("abstract", None, oid()),
+                            member("transfer", None, oid()),
+                        ]),
+                    ),
+                    ("syntax", oid()),
+                    ("presentation-context-id", integer()),
+                    (
+                        "context-negotiation",
+                        sequence(vec![
+                            member("presentation-context-id", None, integer()),
+                            member("transfer-syntax", None, oid()),
+                        ]),
+                    ),
+                    ("transfer-syntax", oid()),
+                    ("fixed", ASN1Type::Null),
+                ];
+                let identification = choice(
+                    options
+                        .into_iter()
+                        .zip(0..)
+                        .map(|((name, ty), id)| (name, tag(TaggingEnvironment::Implicit, id), ty))
+                        .collect(),
+                );
+                Some(sequence(vec![
+                    member(
+                        "identification",
+                        tag(TaggingEnvironment::Explicit, 0),
+                        identification,
+                    ),
+                    member(
+                        "data-value",
+                        tag(TaggingEnvironment::Implicit, 2),
+                        ASN1Type::OctetString(OctetString {
+                            constraints: vec![],
+                        }),
+                    ),
+                ]))
+            }
+            ASN1Type::External => {
+                let encoding = choice(vec![
+                    (
+                        "single-ASN1-type",
+                        tag(TaggingEnvironment::Explicit, 0),
+                        ASN1Type::InformationObjectFieldReference(
+                            InformationObjectFieldReference {
+                                class: "ABSTRACT-SYNTAX".into(),
+                                field_path: vec![ObjectFieldIdentifier::MultipleValue(
+                                    "&Type".into(),
+                                )],
+                                constraints: vec![],
+                            },
+                        ),
+                    ),
+                    (
+                        "octet-aligned",
+                        tag(TaggingEnvironment::Implicit, 1),
+                        ASN1Type::OctetString(OctetString {
+                            constraints: vec![],
+                        }),
+                    ),
+                    (
+                        "arbitrary",
+                        tag(TaggingEnvironment::Implicit, 2),
+                        ASN1Type::BitString(BitString {
+                            constraints: vec![],
+                            distinguished_values: None,
+                        }),
+                    ),
+                ]);
+                Some(sequence(vec![
+                    optional_member("direct-reference", None, oid(), true),
+                    optional_member("indirect-reference", None, integer(), true),
+                    optional_member(
+                        "data-value-descriptor",
+                        None,
+                        ASN1Type::CharacterString(CharacterString {
+                            constraints: vec![],
+                            ty: CharacterStringType::ObjectDescriptor,
+                        }),
+                        true,
+                    ),
+                    member("encoding", None, encoding),
+                ]))
+            }
+            _ => None,
+        }
+    }
+
     pub fn builtin_or_elsewhere(
         parent: Option<&str>,
         identifier: &str,
@@ -875,9 +1150,15 @@ impl ASN1Type {
             (None, UTC_TIME) => ASN1Type::UTCTime(UTCTime {
                 constraints: constraints.unwrap_or_default(),
             }),
-            (None, OBJECT_IDENTIFIER) => ASN1Type::ObjectIdentifier(ObjectIdentifier {
-                constraints: constraints.unwrap_or_default(),
-            }),
+            (None, TIME | DATE | TIME_OF_DAY | DATE_TIME | DURATION) => {
+                ASN1Type::Time((identifier, constraints).into())
+            }
+            (None, OBJECT_IDENTIFIER | RELATIVE_OID) => {
+                ASN1Type::ObjectIdentifier((identifier, constraints).into())
+            }
+            (None, OID_IRI | RELATIVE_OID_IRI) => {
+                ASN1Type::ObjectIdentifierIri((identifier, constraints).into())
+            }
             (None, BMP_STRING) => ASN1Type::CharacterString(CharacterString {
                 constraints: constraints.unwrap_or_default(),
                 ty: CharacterStringType::BMPString,
@@ -918,6 +1199,10 @@ impl ASN1Type {
                 constraints: constraints.unwrap_or_default(),
                 ty: CharacterStringType::GraphicString,
             }),
+            (None, OBJECT_DESCRIPTOR) => ASN1Type::CharacterString(CharacterString {
+                constraints: constraints.unwrap_or_default(),
+                ty: CharacterStringType::ObjectDescriptor,
+            }),
             (None, NUMERIC_STRING) => ASN1Type::CharacterString(CharacterString {
                 constraints: constraints.unwrap_or_default(),
                 ty: CharacterStringType::NumericString,
@@ -945,6 +1230,7 @@ impl ASN1Type {
             ASN1Type::CharacterString(c) => Some(c.constraints()),
             ASN1Type::Enumerated(e) => Some(e.constraints()),
             ASN1Type::Time(t) => Some(t.constraints()),
+            ASN1Type::ObjectIdentifierIri(o) => Some(o.constraints()),
             ASN1Type::Choice(c) => Some(c.constraints()),
             ASN1Type::Set(s) | ASN1Type::Sequence(s) => Some(s.constraints()),
             ASN1Type::SetOf(s) | ASN1Type::SequenceOf(s) => Some(s.constraints()),
@@ -964,6 +1250,7 @@ impl ASN1Type {
             ASN1Type::CharacterString(c) => Some(c.constraints_mut()),
             ASN1Type::Enumerated(e) => Some(e.constraints_mut()),
             ASN1Type::Time(t) => Some(t.constraints_mut()),
+            ASN1Type::ObjectIdentifierIri(o) => Some(o.constraints_mut()),
             ASN1Type::Choice(c) => Some(c.constraints_mut()),
             ASN1Type::Set(s) | ASN1Type::Sequence(s) => Some(s.constraints_mut()),
             ASN1Type::SetOf(s) | ASN1Type::SequenceOf(s) => Some(s.constraints_mut()),
@@ -972,6 +1259,31 @@ impl ASN1Type {
             _ => None,
         }
     }
+
+    /// Marks `self` and the types nested in `self` as extensible,
+    /// unless they already contain an extension marker.
+    /// See [ToplevelDefinition::apply_extensibility_environment]
+    fn imply_extensibility(&mut self) {
+        match self {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                s.extensible.get_or_insert(s.members.len());
+                s.members
+                    .iter_mut()
+                    .for_each(|m| m.ty.imply_extensibility());
+            }
+            ASN1Type::Choice(c) => {
+                c.extensible.get_or_insert(c.options.len());
+                c.options
+                    .iter_mut()
+                    .for_each(|o| o.ty.imply_extensibility());
+            }
+            ASN1Type::Enumerated(e) => {
+                e.extensible.get_or_insert(e.members.len());
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => s.element_type.imply_extensibility(),
+            _ => (),
+        }
+    }
 }
 
 pub const NUMERIC_STRING_CHARSET: [char; 11] =
@@ -999,6 +1311,8 @@ pub enum CharacterStringType {
     UTF8String,
     BMPString,
     PrintableString,
+    /// `ObjectDescriptor ::= [UNIVERSAL 7] IMPLICIT GraphicString`
+    ObjectDescriptor,
 }
 
 impl CharacterStringType {
@@ -1032,6 +1346,7 @@ impl From<&str> for CharacterStringType {
             VIDEOTEX_STRING => Self::VideotexString,
             GRAPHIC_STRING => Self::GraphicString,
             GENERAL_STRING => Self::GeneralString,
+            OBJECT_DESCRIPTOR => Self::ObjectDescriptor,
             UNIVERSAL_STRING => Self::UniversalString,
             BMP_STRING => Self::BMPString,
             PRINTABLE_STRING => Self::PrintableString,
@@ -1040,6 +1355,44 @@ impl From<&str> for CharacterStringType {
     }
 }
 
+/// The types of ASN1 time types. Apart from `TIME` itself, these are the useful
+/// time types that X.680 defines as `TIME` with fixed property settings.
+#[cfg_attr(test, derive(EnumDebug))]
+#[cfg_attr(not(test), derive(Debug))]
+#[derive(Clone, PartialEq, Copy)]
+pub enum TimeType {
+    Time,
+    Date,
+    TimeOfDay,
+    DateTime,
+    Duration,
+}
+
+impl TimeType {
+    /// Returns the ASN.1 notation of the time type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeType::Time => TIME,
+            TimeType::Date => DATE,
+            TimeType::TimeOfDay => TIME_OF_DAY,
+            TimeType::DateTime => DATE_TIME,
+            TimeType::Duration => DURATION,
+        }
+    }
+}
+
+impl From<&str> for TimeType {
+    fn from(value: &str) -> Self {
+        match value {
+            DATE => Self::Date,
+            TIME_OF_DAY => Self::TimeOfDay,
+            DATE_TIME => Self::DateTime,
+            DURATION => Self::Duration,
+            _ => Self::Time,
+        }
+    }
+}
+
 /// Representation of common integer types
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IntegerType {
@@ -1074,6 +1427,22 @@ impl IntegerType {
     pub fn is_unbounded(&self) -> bool {
         self == &IntegerType::Unbounded
     }
+
+    /// Returns whether `value` lies in the value range of the integer type
+    pub fn can_represent(&self, value: i128) -> bool {
+        match self {
+            IntegerType::Int8 => i8::try_from(value).is_ok(),
+            IntegerType::Uint8 => u8::try_from(value).is_ok(),
+            IntegerType::Int16 => i16::try_from(value).is_ok(),
+            IntegerType::Uint16 => u16::try_from(value).is_ok(),
+            IntegerType::Int32 => i32::try_from(value).is_ok(),
+            IntegerType::Uint32 => u32::try_from(value).is_ok(),
+            IntegerType::Int64 => i64::try_from(value).is_ok(),
+            IntegerType::Uint64 => u64::try_from(value).is_ok(),
+            IntegerType::Unbounded => true,
+        }
+    }
+
     /// Returns the Integer type with more restrictions
     /// - an IntegerType with a smaller set of values is considered more restrictive
     /// - an unsigned IntegerType is considered more restrictive if the size of the set of values is equal
@@ -1099,7 +1468,6 @@ impl IntegerType {
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
 pub enum ASN1Value {
-    All,
     Null,
     Boolean(bool),
     Choice {
@@ -1124,6 +1492,20 @@ pub enum ASN1Value {
         parent: Option<String>,
         identifier: String,
     },
+    /// Reference to a parameterized value definition, e.g. `maxOf{5}`.
+    /// The linker substitutes the actual parameters and replaces the reference with a concrete value.
+    ParameterizedValueReference {
+        identifier: String,
+        parameters: Vec<Parameter>,
+    },
+    /// Arithmetic expression in the bound of a constraint, e.g. `maxEntries - 1` in `INTEGER (0..maxEntries - 1)`.
+    /// Arithmetic expressions are not part of X.680, but some specifications use them nevertheless.
+    /// The linker evaluates the expression and replaces it with the resulting integer value.
+    Arithmetic {
+        operator: ArithmeticOperator,
+        lhs: Box<ASN1Value>,
+        rhs: Box<ASN1Value>,
+    },
     ObjectIdentifier(ObjectIdentifierValue),
     /// In ASN1 value declarations, the value type is not straighforward to parse.
     /// For example, in the following ASN1
@@ -1154,6 +1536,9 @@ pub enum ASN1Value {
     LinkedArrayLikeValue(Vec<Box<ASN1Value>>),
     /// Character string values such as UTF8String values need type information that will not always be picked up by the lexer on first pass.
     LinkedCharStringValue(CharacterStringType, String),
+    /// Time values such as GeneralizedTime values need type information that will not always be picked up by the lexer on first pass.
+    /// Contains the date and time denoted by the value
+    LinkedTimeValue(DateTime<FixedOffset>),
     LinkedElsewhereDefinedValue {
         parent: Option<String>,
         identifier: String,
@@ -1161,6 +1546,17 @@ pub enum ASN1Value {
     },
 }
 
+/// Operators of arithmetic expressions in the bounds of constraints
+#[cfg_attr(test, derive(EnumDebug))]
+#[cfg_attr(not(test), derive(Debug))]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
 /// Representation of a field value of a struct-like ASN1 value
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
@@ -1198,6 +1594,21 @@ impl AsMut<ASN1Value> for ASN1Value {
 }
 
 impl ASN1Value {
+    /// Returns the octets of an OCTET STRING value. Hexadecimal and binary string values
+    /// are parsed as BIT STRING values, which are returned if their length is a multiple of eight.
+    pub fn as_octets(&self) -> Option<Vec<u8>> {
+        match self {
+            ASN1Value::OctetString(octets) => Some(octets.clone()),
+            ASN1Value::BitString(bits) if bits.chunks_exact(8).remainder().is_empty() => Some(
+                bits.chunks_exact(8)
+                    .map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | u8::from(*bit)))
+                    .collect(),
+            ),
+            ASN1Value::LinkedNestedValue { value, .. } => value.as_octets(),
+            _ => None,
+        }
+    }
+
     pub fn max(
         &self,
         other: &ASN1Value,
@@ -0,0 +1,716 @@
+//! The `notation` module renders the intermediate representation
+//! back into ASN.1 notation. The output is canonical in the sense
+//! that it does not depend on the formatting of the original specification:
+//! every type, value, and constraint is printed on a single line with
+//! uniform spacing. This makes it suitable for normalizing specifications
+//! and for displaying the effective constraints of a type in diagnostics.
+
+use super::{
+    constraints::*,
+    information_object::{
+        InformationObjectField, InformationObjectFields, ObjectSet, ObjectSetValue,
+        SyntaxApplication,
+    },
+    types::*,
+    *,
+};
+
+impl ASN1Type {
+    /// Renders the type in ASN.1 notation.
+    /// ### Example
+    /// ```
+    /// # use rasn_compiler::prelude::ir::*;
+    /// let ty = ASN1Type::Integer(Integer {
+    ///     constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+    ///         set: ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+    ///             min: Some(ASN1Value::Integer(0)),
+    ///             max: Some(ASN1Value::Integer(255)),
+    ///             extensible: true,
+    ///         }),
+    ///         extensible: false,
+    ///     })],
+    ///     distinguished_values: None,
+    /// });
+    /// assert_eq!(ty.to_asn1_string(), "INTEGER (0..255, ...)");
+    /// ```
+    pub fn to_asn1_string(&self) -> String {
+        match self {
+            ASN1Type::Null => NULL.to_owned(),
+            ASN1Type::Boolean(b) => with_constraints(BOOLEAN, &b.constraints),
+            ASN1Type::Integer(i) => with_constraints(
+                &with_distinguished_values(INTEGER, &i.distinguished_values),
+                &i.constraints,
+            ),
+            ASN1Type::Real(r) => with_constraints(REAL, &r.constraints),
+            ASN1Type::BitString(b) => with_constraints(
+                &with_distinguished_values(BIT_STRING, &b.distinguished_values),
+                &b.constraints,
+            ),
+            ASN1Type::OctetString(o) => with_constraints(OCTET_STRING, &o.constraints),
+            ASN1Type::CharacterString(c) => with_constraints(&self.as_str(), &c.constraints),
+            ASN1Type::Enumerated(e) => {
+                let enumerals = e
+                    .members
+                    .iter()
+                    .map(|enumeral| match &enumeral.numbering {
+                        EnumeralNumbering::Explicit => {
+                            format!("{}({})", enumeral.name, enumeral.index)
+                        }
+                        EnumeralNumbering::Reference(reference) => {
+                            format!("{}({reference})", enumeral.name)
+                        }
+                        EnumeralNumbering::Automatic => enumeral.name.clone(),
+                    })
+                    .collect();
+                with_constraints(
+                    &format!(
+                        "{ENUMERATED} {}",
+                        braced(with_extension(enumerals, e.extensible))
+                    ),
+                    &e.constraints,
+                )
+            }
+            ASN1Type::Choice(c) => {
+                let options = c
+                    .options
+                    .iter()
+                    .map(|option| {
+                        format!(
+                            "{} {}{}",
+                            option.name,
+                            tag_prefix(&option.tag),
+                            with_constraints(&option.ty.to_asn1_string(), &option.constraints)
+                        )
+                    })
+                    .collect();
+                with_constraints(
+                    &format!("{CHOICE} {}", braced(with_extension(options, c.extensible))),
+                    &c.constraints,
+                )
+            }
+            ASN1Type::Sequence(s) => sequence_or_set_to_string(SEQUENCE, s),
+            ASN1Type::Set(s) => sequence_or_set_to_string(SET, s),
+            ASN1Type::SequenceOf(s) => sequence_or_set_of_to_string(SEQUENCE, s),
+            ASN1Type::SetOf(s) => sequence_or_set_of_to_string(SET, s),
+            ASN1Type::Time(t) => with_constraints(t.ty.as_str(), &t.constraints),
+            ASN1Type::GeneralizedTime(t) => with_constraints(GENERALIZED_TIME, &t.constraints),
+            ASN1Type::UTCTime(t) => with_constraints(UTC_TIME, &t.constraints),
+            ASN1Type::ElsewhereDeclaredType(e) => {
+                let reference = match &e.parent {
+                    Some(parent) => format!("{parent}{DOT}{}", e.identifier),
+                    None => e.identifier.clone(),
+                };
+                with_constraints(&reference, &e.constraints)
+            }
+            ASN1Type::ChoiceSelectionType(c) => {
+                format!("{} {LEFT_CHEVRON} {}", c.selected_option, c.choice_name)
+            }
+            ASN1Type::ObjectIdentifier(o) if o.is_relative => {
+                with_constraints(RELATIVE_OID, &o.constraints)
+            }
+            ASN1Type::ObjectIdentifier(o) => with_constraints(OBJECT_IDENTIFIER, &o.constraints),
+            ASN1Type::ObjectIdentifierIri(o) if o.is_relative => {
+                with_constraints(RELATIVE_OID_IRI, &o.constraints)
+            }
+            ASN1Type::ObjectIdentifierIri(o) => with_constraints(OID_IRI, &o.constraints),
+            ASN1Type::InformationObjectFieldReference(r) => {
+                let path = r
+                    .field_path
+                    .iter()
+                    .map(|field| field.identifier().as_str())
+                    .collect::<Vec<_>>()
+                    .join(&DOT.to_string());
+                with_constraints(&format!("{}{DOT}{path}", r.class), &r.constraints)
+            }
+            ASN1Type::EmbeddedPdv => EMBEDDED_PDV.to_owned(),
+            ASN1Type::External => EXTERNAL.to_owned(),
+        }
+    }
+}
+
+impl Constraint {
+    /// Renders the constraint in ASN.1 notation.
+    /// Subtype, table, and content constraints are enclosed in parentheses,
+    /// actual parameters of a parameterized reference are enclosed in braces.
+    pub fn to_asn1_string(&self) -> String {
+        match self {
+            Constraint::SubtypeConstraint(set) => {
+                format!(
+                    "{LEFT_PARENTHESIS}{}{RIGHT_PARENTHESIS}",
+                    element_set_to_string(set)
+                )
+            }
+            Constraint::TableConstraint(table) => {
+                let mut notation = object_set_to_string(&table.object_set);
+                if !table.linked_fields.is_empty() {
+                    notation.push_str(&braced(
+                        table
+                            .linked_fields
+                            .iter()
+                            .map(|field| {
+                                format!(
+                                    "{AT}{}{}",
+                                    DOT.to_string().repeat(field.level),
+                                    [&field.field_name]
+                                        .into_iter()
+                                        .chain(&field.component_path)
+                                        .map(String::as_str)
+                                        .collect::<Vec<_>>()
+                                        .join(&DOT.to_string())
+                                )
+                            })
+                            .collect(),
+                    ));
+                }
+                format!("{LEFT_PARENTHESIS}{notation}{RIGHT_PARENTHESIS}")
+            }
+            Constraint::Parameter(parameters) => braced(
+                parameters
+                    .iter()
+                    .map(|parameter| match parameter {
+                        Parameter::ValueParameter(v) => v.to_asn1_string(),
+                        Parameter::TypeParameter(t) => t.to_asn1_string(),
+                        Parameter::InformationObjectParameter(fields) => {
+                            information_object_to_string(fields)
+                        }
+                        Parameter::ObjectSetParameter(set) => object_set_to_string(set),
+                    })
+                    .collect(),
+            ),
+            Constraint::ContentConstraint(content) => {
+                let notation = match content {
+                    ContentConstraint::Containing(ty) => {
+                        format!("{CONTAINING} {}", ty.to_asn1_string())
+                    }
+                    ContentConstraint::EncodedBy(value) => {
+                        format!("{ENCODED_BY} {}", value.to_asn1_string())
+                    }
+                    ContentConstraint::ContainingEncodedBy {
+                        containing,
+                        encoded_by,
+                    } => format!(
+                        "{CONTAINING} {} {ENCODED_BY} {}",
+                        containing.to_asn1_string(),
+                        encoded_by.to_asn1_string()
+                    ),
+                };
+                format!("{LEFT_PARENTHESIS}{notation}{RIGHT_PARENTHESIS}")
+            }
+        }
+    }
+}
+
+impl ASN1Value {
+    /// Renders the value in ASN.1 notation.
+    /// Values that the linker has resolved are printed in their resolved form,
+    /// default values that the linker filled in for omitted members are left out.
+    pub fn to_asn1_string(&self) -> String {
+        match self {
+            ASN1Value::Null => NULL.to_owned(),
+            ASN1Value::Boolean(true) => TRUE.to_owned(),
+            ASN1Value::Boolean(false) => FALSE.to_owned(),
+            ASN1Value::Choice {
+                variant_name,
+                inner_value,
+                ..
+            } => format!("{variant_name} {COLON} {}", inner_value.to_asn1_string()),
+            ASN1Value::SequenceOrSet(fields) => braced(
+                fields
+                    .iter()
+                    .map(|(name, value)| match name {
+                        Some(name) => format!("{name} {}", value.to_asn1_string()),
+                        None => value.to_asn1_string(),
+                    })
+                    .collect(),
+            ),
+            ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => i.to_string(),
+            ASN1Value::Real(r) if r.is_nan() => NOT_A_NUMBER.to_owned(),
+            ASN1Value::Real(r) if r.is_infinite() && r.is_sign_positive() => {
+                PLUS_INFINITY.to_owned()
+            }
+            ASN1Value::Real(r) if r.is_infinite() => MINUS_INFINITY.to_owned(),
+            ASN1Value::Real(r) => format!("{r:?}"),
+            ASN1Value::String(s) | ASN1Value::Time(s) | ASN1Value::LinkedCharStringValue(_, s) => {
+                quoted(s)
+            }
+            ASN1Value::LinkedTimeValue(t) => quoted(&t.format("%Y%m%d%H%M%S%.f%z").to_string()),
+            ASN1Value::BitString(bits) => format!(
+                "{SINGLE_QUOTE}{}{SINGLE_QUOTE}B",
+                bits.iter()
+                    .map(|bit| if *bit { '1' } else { '0' })
+                    .collect::<String>()
+            ),
+            ASN1Value::OctetString(octets) => format!(
+                "{SINGLE_QUOTE}{}{SINGLE_QUOTE}H",
+                octets
+                    .iter()
+                    .map(|octet| format!("{octet:02X}"))
+                    .collect::<String>()
+            ),
+            ASN1Value::EnumeratedValue { enumerable, .. } => enumerable.clone(),
+            ASN1Value::ElsewhereDeclaredValue { parent, identifier }
+            | ASN1Value::LinkedElsewhereDefinedValue {
+                parent, identifier, ..
+            } => match parent {
+                Some(parent) => format!("{parent}{DOT}{identifier}"),
+                None => identifier.clone(),
+            },
+            ASN1Value::ParameterizedValueReference {
+                identifier,
+                parameters,
+            } => format!(
+                "{identifier} {}",
+                Constraint::Parameter(parameters.clone()).to_asn1_string()
+            ),
+            ASN1Value::Arithmetic { operator, lhs, rhs } => {
+                let operand = |value: &ASN1Value, parenthesize_equal: bool| match value {
+                    ASN1Value::Arithmetic {
+                        operator: inner, ..
+                    } if precedence(inner) < precedence(operator)
+                        || (parenthesize_equal && precedence(inner) == precedence(operator)) =>
+                    {
+                        format!(
+                            "{LEFT_PARENTHESIS}{}{RIGHT_PARENTHESIS}",
+                            value.to_asn1_string()
+                        )
+                    }
+                    _ => value.to_asn1_string(),
+                };
+                let symbol = match operator {
+                    ArithmeticOperator::Add => PLUS,
+                    ArithmeticOperator::Subtract => HYPHEN_MINUS,
+                    ArithmeticOperator::Multiply => ASTERISK,
+                    ArithmeticOperator::Divide => SOLIDUS,
+                };
+                format!("{} {symbol} {}", operand(lhs, false), operand(rhs, true))
+            }
+            ASN1Value::ObjectIdentifier(oid) => braced_with(
+                oid.0
+                    .iter()
+                    .map(|arc| match (&arc.name, arc.number) {
+                        (Some(name), Some(number)) => format!("{name}({number})"),
+                        (Some(name), None) => name.clone(),
+                        (None, Some(number)) => number.to_string(),
+                        (None, None) => String::new(),
+                    })
+                    .collect(),
+                " ",
+            ),
+            ASN1Value::LinkedNestedValue { value, .. } => value.to_asn1_string(),
+            ASN1Value::LinkedStructLikeValue(fields) => braced(
+                fields
+                    .iter()
+                    .filter_map(|(name, _, value)| match value {
+                        StructLikeFieldValue::Explicit(v) => {
+                            Some(format!("{name} {}", v.to_asn1_string()))
+                        }
+                        StructLikeFieldValue::Implicit(_) => None,
+                    })
+                    .collect(),
+            ),
+            ASN1Value::LinkedArrayLikeValue(values) => {
+                braced(values.iter().map(|v| v.to_asn1_string()).collect())
+            }
+        }
+    }
+}
+
+fn precedence(operator: &ArithmeticOperator) -> u8 {
+    match operator {
+        ArithmeticOperator::Add | ArithmeticOperator::Subtract => 0,
+        ArithmeticOperator::Multiply | ArithmeticOperator::Divide => 1,
+    }
+}
+
+fn with_constraints(notation: &str, constraints: &[Constraint]) -> String {
+    constraints.iter().fold(notation.to_owned(), |acc, c| {
+        format!("{acc} {}", c.to_asn1_string())
+    })
+}
+
+fn with_distinguished_values(
+    notation: &str,
+    distinguished_values: &Option<Vec<DistinguishedValue>>,
+) -> String {
+    match distinguished_values {
+        Some(values) => format!(
+            "{notation} {}",
+            braced(
+                values
+                    .iter()
+                    .map(|v| format!("{}({})", v.name, v.value))
+                    .collect()
+            )
+        ),
+        None => notation.to_owned(),
+    }
+}
+
+/// Inserts an extension marker into a list of components at the given index.
+fn with_extension(mut items: Vec<String>, extensible: Option<usize>) -> Vec<String> {
+    if let Some(index) = extensible {
+        items.insert(index.min(items.len()), ELLIPSIS.to_owned());
+    }
+    items
+}
+
+fn braced(items: Vec<String>) -> String {
+    braced_with(items, ", ")
+}
+
+fn braced_with(items: Vec<String>, separator: &str) -> String {
+    if items.is_empty() {
+        format!("{LEFT_BRACE}{RIGHT_BRACE}")
+    } else {
+        format!("{LEFT_BRACE} {} {RIGHT_BRACE}", items.join(separator))
+    }
+}
+
+fn quoted(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+fn tag_prefix(tag: &Option<AsnTag>) -> String {
+    match tag {
+        Some(tag) => {
+            let class = match tag.tag_class {
+                TagClass::Universal => format!("{UNIVERSAL} "),
+                TagClass::Application => format!("{APPLICATION} "),
+                TagClass::Private => format!("{PRIVATE} "),
+                TagClass::ContextSpecific => String::new(),
+            };
+            let environment = match tag.environment {
+                TaggingEnvironment::Automatic => String::new(),
+                TaggingEnvironment::Implicit => format!(" {IMPLICIT}"),
+                TaggingEnvironment::Explicit => format!(" {EXPLICIT}"),
+            };
+            format!(
+                "{LEFT_BRACKET}{class}{}{RIGHT_BRACKET}{environment} ",
+                tag.id
+            )
+        }
+        None => String::new(),
+    }
+}
+
+fn sequence_or_set_to_string(keyword: &str, sequence_or_set: &SequenceOrSet) -> String {
+    let mut members = sequence_or_set
+        .components_of
+        .iter()
+        .map(|reference| format!("{COMPONENTS_OF} {reference}"))
+        .collect::<Vec<_>>();
+    let extensible = sequence_or_set
+        .extensible
+        .map(|index| index + members.len());
+    members.extend(sequence_or_set.members.iter().map(member_to_string));
+    with_constraints(
+        &format!("{keyword} {}", braced(with_extension(members, extensible))),
+        &sequence_or_set.constraints,
+    )
+}
+
+fn member_to_string(member: &SequenceOrSetMember) -> String {
+    if let (true, ASN1Type::Sequence(group)) = (member.name.starts_with("ext_group_"), &member.ty) {
+        return format!(
+            "{LEFT_BRACKET}{LEFT_BRACKET} {} {RIGHT_BRACKET}{RIGHT_BRACKET}",
+            group
+                .members
+                .iter()
+                .map(member_to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    let mut notation = format!(
+        "{} {}{}",
+        member.name,
+        tag_prefix(&member.tag),
+        with_constraints(&member.ty.to_asn1_string(), &member.constraints)
+    );
+    if let Some(default) = &member.default_value {
+        notation.push_str(&format!(" {DEFAULT} {}", default.to_asn1_string()));
+    } else if member.is_optional {
+        notation.push_str(&format!(" {OPTIONAL}"));
+    }
+    notation
+}
+
+fn sequence_or_set_of_to_string(keyword: &str, sequence_or_set_of: &SequenceOrSetOf) -> String {
+    format!(
+        "{} {OF} {}",
+        with_constraints(keyword, &sequence_or_set_of.constraints),
+        sequence_or_set_of.element_type.to_asn1_string()
+    )
+}
+
+fn element_set_to_string(set: &ElementSet) -> String {
+    let notation = element_or_set_operation_to_string(&set.set);
+    if set.extensible && !notation.ends_with(ELLIPSIS) {
+        format!("{notation}, {ELLIPSIS}")
+    } else {
+        notation
+    }
+}
+
+fn element_or_set_operation_to_string(set: &ElementOrSetOperation) -> String {
+    match set {
+        ElementOrSetOperation::Element(element) => subtype_element_to_string(element),
+        ElementOrSetOperation::SetOperation(operation) => set_operation_to_string(operation),
+    }
+}
+
+fn set_operation_to_string(operation: &SetOperation) -> String {
+    let operator = match operation.operator {
+        SetOperator::Intersection => CARET,
+        SetOperator::Union => PIPE,
+        SetOperator::Except => EXCEPT,
+    };
+    format!(
+        "{} {operator} {}",
+        subtype_element_to_string(&operation.base),
+        element_or_set_operation_to_string(&operation.operant)
+    )
+}
+
+fn subtype_element_to_string(element: &SubtypeElement) -> String {
+    let extended = |notation: String, extensible: bool| {
+        if extensible {
+            format!("{notation}, {ELLIPSIS}")
+        } else {
+            notation
+        }
+    };
+    match element {
+        SubtypeElement::All => ALL.to_owned(),
+        SubtypeElement::SingleValue { value, extensible } => {
+            extended(value.to_asn1_string(), *extensible)
+        }
+        SubtypeElement::ContainedSubtype {
+            subtype,
+            extensible,
+        } => extended(
+            format!("{INCLUDES} {}", subtype.to_asn1_string()),
+            *extensible,
+        ),
+        SubtypeElement::ValueRange {
+            min,
+            max,
+            extensible,
+        } => extended(
+            format!(
+                "{}{RANGE}{}",
+                min.as_ref().map_or(MIN.to_owned(), |v| v.to_asn1_string()),
+                max.as_ref().map_or(MAX.to_owned(), |v| v.to_asn1_string())
+            ),
+            *extensible,
+        ),
+        SubtypeElement::PermittedAlphabet(set) => format!(
+            "{FROM} {LEFT_PARENTHESIS}{}{RIGHT_PARENTHESIS}",
+            element_or_set_operation_to_string(set)
+        ),
+        SubtypeElement::SizeConstraint(set) => format!(
+            "{SIZE} {LEFT_PARENTHESIS}{}{RIGHT_PARENTHESIS}",
+            element_or_set_operation_to_string(set)
+        ),
+        SubtypeElement::TypeConstraint(ty) => ty.to_asn1_string(),
+        SubtypeElement::SingleTypeConstraint(inner) => inner_type_constraint_to_string(inner),
+        SubtypeElement::MultipleTypeConstraints(inner) => format!(
+            "{WITH_COMPONENT} {LEFT_PARENTHESIS}{}{RIGHT_PARENTHESIS}",
+            inner_type_constraint_to_string(inner)
+        ),
+        SubtypeElement::ComponentTypeConstraint(constraints) => {
+            with_constraints(WITH_COMPONENT, constraints)
+        }
+        SubtypeElement::PatternConstraint(pattern) => {
+            format!("{PATTERN} {}", quoted(&pattern.pattern))
+        }
+        SubtypeElement::UserDefinedConstraint(user_defined) => format!(
+            "{CONSTRAINED_BY} {LEFT_BRACE}{}{RIGHT_BRACE}",
+            user_defined.definition
+        ),
+        SubtypeElement::NestedSet(operation) => format!(
+            "{LEFT_PARENTHESIS}{}{RIGHT_PARENTHESIS}",
+            set_operation_to_string(operation)
+        ),
+        SubtypeElement::PropertySettings(settings) => format!(
+            "SETTINGS {}",
+            quoted(
+                &settings
+                    .property_settings_list
+                    .iter()
+                    .map(property_setting_to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        ),
+    }
+}
+
+fn property_setting_to_string(pair: &PropertyAndSettingsPair) -> String {
+    fn setting<S: PropertySetting>(setting: &S) -> String {
+        format!("{}={}", S::NAME, setting.setting_name())
+    }
+    match pair {
+        PropertyAndSettingsPair::Basic(s) => setting(s),
+        PropertyAndSettingsPair::Date(s) => setting(s),
+        PropertyAndSettingsPair::Year(s) => setting(s),
+        PropertyAndSettingsPair::Time(s) => setting(s),
+        PropertyAndSettingsPair::LocalOrUtc(s) => setting(s),
+        PropertyAndSettingsPair::IntervalType(s) => setting(s),
+        PropertyAndSettingsPair::StartEndPoint(s) => setting(s),
+        PropertyAndSettingsPair::Recurrence(s) => setting(s),
+        PropertyAndSettingsPair::Midnight(s) => setting(s),
+    }
+}
+
+fn inner_type_constraint_to_string(inner: &InnerTypeConstraint) -> String {
+    let mut components = inner
+        .constraints
+        .iter()
+        .map(|component| {
+            let mut notation = with_constraints(&component.identifier, &component.constraints);
+            match component.presence {
+                ComponentPresence::Absent => notation.push_str(&format!(" {ABSENT}")),
+                ComponentPresence::Present => notation.push_str(&format!(" {PRESENT}")),
+                ComponentPresence::Unspecified => (),
+            }
+            notation
+        })
+        .collect::<Vec<_>>();
+    if inner.is_partial {
+        components.insert(0, ELLIPSIS.to_owned());
+    }
+    format!("{WITH_COMPONENTS} {}", braced(components))
+}
+
+fn object_set_to_string(set: &ObjectSet) -> String {
+    let values = set
+        .values
+        .iter()
+        .map(|value| match value {
+            ObjectSetValue::Reference(reference) => reference.clone(),
+            ObjectSetValue::ParameterizedReference {
+                identifier,
+                parameters,
+            } => format!(
+                "{identifier} {}",
+                Constraint::Parameter(parameters.clone()).to_asn1_string()
+            ),
+            ObjectSetValue::Inline(fields) => information_object_to_string(fields),
+        })
+        .collect::<Vec<_>>();
+    let (root, additions) =
+        values.split_at(set.extensible.unwrap_or(values.len()).min(values.len()));
+    let mut items = Vec::new();
+    if !root.is_empty() {
+        items.push(root.join(&format!(" {PIPE} ")));
+    }
+    if set.extensible.is_some() {
+        items.push(ELLIPSIS.to_owned());
+        if !additions.is_empty() {
+            items.push(additions.join(&format!(" {PIPE} ")));
+        }
+    }
+    braced(items)
+}
+
+fn information_object_to_string(fields: &InformationObjectFields) -> String {
+    match fields {
+        InformationObjectFields::DefaultSyntax(fields) => braced(
+            fields
+                .iter()
+                .map(|field| match field {
+                    InformationObjectField::TypeField(f) => {
+                        format!("{} {}", f.identifier, f.ty.to_asn1_string())
+                    }
+                    InformationObjectField::FixedValueField(f) => {
+                        format!("{} {}", f.identifier, f.value.to_asn1_string())
+                    }
+                    InformationObjectField::ObjectSetField(f) => {
+                        format!("{} {}", f.identifier, object_set_to_string(&f.value))
+                    }
+                })
+                .collect(),
+        ),
+        InformationObjectFields::CustomSyntax(syntax) => {
+            let notation = syntax
+                .iter()
+                .map(|application| match application {
+                    SyntaxApplication::ObjectSetDeclaration(set) => object_set_to_string(set),
+                    SyntaxApplication::ValueReference(value) => value.to_asn1_string(),
+                    SyntaxApplication::TypeReference(ty) => ty.to_asn1_string(),
+                    SyntaxApplication::Comma => COMMA.to_string(),
+                    SyntaxApplication::Literal(literal) => literal.clone(),
+                    SyntaxApplication::LiteralOrTypeReference(reference) => {
+                        ASN1Type::ElsewhereDeclaredType(reference.clone()).to_asn1_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace(" ,", ",");
+            braced_with(vec![notation], "")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        intermediate::ASN1Value,
+        lexer::{asn1_type, asn1_value},
+    };
+
+    fn round_trip_type(notation: &str) {
+        let ty = asn1_type(notation).unwrap().1;
+        assert_eq!(ty.to_asn1_string(), notation);
+        assert_eq!(asn1_type(&ty.to_asn1_string()).unwrap().1, ty);
+    }
+
+    fn round_trip_value(notation: &str) {
+        let value = asn1_value(notation).unwrap().1;
+        assert_eq!(value.to_asn1_string(), notation);
+    }
+
+    #[test]
+    fn renders_constrained_builtin_types() {
+        round_trip_type("INTEGER (0..255, ...)");
+        round_trip_type("INTEGER { one(1), two(2) } (MIN..2)");
+        round_trip_type("OCTET STRING (SIZE (3..16, ...))");
+        round_trip_type("IA5String (FROM (\"a\"..\"z\")) (SIZE (1..8))");
+        round_trip_type("BIT STRING { flag(0), other(1) } (SIZE (2))");
+        round_trip_type("INTEGER (1 | 3 | 5..7)");
+        round_trip_type("INTEGER (0..maxValue * 2 - 1)");
+        round_trip_type("SEQUENCE (SIZE (1..4)) OF RegionalExtension { { Reg-MapData } }");
+    }
+
+    #[test]
+    fn renders_constructed_types() {
+        round_trip_type("ENUMERATED { red, green(5), ..., blue }");
+        round_trip_type(
+            "SEQUENCE { id INTEGER (0..7), name [1] IMPLICIT UTF8String OPTIONAL, ..., \
+             [[ flag BOOLEAN DEFAULT TRUE ]] }",
+        );
+        round_trip_type("CHOICE { number [APPLICATION 2] INTEGER, text VisibleString, ... }");
+        round_trip_type("SEQUENCE {}");
+    }
+
+    #[test]
+    fn renders_values() {
+        round_trip_value("{ id 5, name \"say \"\"hi\"\"\" }");
+        round_trip_value("'0101'B");
+        round_trip_value("{ iso(1) member-body(2) 840 }");
+        assert_eq!(
+            ASN1Value::Choice {
+                type_name: None,
+                variant_name: "flag".into(),
+                inner_value: Box::new(ASN1Value::Boolean(true)),
+            }
+            .to_asn1_string(),
+            "flag : TRUE"
+        );
+        assert_eq!(
+            ASN1Value::OctetString(vec![0x0a, 0x1b]).to_asn1_string(),
+            "'0A1B'H"
+        );
+    }
+}
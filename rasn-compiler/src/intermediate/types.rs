@@ -1,8 +1,9 @@
 //! `types` contains representations for ASN.1's basic types, such as `BOOLEAN`s
 //! or `SEQUENCE`s.
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Offset, Utc};
 #[cfg(test)]
 use internal_macros::EnumDebug;
-use std::vec;
+use std::{collections::BTreeSet, vec};
 
 use super::{constraints::*, *};
 
@@ -42,6 +43,7 @@ constrainable!(Enumerated);
 constrainable!(DeclarationElsewhere);
 constrainable!(InformationObjectFieldReference);
 constrainable!(Time);
+constrainable!(ObjectIdentifierIri);
 
 /// Representation of an ASN1 BOOLEAN data element
 /// with corresponding constraints.
@@ -165,6 +167,144 @@ pub struct UTCTime {
     pub constraints: Vec<Constraint>,
 }
 
+impl GeneralizedTime {
+    /// Parses a GeneralizedTime value of the form `YYYYMMDDHH[MM[SS]][(.|,)F...][Z|(+|-)HH[MM]]`.
+    /// A local time without a time difference is interpreted as UTC.
+    pub fn parse_value(value: &str) -> Result<DateTime<FixedOffset>, GrammarError> {
+        let digits = value.bytes().take_while(u8::is_ascii_digit).count();
+        let (date_time, rest) = value.split_at(digits);
+        let unit = match digits {
+            10 => 3600,
+            12 => 60,
+            14 => 1,
+            _ => {
+                return Err(time_value_error(
+                    value,
+                    "expected a date and time of the form YYYYMMDDHH[MM[SS]]",
+                ))
+            }
+        };
+        let (fraction, zone) = match rest.strip_prefix(['.', ',']) {
+            Some(fraction) => {
+                let fraction_digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+                if fraction_digits == 0 {
+                    return Err(time_value_error(
+                        value,
+                        "expected a fraction after the decimal sign",
+                    ));
+                }
+                let (fraction, zone) = fraction.split_at(fraction_digits);
+                (
+                    format!("0.{fraction}").parse::<f64>().unwrap_or_default(),
+                    zone,
+                )
+            }
+            None => (0.0, rest),
+        };
+        let year = date_time[..4].parse().unwrap_or_default();
+        let date_time = parse_date_time(value, year, &date_time[4..])?
+            + Duration::nanoseconds((fraction * unit as f64 * 1e9) as i64);
+        let offset = match zone {
+            "" => Utc.fix(),
+            zone => parse_time_difference(value, zone)?,
+        };
+        date_time
+            .and_local_timezone(offset)
+            .single()
+            .ok_or_else(|| time_value_error(value, "ambiguous local time"))
+    }
+}
+
+impl UTCTime {
+    /// Parses a UTCTime value of the form `YYMMDDhhmm[ss](Z|(+|-)hhmm)`.
+    /// Two-digit years denote the years 1950 to 2049.
+    pub fn parse_value(value: &str) -> Result<DateTime<FixedOffset>, GrammarError> {
+        let digits = value.bytes().take_while(u8::is_ascii_digit).count();
+        let (date_time, zone) = value.split_at(digits);
+        if !matches!(digits, 10 | 12) {
+            return Err(time_value_error(
+                value,
+                "expected a date and time of the form YYMMDDhhmm[ss]",
+            ));
+        }
+        if !matches!(zone.len(), 1 | 5) {
+            return Err(time_value_error(
+                value,
+                "expected a time difference of the form Z or (+|-)hhmm",
+            ));
+        }
+        let year = match date_time[..2].parse().unwrap_or_default() {
+            year @ 0..=49 => 2000 + year,
+            year => 1900 + year,
+        };
+        let offset = parse_time_difference(value, zone)?;
+        parse_date_time(value, year, &date_time[2..])?
+            .and_local_timezone(offset)
+            .single()
+            .ok_or_else(|| time_value_error(value, "ambiguous local time"))
+    }
+}
+
+fn time_value_error(value: &str, reason: &str) -> GrammarError {
+    GrammarError::new(
+        &format!("Invalid time value \"{value}\": {reason}!"),
+        GrammarErrorType::SyntaxMismatch,
+    )
+}
+
+/// Parses the two-digit fields `MMDDhh[mm[ss]]` following the year of a time value.
+fn parse_date_time(value: &str, year: i32, digits: &str) -> Result<NaiveDateTime, GrammarError> {
+    let mut fields = digits
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| u32::from((pair[0] - b'0') * 10 + pair[1] - b'0'));
+    let mut next = || fields.next().unwrap_or_default();
+    let (month, day, hour, minute, second) = (next(), next(), next(), next(), next());
+    if !(1..=12).contains(&month) {
+        return Err(time_value_error(
+            value,
+            &format!("month {month} is out of range"),
+        ));
+    }
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+        time_value_error(
+            value,
+            &format!("day {day} is out of range for month {month}"),
+        )
+    })?;
+    date.and_hms_opt(hour, minute, second).ok_or_else(|| {
+        time_value_error(
+            value,
+            &format!("time of day {hour:02}:{minute:02}:{second:02} is out of range"),
+        )
+    })
+}
+
+/// Parses a time difference of the form `Z` or `(+|-)hh[mm]`.
+fn parse_time_difference(value: &str, zone: &str) -> Result<FixedOffset, GrammarError> {
+    let invalid = || time_value_error(value, &format!("invalid time difference `{zone}`"));
+    if zone == "Z" {
+        return Ok(Utc.fix());
+    }
+    let (sign, difference) = match (zone.strip_prefix('+'), zone.strip_prefix('-')) {
+        (Some(difference), _) => (1, difference),
+        (_, Some(difference)) => (-1, difference),
+        _ => return Err(invalid()),
+    };
+    if !matches!(difference.len(), 2 | 4) || !difference.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let hours: i32 = difference[..2].parse().map_err(|_| invalid())?;
+    let minutes: i32 = match &difference[2..] {
+        "" => 0,
+        minutes => minutes.parse().map_err(|_| invalid())?,
+    };
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
 /// Representation of an ASN1 OCTET STRING data element
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §23*
@@ -181,6 +321,46 @@ impl From<Option<Vec<Constraint>>> for OctetString {
     }
 }
 
+/// The endpoints of a value range constraint of an OCTET STRING.
+/// Endpoints are `None` for `MIN` and `MAX`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OctetStringValueRange {
+    pub min: Option<Vec<u8>>,
+    pub max: Option<Vec<u8>>,
+}
+
+impl OctetString {
+    /// Returns the value range constraint of the OCTET STRING, e.g. `(SIZE(4) ^ ('00000000'H..'0000FFFF'H))`.
+    /// Value ranges are not defined for OCTET STRINGs by Rec. ITU-T X.680, but some specifications
+    /// use them to restrict the content of fixed-size OCTET STRINGs.
+    pub fn value_range(&self) -> Option<OctetStringValueRange> {
+        fn find_range(set: &ElementOrSetOperation) -> Option<&SubtypeElement> {
+            match set {
+                ElementOrSetOperation::Element(e @ SubtypeElement::ValueRange { .. }) => Some(e),
+                ElementOrSetOperation::SetOperation(SetOperation {
+                    base,
+                    operator: SetOperator::Intersection,
+                    operant,
+                }) => match base {
+                    SubtypeElement::ValueRange { .. } => Some(base),
+                    _ => find_range(operant),
+                },
+                _ => None,
+            }
+        }
+        self.constraints.iter().find_map(|c| match c {
+            Constraint::SubtypeConstraint(ElementSet { set, .. }) => match find_range(set) {
+                Some(SubtypeElement::ValueRange { min, max, .. }) => Some(OctetStringValueRange {
+                    min: min.as_ref().and_then(ASN1Value::as_octets),
+                    max: max.as_ref().and_then(ASN1Value::as_octets),
+                }),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+}
+
 /// Representation of an ASN1 BIT STRING data element
 /// with corresponding constraints and distinguished values
 /// defining the individual bits.
@@ -200,34 +380,75 @@ impl From<(Option<Vec<DistinguishedValue>>, Option<Vec<Constraint>>)> for BitStr
     }
 }
 
-/// Representation of an ASN1 OBJECT IDENTIFIER data element
+/// Representation of an ASN1 OBJECT IDENTIFIER or RELATIVE-OID data element
 /// with corresponding constraints.
-/// *As defined in Rec. ITU-T X.680 (02/2021) §32*
+/// *As defined in Rec. ITU-T X.680 (02/2021) §32 and §33*
 #[derive(Debug, Clone, PartialEq)]
 pub struct ObjectIdentifier {
     pub constraints: Vec<Constraint>,
+    pub is_relative: bool,
 }
 
 impl From<Option<Vec<Constraint>>> for ObjectIdentifier {
     fn from(value: Option<Vec<Constraint>>) -> Self {
         ObjectIdentifier {
             constraints: value.unwrap_or_default(),
+            is_relative: false,
         }
     }
 }
 
-/// Representation of an ASN1 TIME data element
+impl From<(&str, Option<Vec<Constraint>>)> for ObjectIdentifier {
+    fn from(value: (&str, Option<Vec<Constraint>>)) -> Self {
+        ObjectIdentifier {
+            constraints: value.1.unwrap_or_default(),
+            is_relative: value.0 == RELATIVE_OID,
+        }
+    }
+}
+
+/// Representation of an ASN1 OID-IRI or RELATIVE-OID-IRI data element
 /// with corresponding constraints.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §34 and §35*
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectIdentifierIri {
+    pub constraints: Vec<Constraint>,
+    pub is_relative: bool,
+}
+
+impl From<(&str, Option<Vec<Constraint>>)> for ObjectIdentifierIri {
+    fn from(value: (&str, Option<Vec<Constraint>>)) -> Self {
+        ObjectIdentifierIri {
+            constraints: value.1.unwrap_or_default(),
+            is_relative: value.0 == RELATIVE_OID_IRI,
+        }
+    }
+}
+
+/// Representation of an ASN1 TIME data element
+/// with corresponding constraints. ASN1 TIME types
+/// include DATE, TIME-OF-DAY, DATE-TIME, and DURATION.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §38*
 #[derive(Debug, Clone, PartialEq)]
 pub struct Time {
     pub constraints: Vec<Constraint>,
+    pub ty: TimeType,
 }
 
 impl From<Option<Vec<Constraint>>> for Time {
     fn from(value: Option<Vec<Constraint>>) -> Self {
         Time {
             constraints: value.unwrap_or_default(),
+            ty: TimeType::Time,
+        }
+    }
+}
+
+impl From<(&str, Option<Vec<Constraint>>)> for Time {
+    fn from(value: (&str, Option<Vec<Constraint>>)) -> Self {
+        Time {
+            constraints: value.1.unwrap_or_default(),
+            ty: value.0.into(),
         }
     }
 }
@@ -307,25 +528,37 @@ impl
             Vec<SequenceComponent>,
             Option<ExtensionMarker>,
             Option<Vec<SequenceComponent>>,
+            Option<Vec<SequenceComponent>>,
         ),
         Option<Vec<Constraint>>,
     )> for SequenceOrSet
 {
+    /// Components that follow a second extension marker belong to the extension root.
+    /// They are placed behind the leading root components, so that all root components
+    /// precede the extension additions. This matches the order in which root components
+    /// are encoded and in which automatic tags are assigned.
     fn from(
-        mut value: (
+        value: (
             (
                 Vec<SequenceComponent>,
                 Option<ExtensionMarker>,
                 Option<Vec<SequenceComponent>>,
+                Option<Vec<SequenceComponent>>,
             ),
             Option<Vec<Constraint>>,
         ),
     ) -> Self {
-        let index_of_first_extension = value.0 .0.len();
-        value.0 .0.append(&mut value.0 .2.unwrap_or_default());
+        let ((mut components, extension_marker, extension_additions, trailing_root), constraints) =
+            value;
+        components.append(&mut trailing_root.unwrap_or_default());
+        let index_of_first_extension = components
+            .iter()
+            .filter(|c| matches!(c, SequenceComponent::Member(_)))
+            .count();
+        components.append(&mut extension_additions.unwrap_or_default());
         let mut components_of = vec![];
         let mut members = vec![];
-        for comp in value.0 .0 {
+        for comp in components {
             match comp {
                 SequenceComponent::Member(m) => members.push(m),
                 SequenceComponent::ComponentsOf(c) => components_of.push(c),
@@ -333,8 +566,8 @@ impl
         }
         SequenceOrSet {
             components_of,
-            constraints: value.1.unwrap_or_default(),
-            extensible: value.0 .1.map(|_| index_of_first_extension),
+            constraints: constraints.unwrap_or_default(),
+            extensible: extension_marker.map(|_| index_of_first_extension),
             members,
         }
     }
@@ -576,10 +809,52 @@ impl
     ) -> Self {
         let index_of_first_extension = value.0.len();
         value.0.append(&mut value.2.unwrap_or_default());
-        Enumerated {
+        let mut enumerated = Enumerated {
             members: value.0,
             extensible: value.1.map(|_| index_of_first_extension),
             constraints: vec![],
+        };
+        enumerated.number_enumerals();
+        enumerated
+    }
+}
+
+impl Enumerated {
+    /// Assigns indices to the automatically numbered enumerals.
+    /// An automatically numbered root enumeral is assigned the smallest non-negative integer
+    /// that is not used by any other root enumeral. An automatically numbered extension addition
+    /// is assigned the smallest integer that is not used by any root enumeral and that exceeds
+    /// the indices of the preceding extension additions.
+    /// Enumerals that are numbered by a value reference are ignored until the reference is resolved.
+    /// *As defined in Rec. ITU-T X.680 (02/2021) §20.2 - §20.5*
+    pub fn number_enumerals(&mut self) {
+        let root_length = self.extensible.unwrap_or(self.members.len());
+        let (root, additions) = self.members.split_at_mut(root_length);
+        let mut used_indices = root
+            .iter()
+            .filter(|m| m.numbering == EnumeralNumbering::Explicit)
+            .map(|m| m.index)
+            .collect::<BTreeSet<_>>();
+        let mut next_index = 0;
+        for member in root
+            .iter_mut()
+            .filter(|m| m.numbering == EnumeralNumbering::Automatic)
+        {
+            while used_indices.contains(&next_index) {
+                next_index += 1;
+            }
+            member.index = next_index;
+            used_indices.insert(next_index);
+        }
+        let mut next_index = 0;
+        for member in additions {
+            if member.numbering == EnumeralNumbering::Automatic {
+                while used_indices.contains(&next_index) {
+                    next_index += 1;
+                }
+                member.index = next_index;
+            }
+            next_index = next_index.max(member.index + 1);
         }
     }
 }
@@ -600,7 +875,8 @@ impl
 /// Enumeral {
 ///     name: String::from("first-item"),
 ///     description: Some(String::from(" This is the first item of Test-Enum")),
-///     index: 7
+///     index: 7,
+///     numbering: EnumeralNumbering::Explicit,
 /// }
 /// # ;
 /// ```
@@ -609,6 +885,20 @@ pub struct Enumeral {
     pub name: String,
     pub description: Option<String>,
     pub index: i128,
+    pub numbering: EnumeralNumbering,
+}
+
+/// Notation that determines the index of an enumeral
+/// *As defined in Rec. ITU-T X.680 (02/2021) §20.1*
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumeralNumbering {
+    /// The index is given as a number, e.g. `first-item(7)`
+    Explicit,
+    /// The index is given as a reference to an integer value, e.g. `first-item(base-value)`.
+    /// The linker resolves the reference and marks the enumeral as explicitly numbered.
+    Reference(String),
+    /// The index is omitted and assigned automatically, e.g. `first-item`
+    Automatic,
 }
 
 /// Representation of a ASN1 distinguished value,
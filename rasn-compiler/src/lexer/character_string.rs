@@ -1,8 +1,9 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
+    bytes::complete::{is_not, tag},
     character::complete::{char, u8},
-    combinator::{map, map_res, opt},
+    combinator::{map, map_res, opt, value},
+    multi::many0,
     sequence::{delimited, pair, terminated, tuple},
     IResult,
 };
@@ -13,17 +14,48 @@ use super::{common::*, constraint::constraint};
 
 pub fn character_string_value(input: &str) -> IResult<&str, ASN1Value> {
     map(
-        skip_ws_and_comments(alt((
-            map(
-                delimited(tag("\""), take_until("\""), tag("\"")),
-                ToString::to_string,
-            ),
-            map(quadruple, |c| c.to_string()),
-        ))),
+        skip_ws_and_comments(alt((cstring, map(quadruple, |c| c.to_string())))),
         |m: String| ASN1Value::String(m),
     )(input)
 }
 
+/// Parses an ASN1 `cstring`, i.e. a character string value in double quotes.
+///
+/// _12.14.1 The cstring may span more than one line of text, in which case the character_
+/// _string being represented shall not include spacing characters in the position prior_
+/// _to or following the end of line in the cstring._
+///
+/// _12.14.3 [...] If the character string being represented contains a quotation mark,_
+/// _that quotation mark shall be represented in the cstring by a pair of quotation marks_
+/// _on the same line with no intervening spacing character._
+fn cstring(input: &str) -> IResult<&str, String> {
+    map(
+        delimited(
+            tag("\""),
+            many0(alt((is_not("\""), value("\"", tag("\"\""))))),
+            tag("\""),
+        ),
+        |segments| {
+            let cstring = segments.concat();
+            if cstring.contains('\n') {
+                let lines = cstring.split('\n').collect::<Vec<_>>();
+                let last = lines.len() - 1;
+                lines
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, line)| match index {
+                        0 => line.trim_end(),
+                        i if i == last => line.trim_start(),
+                        _ => line.trim(),
+                    })
+                    .collect()
+            } else {
+                cstring
+            }
+        },
+    )(input)
+}
+
 /// A ASN1 character value can be specified "by reference to a registration number in the ISO
 /// International Register of Coded Character Sets (see ISO International Register of Coded Character
 /// Sets to be used with Escape Sequences), or by reference to ISO/IEC 10646." The registration number
@@ -87,6 +119,7 @@ pub fn character_string(input: &str) -> IResult<&str, ASN1Type> {
                 tag(VIDEOTEX_STRING),
                 tag(GRAPHIC_STRING),
                 tag(GENERAL_STRING),
+                tag(OBJECT_DESCRIPTOR),
                 tag(UNIVERSAL_STRING),
                 tag(BMP_STRING),
                 tag(PRINTABLE_STRING),
@@ -120,6 +153,37 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_legacy_characterstrings() {
+        for (sample, ty) in [
+            ("TeletexString", CharacterStringType::TeletexString),
+            ("VideotexString", CharacterStringType::VideotexString),
+            ("GraphicString", CharacterStringType::GraphicString),
+            ("GeneralString", CharacterStringType::GeneralString),
+            ("UniversalString", CharacterStringType::UniversalString),
+        ] {
+            assert_eq!(
+                character_string(sample).unwrap().1,
+                ASN1Type::CharacterString(CharacterString {
+                    constraints: vec![],
+                    ty
+                })
+            )
+        }
+    }
+
+    #[test]
+    fn parses_object_descriptor() {
+        let sample = "   ObjectDescriptor";
+        assert_eq!(
+            character_string(sample).unwrap().1,
+            ASN1Type::CharacterString(CharacterString {
+                constraints: vec![],
+                ty: CharacterStringType::ObjectDescriptor
+            })
+        )
+    }
+
     #[test]
     fn parses_strictly_constrained_characterstring() {
         let sample = "   IA5String(SIZE (8))";
@@ -211,6 +275,32 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_character_string_value_with_escaped_quotes() {
+        assert_eq!(
+            character_string_value(r#""He said ""hi""""#).unwrap().1,
+            ASN1Value::String(r#"He said "hi""#.to_owned())
+        );
+        assert_eq!(
+            character_string_value(r#""""""#).unwrap().1,
+            ASN1Value::String(r#"""#.to_owned())
+        );
+        assert_eq!(
+            character_string_value(r#""""#).unwrap().1,
+            ASN1Value::String(String::new())
+        );
+    }
+
+    #[test]
+    fn parses_multiline_character_string_value() {
+        assert_eq!(
+            character_string_value("\"first line  \n    second\tline \r\n  end\"")
+                .unwrap()
+                .1,
+            ASN1Value::String("first linesecond\tlineend".to_owned())
+        )
+    }
+
     #[test]
     fn parses_character_string_asn1_value() {
         assert_eq!(
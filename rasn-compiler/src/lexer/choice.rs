@@ -13,7 +13,11 @@ use super::{constraint::constraint, *};
 
 pub fn choice_value(input: &str) -> IResult<&str, ASN1Value> {
     map(
-        skip_ws_and_comments(separated_pair(identifier, char(':'), asn1_value)),
+        skip_ws_and_comments(separated_pair(
+            identifier,
+            skip_ws_and_comments(char(COLON)),
+            asn1_value,
+        )),
         |(id, val)| ASN1Value::Choice {
             type_name: None,
             variant_name: id.to_owned(),
@@ -112,9 +116,9 @@ mod tests {
     use crate::{
         intermediate::{
             types::{Choice, ChoiceOption, ChoiceSelectionType},
-            ASN1Type, DeclarationElsewhere,
+            ASN1Type, ASN1Value, DeclarationElsewhere,
         },
-        lexer::choice::selection_type_choice,
+        lexer::choice::{choice_value, selection_type_choice},
     };
 
     use crate::lexer::choice;
@@ -158,6 +162,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_choice_value_with_whitespace_around_colon() {
+        assert_eq!(
+            choice_value(r#"utcTime : "240101120000Z""#).unwrap().1,
+            ASN1Value::Choice {
+                type_name: None,
+                variant_name: "utcTime".into(),
+                inner_value: Box::new(ASN1Value::Time("240101120000Z".into()))
+            }
+        )
+    }
+
     #[test]
     fn parses_selection_type_choice() {
         assert_eq!(
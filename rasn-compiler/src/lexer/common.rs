@@ -162,10 +162,6 @@ where
     )
 }
 
-pub fn all_value(input: &str) -> IResult<&str, ASN1Value> {
-    value(ASN1Value::All, skip_ws_and_comments(tag(ALL)))(input)
-}
-
 pub fn asn_tag(input: &str) -> IResult<&str, AsnTag> {
     into(pair(
         in_brackets(pair(
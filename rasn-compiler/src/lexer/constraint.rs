@@ -2,9 +2,9 @@ use crate::intermediate::{constraints::*, *};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::char,
-    combinator::{into, map, map_res, opt, value},
-    multi::{many0_count, many1, separated_list0, separated_list1},
+    character::complete::{char, satisfy},
+    combinator::{into, map, map_res, not, opt, value},
+    multi::{many0, many0_count, many1, separated_list0, separated_list1},
     sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
     IResult,
 };
@@ -43,22 +43,33 @@ pub fn single_constraint(input: &str) -> IResult<&str, Constraint> {
     ))))(input)
 }
 
-pub fn set_operator(input: &str) -> IResult<&str, SetOperator> {
-    skip_ws_and_comments(alt((
-        value(SetOperator::Intersection, tag(INTERSECTION)),
-        value(SetOperator::Intersection, tag(CARET)),
-        value(SetOperator::Union, tag(UNION)),
-        value(SetOperator::Union, tag(PIPE)),
-        value(SetOperator::Except, tag(EXCEPT)),
-    )))(input)
+/// Parses the value set of a value set assignment, e.g. `{ 1 | 2 | 3 }`.
+/// The value set is represented as a subtype constraint of the governing type.
+pub fn value_set(input: &str) -> IResult<&str, Constraint> {
+    map(
+        skip_ws_and_comments(in_braces(element_set)),
+        Constraint::SubtypeConstraint,
+    )(input)
+}
+
+fn union_mark(input: &str) -> IResult<&str, SetOperator> {
+    skip_ws_and_comments(value(SetOperator::Union, alt((tag(UNION), tag(PIPE)))))(input)
+}
+
+fn intersection_mark(input: &str) -> IResult<&str, SetOperator> {
+    skip_ws_and_comments(value(
+        SetOperator::Intersection,
+        alt((tag(INTERSECTION), tag(CARET))),
+    ))(input)
+}
+
+fn except_mark(input: &str) -> IResult<&str, SetOperator> {
+    skip_ws_and_comments(value(SetOperator::Except, tag(EXCEPT)))(input)
 }
 
 fn element_set(input: &str) -> IResult<&str, ElementSet> {
     into(pair(
-        alt((
-            map(set_operation, ElementOrSetOperation::SetOperation),
-            map(subtype_element, ElementOrSetOperation::Element),
-        )),
+        element_set_spec,
         opt(skip_ws_and_comments(preceded(
             char(COMMA),
             extension_marker,
@@ -66,15 +77,96 @@ fn element_set(input: &str) -> IResult<&str, ElementSet> {
     ))(input)
 }
 
-fn set_operation(input: &str) -> IResult<&str, SetOperation> {
-    into(tuple((
-        subtype_element,
-        set_operator,
-        alt((
-            map(set_operation, ElementOrSetOperation::SetOperation),
-            map(subtype_element, ElementOrSetOperation::Element),
-        )),
-    )))(input)
+fn element_set_spec(input: &str) -> IResult<&str, ElementOrSetOperation> {
+    alt((all_exclusions, unions))(input)
+}
+
+/// Parses the set of all values of the constrained type, optionally without some excluded values.
+/// A bare `ALL` is not part of the grammar, but accepted as an unconstraining set.
+/// ### Reference in X680
+/// >* _50.1 `ElementSetSpec ::= Unions | ALL Exclusions`_
+/// >* _`Exclusions ::= EXCEPT Elements`_
+fn all_exclusions(input: &str) -> IResult<&str, ElementOrSetOperation> {
+    map(
+        preceded(
+            skip_ws_and_comments(terminated(
+                tag(ALL),
+                not(satisfy(|c| c.is_alphanumeric() || c == '-')),
+            )),
+            opt(pair(except_mark, elements)),
+        ),
+        |exclusions| {
+            fold_set_operations((
+                ElementOrSetOperation::Element(SubtypeElement::All),
+                exclusions.into_iter().collect(),
+            ))
+        },
+    )(input)
+}
+
+/// Parses a set of constraint elements combined by set operators.
+/// ### Reference in X680
+/// >* _`Unions ::= Intersections | UElems UnionMark Intersections`_
+/// >* _`Intersections ::= IntersectionElements | IElems IntersectionMark IntersectionElements`_
+/// >* _`IntersectionElements ::= Elements | Elems Exclusions`_
+/// >* _`Elements ::= SubtypeElements | ObjectSetElements | "(" ElementSetSpec ")"`_
+///
+/// The operators are parsed with ASN.1 precedence, i.e. `EXCEPT` binds tighter than
+/// `INTERSECTION`, which in turn binds tighter than `UNION`. Chains of the same operator
+/// are nested to the right. Operations that bind tighter than the operation whose
+/// base they form are preserved as `SubtypeElement::NestedSet`.
+fn unions(input: &str) -> IResult<&str, ElementOrSetOperation> {
+    map(
+        pair(intersections, many0(pair(union_mark, intersections))),
+        fold_set_operations,
+    )(input)
+}
+
+fn intersections(input: &str) -> IResult<&str, ElementOrSetOperation> {
+    map(
+        pair(
+            intersection_elements,
+            many0(pair(intersection_mark, intersection_elements)),
+        ),
+        fold_set_operations,
+    )(input)
+}
+
+fn intersection_elements(input: &str) -> IResult<&str, ElementOrSetOperation> {
+    map(
+        pair(elements, opt(pair(except_mark, elements))),
+        |(base, exclusions)| fold_set_operations((base, exclusions.into_iter().collect())),
+    )(input)
+}
+
+fn elements(input: &str) -> IResult<&str, ElementOrSetOperation> {
+    alt((
+        map(subtype_element, ElementOrSetOperation::Element),
+        in_parentheses(element_set_spec),
+    ))(input)
+}
+
+fn fold_set_operations(
+    (first, operations): (
+        ElementOrSetOperation,
+        Vec<(SetOperator, ElementOrSetOperation)>,
+    ),
+) -> ElementOrSetOperation {
+    let mut last = first;
+    let mut bases = vec![];
+    for (operator, operand) in operations {
+        bases.push((std::mem::replace(&mut last, operand), operator));
+    }
+    bases.into_iter().rfold(last, |operant, (base, operator)| {
+        ElementOrSetOperation::SetOperation(SetOperation {
+            base: match base {
+                ElementOrSetOperation::Element(e) => e,
+                ElementOrSetOperation::SetOperation(s) => SubtypeElement::NestedSet(Box::new(s)),
+            },
+            operator,
+            operant: Box::new(operant),
+        })
+    })
 }
 
 fn subtype_element(input: &str) -> IResult<&str, SubtypeElement> {
@@ -102,24 +194,9 @@ fn extension_additions(input: &str) -> IResult<&str, ()> {
                 skip_ws_and_comments(alt((
                     value(
                         0,
-                        pair(
-                            terminated(
-                                alt((value(None, tag(MIN)), map(asn1_value, Some))),
-                                skip_ws_and_comments(opt(char(GREATER_THAN))),
-                            ),
-                            preceded(
-                                range_seperator,
-                                preceded(
-                                    opt(char(LESS_THAN)),
-                                    skip_ws_and_comments(alt((
-                                        value(None, tag(MAX)),
-                                        map(asn1_value, Some),
-                                    ))),
-                                ),
-                            ),
-                        ),
+                        pair(lower_endpoint, preceded(range_seperator, upper_endpoint)),
                     ),
-                    value(0, asn1_value),
+                    value(0, bound_value),
                 ))),
             )),
         )),
@@ -127,18 +204,26 @@ fn extension_additions(input: &str) -> IResult<&str, ()> {
 }
 
 fn single_value(input: &str) -> IResult<&str, SubtypeElement> {
-    opt_delimited::<char, SubtypeElement, char, _, _, _>(
-        skip_ws_and_comments(char(LEFT_PARENTHESIS)),
-        skip_ws_and_comments(into(pair(
-            asn1_value,
-            opt(skip_ws_and_comments(delimited(
-                char(COMMA),
-                extension_marker,
-                extension_additions,
-            ))),
+    // Values that start with a parenthesized arithmetic expression are not delimited
+    alt((
+        single_value_element,
+        opt_delimited::<char, SubtypeElement, char, _, _, _>(
+            skip_ws_and_comments(char(LEFT_PARENTHESIS)),
+            single_value_element,
+            skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
+        ),
+    ))(input)
+}
+
+fn single_value_element(input: &str) -> IResult<&str, SubtypeElement> {
+    skip_ws_and_comments(into(pair(
+        bound_value,
+        opt(skip_ws_and_comments(delimited(
+            char(COMMA),
+            extension_marker,
+            extension_additions,
         ))),
-        skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
-    )(input)
+    )))(input)
 }
 
 fn contained_subtype(input: &str) -> IResult<&str, SubtypeElement> {
@@ -162,37 +247,185 @@ fn contained_subtype(input: &str) -> IResult<&str, SubtypeElement> {
 }
 
 fn value_range(input: &str) -> IResult<&str, SubtypeElement> {
-    opt_delimited::<char, SubtypeElement, char, _, _, _>(
-        skip_ws_and_comments(char(LEFT_PARENTHESIS)),
-        skip_ws_and_comments(map(
-            tuple((
-                terminated(
-                    alt((value(None, tag(MIN)), map(asn1_value, Some))),
-                    skip_ws_and_comments(opt(char(GREATER_THAN))),
-                ),
-                preceded(
-                    range_seperator,
-                    preceded(
-                        opt(char(LESS_THAN)),
-                        skip_ws_and_comments(alt((value(None, tag(MAX)), map(asn1_value, Some)))),
-                    ),
-                ),
-                opt(skip_ws_and_comments(delimited(
-                    char(COMMA),
-                    extension_marker,
-                    extension_additions,
-                ))),
-            )),
-            |(min, max, ext)| SubtypeElement::ValueRange {
-                min,
-                max,
-                extensible: ext.is_some(),
-            },
+    // Ranges that start with a parenthesized arithmetic expression are not delimited
+    alt((
+        value_range_element,
+        opt_delimited::<char, SubtypeElement, char, _, _, _>(
+            skip_ws_and_comments(char(LEFT_PARENTHESIS)),
+            value_range_element,
+            skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
+        ),
+    ))(input)
+}
+
+fn value_range_element(input: &str) -> IResult<&str, SubtypeElement> {
+    skip_ws_and_comments(map(
+        tuple((
+            lower_endpoint,
+            preceded(range_seperator, upper_endpoint),
+            opt(skip_ws_and_comments(delimited(
+                char(COMMA),
+                extension_marker,
+                extension_additions,
+            ))),
         )),
-        skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
+        |(min, max, ext)| SubtypeElement::ValueRange {
+            min,
+            max,
+            extensible: ext.is_some(),
+        },
+    ))(input)
+}
+
+/// Parses the lower endpoint of a value range. `MIN` is represented by `None`.
+/// Exclusive endpoints are adjusted to the next value that is included in the range.
+/// ### Reference in X680
+/// >* _51.4.1 `LowerEndpoint ::= LowerEndValue | LowerEndValue "<"`_
+/// >* _`LowerEndValue ::= Value | MIN`_
+fn lower_endpoint(input: &str) -> IResult<&str, Option<ASN1Value>> {
+    map(
+        pair(
+            skip_ws_and_comments(alt((value(None, tag(MIN)), map(bound_value, Some)))),
+            skip_ws_and_comments(opt(char(LESS_THAN))),
+        ),
+        |(bound, exclusive)| match exclusive {
+            Some(_) => bound.map(|b| exclude_endpoint(b, ArithmeticOperator::Add)),
+            None => bound,
+        },
+    )(input)
+}
+
+/// Parses the upper endpoint of a value range. `MAX` is represented by `None`.
+/// Exclusive endpoints are adjusted to the previous value that is included in the range.
+/// ### Reference in X680
+/// >* _51.4.1 `UpperEndpoint ::= UpperEndValue | "<" UpperEndValue`_
+/// >* _`UpperEndValue ::= Value | MAX`_
+fn upper_endpoint(input: &str) -> IResult<&str, Option<ASN1Value>> {
+    map(
+        pair(
+            skip_ws_and_comments(opt(char(LESS_THAN))),
+            skip_ws_and_comments(alt((value(None, tag(MAX)), map(bound_value, Some)))),
+        ),
+        |(exclusive, bound)| match exclusive {
+            Some(_) => bound.map(|b| exclude_endpoint(b, ArithmeticOperator::Subtract)),
+            None => bound,
+        },
     )(input)
 }
 
+/// Steps over an excluded endpoint in the direction of `operator`.
+/// Characters are adjusted right away, while integers and references are turned into
+/// arithmetic expressions that the linker evaluates. Since the lexer does not know the
+/// governing type of the range at this point, see [real_endpoints] for `REAL` ranges.
+fn exclude_endpoint(endpoint: ASN1Value, operator: ArithmeticOperator) -> ASN1Value {
+    match endpoint {
+        ASN1Value::String(s) if s.chars().count() == 1 => {
+            let c = s.chars().next().unwrap_or_default() as u32;
+            let next = match operator {
+                ArithmeticOperator::Subtract => c.checked_sub(1),
+                _ => c.checked_add(1),
+            };
+            match next.and_then(char::from_u32) {
+                Some(next) => ASN1Value::String(next.to_string()),
+                None => ASN1Value::String(s),
+            }
+        }
+        ASN1Value::Real(_) => endpoint,
+        _ => ASN1Value::Arithmetic {
+            operator,
+            lhs: Box::new(endpoint),
+            rhs: Box::new(ASN1Value::Integer(1)),
+        },
+    }
+}
+
+/// Restores the numeric endpoints of the value ranges of a `REAL` type that
+/// [exclude_endpoint] stepped over. The values of a `REAL` range are not discrete,
+/// so that its excluded endpoints are kept as inclusive bounds of the range.
+pub fn real_endpoints(constraints: Vec<Constraint>) -> Vec<Constraint> {
+    fn restore_set(set: &mut ElementOrSetOperation) {
+        match set {
+            ElementOrSetOperation::Element(e) => restore_element(e),
+            ElementOrSetOperation::SetOperation(s) => restore_operation(s),
+        }
+    }
+    fn restore_operation(set: &mut SetOperation) {
+        restore_element(&mut set.base);
+        restore_set(&mut set.operant);
+    }
+    fn restore_element(element: &mut SubtypeElement) {
+        match element {
+            SubtypeElement::ValueRange { min, max, .. } => {
+                for bound in [min, max].into_iter().flatten() {
+                    if let ASN1Value::Arithmetic { lhs, rhs, .. } = bound {
+                        if matches!(**lhs, ASN1Value::Integer(_)) && **rhs == ASN1Value::Integer(1)
+                        {
+                            *bound = (**lhs).clone();
+                        }
+                    }
+                }
+            }
+            SubtypeElement::NestedSet(s) => restore_operation(s),
+            _ => (),
+        }
+    }
+    constraints
+        .into_iter()
+        .map(|mut c| {
+            if let Constraint::SubtypeConstraint(ElementSet { set, .. }) = &mut c {
+                restore_set(set);
+            }
+            c
+        })
+        .collect()
+}
+
+/// Parses a value that may be an arithmetic expression, such as `maxLen * 2`.
+/// Multiplication and division take precedence over addition and subtraction,
+/// parentheses group subexpressions. Expressions are evaluated by the linker.
+fn bound_value(input: &str) -> IResult<&str, ASN1Value> {
+    arithmetic_term(
+        arithmetic_factor,
+        alt((
+            value(ArithmeticOperator::Add, char(PLUS)),
+            value(ArithmeticOperator::Subtract, char(HYPHEN_MINUS)),
+        )),
+    )(input)
+}
+
+fn arithmetic_factor(input: &str) -> IResult<&str, ASN1Value> {
+    arithmetic_term(
+        alt((in_parentheses(bound_value), asn1_value)),
+        alt((
+            value(ArithmeticOperator::Multiply, char(ASTERISK)),
+            value(ArithmeticOperator::Divide, char(SOLIDUS)),
+        )),
+    )(input)
+}
+
+/// Parses a left-associative chain of operands that are joined by the given operators
+fn arithmetic_term<'a>(
+    mut operand: impl FnMut(&'a str) -> IResult<&'a str, ASN1Value>,
+    mut operator: impl FnMut(&'a str) -> IResult<&'a str, ArithmeticOperator>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, ASN1Value> {
+    move |input| {
+        let (mut input, mut lhs) = skip_ws_and_comments(&mut operand)(input)?;
+        while let Ok((remaining, (operator, rhs))) = pair(
+            skip_ws_and_comments(&mut operator),
+            skip_ws_and_comments(&mut operand),
+        )(input)
+        {
+            lhs = ASN1Value::Arithmetic {
+                operator,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+            input = remaining;
+        }
+        Ok((input, lhs))
+    }
+}
+
 fn size_constraint(input: &str) -> IResult<&str, SubtypeElement> {
     opt_delimited::<char, SubtypeElement, char, _, _, _>(
         skip_ws_and_comments(char(LEFT_PARENTHESIS)),
@@ -205,14 +438,18 @@ fn pattern_constraint(input: &str) -> IResult<&str, SubtypeElement> {
     map(
         opt_delimited::<char, PatternConstraint, char, _, _, _>(
             skip_ws_and_comments(char(LEFT_PARENTHESIS)),
-            skip_ws_and_comments(into(preceded(
-                tag(PATTERN),
-                skip_ws_and_comments(delimited(
-                    char('"'),
-                    take_until_and_not("\"", "\"\""),
-                    char('"'),
-                )),
-            ))),
+            skip_ws_and_comments(map(
+                preceded(
+                    tag(PATTERN),
+                    skip_ws_and_comments(delimited(
+                        char('"'),
+                        take_until_and_not("\"", "\"\""),
+                        char('"'),
+                    )),
+                ),
+                // Quotation marks are escaped by pairs of quotation marks within the pattern
+                |pattern: &str| PatternConstraint::from(pattern.replace("\"\"", "\"").as_str()),
+            )),
             skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
         ),
         SubtypeElement::PatternConstraint,
@@ -246,16 +483,9 @@ fn user_defined_constraint(input: &str) -> IResult<&str, SubtypeElement> {
 fn permitted_alphabet_constraint(input: &str) -> IResult<&str, SubtypeElement> {
     opt_delimited::<char, SubtypeElement, char, _, _, _>(
         skip_ws_and_comments(char(LEFT_PARENTHESIS)),
-        skip_ws_and_comments(map(
-            preceded(
-                tag(FROM),
-                in_parentheses(alt((
-                    map(set_operation, ElementOrSetOperation::SetOperation),
-                    map(subtype_element, ElementOrSetOperation::Element),
-                ))),
-            ),
-            |i| SubtypeElement::PermittedAlphabet(Box::new(i)),
-        )),
+        skip_ws_and_comments(map(preceded(tag(FROM), in_parentheses(unions)), |i| {
+            SubtypeElement::PermittedAlphabet(Box::new(i))
+        })),
         skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
     )(input)
 }
@@ -294,6 +524,7 @@ fn multiple_type_constraints(input: &str) -> IResult<&str, SubtypeElement> {
                     }
                 }),
                 multiple_type_constraints,
+                map(constraint, SubtypeElement::ComponentTypeConstraint),
             ))),
         )),
         skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
@@ -351,7 +582,10 @@ fn table_constraint(input: &str) -> IResult<&str, TableConstraint> {
 fn relational_constraint(input: &str) -> IResult<&str, RelationalConstraint> {
     into(skip_ws_and_comments(preceded(
         char(AT),
-        pair(many0_count(char(DOT)), identifier),
+        pair(
+            many0_count(char(DOT)),
+            separated_list1(char(DOT), identifier),
+        ),
     )))(input)
 }
 
@@ -528,10 +762,7 @@ mod tests {
             constraint(r#"(ALL EXCEPT 1)"#).unwrap().1,
             vec![Constraint::SubtypeConstraint(ElementSet {
                 set: ElementOrSetOperation::SetOperation(SetOperation {
-                    base: SubtypeElement::SingleValue {
-                        value: ASN1Value::All,
-                        extensible: false
-                    },
+                    base: SubtypeElement::All,
                     operator: SetOperator::Except,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElement::SingleValue {
@@ -545,6 +776,56 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_all_values() {
+        assert_eq!(
+            constraint("(ALL)").unwrap().1,
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::All),
+                extensible: false
+            })]
+        );
+        assert_eq!(
+            constraint("((ALL EXCEPT 1) | 2)").unwrap().1,
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::SetOperation(SetOperation {
+                    base: SubtypeElement::NestedSet(Box::new(SetOperation {
+                        base: SubtypeElement::All,
+                        operator: SetOperator::Except,
+                        operant: Box::new(ElementOrSetOperation::Element(
+                            SubtypeElement::SingleValue {
+                                value: ASN1Value::Integer(1),
+                                extensible: false
+                            }
+                        ))
+                    })),
+                    operator: SetOperator::Union,
+                    operant: Box::new(ElementOrSetOperation::Element(
+                        SubtypeElement::SingleValue {
+                            value: ASN1Value::Integer(2),
+                            extensible: false
+                        }
+                    ))
+                }),
+                extensible: false
+            })]
+        );
+        assert_eq!(
+            constraint("(ALLOWED-Values)").unwrap().1,
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::ContainedSubtype {
+                    subtype: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                        parent: None,
+                        identifier: "ALLOWED-Values".into(),
+                        constraints: vec![]
+                    }),
+                    extensible: false
+                }),
+                extensible: false
+            })]
+        );
+    }
+
     #[test]
     fn parses_complex_set() {
         assert_eq!(
@@ -562,10 +843,7 @@ mod tests {
                             identifier: "containerId".into(),
                             constraints: vec![Constraint::SubtypeConstraint(ElementSet {
                                 set: ElementOrSetOperation::SetOperation(SetOperation {
-                                    base: SubtypeElement::SingleValue {
-                                        value: ASN1Value::All,
-                                        extensible: false
-                                    },
+                                    base: SubtypeElement::All,
                                     operator: SetOperator::Except,
                                     operant: Box::new(ElementOrSetOperation::Element(
                                         SubtypeElement::SingleValue {
@@ -587,10 +865,7 @@ mod tests {
                                 identifier: "containerId".into(),
                                 constraints: vec![Constraint::SubtypeConstraint(ElementSet {
                                     set: ElementOrSetOperation::SetOperation(SetOperation {
-                                        base: SubtypeElement::SingleValue {
-                                            value: ASN1Value::All,
-                                            extensible: false
-                                        },
+                                        base: SubtypeElement::All,
                                         operator: SetOperator::Except,
                                         operant: Box::new(ElementOrSetOperation::Element(
                                             SubtypeElement::SingleValue {
@@ -875,6 +1150,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_table_constraint_with_component_paths() {
+        assert_eq!(
+            constraint("({SUPPORTED-MESSAGES}{@header.messageId, @..kind})")
+                .unwrap()
+                .1,
+            vec![Constraint::TableConstraint(TableConstraint {
+                object_set: ObjectSet {
+                    values: vec![ObjectSetValue::Reference("SUPPORTED-MESSAGES".into())],
+                    extensible: None
+                },
+                linked_fields: vec![
+                    RelationalConstraint {
+                        field_name: "header".into(),
+                        level: 0,
+                        component_path: vec!["messageId".into()]
+                    },
+                    RelationalConstraint {
+                        field_name: "kind".into(),
+                        level: 2,
+                        component_path: vec![]
+                    }
+                ]
+            })]
+        );
+    }
+
     #[test]
     fn parses_table_constraint() {
         assert_eq!(
@@ -1139,6 +1441,33 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_pattern_constraint_with_escaped_quotation_marks() {
+        assert_eq!(
+            constraint(r#"(SIZE(1..8) ^ PATTERN "\d+""[a-z]""")"#)
+                .unwrap()
+                .1,
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::SetOperation(SetOperation {
+                    base: SubtypeElement::SizeConstraint(Box::new(ElementOrSetOperation::Element(
+                        SubtypeElement::ValueRange {
+                            min: Some(ASN1Value::Integer(1)),
+                            max: Some(ASN1Value::Integer(8)),
+                            extensible: false
+                        }
+                    ))),
+                    operator: SetOperator::Intersection,
+                    operant: Box::new(ElementOrSetOperation::Element(
+                        SubtypeElement::PatternConstraint(PatternConstraint {
+                            pattern: r#"\d+"[a-z]""#.into()
+                        })
+                    ))
+                }),
+                extensible: false
+            })]
+        )
+    }
+
     #[test]
     fn parses_user_defined_constraint() {
         assert_eq!(
@@ -1223,4 +1552,402 @@ mod tests {
             })]
         )
     }
+
+    fn integer(value: i128) -> SubtypeElement {
+        SubtypeElement::SingleValue {
+            value: ASN1Value::Integer(value),
+            extensible: false,
+        }
+    }
+
+    fn operation(
+        base: SubtypeElement,
+        operator: SetOperator,
+        operant: ElementOrSetOperation,
+    ) -> ElementOrSetOperation {
+        ElementOrSetOperation::SetOperation(SetOperation {
+            base,
+            operator,
+            operant: Box::new(operant),
+        })
+    }
+
+    fn nested(set: ElementOrSetOperation) -> SubtypeElement {
+        match set {
+            ElementOrSetOperation::SetOperation(s) => SubtypeElement::NestedSet(Box::new(s)),
+            ElementOrSetOperation::Element(e) => e,
+        }
+    }
+
+    fn set_of(input: &str) -> ElementOrSetOperation {
+        match constraint(input).unwrap().1.pop() {
+            Some(Constraint::SubtypeConstraint(ElementSet { set, .. })) => set,
+            c => panic!("Unexpected constraint {c:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_intersection_of_parenthesized_constraints() {
+        let expected = operation(
+            SubtypeElement::SizeConstraint(Box::new(ElementOrSetOperation::Element(
+                SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::Integer(1)),
+                    max: Some(ASN1Value::Integer(10)),
+                    extensible: false,
+                },
+            ))),
+            SetOperator::Intersection,
+            ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(Box::new(
+                ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                    value: ASN1Value::String("abc".into()),
+                    extensible: false,
+                }),
+            ))),
+        );
+        assert_eq!(set_of(r#"((SIZE(1..10)) ^ (FROM("abc")))"#), expected);
+        assert_eq!(
+            set_of(r#"(SIZE(1..10) INTERSECTION FROM("abc"))"#),
+            expected
+        );
+    }
+
+    #[test]
+    fn intersection_binds_tighter_than_union() {
+        assert_eq!(
+            set_of("(1 ^ 2 | 3)"),
+            operation(
+                nested(operation(
+                    integer(1),
+                    SetOperator::Intersection,
+                    ElementOrSetOperation::Element(integer(2))
+                )),
+                SetOperator::Union,
+                ElementOrSetOperation::Element(integer(3))
+            )
+        );
+        assert_eq!(
+            set_of("(1 UNION 2 INTERSECTION 3)"),
+            operation(
+                integer(1),
+                SetOperator::Union,
+                operation(
+                    integer(2),
+                    SetOperator::Intersection,
+                    ElementOrSetOperation::Element(integer(3))
+                )
+            )
+        );
+        assert_eq!(
+            set_of("(1 ^ 2 | 3 ^ 4)"),
+            operation(
+                nested(operation(
+                    integer(1),
+                    SetOperator::Intersection,
+                    ElementOrSetOperation::Element(integer(2))
+                )),
+                SetOperator::Union,
+                operation(
+                    integer(3),
+                    SetOperator::Intersection,
+                    ElementOrSetOperation::Element(integer(4))
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn except_binds_tighter_than_intersection() {
+        assert_eq!(
+            set_of("(1 EXCEPT 2 ^ 3)"),
+            operation(
+                nested(operation(
+                    integer(1),
+                    SetOperator::Except,
+                    ElementOrSetOperation::Element(integer(2))
+                )),
+                SetOperator::Intersection,
+                ElementOrSetOperation::Element(integer(3))
+            )
+        );
+        assert_eq!(
+            set_of("(1 | 2 EXCEPT 3)"),
+            operation(
+                integer(1),
+                SetOperator::Union,
+                operation(
+                    integer(2),
+                    SetOperator::Except,
+                    ElementOrSetOperation::Element(integer(3))
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn nests_chains_of_the_same_operator_to_the_right() {
+        assert_eq!(
+            set_of("(1 | 2 | 3)"),
+            operation(
+                integer(1),
+                SetOperator::Union,
+                operation(
+                    integer(2),
+                    SetOperator::Union,
+                    ElementOrSetOperation::Element(integer(3))
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn parses_parenthesized_groups() {
+        let union = |a, b| {
+            operation(
+                integer(a),
+                SetOperator::Union,
+                ElementOrSetOperation::Element(integer(b)),
+            )
+        };
+        assert_eq!(
+            set_of("((1 | 2) ^ 3)"),
+            operation(
+                nested(union(1, 2)),
+                SetOperator::Intersection,
+                ElementOrSetOperation::Element(integer(3))
+            )
+        );
+        assert_eq!(
+            set_of("(1 ^ (2 | 3))"),
+            operation(integer(1), SetOperator::Intersection, union(2, 3))
+        );
+        assert_eq!(
+            set_of("((1 | 2) ^ (3 | 4))"),
+            operation(nested(union(1, 2)), SetOperator::Intersection, union(3, 4))
+        );
+        assert_eq!(
+            set_of("(((1 | 2)) ^ 3)"),
+            operation(
+                nested(union(1, 2)),
+                SetOperator::Intersection,
+                ElementOrSetOperation::Element(integer(3))
+            )
+        );
+        assert_eq!(
+            set_of("((1 | 2) | 3)"),
+            operation(
+                nested(union(1, 2)),
+                SetOperator::Union,
+                ElementOrSetOperation::Element(integer(3))
+            )
+        );
+        assert_eq!(
+            set_of("(ALL EXCEPT (1 | 2))"),
+            operation(SubtypeElement::All, SetOperator::Except, union(1, 2))
+        );
+    }
+
+    #[test]
+    fn parses_inner_subtyping() {
+        let range = |min, max| {
+            Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::Integer(min)),
+                    max: Some(ASN1Value::Integer(max)),
+                    extensible: false,
+                }),
+                extensible: false,
+            })
+        };
+        assert_eq!(
+            set_of("(WITH COMPONENT (0..7))"),
+            ElementOrSetOperation::Element(SubtypeElement::ComponentTypeConstraint(vec![range(
+                0, 7
+            )]))
+        );
+        assert_eq!(
+            set_of("(WITH COMPONENTS {..., note (SIZE(1..4)) PRESENT, inner (WITH COMPONENTS { id }) })"),
+            ElementOrSetOperation::Element(SubtypeElement::SingleTypeConstraint(
+                InnerTypeConstraint {
+                    is_partial: true,
+                    constraints: vec![
+                        ConstrainedComponent {
+                            identifier: "note".into(),
+                            constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                                set: ElementOrSetOperation::Element(
+                                    SubtypeElement::SizeConstraint(Box::new(
+                                        ElementOrSetOperation::Element(
+                                            SubtypeElement::ValueRange {
+                                                min: Some(ASN1Value::Integer(1)),
+                                                max: Some(ASN1Value::Integer(4)),
+                                                extensible: false,
+                                            }
+                                        )
+                                    ))
+                                ),
+                                extensible: false,
+                            })],
+                            presence: ComponentPresence::Present
+                        },
+                        ConstrainedComponent {
+                            identifier: "inner".into(),
+                            constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                                set: ElementOrSetOperation::Element(
+                                    SubtypeElement::SingleTypeConstraint(InnerTypeConstraint {
+                                        is_partial: false,
+                                        constraints: vec![ConstrainedComponent {
+                                            identifier: "id".into(),
+                                            constraints: vec![],
+                                            presence: ComponentPresence::Unspecified
+                                        }]
+                                    })
+                                ),
+                                extensible: false,
+                            })],
+                            presence: ComponentPresence::Unspecified
+                        }
+                    ]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_nested_groups_in_permitted_alphabet() {
+        assert_eq!(
+            set_of(r#"(FROM(("a".."z" | "0".."9") EXCEPT "x"))"#),
+            ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(Box::new(operation(
+                nested(operation(
+                    SubtypeElement::ValueRange {
+                        min: Some(ASN1Value::String("a".into())),
+                        max: Some(ASN1Value::String("z".into())),
+                        extensible: false
+                    },
+                    SetOperator::Union,
+                    ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                        min: Some(ASN1Value::String("0".into())),
+                        max: Some(ASN1Value::String("9".into())),
+                        extensible: false
+                    })
+                )),
+                SetOperator::Except,
+                ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                    value: ASN1Value::String("x".into()),
+                    extensible: false
+                })
+            ))))
+        );
+    }
+
+    #[test]
+    fn parses_arithmetic_in_bounds() {
+        let arithmetic = |lhs, operator, rhs| ASN1Value::Arithmetic {
+            operator,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        };
+        let reference = |identifier: &str| ASN1Value::ElsewhereDeclaredValue {
+            parent: None,
+            identifier: identifier.into(),
+        };
+        assert_eq!(
+            set_of("(1 + maxLen * 2..(maxLen - 1) / 2)"),
+            ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                min: Some(arithmetic(
+                    ASN1Value::Integer(1),
+                    ArithmeticOperator::Add,
+                    arithmetic(
+                        reference("maxLen"),
+                        ArithmeticOperator::Multiply,
+                        ASN1Value::Integer(2)
+                    )
+                )),
+                max: Some(arithmetic(
+                    arithmetic(
+                        reference("maxLen"),
+                        ArithmeticOperator::Subtract,
+                        ASN1Value::Integer(1)
+                    ),
+                    ArithmeticOperator::Divide,
+                    ASN1Value::Integer(2)
+                )),
+                extensible: false
+            })
+        );
+        assert_eq!(
+            set_of("(10 - 2 - 3)"),
+            ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                value: arithmetic(
+                    arithmetic(
+                        ASN1Value::Integer(10),
+                        ArithmeticOperator::Subtract,
+                        ASN1Value::Integer(2)
+                    ),
+                    ArithmeticOperator::Subtract,
+                    ASN1Value::Integer(3)
+                ),
+                extensible: false
+            })
+        );
+    }
+
+    #[test]
+    fn parses_exclusive_range_endpoints() {
+        let arithmetic = |lhs, operator, rhs| ASN1Value::Arithmetic {
+            operator,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        };
+        assert_eq!(
+            set_of("(0<..MAX)"),
+            ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                min: Some(arithmetic(
+                    ASN1Value::Integer(0),
+                    ArithmeticOperator::Add,
+                    ASN1Value::Integer(1)
+                )),
+                max: None,
+                extensible: false
+            })
+        );
+        assert_eq!(
+            set_of("(MIN<..<maxLen)"),
+            ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                min: None,
+                max: Some(arithmetic(
+                    ASN1Value::ElsewhereDeclaredValue {
+                        parent: None,
+                        identifier: "maxLen".into()
+                    },
+                    ArithmeticOperator::Subtract,
+                    ASN1Value::Integer(1)
+                )),
+                extensible: false
+            })
+        );
+        assert_eq!(
+            set_of(r#"(FROM ("a"<..<"z"))"#),
+            ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(Box::new(
+                ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::String("b".into())),
+                    max: Some(ASN1Value::String("y".into())),
+                    extensible: false
+                })
+            )))
+        );
+    }
+
+    #[test]
+    fn keeps_exclusive_endpoints_of_real_ranges() {
+        assert_eq!(
+            real_endpoints(constraint("(0<..<1)").unwrap().1),
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::Integer(0)),
+                    max: Some(ASN1Value::Integer(1)),
+                    extensible: false
+                }),
+                extensible: false
+            })]
+        );
+    }
 }
@@ -1,8 +1,13 @@
-use nom::{bytes::complete::tag, combinator::value, IResult};
+use nom::{
+    bytes::complete::tag,
+    combinator::{opt, value},
+    sequence::pair,
+    IResult,
+};
 
 use crate::intermediate::*;
 
-use super::common::skip_ws_and_comments;
+use super::{common::skip_ws_and_comments, constraint::constraint};
 
 /// Tries to parse an ASN1 EMBEDDED PDV
 ///
@@ -20,9 +25,44 @@ use super::common::skip_ws_and_comments;
 /// _"Embedded Presentation Data Value" from its use in the OSI_
 /// _Presentation Layer, but this expansion is not used today,_
 /// _and it should be interpreted as "embedded value"._
+///
+/// Constraints on the components of the associated type are parsed, but not retained.
 pub fn embedded_pdv(input: &str) -> IResult<&str, ASN1Type> {
     value(
         ASN1Type::EmbeddedPdv,
-        skip_ws_and_comments(tag(EMBEDDED_PDV)),
+        skip_ws_and_comments(pair(tag(EMBEDDED_PDV), opt(constraint))),
     )(input)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::intermediate::*;
+
+    use super::embedded_pdv;
+
+    #[test]
+    fn parses_embedded_pdv() {
+        assert_eq!(
+            embedded_pdv("EMBEDDED PDV").unwrap(),
+            ("", ASN1Type::EmbeddedPdv)
+        );
+        assert_eq!(
+            embedded_pdv(" EMBEDDED PDV OPTIONAL").unwrap(),
+            (" OPTIONAL", ASN1Type::EmbeddedPdv)
+        );
+    }
+
+    #[test]
+    fn parses_embedded_pdv_with_inner_type_constraint() {
+        assert_eq!(
+            embedded_pdv(
+                r#"EMBEDDED PDV (WITH COMPONENTS {
+                    ...,
+                    identification (WITH COMPONENTS { ..., syntax PRESENT }),
+                    data-value-descriptor ABSENT })"#
+            )
+            .unwrap(),
+            ("", ASN1Type::EmbeddedPdv)
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::{char, i128},
     combinator::{map, opt},
-    multi::{fold_many0, many0},
+    multi::many0,
     sequence::{preceded, terminated, tuple},
     IResult,
 };
@@ -55,28 +56,39 @@ pub fn enumerated(input: &str) -> IResult<&str, ASN1Type> {
     )(input)
 }
 
-fn enumeral(input: &str) -> IResult<&str, (&str, Option<i128>, Option<char>, Option<&str>)> {
-    skip_ws_and_comments(tuple((
-        skip_ws(identifier),
-        skip_ws(opt(in_parentheses(skip_ws_and_comments(i128)))),
-        opt(skip_ws_and_comments(char(COMMA))),
-        skip_ws(opt(comment)),
-    )))(input)
+fn enumeral_number(input: &str) -> IResult<&str, (i128, EnumeralNumbering)> {
+    in_parentheses(skip_ws_and_comments(alt((
+        map(i128, |index| (index, EnumeralNumbering::Explicit)),
+        map(value_identifier, |reference| {
+            (0, EnumeralNumbering::Reference(reference.into()))
+        }),
+    ))))(input)
 }
 
-fn enumerals<'a>(start_index: usize) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Enumeral>> {
-    fold_many0(
-        enumeral,
-        Vec::<Enumeral>::new,
-        move |mut acc, (name, index, _, comments)| {
-            acc.push(Enumeral {
+fn enumeral(input: &str) -> IResult<&str, Enumeral> {
+    map(
+        skip_ws_and_comments(tuple((
+            skip_ws(identifier),
+            skip_ws(opt(enumeral_number)),
+            opt(skip_ws_and_comments(char(COMMA))),
+            skip_ws(opt(comment)),
+        ))),
+        |(name, number, _, comments)| {
+            let (index, numbering) = number.unwrap_or((0, EnumeralNumbering::Automatic));
+            Enumeral {
                 name: name.into(),
                 description: comments.map(|c| c.into()),
-                index: index.unwrap_or((acc.len() + start_index) as i128),
-            });
-            acc
+                index,
+                numbering,
+            }
         },
-    )
+    )(input)
+}
+
+/// Parses a list of enumerals. The indices of automatically numbered enumerals
+/// are assigned once the complete ENUMERATED is known.
+fn enumerals(input: &str) -> IResult<&str, Vec<Enumeral>> {
+    many0(enumeral)(input)
 }
 
 fn enumerated_body(
@@ -90,9 +102,9 @@ fn enumerated_body(
     ),
 > {
     in_braces(|input| {
-        let (input, root_enumerals) = enumerals(0)(input)?;
+        let (input, root_enumerals) = enumerals(input)?;
         let (input, ext_marker) = opt(terminated(extension_marker, opt(char(COMMA))))(input)?;
-        let (input, ext_enumerals) = opt(enumerals(root_enumerals.len()))(input)?;
+        let (input, ext_enumerals) = opt(enumerals)(input)?;
         Ok((input, (root_enumerals, ext_marker, ext_enumerals)))
     })(input)
 }
@@ -105,7 +117,7 @@ mod tests {
     #[test]
     fn parses_enumerals_with_line_comments() {
         assert_eq!(
-            enumerals(0)(
+            enumerals(
                 r#"forward     (1), -- This means forward
       backward    (2), -- This means backward
       unavailable (3)  -- This means nothing
@@ -118,16 +130,19 @@ mod tests {
                     name: "forward".into(),
                     description: Some(" This means forward".into(),),
                     index: 1,
+                    numbering: EnumeralNumbering::Explicit,
                 },
                 Enumeral {
                     name: "backward".into(),
                     description: Some(" This means backward".into(),),
                     index: 2,
+                    numbering: EnumeralNumbering::Explicit,
                 },
                 Enumeral {
                     name: "unavailable".into(),
                     description: Some(" This means nothing".into(),),
                     index: 3,
+                    numbering: EnumeralNumbering::Explicit,
                 },
             ],
         )
@@ -151,17 +166,20 @@ mod tests {
                     Enumeral {
                         name: "onePerMeter-0-1".into(),
                         description: None,
-                        index: 0
+                        index: 0,
+                        numbering: EnumeralNumbering::Automatic,
                     },
                     Enumeral {
                         name: "outOfRange".into(),
                         description: None,
-                        index: 1
+                        index: 1,
+                        numbering: EnumeralNumbering::Automatic,
                     },
                     Enumeral {
                         name: "unavailable".into(),
                         description: None,
-                        index: 2
+                        index: 2,
+                        numbering: EnumeralNumbering::Automatic,
                     }
                 ],
                 extensible: None
@@ -181,17 +199,20 @@ mod tests {
                     Enumeral {
                         name: "m1".into(),
                         description: None,
-                        index: 0
+                        index: 0,
+                        numbering: EnumeralNumbering::Automatic,
                     },
                     Enumeral {
                         name: "m2".into(),
                         description: None,
-                        index: 1
+                        index: 1,
+                        numbering: EnumeralNumbering::Automatic,
                     },
                     Enumeral {
                         name: "m3".into(),
                         description: None,
-                        index: 2
+                        index: 2,
+                        numbering: EnumeralNumbering::Automatic,
                     }
                 ],
                 extensible: Some(3)
@@ -209,12 +230,14 @@ mod tests {
                     Enumeral {
                         name: "One".into(),
                         description: None,
-                        index: 0
+                        index: 0,
+                        numbering: EnumeralNumbering::Automatic,
                     },
                     Enumeral {
                         name: "Three".into(),
                         description: None,
-                        index: 1
+                        index: 1,
+                        numbering: EnumeralNumbering::Automatic,
                     }
                 ],
                 extensible: Some(1)
@@ -240,12 +263,14 @@ mod tests {
                     Enumeral {
                         name: "permanentCenDsrcTolling".into(),
                         description: None,
-                        index: 0
+                        index: 0,
+                        numbering: EnumeralNumbering::Explicit,
                     },
                     Enumeral {
                         name: "temporaryCenDsrcTolling".into(),
                         description: None,
-                        index: 1
+                        index: 1,
+                        numbering: EnumeralNumbering::Explicit,
                     }
                 ],
                 extensible: Some(1)
@@ -272,17 +297,20 @@ mod tests {
                     Enumeral {
                         name: "forward".into(),
                         description: Some("This means forward".into()),
-                        index: 1
+                        index: 1,
+                        numbering: EnumeralNumbering::Explicit,
                     },
                     Enumeral {
                         name: "backward".into(),
                         description: Some("This means backward".into()),
-                        index: 2
+                        index: 2,
+                        numbering: EnumeralNumbering::Explicit,
                     },
                     Enumeral {
                         name: "unavailable".into(),
                         description: Some("This means nothing".into()),
-                        index: 3
+                        index: 3,
+                        numbering: EnumeralNumbering::Explicit,
                     }
                 ],
                 extensible: None
@@ -308,13 +336,53 @@ mod tests {
                     description: Some(
                         " let's consider this a comment concerning 'forward' ".into()
                     ),
-                    index: 1
+                    index: 1,
+                    numbering: EnumeralNumbering::Explicit,
                 },],
                 extensible: Some(1)
             })
         )
     }
 
+    #[test]
+    fn parses_enumerated_with_value_reference() {
+        assert_eq!(
+            enumerated("ENUMERATED { a, b(base-value), c(0), ..., d }")
+                .unwrap()
+                .1,
+            ASN1Type::Enumerated(Enumerated {
+                constraints: vec![],
+                members: vec![
+                    Enumeral {
+                        name: "a".into(),
+                        description: None,
+                        index: 1,
+                        numbering: EnumeralNumbering::Automatic,
+                    },
+                    Enumeral {
+                        name: "b".into(),
+                        description: None,
+                        index: 0,
+                        numbering: EnumeralNumbering::Reference("base-value".into()),
+                    },
+                    Enumeral {
+                        name: "c".into(),
+                        description: None,
+                        index: 0,
+                        numbering: EnumeralNumbering::Explicit,
+                    },
+                    Enumeral {
+                        name: "d".into(),
+                        description: None,
+                        index: 2,
+                        numbering: EnumeralNumbering::Automatic,
+                    }
+                ],
+                extensible: Some(3)
+            })
+        )
+    }
+
     #[test]
     fn parses_enumerated_value() {
         assert_eq!(
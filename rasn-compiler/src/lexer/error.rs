@@ -1,10 +1,63 @@
 use core::fmt::{Display, Formatter, Result};
-use std::error::Error;
+use std::{error::Error, ops::Range};
 
 #[derive(Debug, Clone)]
 pub struct LexerError {
     pub details: String,
     pub kind: LexerErrorType,
+    /// Byte range of the ASN.1 fragment that could not be parsed, if known
+    pub span: Option<Range<usize>>,
+}
+
+impl LexerError {
+    /// Converts a `nom` error into a lexer error whose span points at the line of `input`
+    /// at which parsing failed.
+    pub(crate) fn locate(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        let span = match &error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let fragment = e.input.trim_start();
+                let start = input.len().saturating_sub(fragment.len());
+                let line = fragment.lines().next().unwrap_or_default().trim_end();
+                Some(start..start + line.len())
+            }
+            nom::Err::Incomplete(_) => None,
+        };
+        Self {
+            span,
+            ..error.into()
+        }
+    }
+
+    /// Renders the error as a diagnostic that quotes the offending fragment of the ASN.1 `source`
+    /// and marks it with carets. `source_name` identifies the source in the diagnostic, e.g. by its path.
+    /// The `source` must be the text that the error was encountered in.
+    #[cfg(feature = "pretty-diagnostics")]
+    pub fn render_pretty(&self, source_name: &str, source: &str) -> String {
+        use ariadne::{Color, Config, IndexType, Label, Report, ReportKind, Source};
+
+        let span = self.span.clone().unwrap_or(source.len()..source.len());
+        let message = match &self.kind {
+            LexerErrorType::NotEnoughData => "Unexpected end of ASN.1 source".to_string(),
+            LexerErrorType::MatchingError(kind) | LexerErrorType::Failure(kind) => {
+                format!("Failed to parse ASN.1 source ({kind:?})")
+            }
+        };
+        let mut rendered = Vec::new();
+        let result = Report::build(ReportKind::Error, (source_name, span.clone()))
+            .with_config(Config::default().with_index_type(IndexType::Byte))
+            .with_message(message)
+            .with_label(
+                Label::new((source_name, span))
+                    .with_message("no valid ASN.1 declaration starts here")
+                    .with_color(Color::Red),
+            )
+            .finish()
+            .write((source_name, Source::from(source)), &mut rendered);
+        match result {
+            Ok(()) => String::from_utf8_lossy(&rendered).into_owned(),
+            Err(_) => self.to_string(),
+        }
+    }
 }
 
 impl<'a> From<nom::Err<nom::error::Error<&'a str>>> for LexerError {
@@ -13,14 +66,17 @@ impl<'a> From<nom::Err<nom::error::Error<&'a str>>> for LexerError {
             nom::Err::Incomplete(_) => Self {
                 details: "Unexpected end of input!".into(),
                 kind: LexerErrorType::NotEnoughData,
+                span: None,
             },
             nom::Err::Error(e) => Self {
                 details: "Error matching ASN syntax while parsing:".to_owned() + e.input,
                 kind: LexerErrorType::MatchingError(e.code),
+                span: None,
             },
             nom::Err::Failure(e) => Self {
                 details: "Unrecoverable error while parsing:".to_owned() + e.input,
                 kind: LexerErrorType::Failure(e.code),
+                span: None,
             },
         }
     }
@@ -13,5 +13,22 @@ use super::common::skip_ws_and_comments;
 /// and an `ASN1Type::External` value representing the ASN1 declaration.
 /// If the match fails, the lexer will not consume the input and will return an error.
 pub fn external(input: &str) -> IResult<&str, ASN1Type> {
-    value(ASN1Type::External, skip_ws_and_comments(tag(EMBEDDED_PDV)))(input)
+    value(ASN1Type::External, skip_ws_and_comments(tag(EXTERNAL)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intermediate::*;
+
+    use super::external;
+
+    #[test]
+    fn parses_external() {
+        assert_eq!(external("EXTERNAL").unwrap(), ("", ASN1Type::External));
+        assert_eq!(
+            external(" EXTERNAL OPTIONAL").unwrap(),
+            (" OPTIONAL", ASN1Type::External)
+        );
+        assert!(external("EMBEDDED PDV").is_err());
+    }
 }
@@ -2,24 +2,26 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{alphanumeric1, char, one_of},
-    combinator::{into, map, opt, recognize, value},
-    multi::{many0, many1, separated_list0, separated_list1},
+    combinator::{into, map, map_opt, opt, recognize, value},
+    multi::{many0, many1, separated_list1},
     sequence::{pair, preceded, terminated, tuple},
     IResult,
 };
 
-use crate::intermediate::{information_object::*, types::ObjectIdentifier, *};
+use crate::intermediate::{information_object::*, *};
 
 use super::{
     asn1_type, asn1_value,
     common::{
-        default, extension_marker, identifier, in_braces, in_brackets, optional_comma,
-        optional_marker, skip_ws_and_comments, uppercase_identifier,
+        default, extension_marker, identifier, in_braces, in_brackets, in_parentheses,
+        optional_comma, optional_marker, skip_ws_and_comments, uppercase_identifier,
     },
     constraint::constraint,
+    parameterization::parameters,
 };
 
-/// Tries to parse an ASN1 TYPE-IDENTIFIER
+/// Tries to parse a reference to a built-in information object class,
+/// i.e. `TYPE-IDENTIFIER` or `ABSTRACT-SYNTAX`
 ///
 /// *`input` - string slice to be matched against
 ///
@@ -33,30 +35,10 @@ use super::{
 /// }
 /// WITH SYNTAX {&Type IDENTIFIED BY &id}
 /// ```
-pub fn type_identifier(input: &str) -> IResult<&str, InformationObjectClass> {
-    skip_ws_and_comments(value(
-        InformationObjectClass {
-            fields: vec![
-                InformationObjectClassField {
-                    identifier: ObjectFieldIdentifier::SingleValue("id".into()),
-                    ty: Some(ASN1Type::ObjectIdentifier(ObjectIdentifier {
-                        constraints: vec![],
-                    })),
-                    is_optional: false,
-                    default: None,
-                    is_unique: true,
-                },
-                InformationObjectClassField {
-                    identifier: ObjectFieldIdentifier::MultipleValue("Type".into()),
-                    ty: None,
-                    is_optional: false,
-                    default: None,
-                    is_unique: false,
-                },
-            ],
-            syntax: None,
-        },
-        tag(TYPE_IDENTIFIER),
+pub fn built_in_class(input: &str) -> IResult<&str, InformationObjectClass> {
+    skip_ws_and_comments(map_opt(
+        alt((tag(TYPE_IDENTIFIER), tag(ABSTRACT_SYNTAX))),
+        InformationObjectClass::built_in,
     ))(input)
 }
 
@@ -121,30 +103,55 @@ pub fn information_object(input: &str) -> IResult<&str, InformationObjectFields>
 
 pub fn object_set(input: &str) -> IResult<&str, ObjectSet> {
     into(in_braces(tuple((
-        separated_list0(
-            skip_ws_and_comments(alt((tag(PIPE), tag(UNION)))),
-            skip_ws_and_comments(alt((
-                into(information_object),
-                into(skip_ws_and_comments(identifier)),
-            ))),
-        ),
+        map(opt(object_set_union), Option::unwrap_or_default),
         opt(skip_ws_and_comments(preceded(
             opt(char(COMMA)),
             extension_marker,
         ))),
         opt(skip_ws_and_comments(preceded(
             char(COMMA),
-            separated_list1(
-                skip_ws_and_comments(alt((tag(PIPE), tag(UNION)))),
-                skip_ws_and_comments(alt((
-                    into(information_object),
-                    into(skip_ws_and_comments(identifier)),
-                ))),
-            )
+            object_set_union,
         ))),
     ))))(input)
 }
 
+/// Parses a union of object set elements. The elements of parenthesized unions
+/// are flattened into the union, e.g. `ie-a | (ie-b | ie-c)` yields `ie-a`, `ie-b`, and `ie-c`
+fn object_set_union(input: &str) -> IResult<&str, Vec<ObjectSetValue>> {
+    map(
+        separated_list1(
+            skip_ws_and_comments(alt((tag(PIPE), tag(UNION)))),
+            skip_ws_and_comments(alt((
+                map(
+                    alt((
+                        into(information_object),
+                        parameterized_object_set_reference,
+                        into(skip_ws_and_comments(identifier)),
+                    )),
+                    |element| vec![element],
+                ),
+                in_parentheses(object_set_union),
+            ))),
+        ),
+        |elements| elements.concat(),
+    )(input)
+}
+
+/// Parses a reference to a parameterized object set as an element of an object set,
+/// e.g. `BaseIEs { {ie-c} }`
+fn parameterized_object_set_reference(input: &str) -> IResult<&str, ObjectSetValue> {
+    map(
+        pair(
+            skip_ws_and_comments(identifier),
+            skip_ws_and_comments(parameters),
+        ),
+        |(identifier, parameters)| ObjectSetValue::ParameterizedReference {
+            identifier: identifier.into(),
+            parameters,
+        },
+    )(input)
+}
+
 fn custom_syntax_information_object(input: &str) -> IResult<&str, InformationObjectFields> {
     map(
         skip_ws_and_comments(many1(skip_ws_and_comments(alt((
@@ -182,13 +189,27 @@ fn default_syntax_information_object(input: &str) -> IResult<&str, InformationOb
 }
 
 fn information_object_field(input: &str) -> IResult<&str, InformationObjectClassField> {
-    into(tuple((
-        skip_ws_and_comments(object_field_identifier),
-        opt(skip_ws_and_comments(asn1_type)),
-        opt(skip_ws_and_comments(tag(UNIQUE))),
-        optional_marker,
-        default,
-    )))(input)
+    alt((
+        type_field_with_default,
+        into(tuple((
+            skip_ws_and_comments(object_field_identifier),
+            opt(skip_ws_and_comments(asn1_type)),
+            opt(skip_ws_and_comments(tag(UNIQUE))),
+            optional_marker,
+            default,
+        ))),
+    ))(input)
+}
+
+/// Parses a type field with a default type, such as `&ArgumentType DEFAULT NULL`
+fn type_field_with_default(input: &str) -> IResult<&str, InformationObjectClassField> {
+    into(pair(
+        skip_ws_and_comments(multiple_value_field_id),
+        preceded(
+            skip_ws_and_comments(tag(DEFAULT)),
+            skip_ws_and_comments(asn1_type),
+        ),
+    ))(input)
 }
 
 fn object_field_identifier(input: &str) -> IResult<&str, ObjectFieldIdentifier> {
@@ -248,7 +269,7 @@ fn syntax_literal(input: &str) -> IResult<&str, &str> {
 mod tests {
     use std::vec;
 
-    use crate::intermediate::types::*;
+    use crate::intermediate::{constraints::Parameter, types::*};
 
     use crate::lexer::information_object_class::{information_object_class, object_set};
     use crate::lexer::top_level_type_declaration;
@@ -331,6 +352,48 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_information_object_class_with_defaults() {
+        assert_eq!(
+            information_object_class(
+                r#"CLASS {
+                    &ArgumentType DEFAULT NULL,
+                    &maxSize INTEGER DEFAULT 10
+                }"#
+            )
+            .unwrap()
+            .1,
+            InformationObjectClass {
+                syntax: None,
+                fields: vec![
+                    InformationObjectClassField {
+                        identifier: ObjectFieldIdentifier::MultipleValue("&ArgumentType".into()),
+                        ty: None,
+                        is_optional: true,
+                        is_unique: false,
+                        default: Some(InformationObjectField::TypeField(TypeField {
+                            identifier: "&ArgumentType".into(),
+                            ty: ASN1Type::Null
+                        }))
+                    },
+                    InformationObjectClassField {
+                        identifier: ObjectFieldIdentifier::SingleValue("&maxSize".into()),
+                        ty: Some(ASN1Type::Integer(Integer {
+                            constraints: vec![],
+                            distinguished_values: None,
+                        })),
+                        is_optional: true,
+                        is_unique: false,
+                        default: Some(InformationObjectField::FixedValueField(FixedValueField {
+                            identifier: "&maxSize".into(),
+                            value: ASN1Value::Integer(10)
+                        }))
+                    }
+                ]
+            }
+        )
+    }
+
     #[test]
     fn parses_simple_object_set() {
         assert_eq!(
@@ -356,6 +419,46 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_object_set_with_parenthesized_unions() {
+        assert_eq!(
+            object_set(r#"{ op-a UNION (My-ops | op-b), ..., (op-c) }"#)
+                .unwrap()
+                .1,
+            ObjectSet {
+                values: vec![
+                    ObjectSetValue::Reference("op-a".into()),
+                    ObjectSetValue::Reference("My-ops".into()),
+                    ObjectSetValue::Reference("op-b".into()),
+                    ObjectSetValue::Reference("op-c".into())
+                ],
+                extensible: Some(3)
+            }
+        )
+    }
+
+    #[test]
+    fn parses_parameterized_object_set_reference() {
+        assert_eq!(
+            object_set(r#"{ BaseIEs { {Extra-IEs} } | ie-c, ... }"#)
+                .unwrap()
+                .1,
+            ObjectSet {
+                values: vec![
+                    ObjectSetValue::ParameterizedReference {
+                        identifier: "BaseIEs".into(),
+                        parameters: vec![Parameter::ObjectSetParameter(ObjectSet {
+                            values: vec![ObjectSetValue::Reference("Extra-IEs".into())],
+                            extensible: None
+                        })]
+                    },
+                    ObjectSetValue::Reference("ie-c".into())
+                ],
+                extensible: Some(2)
+            }
+        )
+    }
+
     #[test]
     fn parses_inline_declaration_value_set() {
         assert_eq!(
@@ -449,6 +552,49 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_custom_syntax_with_nested_optional_groups() {
+        assert_eq!(
+            information_object_class(
+                r#"CLASS {
+            &ArgumentType OPTIONAL,
+            &argumentTypeOptional BOOLEAN OPTIONAL,
+            &alwaysReturns BOOLEAN DEFAULT TRUE
+            }
+            WITH SYNTAX {
+                [ARGUMENT &ArgumentType [OPTIONAL &argumentTypeOptional]]
+                [ALWAYS RESPONDS &alwaysReturns]
+            }"#
+            )
+            .unwrap()
+            .1
+            .syntax,
+            Some(InformationObjectSyntax {
+                expressions: vec![
+                    SyntaxExpression::Optional(vec![
+                        SyntaxExpression::Required(SyntaxToken::Literal("ARGUMENT".into())),
+                        SyntaxExpression::Required(SyntaxToken::Field(
+                            ObjectFieldIdentifier::MultipleValue("&ArgumentType".into())
+                        )),
+                        SyntaxExpression::Optional(vec![
+                            SyntaxExpression::Required(SyntaxToken::Literal("OPTIONAL".into())),
+                            SyntaxExpression::Required(SyntaxToken::Field(
+                                ObjectFieldIdentifier::SingleValue("&argumentTypeOptional".into())
+                            ))
+                        ])
+                    ]),
+                    SyntaxExpression::Optional(vec![
+                        SyntaxExpression::Required(SyntaxToken::Literal("ALWAYS".into())),
+                        SyntaxExpression::Required(SyntaxToken::Literal("RESPONDS".into())),
+                        SyntaxExpression::Required(SyntaxToken::Field(
+                            ObjectFieldIdentifier::SingleValue("&alwaysReturns".into())
+                        ))
+                    ])
+                ]
+            })
+        )
+    }
+
     #[test]
     fn parses_information_object_with_custom_syntax() {
         println!(
@@ -480,7 +626,9 @@ mod tests {
                     constraints: vec![]
                 }),
                 parameterization: None,
-                index: None
+                index: None,
+                selection: None,
+                is_alias: false
             }
         )
     }
@@ -10,11 +10,11 @@
 //! contains helper lexers not specific to ASN1's notation.
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
+    bytes::complete::{is_not, tag},
     character::complete::multispace1,
-    combinator::{into, map, opt, recognize},
+    combinator::{into, map, map_opt, opt, recognize, verify},
     multi::{many0, many1},
-    sequence::{delimited, pair, preceded, terminated, tuple},
+    sequence::{pair, preceded, terminated, tuple},
     IResult,
 };
 
@@ -35,7 +35,7 @@ mod common;
 mod constraint;
 mod embedded_pdv;
 mod enumerated;
-mod error;
+pub(crate) mod error;
 mod external;
 mod information_object_class;
 mod integer;
@@ -58,29 +58,90 @@ mod tests;
 pub fn asn_spec(
     input: &str,
 ) -> Result<Vec<(ModuleReference, Vec<ToplevelDefinition>)>, LexerError> {
-    many1(pair(
-        module_reference,
-        terminated(
+    many1(map(
+        tuple((
+            module_reference,
             many0(skip_ws(alt((
-                map(
-                    top_level_information_declaration,
-                    ToplevelDefinition::Information,
-                ),
-                map(top_level_type_declaration, ToplevelDefinition::Type),
-                map(top_level_value_declaration, ToplevelDefinition::Value),
+                map(macro_definition, ModuleBodyItem::Macro),
+                map(top_level_information_declaration, |i| {
+                    ModuleBodyItem::Definition(ToplevelDefinition::Information(i))
+                }),
+                map(top_level_type_declaration, |t| {
+                    ModuleBodyItem::Definition(ToplevelDefinition::Type(t))
+                }),
+                map(top_level_value_set_declaration, |t| {
+                    ModuleBodyItem::Definition(ToplevelDefinition::Type(t))
+                }),
+                map(top_level_value_declaration, |v| {
+                    ModuleBodyItem::Definition(ToplevelDefinition::Value(v))
+                }),
             )))),
-            skip_ws_and_comments(alt((encoding_control, end))),
-        ),
+            many0(encoding_control),
+            skip_ws_and_comments(end),
+        )),
+        |(mut header, items, encoding_control, _)| {
+            header.encoding_control = encoding_control;
+            let mut tlds = vec![];
+            for item in items {
+                match item {
+                    ModuleBodyItem::Definition(tld) => tlds.push(tld),
+                    ModuleBodyItem::Macro(name) => header.macros.push(name.into()),
+                }
+            }
+            (header, tlds)
+        },
     ))(input)
     .map(|(_, res)| res)
-    .map_err(|e| e.into())
+    .map_err(|e| LexerError::locate(input, e))
 }
 
-fn encoding_control(input: &str) -> IResult<&str, &str> {
-    delimited(
-        skip_ws_and_comments(tag("ENCODING-CONTROL")),
-        take_until(END),
-        end,
+#[allow(clippy::large_enum_variant)] // items are unpacked right after parsing
+enum ModuleBodyItem<'a> {
+    Definition(ToplevelDefinition),
+    Macro(&'a str),
+}
+
+/// Parses a `MACRO` definition of the macro notation of Rec. ITU-T X.208,
+/// which has been removed from later versions of the ASN.1 standard.
+/// The macro body is skipped up to its closing `END`, only the macro's name is returned.
+fn macro_definition(input: &str) -> IResult<&str, &str> {
+    terminated(
+        skip_ws_and_comments(uppercase_identifier),
+        tuple((
+            skip_ws_and_comments(tag(MACRO)),
+            assignment,
+            skip_ws_and_comments(tag(BEGIN)),
+            many0(alt((
+                comment,
+                multispace1,
+                verify(is_not(" \t\r\n"), |word: &str| word != END),
+            ))),
+            tag(END),
+        )),
+    )(input)
+}
+
+/// Parses an encoding control section as defined in Rec. ITU-T X.680 (02/2021) § 54.
+/// The encoding instructions of a section extend up to the next section or the end of the module.
+fn encoding_control(input: &str) -> IResult<&str, EncodingControl> {
+    map(
+        preceded(
+            skip_ws_and_comments(tag(ENCODING_CONTROL)),
+            pair(
+                skip_ws_and_comments(identifier),
+                recognize(many0(alt((
+                    comment,
+                    multispace1,
+                    verify(is_not(" \t\r\n"), |word: &str| {
+                        word != END && word != ENCODING_CONTROL
+                    }),
+                )))),
+            ),
+        ),
+        |(encoding_reference, instructions)| EncodingControl {
+            encoding_reference: encoding_reference.into(),
+            instructions: instructions.trim().into(),
+        },
     )(input)
 }
 
@@ -100,6 +161,27 @@ pub fn top_level_type_declaration(input: &str) -> IResult<&str, ToplevelTypeDefi
     )))(input)
 }
 
+/// Parses a value set assignment, e.g. `Small INTEGER ::= { 1 | 2 | 3 }`.
+/// A value set assignment is equivalent to assigning the governing type constrained by
+/// the value set, i.e. `Small ::= INTEGER (1 | 2 | 3)`, and is represented as such.
+pub fn top_level_value_set_declaration(input: &str) -> IResult<&str, ToplevelTypeDefinition> {
+    into(tuple((
+        skip_ws(many0(comment)),
+        skip_ws(title_case_identifier),
+        opt(parameterization),
+        map_opt(
+            pair(
+                skip_ws_and_comments(asn1_type),
+                preceded(assignment, value_set),
+            ),
+            |(mut ty, value_set)| {
+                ty.constraints_mut()?.push(value_set);
+                Some((None, ty))
+            },
+        ),
+    )))(input)
+}
+
 pub fn top_level_information_declaration(
     input: &str,
 ) -> IResult<&str, ToplevelInformationDefinition> {
@@ -115,6 +197,8 @@ pub fn asn1_type(input: &str) -> IResult<&str, ASN1Type> {
         alt((
             null,
             selection_type_choice,
+            // RELATIVE-OID-IRI needs to be matched before RELATIVE-OID
+            object_identifier_iri,
             object_identifier,
             sequence_of,
             sequence,
@@ -146,7 +230,6 @@ pub fn asn1_type(input: &str) -> IResult<&str, ASN1Type> {
 
 pub fn asn1_value(input: &str) -> IResult<&str, ASN1Value> {
     alt((
-        all_value,
         null_value,
         map(object_identifier_value, ASN1Value::ObjectIdentifier),
         choice_value,
@@ -157,10 +240,25 @@ pub fn asn1_value(input: &str) -> IResult<&str, ASN1Value> {
         boolean_value,
         integer_value,
         character_string_value,
+        parameterized_value_reference,
         elsewhere_declared_value,
     ))(input)
 }
 
+/// Parses a reference to a parameterized value definition, e.g. `maxOf{5}`.
+pub fn parameterized_value_reference(input: &str) -> IResult<&str, ASN1Value> {
+    map(
+        pair(
+            skip_ws_and_comments(value_identifier),
+            skip_ws_and_comments(parameters),
+        ),
+        |(id, parameters)| ASN1Value::ParameterizedValueReference {
+            identifier: id.into(),
+            parameters,
+        },
+    )(input)
+}
+
 pub fn elsewhere_declared_value(input: &str) -> IResult<&str, ASN1Value> {
     map(
         pair(
@@ -209,9 +307,13 @@ fn top_level_information_object_declaration(
 ) -> IResult<&str, ToplevelInformationDefinition> {
     into(tuple((
         skip_ws(many0(comment)),
-        skip_ws(identifier),
+        // Object references start with a lowercase letter, object set references with an uppercase one
+        skip_ws(value_identifier),
         skip_ws(opt(parameterization)),
-        skip_ws(uppercase_identifier),
+        // Built-in types like `RELATIVE-OID` govern values rather than objects
+        skip_ws(verify(uppercase_identifier, |class: &str| {
+            !ASN1_KEYWORDS.contains(&class) || [TYPE_IDENTIFIER, ABSTRACT_SYNTAX].contains(&class)
+        })),
         preceded(assignment, information_object),
     )))(input)
 }
@@ -221,7 +323,10 @@ fn top_level_object_set_declaration(input: &str) -> IResult<&str, ToplevelInform
         skip_ws(many0(comment)),
         skip_ws(identifier),
         skip_ws(opt(parameterization)),
-        skip_ws(uppercase_identifier),
+        // Built-in types like `INTEGER` govern value sets rather than object sets
+        skip_ws(verify(uppercase_identifier, |class: &str| {
+            !ASN1_KEYWORDS.contains(&class) || [TYPE_IDENTIFIER, ABSTRACT_SYNTAX].contains(&class)
+        })),
         preceded(assignment, object_set),
     )))(input)
 }
@@ -231,6 +336,6 @@ fn top_level_object_class_declaration(input: &str) -> IResult<&str, ToplevelInfo
         skip_ws(many0(comment)),
         skip_ws(uppercase_identifier),
         skip_ws(opt(parameterization)),
-        preceded(assignment, alt((type_identifier, information_object_class))),
+        preceded(assignment, alt((built_in_class, information_object_class))),
     )))(input)
 }
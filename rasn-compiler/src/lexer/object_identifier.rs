@@ -19,7 +19,7 @@ use nom::{
 use super::{
     common::{in_braces, in_parentheses, skip_ws, skip_ws_and_comments, value_identifier},
     constraint::constraint,
-    RELATIVE_OID,
+    OID_IRI, RELATIVE_OID, RELATIVE_OID_IRI,
 };
 
 /// Tries to parse an ASN1 OBJECT IDENTIFIER
@@ -41,9 +41,22 @@ pub fn object_identifier_value(input: &str) -> IResult<&str, ObjectIdentifierVal
     )))(input)
 }
 
+/// Tries to parse an ASN1 OID-IRI or RELATIVE-OID-IRI type.
+/// Values of these types are written in the quoted-slash notation of IRIs,
+/// such as `"/ISO/Registration_Authority/19785.CBEFF"`.
+pub fn object_identifier_iri(input: &str) -> IResult<&str, ASN1Type> {
+    map(
+        into(pair(
+            skip_ws_and_comments(alt((tag(OID_IRI), tag(RELATIVE_OID_IRI)))),
+            opt(skip_ws_and_comments(constraint)),
+        )),
+        ASN1Type::ObjectIdentifierIri,
+    )(input)
+}
+
 pub fn object_identifier(input: &str) -> IResult<&str, ASN1Type> {
     map(
-        into(preceded(
+        into(pair(
             skip_ws_and_comments(alt((tag(OBJECT_IDENTIFIER), tag(RELATIVE_OID)))),
             opt(skip_ws_and_comments(constraint)),
         )),
@@ -101,4 +114,50 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn distinguishes_relative_oid() {
+        assert_eq!(
+            object_identifier("OBJECT IDENTIFIER").unwrap().1,
+            ASN1Type::ObjectIdentifier(crate::intermediate::types::ObjectIdentifier {
+                constraints: vec![],
+                is_relative: false
+            })
+        );
+        assert_eq!(
+            object_identifier("RELATIVE-OID").unwrap().1,
+            ASN1Type::ObjectIdentifier(crate::intermediate::types::ObjectIdentifier {
+                constraints: vec![],
+                is_relative: true
+            })
+        );
+    }
+
+    #[test]
+    fn parses_oid_iri_types() {
+        assert_eq!(
+            object_identifier_iri("OID-IRI").unwrap().1,
+            ASN1Type::ObjectIdentifierIri(crate::intermediate::types::ObjectIdentifierIri {
+                constraints: vec![],
+                is_relative: false
+            })
+        );
+        assert_eq!(
+            object_identifier_iri("RELATIVE-OID-IRI").unwrap().1,
+            ASN1Type::ObjectIdentifierIri(crate::intermediate::types::ObjectIdentifierIri {
+                constraints: vec![],
+                is_relative: true
+            })
+        );
+        assert_eq!(
+            crate::lexer::asn1_type("RELATIVE-OID-IRI").unwrap(),
+            (
+                "",
+                ASN1Type::ObjectIdentifierIri(crate::intermediate::types::ObjectIdentifierIri {
+                    constraints: vec![],
+                    is_relative: true
+                })
+            )
+        );
+    }
 }
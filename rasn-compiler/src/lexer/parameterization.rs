@@ -25,12 +25,12 @@ pub fn parameterization(input: &str) -> IResult<&str, Parameterization> {
                 skip_ws_and_comments(char(COLON)),
                 skip_ws_and_comments(value_identifier),
             )),
-            into(skip_ws_and_comments(value_identifier)),
             into(skip_ws_and_comments(separated_pair(
                 identifier,
                 skip_ws_and_comments(char(COLON)),
                 skip_ws_and_comments(identifier),
             ))),
+            into(skip_ws_and_comments(identifier)),
         ))),
     )))(input)
 }
@@ -76,6 +76,27 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_class_and_dependent_object_set_params() {
+        assert_eq!(
+            parameterization(r#"{ALGORITHM-TYPE, ALGORITHM-TYPE:AlgorithmSet}"#)
+                .unwrap()
+                .1,
+            Parameterization {
+                parameters: vec![
+                    ParameterizationArgument {
+                        dummy_reference: "ALGORITHM-TYPE".into(),
+                        param_governor: ParameterGovernor::None
+                    },
+                    ParameterizationArgument {
+                        dummy_reference: "AlgorithmSet".into(),
+                        param_governor: ParameterGovernor::Class("ALGORITHM-TYPE".into())
+                    }
+                ]
+            }
+        )
+    }
+
     #[test]
     fn parses_object_set_parameter() {
         assert_eq!(
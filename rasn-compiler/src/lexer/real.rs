@@ -10,12 +10,12 @@ use nom::{
 
 use super::{
     common::{in_braces, skip_ws_and_comments},
-    constraint::constraint,
+    constraint::{constraint, real_endpoints},
 };
 
 pub fn real_value(input: &str) -> IResult<&str, ASN1Value> {
     map(
-        skip_ws_and_comments(alt((dot_notation, mbe_notation))),
+        skip_ws_and_comments(alt((special_real_value, dot_notation, mbe_notation))),
         ASN1Value::Real,
     )(input)
 }
@@ -32,12 +32,22 @@ pub fn real(input: &str) -> IResult<&str, ASN1Type> {
     map(
         preceded(
             skip_ws_and_comments(tag(REAL)),
-            opt(skip_ws_and_comments(constraint)),
+            opt(skip_ws_and_comments(map(constraint, real_endpoints))),
         ),
         |m| ASN1Type::Real(m.into()),
     )(input)
 }
 
+/// Matches the special REAL values `PLUS-INFINITY` and `MINUS-INFINITY`.
+/// The special value `0` is matched by the integer lexer and
+/// converted into a REAL value during linking.
+fn special_real_value(input: &str) -> IResult<&str, f64> {
+    alt((
+        value(f64::INFINITY, tag(PLUS_INFINITY)),
+        value(f64::NEG_INFINITY, tag(MINUS_INFINITY)),
+    ))(input)
+}
+
 fn dot_notation(input: &str) -> IResult<&str, f64> {
     map(
         skip_ws_and_comments(separated_pair(i64, char('.'), u64)),
@@ -183,6 +193,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_special_real_values() {
+        assert_eq!(
+            real_value("PLUS-INFINITY").unwrap().1,
+            ASN1Value::Real(f64::INFINITY)
+        );
+        assert_eq!(
+            real_value(" MINUS-INFINITY").unwrap().1,
+            ASN1Value::Real(f64::NEG_INFINITY)
+        );
+    }
+
     #[test]
     fn parses_mbe_notation_real_value() {
         if let ASN1Value::Real(r) = real_value("{mantissa 334159, base 10, exponent -5}")
@@ -15,10 +15,13 @@ pub fn sequence_value(input: &str) -> IResult<&str, ASN1Value> {
     map(
         in_braces(separated_list0(
             skip_ws_and_comments(char(',')),
-            skip_ws_and_comments(pair(
-                opt(value_identifier),
-                skip_ws_and_comments(asn1_value),
-            )),
+            skip_ws_and_comments(alt((
+                pair(
+                    map(value_identifier, Some),
+                    skip_ws_and_comments(asn1_value),
+                ),
+                map(asn1_value, |val| (None, val)),
+            ))),
         )),
         |fields| {
             ASN1Value::SequenceOrSet(
@@ -56,6 +59,13 @@ pub fn sequence(input: &str) -> IResult<&str, ASN1Type> {
                         skip_ws_and_comments(alt((extension_group, sequence_component))),
                         optional_comma,
                     ))),
+                    opt(preceded(
+                        terminated(extension_marker, opt(char(COMMA))),
+                        many0(terminated(
+                            skip_ws_and_comments(sequence_component),
+                            optional_comma,
+                        )),
+                    )),
                 ))),
                 opt(constraint),
             ),
@@ -108,6 +118,12 @@ pub fn sequence_component(input: &str) -> IResult<&str, SequenceComponent> {
             preceded(
                 tag(COMPONENTS_OF),
                 skip_ws_and_comments(alt((
+                    // the module reference of an external type reference is not needed
+                    // for linking, since the definitions of all modules are linked together
+                    preceded(
+                        pair(title_case_identifier, char('.')),
+                        title_case_identifier,
+                    ),
                     recognize(separated_list1(tag(".&"), identifier)),
                     title_case_identifier,
                 ))),
@@ -692,6 +708,42 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_sequence_with_root_components_after_extensions() {
+        let ASN1Type::Sequence(seq) = sequence(
+            "SEQUENCE {
+                root-a INTEGER OPTIONAL,
+                ...,
+                [[ ext-a INTEGER ]],
+                ext-b BOOLEAN OPTIONAL,
+                ...,
+                root-b BOOLEAN OPTIONAL
+            }",
+        )
+        .unwrap()
+        .1
+        else {
+            panic!("Expected SEQUENCE")
+        };
+        assert_eq!(seq.extensible, Some(2));
+        assert_eq!(
+            seq.members
+                .iter()
+                .map(|m| m.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["root-a", "root-b", "ext_group_ext-a", "ext-b"]
+        );
+        let ASN1Type::Sequence(seq) =
+            sequence("SEQUENCE { root-a INTEGER, ..., ..., root-b BOOLEAN }")
+                .unwrap()
+                .1
+        else {
+            panic!("Expected SEQUENCE")
+        };
+        assert_eq!(seq.extensible, Some(2));
+        assert_eq!(seq.members.len(), 2);
+    }
+
     #[test]
     fn parses_sequence_with_components_of_notation() {
         assert_eq!(
@@ -723,6 +775,27 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_components_of_external_type_reference() {
+        let ASN1Type::Sequence(seq) = sequence(
+            r#"SEQUENCE {
+            COMPONENTS OF ModuleB.TypeA,
+            COMPONENTS OF BILATERAL.&id,
+            flag BOOLEAN
+          }"#,
+        )
+        .unwrap()
+        .1
+        else {
+            panic!("Expected SEQUENCE")
+        };
+        assert_eq!(
+            seq.components_of,
+            vec![String::from("TypeA"), String::from("BILATERAL.&id")]
+        );
+        assert_eq!(seq.members.len(), 1);
+    }
+
     #[test]
     fn parse_x284() {
         println!(
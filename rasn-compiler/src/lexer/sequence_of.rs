@@ -1,8 +1,9 @@
 use crate::intermediate::*;
 use nom::{
     bytes::complete::tag,
-    combinator::{map, opt},
-    sequence::{pair, preceded},
+    character::complete::char,
+    combinator::{map, not, opt},
+    sequence::{pair, preceded, terminated},
     IResult,
 };
 
@@ -28,7 +29,14 @@ pub fn sequence_of(input: &str) -> IResult<&str, ASN1Type> {
                 opt(opt_parentheses(constraint)),
             ),
             preceded(
-                skip_ws_and_comments(pair(tag(OF), opt(skip_ws_and_comments(value_identifier)))),
+                skip_ws_and_comments(pair(
+                    tag(OF),
+                    // The identifier of a selection type is not an element name
+                    opt(terminated(
+                        skip_ws_and_comments(value_identifier),
+                        not(skip_ws_and_comments(char(LEFT_CHEVRON))),
+                    )),
+                )),
                 asn1_type,
             ),
         ),
@@ -47,6 +55,25 @@ mod tests {
 
     use crate::lexer::sequence_of;
 
+    #[test]
+    fn parses_sequence_of_selection_type() {
+        for sample in [
+            "SEQUENCE OF id < Alternatives",
+            "SEQUENCE OF item id < Alternatives",
+        ] {
+            assert_eq!(
+                sequence_of(sample).unwrap().1,
+                ASN1Type::SequenceOf(SequenceOrSetOf {
+                    constraints: vec![],
+                    element_type: Box::new(ASN1Type::ChoiceSelectionType(ChoiceSelectionType {
+                        choice_name: "Alternatives".into(),
+                        selected_option: "id".into()
+                    }))
+                })
+            );
+        }
+    }
+
     #[test]
     fn parses_simple_sequence_of() {
         assert_eq!(
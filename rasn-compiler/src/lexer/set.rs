@@ -36,6 +36,13 @@ pub fn set(input: &str) -> IResult<&str, ASN1Type> {
                         skip_ws_and_comments(sequence_component),
                         optional_comma,
                     ))),
+                    opt(preceded(
+                        terminated(extension_marker, opt(char(COMMA))),
+                        many0(terminated(
+                            skip_ws_and_comments(sequence_component),
+                            optional_comma,
+                        )),
+                    )),
                 ))),
                 opt(constraint),
             ),
@@ -1,8 +1,9 @@
 use crate::intermediate::*;
 use nom::{
     bytes::complete::tag,
-    combinator::{map, opt},
-    sequence::{pair, preceded},
+    character::complete::char,
+    combinator::{map, not, opt},
+    sequence::{pair, preceded, terminated},
     IResult,
 };
 
@@ -28,7 +29,14 @@ pub fn set_of(input: &str) -> IResult<&str, ASN1Type> {
                 opt(opt_parentheses(constraint)),
             ),
             preceded(
-                skip_ws_and_comments(pair(tag(OF), opt(skip_ws_and_comments(value_identifier)))),
+                skip_ws_and_comments(pair(
+                    tag(OF),
+                    // The identifier of a selection type is not an element name
+                    opt(terminated(
+                        skip_ws_and_comments(value_identifier),
+                        not(skip_ws_and_comments(char(LEFT_CHEVRON))),
+                    )),
+                )),
                 asn1_type,
             ),
         ),
@@ -115,7 +115,8 @@ fn parses_toplevel_enumerated_declaration() {
             Enumeral {
                 name: "supported".into(),
                 index: 0,
-                description: None
+                description: None,
+                numbering: EnumeralNumbering::Automatic,
             }
         );
         assert_eq!(e.extensible, Some(1));
@@ -196,7 +197,9 @@ fn parses_toplevel_crossrefering_declaration() {
                 })]
             }),
             tag: None,
-            index: None
+            index: None,
+            selection: None,
+            is_alias: false
         }
     );
 }
@@ -233,7 +236,9 @@ fn parses_anonymous_sequence_of_declaration() {
                 }))
             }),
             tag: None,
-            index: None
+            index: None,
+            selection: None,
+            is_alias: false
         }
     );
 }
@@ -330,6 +335,26 @@ fn parses_empty_extensible_object_set() {
     )
 }
 
+#[test]
+fn parses_object_set_with_single_reference() {
+    assert_eq!(
+        top_level_information_declaration(r#"Other-ops OPERATION ::= { op-b }"#)
+            .unwrap()
+            .1,
+        ToplevelInformationDefinition {
+            comments: "".into(),
+            index: None,
+            parameterization: None,
+            name: "Other-ops".into(),
+            class: Some(ClassLink::ByName("OPERATION".into())),
+            value: ASN1Information::ObjectSet(ObjectSet {
+                values: vec![ObjectSetValue::Reference("op-b".into())],
+                extensible: None
+            })
+        }
+    )
+}
+
 #[test]
 fn parses_class_declaration() {
     assert_eq!(
@@ -399,6 +424,8 @@ fn parses_parameterized_declaration() {
         ToplevelTypeDefinition {
             comments: "".into(),
             index: None,
+            selection: None,
+            is_alias: false,
             name: "RegionalExtension".into(),
             ty: ASN1Type::Sequence(SequenceOrSet {
                 extensible: None,
@@ -441,7 +468,8 @@ fn parses_parameterized_declaration() {
                                     },
                                     linked_fields: vec![RelationalConstraint {
                                         field_name: "regionId".into(),
-                                        level: 0
+                                        level: 0,
+                                        component_path: vec![]
                                     }]
                                 })]
                             }
@@ -478,6 +506,8 @@ fn parses_choice() {
         ToplevelTypeDefinition {
             comments: "".into(),
             index: None,
+            selection: None,
+            is_alias: false,
             name: "Choice-example".into(),
             ty: ASN1Type::Choice(Choice {
                 extensible: Some(2),
@@ -509,6 +539,58 @@ fn parses_choice() {
     )
 }
 
+#[test]
+fn parses_parameterized_value_reference() {
+    assert_eq!(
+        top_level_value_declaration(r#"limit INTEGER ::= maxOf{ 5, lower }"#)
+            .unwrap()
+            .1
+            .value,
+        ASN1Value::ParameterizedValueReference {
+            identifier: "maxOf".into(),
+            parameters: vec![
+                Parameter::ValueParameter(ASN1Value::Integer(5)),
+                Parameter::ValueParameter(ASN1Value::ElsewhereDeclaredValue {
+                    parent: None,
+                    identifier: "lower".into()
+                })
+            ]
+        }
+    )
+}
+
+#[test]
+fn parses_parameterized_value_set() {
+    assert_eq!(
+        top_level_value_set_declaration(
+            r#"Bounded {INTEGER: upper} INTEGER ::= { 0..upper, ... }"#
+        )
+        .unwrap()
+        .1,
+        top_level_type_declaration(r#"Bounded {INTEGER: upper} ::= INTEGER (0..upper, ...)"#)
+            .unwrap()
+            .1
+    );
+    assert!(top_level_information_declaration(r#"Sizes INTEGER ::= { small | large }"#).is_err());
+}
+
+#[test]
+fn parses_relative_oid_value() {
+    let value = top_level_value_declaration(r#"rel RELATIVE-OID ::= {5 6 7}"#)
+        .unwrap()
+        .1;
+    assert_eq!(value.name, "rel");
+    assert_eq!(
+        value.value,
+        ASN1Value::ObjectIdentifier(ObjectIdentifierValue(vec![
+            ObjectIdentifierArc::from(5),
+            ObjectIdentifierArc::from(6),
+            ObjectIdentifierArc::from(7),
+        ]))
+    );
+    assert!(top_level_information_declaration(r#"rel RELATIVE-OID ::= {5 6 7}"#).is_err());
+}
+
 #[test]
 fn parses_sequence_of_value() {
     println!(
@@ -527,3 +609,79 @@ fn parses_comment_after_end() {
         -- Generated by Asnp, the ASN.1 pretty-printer of France Telecom R&D"#)
     .is_ok())
 }
+
+#[test]
+fn captures_encoding_control_sections() {
+    let modules = asn_spec(
+        r#"Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Valid ::= INTEGER (0..7)
+        ENCODING-CONTROL XER
+            GLOBAL-DEFAULTS MODIFIED-ENCODINGS -- END of defaults
+        ENCODING-CONTROL ECN
+            ENDIANNESS big-END
+        END
+        Other DEFINITIONS AUTOMATIC TAGS ::= BEGIN Other-Valid ::= BOOLEAN END"#,
+    )
+    .unwrap();
+    assert_eq!(modules.len(), 2);
+    assert_eq!(
+        modules[0].0.encoding_control,
+        vec![
+            EncodingControl {
+                encoding_reference: "XER".into(),
+                instructions: "GLOBAL-DEFAULTS MODIFIED-ENCODINGS -- END of defaults".into(),
+            },
+            EncodingControl {
+                encoding_reference: "ECN".into(),
+                instructions: "ENDIANNESS big-END".into(),
+            },
+        ]
+    );
+    assert_eq!(modules[0].1.len(), 1);
+    assert!(modules[1].0.encoding_control.is_empty());
+}
+
+#[test]
+fn skips_macro_definitions() {
+    let modules = asn_spec(
+        r#"RFC1155-SMI DEFINITIONS ::= BEGIN
+        OBJECT-TYPE MACRO ::=
+        BEGIN
+            TYPE NOTATION ::= "SYNTAX" type (TYPE ObjectSyntax)
+            VALUE NOTATION ::= value (VALUE ObjectName)
+        END
+        ObjectName ::= OBJECT IDENTIFIER
+        MODULE-IDENTITY MACRO ::= BEGIN TYPE NOTATION ::= "LAST-UPDATED" value(Update UTCTime) END
+        END"#,
+    )
+    .unwrap();
+    assert_eq!(
+        modules[0].0.macros,
+        vec![String::from("OBJECT-TYPE"), String::from("MODULE-IDENTITY")]
+    );
+    assert_eq!(modules[0].1.len(), 1);
+}
+
+#[test]
+fn locates_unparseable_declaration() {
+    let source = r#"Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Valid ::= INTEGER (0..7)
+        Broken ::= SEQUENCE { a INTEGER,, }
+        END"#;
+    let error = asn_spec(source).unwrap_err();
+    assert_eq!(
+        &source[error.span.unwrap()],
+        "Broken ::= SEQUENCE { a INTEGER,, }"
+    );
+}
+
+#[cfg(feature = "pretty-diagnostics")]
+#[test]
+fn renders_unparseable_declaration() {
+    let source = "Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN\nBroken ::= SEQUENCE { , }\nEND";
+    let rendered = asn_spec(source)
+        .unwrap_err()
+        .render_pretty("test.asn", source);
+    assert!(rendered.contains("test.asn:2:1"));
+    assert!(rendered.contains("no valid ASN.1 declaration starts here"));
+}
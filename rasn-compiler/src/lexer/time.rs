@@ -1,16 +1,17 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::{char, one_of},
     combinator::{map, map_res, opt, recognize},
     error::Error,
     multi::many1,
-    sequence::{delimited, preceded},
+    sequence::{delimited, pair, preceded},
     IResult,
 };
 
 use crate::intermediate::{
     types::{GeneralizedTime, UTCTime},
-    ASN1Type, ASN1Value, GENERALIZED_TIME, TIME, UTC_TIME,
+    ASN1Type, ASN1Value, DATE, DATE_TIME, DURATION, GENERALIZED_TIME, TIME, TIME_OF_DAY, UTC_TIME,
 };
 
 use super::{common::skip_ws_and_comments, constraint::constraint};
@@ -21,9 +22,20 @@ pub fn time_value(input: &str) -> IResult<&str, ASN1Value> {
     })(input)
 }
 
+/// Parses a TIME type or one of the useful time types derived from it,
+/// i.e. DATE, TIME-OF-DAY, DATE-TIME, and DURATION
 pub fn time(input: &str) -> IResult<&str, ASN1Type> {
     map(
-        skip_ws_and_comments(preceded(tag(TIME), opt(constraint))),
+        skip_ws_and_comments(pair(
+            alt((
+                tag(DATE_TIME),
+                tag(DATE),
+                tag(TIME_OF_DAY),
+                tag(TIME),
+                tag(DURATION),
+            )),
+            opt(constraint),
+        )),
         |t| ASN1Type::Time(t.into()),
     )(input)
 }
@@ -80,3 +92,66 @@ fn t_string(input: &str) -> IResult<&str, &str> {
         char('"'),
     )(input)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::intermediate::{constraints::*, types::*, *};
+
+    use super::time;
+
+    #[test]
+    fn parses_useful_time_types() {
+        assert_eq!(
+            time("DATE-TIME").unwrap().1,
+            ASN1Type::Time(Time {
+                constraints: vec![],
+                ty: TimeType::DateTime
+            })
+        );
+        assert_eq!(
+            time("DATE").unwrap().1,
+            ASN1Type::Time(Time {
+                constraints: vec![],
+                ty: TimeType::Date
+            })
+        );
+        assert_eq!(
+            time("TIME-OF-DAY").unwrap().1,
+            ASN1Type::Time(Time {
+                constraints: vec![],
+                ty: TimeType::TimeOfDay
+            })
+        );
+        assert_eq!(
+            time("DURATION").unwrap().1,
+            ASN1Type::Time(Time {
+                constraints: vec![],
+                ty: TimeType::Duration
+            })
+        );
+    }
+
+    #[test]
+    fn parses_time_with_settings() {
+        assert_eq!(
+            time(r#"TIME (SETTINGS "Basic=Date Date=YMD Year=Basic")"#)
+                .unwrap()
+                .1,
+            ASN1Type::Time(Time {
+                constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                    set: ElementOrSetOperation::Element(SubtypeElement::PropertySettings(
+                        PropertySettings {
+                            property_settings_list: vec![
+                                PropertyAndSettingsPair::Basic(BasicSettings::Date),
+                                PropertyAndSettingsPair::Date(DateSettings::YearMonthDay),
+                                PropertyAndSettingsPair::Year(YearSettings::Basic),
+                            ]
+                        }
+                    )),
+                    extensible: false
+                })],
+                ty: TimeType::Time
+            })
+        );
+    }
+}
@@ -84,8 +84,11 @@ pub fn take_until_and_not<'a, Error: ParseError<&'a str>>(
                 (&i[index..]).find_substring(t2),
             ) {
                 (None, _) => Err(Err::Error(Error::from_error_kind(i, ErrorKind::TakeUntil))),
-                (Some(offset), None) => Ok(i.take_split(index + offset)),
-                (Some(_), Some(offset)) => recursive_until(i, index + offset + 2, t1, t2),
+                // The `however_tag` only masks the `end_tag` if it starts at the same position
+                (Some(end), Some(however)) if end == however => {
+                    recursive_until(i, index + however + t2.len(), t1, t2)
+                }
+                (Some(offset), _) => Ok(i.take_split(index + offset)),
             }
         }
         let res: IResult<_, _, Error> = recursive_until(i, 0, end_tag, however_tag);
@@ -268,6 +271,14 @@ mod tests {
             .unwrap()
             .1,
             r#"[a-zA-Z]#(1,8)(-[a-zA-Z0-9]#(1,8))*"#
+        );
+        assert_eq!(
+            take_until_and_not::<nom::error::Error<&str>>("\"", "\"\"")(
+                r#"[a-z]+") Quoted ::= UTF8String (PATTERN "a""b")"#
+            )
+            .unwrap()
+            .1,
+            r#"[a-z]+"#
         )
     }
 }
@@ -9,34 +9,50 @@ mod validator;
 
 use std::{
     cell::RefCell,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet, HashSet},
+    env,
     error::Error,
+    fmt::{self, Display, Formatter},
     fs::{self, read_to_string},
+    io,
     path::PathBuf,
     rc::Rc,
     vec,
 };
 
-use generator::Backend;
-use intermediate::ToplevelDefinition;
+use generator::{error::GeneratorError, Backend, GeneratedModule};
+use intermediate::{
+    error::{GrammarError, GrammarErrorType},
+    GlobalModuleReference, ModuleReference, ToplevelDefinition,
+};
 use lexer::asn_spec;
-use validator::Validator;
+use validator::{
+    deduplicate_types, dependency_graph, exclude_definitions, materialize_automatic_tags, Validator,
+};
 
 pub mod prelude {
     //! Convenience module that collects all necessary imports for
     //! using and customizing the compiler.
+    #[cfg(feature = "syn")]
+    pub use super::SynCompileResult;
     pub use super::{
-        CompileResult, Compiler, CompilerMissingParams, CompilerOutputSet, CompilerReady,
-        CompilerSourcesSet,
+        BindingsFormatter, CompileResult, Compiler, CompilerMissingParams, CompilerOutputSet,
+        CompilerReady, CompilerSourcesSet, FailedSource, ImportResolver, ModuleBindings,
+        ModuleBindingsIter, ParsedModule, ProgressCallback, ProgressEvent, SourceEncoding,
+        SourceError,
     };
     pub use crate::generator::{
         error::*,
-        rasn::{Config as RasnConfig, Rasn as RasnBackend},
+        rasn::{
+            CasingStrategy, Codec, Config as RasnConfig, LegacyStringRepresentation,
+            Rasn as RasnBackend, RasnVersion,
+        },
         typescript::{Config as TsConfig, Typescript as TypescriptBackend},
         Backend, GeneratedModule,
     };
+    pub use crate::lexer::error::{LexerError, LexerErrorType};
 
-    pub use crate::intermediate::ToplevelDefinition;
+    pub use crate::intermediate::{GlobalModuleReference, ModuleReference, ToplevelDefinition};
     pub mod ir {
         pub use crate::intermediate::{
             constraints::*,
@@ -107,6 +123,88 @@ pub fn compile_to_rust(
 pub struct Compiler<B: Backend, S: CompilerState> {
     state: S,
     backend: B,
+    options: CompilerOptions,
+}
+
+/// Backend-independent options of the compiler
+#[derive(Default)]
+struct CompilerOptions {
+    deduplicate_types: bool,
+    continue_on_source_errors: bool,
+    module_outputs: BTreeMap<String, PathBuf>,
+    import_resolver: Option<ImportResolver>,
+    materialize_automatic_tags: bool,
+    progress_callback: Option<ProgressCallback>,
+    excluded_modules: BTreeSet<String>,
+    excluded_types: BTreeSet<String>,
+    formatter: Option<BindingsFormatting>,
+}
+
+/// Formats generated bindings, e.g. by piping them through an external formatter
+pub type BindingsFormatter = Box<dyn Fn(&str) -> Result<String, Box<dyn Error>>>;
+
+/// Formatting of the generated bindings, see [Compiler::format_bindings] and [Compiler::set_formatter]
+enum BindingsFormatting {
+    /// The backend's own formatter, see [Backend::format_bindings]
+    Backend,
+    Custom(BindingsFormatter),
+}
+
+impl BindingsFormatting {
+    /// Formats the bindings. If formatting fails, the error is added to the `warnings`
+    /// and the bindings are returned unformatted.
+    fn apply<B: Backend>(&self, bindings: String, warnings: &mut Vec<Box<dyn Error>>) -> String {
+        let formatted = match self {
+            BindingsFormatting::Backend => B::format_bindings(&bindings),
+            BindingsFormatting::Custom(formatter) => formatter(&bindings),
+        };
+        formatted.unwrap_or_else(|e| {
+            warnings.push(
+                format!("Failed to format the generated bindings, returning them unformatted: {e}")
+                    .into(),
+            );
+            bindings
+        })
+    }
+}
+
+/// Supplies the ASN.1 source of an imported module that is not among the compiler's sources
+pub type ImportResolver = Box<dyn Fn(&GlobalModuleReference) -> Option<String>>;
+
+/// Receives the progress of a compilation
+pub type ProgressCallback = Box<dyn FnMut(ProgressEvent)>;
+
+/// Progress of a compilation, reported in the order of the compilation's phases
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressEvent {
+    /// A readable ASN.1 source was parsed, or skipped because it could not be parsed.
+    /// `parsed` counts the sources that have been processed so far.
+    SourceParsed { parsed: usize, total: usize },
+    /// The parsed modules were linked and validated.
+    Validated,
+    /// The bindings of an ASN.1 module were generated.
+    /// `generated` counts the modules that have been generated so far.
+    ModuleGenerated {
+        module: String,
+        generated: usize,
+        total: usize,
+    },
+}
+
+impl Display for ProgressEvent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ProgressEvent::SourceParsed { parsed, total } => {
+                write!(f, "parsed {parsed}/{total} sources")
+            }
+            ProgressEvent::Validated => write!(f, "validated modules"),
+            ProgressEvent::ModuleGenerated {
+                module,
+                generated,
+                total,
+            } => write!(f, "generated module {module} ({generated}/{total})"),
+        }
+    }
 }
 
 /// Typestate representing compiler with missing parameters
@@ -145,19 +243,277 @@ impl CompilerState for CompilerMissingParams {}
 pub struct CompileResult {
     pub generated: String,
     pub warnings: Vec<Box<dyn Error>>,
+    /// Sources that were skipped because they could not be read or parsed.
+    /// Only populated if the compiler is set to continue on source errors.
+    pub failed_sources: Vec<FailedSource>,
 }
 
 impl CompileResult {
-    fn fmt<B: Backend>(mut self) -> Self {
-        self.generated = B::format_bindings(&self.generated).unwrap_or(self.generated);
+    fn fmt<B: Backend>(mut self, formatter: Option<&BindingsFormatting>) -> Self {
+        if let Some(formatter) = formatter {
+            self.generated = formatter.apply::<B>(self.generated, &mut self.warnings);
+        }
         self
     }
 }
 
+/// Result of [Compiler::compile_to_syn]
+#[cfg(feature = "syn")]
+pub struct SynCompileResult {
+    pub generated: syn::File,
+    pub warnings: Vec<Box<dyn Error>>,
+    /// Sources that were skipped because they could not be read or parsed.
+    /// Only populated if the compiler is set to continue on source errors.
+    pub failed_sources: Vec<FailedSource>,
+}
+
+/// Bindings of the individual ASN.1 modules, keyed by module name
+struct CompiledModules<T = String> {
+    modules: Vec<(String, T)>,
+    warnings: Vec<Box<dyn Error>>,
+    failed_sources: Vec<FailedSource>,
+}
+
+/// Linked top-level declarations of the individual ASN.1 modules, keyed by module name
+struct LinkedModules {
+    modules: BTreeMap<String, Vec<ToplevelDefinition>>,
+    warnings: Vec<Box<dyn Error>>,
+    validator_errors: Vec<Box<dyn Error>>,
+    failed_sources: Vec<FailedSource>,
+}
+
+/// Formatted bindings of the output files of the compiler, keyed by output path
+struct OutputFiles {
+    files: BTreeMap<PathBuf, String>,
+    warnings: Vec<Box<dyn Error>>,
+}
+
+/// Bindings of a single ASN.1 module, as yielded by [ModuleBindingsIter]
+#[derive(Debug)]
+pub struct ModuleBindings {
+    /// Name of the ASN.1 module
+    pub module: String,
+    /// Bindings of the module, formatted if a formatter is configured
+    pub generated: String,
+    /// Warnings raised while generating the bindings of the module
+    pub warnings: Vec<Box<dyn Error>>,
+}
+
+/// Iterator that generates the bindings of linked ASN.1 modules lazily, one module at a time.
+/// Modules are generated in the alphabetical order of their names.
+/// Modules without any bindings are skipped.
+pub struct ModuleBindingsIter<B: Backend> {
+    backend: B,
+    modules: std::collections::btree_map::IntoIter<String, Vec<ToplevelDefinition>>,
+    generated: usize,
+    total: usize,
+    progress_callback: Option<ProgressCallback>,
+    formatter: Option<BindingsFormatting>,
+    warnings: Vec<Box<dyn Error>>,
+    failed_sources: Vec<FailedSource>,
+}
+
+impl<B: Backend> ModuleBindingsIter<B> {
+    /// Warnings raised while parsing, linking, and validating the modules,
+    /// as well as warnings of the skipped modules that have been generated so far
+    pub fn warnings(&self) -> &[Box<dyn Error>] {
+        &self.warnings
+    }
+
+    /// Sources that were skipped because they could not be read or parsed.
+    /// Only populated if the compiler is set to continue on source errors.
+    pub fn failed_sources(&self) -> &[FailedSource] {
+        &self.failed_sources
+    }
+}
+
+impl<B: Backend> Iterator for ModuleBindingsIter<B> {
+    type Item = Result<ModuleBindings, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (module, tlds) = self.modules.next()?;
+            let mut generated_module = match self.backend.generate_module(tlds) {
+                Ok(generated_module) => generated_module,
+                Err(e) => return Some(Err(Box::new(e))),
+            };
+            self.generated += 1;
+            if let Some(callback) = &mut self.progress_callback {
+                callback(ProgressEvent::ModuleGenerated {
+                    module: module.clone(),
+                    generated: self.generated,
+                    total: self.total,
+                });
+            }
+            match generated_module.generated {
+                Some(mut generated) => {
+                    if let Some(formatter) = &self.formatter {
+                        generated = formatter.apply::<B>(generated, &mut generated_module.warnings);
+                    }
+                    return Some(Ok(ModuleBindings {
+                        module,
+                        generated,
+                        warnings: generated_module.warnings,
+                    }));
+                }
+                None => self.warnings.append(&mut generated_module.warnings),
+            }
+        }
+    }
+}
+
+impl From<CompiledModules> for CompileResult {
+    fn from(value: CompiledModules) -> Self {
+        CompileResult {
+            generated: value
+                .modules
+                .into_iter()
+                .map(|(_, generated)| generated)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            warnings: value.warnings,
+            failed_sources: value.failed_sources,
+        }
+    }
+}
+
+/// Header and top-level declarations of an ASN.1 module as returned by the parser
+pub type ParsedModule = (ModuleReference, Vec<ToplevelDefinition>);
+
+/// Error raised if ASN.1 source files cannot be read or byte sources cannot be decoded.
+/// The compiler reads all sources before it starts parsing,
+/// so that every unreadable source is reported at once.
+#[derive(Debug)]
+pub struct SourceError {
+    /// Paths of the unreadable source files or names of the undecodable byte sources
+    /// along with the respective IO errors
+    pub unreadable_sources: Vec<(PathBuf, io::Error)>,
+}
+
+impl Error for SourceError {}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Failed to read ASN.1 source file(s):")?;
+        for (path, error) in &self.unreadable_sources {
+            write!(f, "\n  {}: {error}", path.display())?;
+        }
+        if self
+            .unreadable_sources
+            .iter()
+            .any(|(_, error)| error.kind() == io::ErrorKind::NotFound)
+        {
+            write!(
+                f,
+                "\nHint: Check the paths passed to `add_asn_by_path` and `add_asn_sources_by_path`."
+            )?;
+            if let Ok(working_dir) = env::current_dir() {
+                write!(
+                    f,
+                    " Relative paths are resolved against the working directory {}.",
+                    working_dir.display()
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// ASN.1 source that was skipped by a compiler that continues on source errors
+#[derive(Debug)]
+pub struct FailedSource {
+    /// Position of the source in the order in which the sources were added to the compiler
+    pub index: usize,
+    /// Path of the source file or name of the byte source, `None` for ASN.1 literals
+    pub path: Option<PathBuf>,
+    /// Error that was raised while reading or parsing the source
+    pub error: Box<dyn Error>,
+}
+
+impl Error for FailedSource {}
+
+impl Display for FailedSource {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "Skipped ASN.1 source file {}: ", path.display())?,
+            None => write!(f, "Skipped ASN.1 literal source #{}: ", self.index)?,
+        }
+        write!(f, "{}", self.error)
+    }
+}
+
+/// Character encoding of an ASN.1 source that is added as bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SourceEncoding {
+    #[default]
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    /// ISO/IEC 8859-1, every byte is decoded as the Unicode code point of the same value
+    Latin1,
+}
+
+impl SourceEncoding {
+    /// Decodes the bytes of an ASN.1 source. A leading byte order mark is dropped.
+    fn decode(self, bytes: &[u8]) -> io::Result<String> {
+        let invalid_data = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+        let mut decoded = match self {
+            SourceEncoding::Utf8 => {
+                String::from_utf8(bytes.to_vec()).map_err(|e| invalid_data(e.to_string()))?
+            }
+            SourceEncoding::Utf16Le | SourceEncoding::Utf16Be => {
+                let chunks = bytes.chunks_exact(2);
+                if !chunks.remainder().is_empty() {
+                    return Err(invalid_data(format!(
+                        "{self:?} source has an odd number of bytes"
+                    )));
+                }
+                let units = chunks.map(|unit| match self {
+                    SourceEncoding::Utf16Le => u16::from_le_bytes([unit[0], unit[1]]),
+                    _ => u16::from_be_bytes([unit[0], unit[1]]),
+                });
+                char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .map_err(|e| invalid_data(e.to_string()))?
+            }
+            SourceEncoding::Latin1 => bytes.iter().map(|b| char::from(*b)).collect(),
+        };
+        if decoded.starts_with('\u{FEFF}') {
+            decoded.remove(0);
+        }
+        Ok(decoded)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum AsnSource {
     Path(PathBuf),
     Literal(String),
+    Bytes {
+        name: String,
+        bytes: Vec<u8>,
+        encoding: SourceEncoding,
+    },
+}
+
+impl AsnSource {
+    fn path(&self) -> Option<PathBuf> {
+        match self {
+            AsnSource::Path(p) => Some(p.clone()),
+            AsnSource::Bytes { name, .. } => Some(PathBuf::from(name)),
+            AsnSource::Literal(_) => None,
+        }
+    }
+
+    fn read(&self) -> io::Result<String> {
+        match self {
+            AsnSource::Path(p) => read_to_string(p),
+            AsnSource::Literal(l) => Ok(l.clone()),
+            AsnSource::Bytes {
+                bytes, encoding, ..
+            } => encoding.decode(bytes),
+        }
+    }
 }
 
 impl<B: Backend> Default for Compiler<B, CompilerMissingParams> {
@@ -171,8 +527,150 @@ impl<B: Backend, S: CompilerState> Compiler<B, S> {
         Compiler {
             state: self.state,
             backend,
+            options: self.options,
         }
     }
+
+    /// Sets whether structurally identical type definitions in different modules should be merged.
+    /// If `deduplicate_types` is `true`, the compiler only generates the first of several identical
+    /// definitions and represents the others as type aliases of that definition.
+    /// Two definitions are considered identical if their types, including constraints and tags, match.
+    pub fn deduplicate_types(mut self, deduplicate_types: bool) -> Self {
+        self.options.deduplicate_types = deduplicate_types;
+        self
+    }
+
+    /// Sets whether the compiler should skip ASN.1 sources that cannot be read or parsed.
+    /// By default, the compiler aborts at the first failed source. If `continue_on_source_errors`
+    /// is `true`, the compiler generates bindings for the remaining sources instead and reports
+    /// every skipped source in [`CompileResult::failed_sources`]. `compile` reports skipped sources
+    /// as warnings.
+    pub fn continue_on_source_errors(mut self, continue_on_source_errors: bool) -> Self {
+        self.options.continue_on_source_errors = continue_on_source_errors;
+        self
+    }
+
+    /// Sets whether the tags that `AUTOMATIC TAGS` implies should be spelled out in the bindings.
+    /// If `materialize_automatic_tags` is `true`, every component of a `SEQUENCE`, `SET`, or `CHOICE`
+    /// that is tagged automatically is annotated with its context-specific tag, so that the tags
+    /// no longer depend on the order of the fields or variants in the generated code.
+    pub fn materialize_automatic_tags(mut self, materialize_automatic_tags: bool) -> Self {
+        self.options.materialize_automatic_tags = materialize_automatic_tags;
+        self
+    }
+
+    /// Sets the file that `compile` writes the bindings of a specific ASN.1 module to.
+    /// Modules without a configured output are written to the compiler's output path as usual.
+    /// If several modules are configured with the same output path, their bindings are written
+    /// to the same file. Methods that do not write files ignore the configured outputs.
+    /// * `module_name` - name of the ASN.1 module as given in its module definition header
+    /// * `output_path` - path to the output file of the module
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new()
+    ///     .set_module_output("Foo", "src/foo.rs")
+    ///     .set_module_output("Bar", "src/bar.rs");
+    /// ```
+    pub fn set_module_output(
+        mut self,
+        module_name: impl Into<String>,
+        output_path: impl Into<PathBuf>,
+    ) -> Self {
+        self.options
+            .module_outputs
+            .insert(module_name.into(), output_path.into());
+        self
+    }
+
+    /// Sets a resolver for imported ASN.1 modules that are not among the compiler's sources.
+    /// For every module that is referenced in the IMPORTS of a source but not defined by any
+    /// source, the compiler calls the resolver once with the module's global reference.
+    /// If the resolver returns the source of the module, it is parsed and compiled along with the
+    /// other sources, and its own imports are resolved in turn. If it returns `None`, the import
+    /// remains unresolved.
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().set_import_resolver(|module: &GlobalModuleReference| {
+    ///     (module.module_reference == "Imported-Module").then(|| {
+    ///         "Imported-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN Id ::= INTEGER END".into()
+    ///     })
+    /// });
+    /// ```
+    pub fn set_import_resolver(
+        mut self,
+        resolver: impl Fn(&GlobalModuleReference) -> Option<String> + 'static,
+    ) -> Self {
+        self.options.import_resolver = Some(Box::new(resolver));
+        self
+    }
+
+    /// Excludes ASN.1 modules from generation. The definitions of excluded modules are still used
+    /// to link the remaining definitions, but no bindings are generated for them. Compilation fails
+    /// if a definition that is generated references a definition of an excluded module.
+    /// * `modules` - names of the ASN.1 modules as given in their module definition headers
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().exclude_modules(&["Legacy-Module"]);
+    /// ```
+    pub fn exclude_modules(mut self, modules: &[&str]) -> Self {
+        self.options
+            .excluded_modules
+            .extend(modules.iter().map(|m| m.to_string()));
+        self
+    }
+
+    /// Excludes top-level declarations from generation by their ASN.1 names. The excluded
+    /// declarations are still used to link the remaining definitions, but no bindings are
+    /// generated for them. Compilation fails if a definition that is generated references
+    /// an excluded declaration.
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().exclude_types(&["Internal-Helper"]);
+    /// ```
+    pub fn exclude_types(mut self, types: &[&str]) -> Self {
+        self.options
+            .excluded_types
+            .extend(types.iter().map(|t| t.to_string()));
+        self
+    }
+
+    /// Sets whether `compile_to_string` and `compile_to_iter` format the bindings with the backend's
+    /// formatter. The `rasn` backend runs the `rustfmt` binary of the cargo installation at `CARGO_HOME`.
+    /// By default, these methods return unformatted bindings and neither access the file system nor
+    /// the environment. If formatting fails, the bindings are returned unformatted with a warning.
+    /// `compile` always formats the files that it writes, silently falling back to unformatted
+    /// bindings unless a formatter is configured explicitly.
+    pub fn format_bindings(mut self, format_bindings: bool) -> Self {
+        self.options.formatter = format_bindings.then_some(BindingsFormatting::Backend);
+        self
+    }
+
+    /// Sets a custom formatter for the generated bindings, which replaces the backend's formatter.
+    /// See [Compiler::format_bindings] for the methods that format their bindings.
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new()
+    ///     .set_formatter(|bindings| Ok(bindings.replace("    ", "\t")));
+    /// ```
+    pub fn set_formatter(
+        mut self,
+        formatter: impl Fn(&str) -> Result<String, Box<dyn Error>> + 'static,
+    ) -> Self {
+        self.options.formatter = Some(BindingsFormatting::Custom(Box::new(formatter)));
+        self
+    }
+
+    /// Sets a callback that is notified of the progress of the compilation.
+    /// The callback is called once for every parsed source, once the parsed modules are validated,
+    /// and once for every module whose bindings were generated.
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().set_progress_callback(|event| println!("{event}"));
+    /// ```
+    pub fn set_progress_callback(mut self, callback: impl FnMut(ProgressEvent) + 'static) -> Self {
+        self.options.progress_callback = Some(Box::new(callback));
+        self
+    }
 }
 
 impl<B: Backend> Compiler<B, CompilerMissingParams> {
@@ -181,6 +679,7 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
         Compiler {
             state: CompilerMissingParams,
             backend: B::default(),
+            options: CompilerOptions::default(),
         }
     }
 
@@ -189,6 +688,7 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
         Compiler {
             state: CompilerMissingParams,
             backend: B::from_config(config),
+            options: CompilerOptions::default(),
         }
     }
 }
@@ -205,6 +705,7 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                 sources: vec![AsnSource::Path(path_to_source.into())],
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -221,6 +722,7 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                     .collect(),
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -239,6 +741,38 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                 sources: vec![AsnSource::Literal(literal.into())],
             },
             backend: self.backend,
+            options: self.options,
+        }
+    }
+
+    /// Add an ASN1 source that is given as bytes to the compile command
+    /// * `name` - name of the source that identifies it in error messages
+    /// * `bytes` - ASN1 source to include
+    /// * `encoding` - character encoding of the source
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().add_asn_bytes(
+    ///     "test_module.asn",
+    ///     b"TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN My-test-integer ::= INTEGER END",
+    ///     SourceEncoding::Utf8,
+    /// ).compile_to_string();
+    /// ```
+    pub fn add_asn_bytes(
+        self,
+        name: impl Into<String>,
+        bytes: &[u8],
+        encoding: SourceEncoding,
+    ) -> Compiler<B, CompilerSourcesSet> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: vec![AsnSource::Bytes {
+                    name: name.into(),
+                    bytes: bytes.to_vec(),
+                    encoding,
+                }],
+            },
+            backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -256,6 +790,7 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
         Compiler {
             state: CompilerOutputSet { output_path: path },
             backend: self.backend,
+            options: self.options,
         }
     }
 }
@@ -270,6 +805,7 @@ impl<B: Backend> Compiler<B, CompilerOutputSet> {
                 output_path: self.state.output_path,
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -287,6 +823,7 @@ impl<B: Backend> Compiler<B, CompilerOutputSet> {
                 output_path: self.state.output_path,
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -306,6 +843,31 @@ impl<B: Backend> Compiler<B, CompilerOutputSet> {
                 output_path: self.state.output_path,
             },
             backend: self.backend,
+            options: self.options,
+        }
+    }
+
+    /// Add an ASN1 source that is given as bytes to the compile command
+    /// * `name` - name of the source that identifies it in error messages
+    /// * `bytes` - ASN1 source to include
+    /// * `encoding` - character encoding of the source
+    pub fn add_asn_bytes(
+        self,
+        name: impl Into<String>,
+        bytes: &[u8],
+        encoding: SourceEncoding,
+    ) -> Compiler<B, CompilerReady> {
+        Compiler {
+            state: CompilerReady {
+                sources: vec![AsnSource::Bytes {
+                    name: name.into(),
+                    bytes: bytes.to_vec(),
+                    encoding,
+                }],
+                output_path: self.state.output_path,
+            },
+            backend: self.backend,
+            options: self.options,
         }
     }
 }
@@ -322,6 +884,7 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
         Compiler {
             state: CompilerSourcesSet { sources },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -336,6 +899,7 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
         Compiler {
             state: CompilerSourcesSet { sources },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -354,6 +918,30 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
         Compiler {
             state: CompilerSourcesSet { sources },
             backend: self.backend,
+            options: self.options,
+        }
+    }
+
+    /// Add an ASN1 source that is given as bytes to the compile command
+    /// * `name` - name of the source that identifies it in error messages
+    /// * `bytes` - ASN1 source to include
+    /// * `encoding` - character encoding of the source
+    pub fn add_asn_bytes(
+        self,
+        name: impl Into<String>,
+        bytes: &[u8],
+        encoding: SourceEncoding,
+    ) -> Compiler<B, CompilerSourcesSet> {
+        let mut sources: Vec<AsnSource> = self.state.sources;
+        sources.push(AsnSource::Bytes {
+            name: name.into(),
+            bytes: bytes.to_vec(),
+            encoding,
+        });
+        Compiler {
+            state: CompilerSourcesSet { sources },
+            backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -368,41 +956,236 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
                 output_path: output_path.into(),
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
     /// Runs the rasn compiler command and returns stringified Rust.
+    /// The bindings are only formatted if a formatter is configured, see [Compiler::format_bindings].
     /// Returns a Result wrapping a compilation result:
     /// * _Ok_  - tuple containing the stringified bindings for the ASN1 spec as well as a vector of warnings raised during the compilation
     /// * _Err_ - Unrecoverable error, no rust representations were generated
-    pub fn compile_to_string(self) -> Result<CompileResult, Box<dyn Error>> {
-        self.internal_compile().map(CompileResult::fmt::<B>)
+    pub fn compile_to_string(mut self) -> Result<CompileResult, Box<dyn Error>> {
+        self.internal_compile()
+            .map(CompileResult::from)
+            .map(|result| result.fmt::<B>(self.options.formatter.as_ref()))
     }
 
-    fn internal_compile(&self) -> Result<CompileResult, Box<dyn Error>> {
-        let mut generated_modules = vec![];
+    /// Links the ASN1 sources and returns an iterator that generates the bindings of one module at a time.
+    /// In contrast to `compile_to_string`, the bindings of all modules are never held in memory at once,
+    /// so callers can write the bindings of each module to disk and drop them before the next module is generated.
+    /// The bindings are only formatted if a formatter is configured, see [Compiler::format_bindings].
+    /// Returns a Result wrapping the iterator:
+    /// * _Ok_  - Iterator over the bindings of the individual modules
+    /// * _Err_ - Unrecoverable error, the ASN1 sources could not be linked
+    /// ```no_run
+    /// # use rasn_compiler::prelude::*;
+    /// let modules = Compiler::<RasnBackend, _>::new()
+    ///     .add_asn_by_path("registry.asn")
+    ///     .compile_to_iter()
+    ///     .unwrap();
+    /// for module in modules {
+    ///     let module = module.unwrap();
+    ///     std::fs::write(format!("{}.rs", module.module), module.generated).unwrap();
+    /// }
+    /// ```
+    pub fn compile_to_iter(mut self) -> Result<ModuleBindingsIter<B>, Box<dyn Error>> {
+        let LinkedModules {
+            modules,
+            mut warnings,
+            mut validator_errors,
+            failed_sources,
+        } = self.link()?;
+        warnings.append(&mut validator_errors);
+        Ok(ModuleBindingsIter {
+            backend: self.backend,
+            generated: 0,
+            total: modules.len(),
+            modules: modules.into_iter(),
+            progress_callback: self.options.progress_callback,
+            formatter: self.options.formatter,
+            warnings,
+            failed_sources,
+        })
+    }
+
+    /// Parses the ASN1 sources without validating, linking, or generating bindings for them.
+    /// Returns a Result wrapping the parsed modules:
+    /// * _Ok_  - Vector of the headers and top-level declarations of the ASN1 modules, exactly as the parser saw them
+    /// * _Err_ - A source could not be read or parsed
+    pub fn parse_only(self) -> Result<Vec<ParsedModule>, Box<dyn Error>> {
+        self.parse_sources()
+    }
+
+    /// Parses the ASN1 sources and returns the dependency graph between their top-level declarations.
+    /// The graph maps the name of every declaration to the names of the declarations that it references,
+    /// e.g. through components, `SEQUENCE OF` elements, `CHOICE` alternatives, constraints, or information
+    /// object classes. Modules supplied by the import resolver are included in the graph.
+    /// Returns a Result wrapping the dependency graph:
+    /// * _Ok_  - Map of declaration names to the names of the declarations that they reference
+    /// * _Err_ - A source could not be read or parsed
+    pub fn dependency_graph(self) -> Result<BTreeMap<String, Vec<String>>, Box<dyn Error>> {
+        let mut specs = self.parse_sources()?;
+        self.resolve_imports(&mut specs)?;
+        let tlds = specs
+            .into_iter()
+            .flat_map(|(_, tlds)| tlds)
+            .collect::<Vec<_>>();
+        Ok(dependency_graph(&tlds))
+    }
+
+    fn parse_sources(&self) -> Result<Vec<ParsedModule>, Box<dyn Error>> {
+        let mut stringified_sources = vec![];
+        let mut unreadable_sources = vec![];
+        for src in &self.state.sources {
+            match src.read() {
+                Ok(s) => stringified_sources.push(s),
+                Err(e) => unreadable_sources.push((src.path().unwrap_or_default(), e)),
+            }
+        }
+        if !unreadable_sources.is_empty() {
+            return Err(Box::new(SourceError { unreadable_sources }));
+        }
+        let mut modules = vec![];
+        for stringified_src in stringified_sources {
+            modules.append(&mut asn_spec(&stringified_src)?);
+        }
+        Ok(modules)
+    }
+
+    /// Appends the modules that the import resolver supplies for imports that none of the `specs` define.
+    fn resolve_imports(&self, specs: &mut Vec<ParsedModule>) -> Result<(), Box<dyn Error>> {
+        let Some(resolver) = &self.options.import_resolver else {
+            return Ok(());
+        };
+        let mut requested = specs
+            .iter()
+            .map(|(header, _)| header.name.clone())
+            .collect::<HashSet<_>>();
+        let mut index = 0;
+        while index < specs.len() {
+            let missing = specs[index]
+                .0
+                .imports
+                .iter()
+                .map(|import| &import.global_module_reference)
+                .filter(|reference| requested.insert(reference.module_reference.clone()))
+                .cloned()
+                .collect::<Vec<_>>();
+            for reference in missing {
+                if let Some(source) = resolver(&reference) {
+                    specs.append(&mut asn_spec(&source)?);
+                }
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+
+    fn report_progress(&mut self, event: ProgressEvent) {
+        if let Some(callback) = &mut self.options.progress_callback {
+            callback(event);
+        }
+    }
+
+    /// Links the ASN1 sources and groups the linked top-level declarations by module.
+    /// Linking requires all modules, so this phase is never lazy.
+    fn link(&mut self) -> Result<LinkedModules, Box<dyn Error>> {
         let mut warnings = Vec::<Box<dyn Error>>::new();
         let mut modules: Vec<ToplevelDefinition> = vec![];
-        for src in &self.state.sources {
-            let stringified_src = match src {
-                AsnSource::Path(p) => read_to_string(p)?,
-                AsnSource::Literal(l) => l.clone(),
-            };
-            modules.append(
-                &mut asn_spec(&stringified_src)?
-                    .into_iter()
-                    .flat_map(|(header, tlds)| {
-                        let header_ref = Rc::new(RefCell::new(header));
-                        tlds.into_iter().enumerate().map(move |(index, mut tld)| {
-                            tld.apply_tagging_environment(&header_ref.borrow().tagging_environment);
-                            tld.set_index(header_ref.clone(), index);
-                            tld
-                        })
+        let mut failed_sources = vec![];
+        let mut unreadable_sources = vec![];
+        let stringified_sources = self
+            .state
+            .sources
+            .iter()
+            .enumerate()
+            .filter_map(|(index, src)| {
+                src.read()
+                    .map_err(|e| {
+                        unreadable_sources.push((index, src.path().unwrap_or_default(), e))
                     })
+                    .ok()
+                    .map(|s| (index, s))
+            })
+            .collect::<Vec<_>>();
+        if self.options.continue_on_source_errors {
+            failed_sources.extend(unreadable_sources.into_iter().map(|(index, path, e)| {
+                FailedSource {
+                    index,
+                    path: Some(path),
+                    error: Box::new(e),
+                }
+            }));
+        } else if !unreadable_sources.is_empty() {
+            return Err(Box::new(SourceError {
+                unreadable_sources: unreadable_sources
+                    .into_iter()
+                    .map(|(_, path, e)| (path, e))
                     .collect(),
-            );
+            }));
+        }
+        let mut specs = vec![];
+        let total = stringified_sources.len();
+        for (parsed, (index, stringified_src)) in stringified_sources.into_iter().enumerate() {
+            match asn_spec(&stringified_src) {
+                Ok(mut spec) => specs.append(&mut spec),
+                Err(e) if self.options.continue_on_source_errors => {
+                    failed_sources.push(FailedSource {
+                        index,
+                        path: self.state.sources[index].path(),
+                        error: Box::new(e),
+                    });
+                }
+                Err(e) => return Err(Box::new(e)),
+            }
+            self.report_progress(ProgressEvent::SourceParsed {
+                parsed: parsed + 1,
+                total,
+            });
+        }
+        self.resolve_imports(&mut specs)?;
+        for (header, tlds) in specs {
+            warnings.extend(header.encoding_control.iter().map(|section| {
+                Box::new(GrammarError::new(
+                    &format!(
+                        "Ignoring the {} encoding control section of module {}. ECN directives are not supported.",
+                        section.encoding_reference, header.name
+                    ),
+                    GrammarErrorType::NotYetInplemented,
+                )) as Box<dyn Error>
+            }));
+            warnings.extend(header.macros.iter().map(|name| {
+                Box::new(GrammarError::new(
+                    &format!(
+                        "Skipping the MACRO definition {name} of module {}. The macro notation is not supported.",
+                        header.name
+                    ),
+                    GrammarErrorType::NotYetInplemented,
+                )) as Box<dyn Error>
+            }));
+            let header_ref = Rc::new(RefCell::new(header));
+            modules.extend(tlds.into_iter().enumerate().map(|(index, mut tld)| {
+                tld.apply_tagging_environment(&header_ref.borrow().tagging_environment);
+                tld.apply_extensibility_environment(&header_ref.borrow().extensibility_environment);
+                tld.set_index(header_ref.clone(), index);
+                tld
+            }));
         }
-        let (valid_items, mut validator_errors) = Validator::new(modules).validate()?;
+        let (mut valid_items, validator_errors) = Validator::new(modules).validate()?;
+        exclude_definitions(
+            &mut valid_items,
+            &self.options.excluded_modules,
+            &self.options.excluded_types,
+        )?;
+        self.report_progress(ProgressEvent::Validated);
+        if self.options.deduplicate_types {
+            deduplicate_types(&mut valid_items);
+        }
+        if self.options.materialize_automatic_tags {
+            materialize_automatic_tags(&mut valid_items);
+        }
+        self.backend.prepare(&valid_items);
         let modules = valid_items.into_iter().fold(
             BTreeMap::<String, Vec<ToplevelDefinition>>::new(),
             |mut modules, tld| {
@@ -420,18 +1203,103 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
                 modules
             },
         );
-        for (_, module) in modules {
-            let mut generated_module = self.backend.generate_module(module)?;
+        Ok(LinkedModules {
+            modules,
+            warnings,
+            validator_errors,
+            failed_sources,
+        })
+    }
+
+    fn internal_compile(&mut self) -> Result<CompiledModules, Box<dyn Error>> {
+        self.generate_modules(|backend, module| backend.generate_module(module))
+    }
+
+    /// Links the ASN1 sources and generates the bindings of each module with the given function
+    fn generate_modules<T>(
+        &mut self,
+        generate: impl Fn(&B, Vec<ToplevelDefinition>) -> Result<GeneratedModule<T>, GeneratorError>,
+    ) -> Result<CompiledModules<T>, Box<dyn Error>> {
+        let LinkedModules {
+            modules,
+            mut warnings,
+            mut validator_errors,
+            failed_sources,
+        } = self.link()?;
+        let mut generated_modules = vec![];
+        let total = modules.len();
+        for (generated, (name, module)) in modules.into_iter().enumerate() {
+            let mut generated_module = generate(&self.backend, module)?;
+            self.report_progress(ProgressEvent::ModuleGenerated {
+                module: name.clone(),
+                generated: generated + 1,
+                total,
+            });
             if let Some(m) = generated_module.generated {
-                generated_modules.push(m);
+                generated_modules.push((name, m));
             }
             warnings.append(&mut generated_module.warnings);
         }
         warnings.append(&mut validator_errors);
 
-        Ok(CompileResult {
-            generated: generated_modules.join("\n"),
+        Ok(CompiledModules {
+            modules: generated_modules,
             warnings,
+            failed_sources,
+        })
+    }
+}
+
+impl<S: CompilerState> Compiler<generator::rasn::Rasn, S> {
+    /// Maps an ASN.1 type to an existing rust type. The compiler does not generate bindings
+    /// for the mapped type, but references the given path wherever the type is referenced.
+    /// The ASN.1 definition of the mapped type is still used to link the remaining definitions,
+    /// and values of the mapped type are skipped with a warning.
+    /// * `asn1_name` - name of the ASN.1 type as given in its type assignment
+    /// * `rust_path` - path of the existing rust type
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().map_type("Timestamp", "crate::time::Timestamp");
+    /// ```
+    pub fn map_type(mut self, asn1_name: impl Into<String>, rust_path: impl Into<String>) -> Self {
+        self.backend.map_type(asn1_name.into(), rust_path.into());
+        self
+    }
+
+    /// Allows the given lints on every generated module in addition to the default lints
+    /// of [RasnConfig::allowed_lints](crate::prelude::RasnConfig::allowed_lints).
+    /// The compiler places the corresponding `#[allow(...)]` attribute on the `mod` item
+    /// that wraps the bindings of each ASN.1 module.
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new().allow_lints(&["dead_code", "clippy::all"]);
+    /// ```
+    pub fn allow_lints(mut self, lints: &[&str]) -> Self {
+        self.backend.allow_lints(lints);
+        self
+    }
+}
+
+#[cfg(feature = "syn")]
+impl Compiler<generator::rasn::Rasn, CompilerSourcesSet> {
+    /// Runs the rasn compiler command and returns the bindings as a parsed `syn::File`.
+    /// The syntax tree is built from the generated token streams, the bindings are never stringified or formatted.
+    /// Returns a Result wrapping a compilation result:
+    /// * _Ok_  - `syn::File` containing the bindings for the ASN1 spec as well as a vector of warnings raised during the compilation
+    /// * _Err_ - Unrecoverable error, no rust representations were generated
+    pub fn compile_to_syn(mut self) -> Result<SynCompileResult, Box<dyn Error>> {
+        let result =
+            self.generate_modules(|backend, module| backend.generate_module_tokens(module))?;
+        Ok(SynCompileResult {
+            generated: syn::parse2(
+                result
+                    .modules
+                    .into_iter()
+                    .map(|(_, generated)| generated)
+                    .collect(),
+            )?,
+            warnings: result.warnings,
+            failed_sources: result.failed_sources,
         })
     }
 }
@@ -448,6 +1316,7 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 sources,
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -465,6 +1334,7 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 output_path: self.state.output_path,
             },
             backend: self.backend,
+            options: self.options,
         }
     }
 
@@ -486,7 +1356,66 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 sources,
             },
             backend: self.backend,
+            options: self.options,
+        }
+    }
+
+    /// Add an ASN1 source that is given as bytes to the compile command
+    /// * `name` - name of the source that identifies it in error messages
+    /// * `bytes` - ASN1 source to include
+    /// * `encoding` - character encoding of the source
+    pub fn add_asn_bytes(
+        self,
+        name: impl Into<String>,
+        bytes: &[u8],
+        encoding: SourceEncoding,
+    ) -> Compiler<B, CompilerReady> {
+        let mut sources: Vec<AsnSource> = self.state.sources;
+        sources.push(AsnSource::Bytes {
+            name: name.into(),
+            bytes: bytes.to_vec(),
+            encoding,
+        });
+        Compiler {
+            state: CompilerReady {
+                output_path: self.state.output_path,
+                sources,
+            },
+            backend: self.backend,
+            options: self.options,
+        }
+    }
+
+    /// Parses the ASN1 sources without validating, linking, or generating bindings for them.
+    /// The output path of the compiler is ignored, no file is written.
+    /// Returns a Result wrapping the parsed modules:
+    /// * _Ok_  - Vector of the headers and top-level declarations of the ASN1 modules, exactly as the parser saw them
+    /// * _Err_ - A source could not be read or parsed
+    pub fn parse_only(self) -> Result<Vec<ParsedModule>, Box<dyn Error>> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: self.state.sources,
+            },
+            backend: self.backend,
+            options: self.options,
+        }
+        .parse_only()
+    }
+
+    /// Parses the ASN1 sources and returns the dependency graph between their top-level declarations.
+    /// The output path of the compiler is ignored, no file is written.
+    /// Returns a Result wrapping the dependency graph:
+    /// * _Ok_  - Map of declaration names to the names of the declarations that they reference
+    /// * _Err_ - A source could not be read or parsed
+    pub fn dependency_graph(self) -> Result<BTreeMap<String, Vec<String>>, Box<dyn Error>> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: self.state.sources,
+            },
+            backend: self.backend,
+            options: self.options,
         }
+        .dependency_graph()
     }
 
     /// Runs the rasn compiler command and returns stringified Rust.
@@ -499,36 +1428,631 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 sources: self.state.sources,
             },
             backend: self.backend,
+            options: self.options,
         }
         .compile_to_string()
     }
 
+    /// Links the ASN1 sources and returns an iterator that generates the bindings of one module at a time.
+    /// The output path of the compiler is ignored, no file is written.
+    /// See [Compiler::compile_to_iter] of a compiler without output path for details.
+    pub fn compile_to_iter(self) -> Result<ModuleBindingsIter<B>, Box<dyn Error>> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: self.state.sources,
+            },
+            backend: self.backend,
+            options: self.options,
+        }
+        .compile_to_iter()
+    }
+
     /// Runs the rasn compiler command.
+    /// Modules with an output configured by `set_module_output` are written to the respective file,
+    /// all other modules are written to the output path of the compiler.
     /// Returns a Result wrapping a compilation result:
-    /// * _Ok_  - Vector of warnings raised during the compilation
+    /// * _Ok_  - Vector of warnings raised during the compilation, including skipped sources
     /// * _Err_ - Unrecoverable error, no rust representations were generated
     pub fn compile(self) -> Result<Vec<Box<dyn Error>>, Box<dyn Error>> {
-        let result = Compiler {
+        let OutputFiles { files, warnings } = self.generate_outputs()?;
+        for (path, bindings) in files {
+            fs::write(path, bindings)?;
+        }
+        Ok(warnings)
+    }
+
+    /// Runs the rasn compiler command without writing any file, similar to `cargo fmt --check`.
+    /// The bindings are generated in memory and compared with the contents of the files that
+    /// `compile` would write, e.g. to verify in CI that committed bindings are up to date.
+    /// Returns a Result wrapping the outcome of the comparison:
+    /// * _Ok_  - `true` if all output files exist and match the generated bindings, `false` otherwise
+    /// * _Err_ - Unrecoverable error, no rust representations were generated, or an existing output file could not be read
+    pub fn check(self) -> Result<bool, Box<dyn Error>> {
+        for (path, bindings) in self.generate_outputs()?.files {
+            match fs::read_to_string(&path) {
+                Ok(existing) if existing == bindings => continue,
+                Ok(_) => return Ok(false),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+                Err(e) => return Err(Box::new(e)),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Generates the formatted bindings of every output file of the compiler.
+    /// The warnings raised during the compilation include skipped sources.
+    fn generate_outputs(self) -> Result<OutputFiles, Box<dyn Error>> {
+        let default_output = self
+            .state
+            .output_path
+            .is_dir()
+            .then(|| {
+                self.state
+                    .output_path
+                    .join(format!("generated{}", B::FILE_EXTENSION))
+            })
+            .unwrap_or(self.state.output_path);
+        let mut compiler = Compiler {
             state: CompilerSourcesSet {
                 sources: self.state.sources,
             },
             backend: self.backend,
+            options: self.options,
+        };
+        let result = compiler.internal_compile()?;
+        let mut outputs = BTreeMap::<PathBuf, Vec<String>>::new();
+        let mut default_bindings = vec![];
+        for (name, generated) in result.modules {
+            match compiler.options.module_outputs.get(&name) {
+                Some(path) => outputs.entry(path.clone()).or_default().push(generated),
+                None => default_bindings.push(generated),
+            }
         }
-        .internal_compile()?
-        .fmt::<B>();
-        fs::write(
-            self.state
-                .output_path
-                .is_dir()
-                .then(|| {
-                    self.state
-                        .output_path
-                        .join(format!("generated{}", B::FILE_EXTENSION))
-                })
-                .unwrap_or(self.state.output_path),
-            result.generated,
-        )?;
+        if !default_bindings.is_empty() || outputs.is_empty() {
+            outputs
+                .entry(default_output)
+                .or_default()
+                .append(&mut default_bindings);
+        }
+        let mut warnings = result.warnings;
+        let files = outputs
+            .into_iter()
+            .map(|(path, bindings)| {
+                let bindings = bindings.join("\n");
+                let formatted = match &compiler.options.formatter {
+                    Some(formatter) => formatter.apply::<B>(bindings, &mut warnings),
+                    None => B::format_bindings(&bindings).unwrap_or(bindings),
+                };
+                (path, formatted)
+            })
+            .collect();
+
+        Ok(OutputFiles {
+            files,
+            warnings: warnings
+                .into_iter()
+                .chain(
+                    result
+                        .failed_sources
+                        .into_iter()
+                        .map(|failed| Box::new(failed) as Box<dyn Error>),
+                )
+                .collect(),
+        })
+    }
+}
+
+#[cfg(feature = "syn")]
+impl Compiler<generator::rasn::Rasn, CompilerReady> {
+    /// Runs the rasn compiler command and returns the bindings as a parsed `syn::File`.
+    /// The output path of the compiler is ignored, no file is written.
+    /// Returns a Result wrapping a compilation result:
+    /// * _Ok_  - `syn::File` containing the bindings for the ASN1 spec as well as a vector of warnings raised during the compilation
+    /// * _Err_ - Unrecoverable error, no rust representations were generated
+    pub fn compile_to_syn(self) -> Result<SynCompileResult, Box<dyn Error>> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: self.state.sources,
+            },
+            backend: self.backend,
+            options: self.options,
+        }
+        .compile_to_syn()
+    }
+}
+
+#[cfg(test)]
+mod source_tests {
+    use std::path::PathBuf;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn reports_all_unreadable_sources() {
+        let error = Compiler::<RasnBackend, _>::new()
+            .add_asn_by_path("missing_1.asn")
+            .add_asn_literal(
+                "TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Int ::= INTEGER (1..128) END",
+            )
+            .add_asn_by_path("missing_2.asn")
+            .compile_to_string()
+            .unwrap_err()
+            .downcast::<SourceError>()
+            .unwrap();
+        assert_eq!(
+            error
+                .unreadable_sources
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PathBuf::from("missing_1.asn"),
+                PathBuf::from("missing_2.asn")
+            ]
+        );
+        let message = error.to_string();
+        assert!(message.contains("missing_1.asn") && message.contains("missing_2.asn"));
+        assert!(message.contains("Hint"));
+    }
+
+    #[test]
+    fn continues_on_source_errors() {
+        let result = Compiler::<RasnBackend, _>::new()
+            .continue_on_source_errors(true)
+            .add_asn_by_path("missing_1.asn")
+            .add_asn_literal(
+                "TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Int ::= INTEGER (1..128) END",
+            )
+            .add_asn_literal("BrokenModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Broken ::= END")
+            .compile_to_string()
+            .unwrap();
+        assert!(result.generated.contains("TestInt"));
+        assert_eq!(
+            result
+                .failed_sources
+                .iter()
+                .map(|failed| (failed.index, failed.path.clone()))
+                .collect::<Vec<_>>(),
+            vec![(0, Some(PathBuf::from("missing_1.asn"))), (2, None)]
+        );
+        assert!(result.failed_sources[1]
+            .to_string()
+            .starts_with("Skipped ASN.1 literal source #2"));
+    }
 
-        Ok(result.warnings)
+    #[test]
+    fn routes_modules_to_configured_outputs() {
+        let output_dir = std::env::temp_dir().join("rasn_compiler_module_outputs");
+        std::fs::create_dir_all(&output_dir).unwrap();
+        let warnings = Compiler::<RasnBackend, _>::new()
+            .set_module_output("Foo", output_dir.join("foo.rs"))
+            .set_module_output("Bar", output_dir.join("bar.rs"))
+            .add_asn_literal("Foo DEFINITIONS AUTOMATIC TAGS ::= BEGIN Foo-Int ::= INTEGER END")
+            .add_asn_literal("Bar DEFINITIONS AUTOMATIC TAGS ::= BEGIN Bar-Int ::= INTEGER END")
+            .add_asn_literal("Baz DEFINITIONS AUTOMATIC TAGS ::= BEGIN Baz-Int ::= INTEGER END")
+            .set_output_path(output_dir.join("default.rs"))
+            .compile()
+            .unwrap();
+        assert!(warnings.is_empty());
+        let read = |file: &str| std::fs::read_to_string(output_dir.join(file)).unwrap();
+        let (foo, bar, default) = (read("foo.rs"), read("bar.rs"), read("default.rs"));
+        std::fs::remove_dir_all(&output_dir).unwrap();
+        assert!(foo.contains("FooInt") && !foo.contains("BarInt") && !foo.contains("BazInt"));
+        assert!(bar.contains("BarInt") && !bar.contains("FooInt") && !bar.contains("BazInt"));
+        assert!(default.contains("BazInt") && !default.contains("FooInt"));
+    }
+
+    #[test]
+    fn checks_generated_bindings_without_writing() {
+        let output = std::env::temp_dir().join("rasn_compiler_check.rs");
+        let compiler = || {
+            Compiler::<RasnBackend, _>::new()
+                .add_asn_literal("Foo DEFINITIONS AUTOMATIC TAGS ::= BEGIN Foo-Int ::= INTEGER END")
+                .set_output_path(&output)
+        };
+        let _ = std::fs::remove_file(&output);
+        assert!(!compiler().check().unwrap());
+        assert!(!output.exists());
+        compiler().compile().unwrap();
+        assert!(compiler().check().unwrap());
+        std::fs::write(&output, "// outdated").unwrap();
+        let up_to_date = compiler().check().unwrap();
+        let unchanged = std::fs::read_to_string(&output).unwrap();
+        std::fs::remove_file(&output).unwrap();
+        assert!(!up_to_date);
+        assert_eq!(unchanged, "// outdated");
+    }
+
+    #[test]
+    fn resolves_imports_with_custom_loader() {
+        let requested = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let requested_by_resolver = requested.clone();
+        let result = Compiler::<RasnBackend, _>::new()
+            .set_import_resolver(move |module: &GlobalModuleReference| {
+                requested_by_resolver
+                    .borrow_mut()
+                    .push(module.module_reference.clone());
+                match module.module_reference.as_str() {
+                    "Middle" => Some(
+                        r#"Middle DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                            IMPORTS Base-Int FROM Base Missing-Int FROM Missing;
+                            Middle-Seq ::= SEQUENCE { base Base-Int }
+                        END"#
+                            .into(),
+                    ),
+                    "Base" => Some(
+                        "Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN Base-Int ::= INTEGER (0..7) END"
+                            .into(),
+                    ),
+                    _ => None,
+                }
+            })
+            .add_asn_literal(
+                r#"Top DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Middle-Seq FROM Middle;
+                    Top-Seq ::= SEQUENCE { middle Middle-Seq }
+                END"#,
+            )
+            .compile_to_string()
+            .unwrap();
+        assert!(["TopSeq", "MiddleSeq", "BaseInt"]
+            .iter()
+            .all(|name| result.generated.contains(name)));
+        assert_eq!(*requested.borrow(), vec!["Middle", "Base", "Missing"]);
+    }
+
+    #[test]
+    fn reports_progress() {
+        let events = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let reported_events = events.clone();
+        Compiler::<RasnBackend, _>::new()
+            .set_progress_callback(move |event| reported_events.borrow_mut().push(event))
+            .add_asn_literal(
+                "Module-B DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Int ::= INTEGER END",
+            )
+            .add_asn_literal(
+                "Module-A DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Bool ::= BOOLEAN END",
+            )
+            .compile_to_string()
+            .unwrap();
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                ProgressEvent::SourceParsed {
+                    parsed: 1,
+                    total: 2
+                },
+                ProgressEvent::SourceParsed {
+                    parsed: 2,
+                    total: 2
+                },
+                ProgressEvent::Validated,
+                ProgressEvent::ModuleGenerated {
+                    module: "Module-A".into(),
+                    generated: 1,
+                    total: 2
+                },
+                ProgressEvent::ModuleGenerated {
+                    module: "Module-B".into(),
+                    generated: 2,
+                    total: 2
+                },
+            ]
+        );
+        assert_eq!(events.borrow()[0].to_string(), "parsed 1/2 sources");
+    }
+
+    #[test]
+    fn materializes_automatic_tags() {
+        let generated = Compiler::<RasnBackend, _>::new()
+            .materialize_automatic_tags(true)
+            .add_asn_literal(
+                r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Test-Choice ::= CHOICE { flag BOOLEAN, number INTEGER }
+                    Test-Seq ::= SEQUENCE { number INTEGER, choice Test-Choice, ..., [[ added BOOLEAN ]] }
+                    Tagged-Seq ::= SEQUENCE { flag [5] BOOLEAN, other BOOLEAN }
+                END"#,
+            )
+            .compile_to_string()
+            .unwrap()
+            .generated
+            .replace(|c: char| c.is_whitespace(), "");
+        assert!(generated
+            .contains("#[rasn(tag(context,0))]flag(bool),#[rasn(tag(context,1))]number(Integer),"));
+        assert!(generated.contains(
+            "#[rasn(tag(context,0))]pubnumber:Integer,#[rasn(tag(explicit(context,1)))]pubchoice:TestChoice,"
+        ));
+        assert!(generated.contains("#[rasn(tag(context,2))]pubadded:bool,"));
+        assert!(generated.contains("#[rasn(tag(context,5))]pubflag:bool,pubother:bool,"));
+    }
+
+    #[test]
+    fn compiles_byte_sources() {
+        let utf16 = "TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Int ::= INTEGER END"
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect::<Vec<_>>();
+        let result = Compiler::<RasnBackend, _>::new()
+            .continue_on_source_errors(true)
+            .add_asn_bytes("utf16.asn", &utf16, SourceEncoding::Utf16Be)
+            .add_asn_bytes(
+                "latin1.asn",
+                b"Other DEFINITIONS AUTOMATIC TAGS ::= BEGIN -- Gr\xFC\xDFe\n Other-Int ::= INTEGER END",
+                SourceEncoding::Latin1,
+            )
+            .add_asn_bytes("invalid.asn", b"\xFF\xFE", SourceEncoding::Utf8)
+            .compile_to_string()
+            .unwrap();
+        assert!(result.generated.contains("TestInt") && result.generated.contains("OtherInt"));
+        assert_eq!(result.failed_sources.len(), 1);
+        assert_eq!(
+            result.failed_sources[0].path,
+            Some(PathBuf::from("invalid.asn"))
+        );
+    }
+
+    #[test]
+    fn warns_about_ignored_encoding_control_sections() {
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Test-Int ::= INTEGER (1..128)
+                    ENCODING-CONTROL PER
+                        -- PER encoding instructions
+                END"#,
+            )
+            .compile_to_string()
+            .unwrap();
+        assert!(result.generated.contains("TestInt"));
+        println!("{:?}", result.warnings);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0]
+            .to_string()
+            .contains("Ignoring the PER encoding control section of module TestModule"));
+    }
+
+    #[test]
+    fn warns_about_skipped_macro_definitions() {
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    OBJECT-TYPE MACRO ::=
+                    BEGIN
+                        TYPE NOTATION ::= "SYNTAX" type(TYPE ObjectSyntax)
+                                          "ACCESS" Access
+                        VALUE NOTATION ::= value(VALUE ObjectName)
+                        Access ::= "read-only" | "read-write" -- END of access
+                    END
+                    Test-Int ::= INTEGER (1..128)
+                END"#,
+            )
+            .compile_to_string()
+            .unwrap();
+        assert!(result.generated.contains("TestInt"));
+        println!("{:?}", result.warnings);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0]
+            .to_string()
+            .contains("Skipping the MACRO definition OBJECT-TYPE of module TestModule"));
+    }
+
+    #[test]
+    fn formats_bindings_only_if_configured() {
+        let compiler = || {
+            Compiler::<RasnBackend, _>::new().add_asn_literal(
+                "TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Int ::= INTEGER (1..128) END",
+            )
+        };
+        let unformatted = compiler().compile_to_string().unwrap();
+        assert!(!unformatted.generated.contains('\n'));
+        assert!(unformatted.warnings.is_empty());
+
+        let formatted = compiler()
+            .set_formatter(|bindings| Ok(bindings.replace("; ", ";\n")))
+            .compile_to_string()
+            .unwrap();
+        assert!(formatted.generated.contains(";\n"));
+        assert!(formatted.warnings.is_empty());
+
+        let failed = compiler()
+            .set_formatter(|_| Err("formatter unavailable".into()))
+            .compile_to_string()
+            .unwrap();
+        assert_eq!(failed.generated, unformatted.generated);
+        assert_eq!(failed.warnings.len(), 1);
+        assert!(failed.warnings[0]
+            .to_string()
+            .contains("formatter unavailable"));
+    }
+
+    #[test]
+    fn parses_only() {
+        let modules = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                "TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Test-Ref ::= Undefined-Type END",
+            )
+            .parse_only()
+            .unwrap();
+        assert_eq!(modules.len(), 1);
+        let (header, tlds) = &modules[0];
+        assert_eq!(header.name, "TestModule");
+        assert!(matches!(
+            &tlds[..],
+            [ToplevelDefinition::Type(t)] if matches!(
+                &t.ty,
+                ir::ASN1Type::ElsewhereDeclaredType(e) if e.identifier == "Undefined-Type"
+            )
+        ));
+    }
+
+    #[test]
+    fn excludes_modules_and_types() {
+        let sources = [
+            r#"Legacy-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN Legacy-Int ::= INTEGER END"#,
+            r#"Main-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Helper ::= BOOLEAN
+                Message ::= SEQUENCE { id INTEGER }
+            END"#,
+        ];
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(sources[0])
+            .add_asn_literal(sources[1])
+            .exclude_modules(&["Legacy-Module"])
+            .exclude_types(&["Helper"])
+            .compile_to_string()
+            .unwrap();
+        assert!(result.generated.contains("Message"));
+        assert!(!result.generated.contains("legacy_module"));
+        assert!(!result.generated.contains("Helper"));
+
+        let error = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"Main-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Helper ::= BOOLEAN
+                    Message ::= SEQUENCE { flag Helper }
+                END"#,
+            )
+            .exclude_types(&["Helper"])
+            .compile_to_string()
+            .unwrap_err();
+        assert!(error.to_string().contains("Helper"));
+    }
+
+    #[test]
+    fn maps_types_to_existing_rust_types() {
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"Time-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Timestamp ::= INTEGER (0..4294967295)
+                    epoch Timestamp ::= 0
+                END"#,
+            )
+            .add_asn_literal(
+                r#"Main-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Timestamp FROM Time-Module;
+                    Timestamps ::= SEQUENCE (SIZE(1..4)) OF Timestamp
+                    Event ::= SEQUENCE { at Timestamp, history Timestamps, last Timestamp (0..100) }
+                END"#,
+            )
+            .map_type("Timestamp", "crate::time::Timestamp")
+            .compile_to_string()
+            .unwrap();
+        let generated = result.generated.replace(' ', "");
+        assert!(!generated.contains("pubstructTimestamp("));
+        assert!(!generated.contains("EPOCH"));
+        assert!(generated.contains("pubat:crate::time::Timestamp,"));
+        assert!(generated.contains("SequenceOf<crate::time::Timestamp>"));
+        assert!(generated.contains("publast:crate::time::Timestamp,"));
+        assert!(!generated.contains("time_module::{Timestamp}"));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.to_string().contains("crate::time::Timestamp")));
+    }
+
+    #[test]
+    fn allows_lints_on_generated_modules() {
+        let asn1 = r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Id ::= INTEGER
+            END"#;
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(asn1)
+            .allow_lints(&["dead_code", "clippy::all"])
+            .compile_to_string()
+            .unwrap();
+        assert!(result.generated.replace(' ', "").contains(
+            "#[allow(clippy::all,dead_code,non_camel_case_types,non_snake_case,non_upper_case_globals,unused)]pubmodtest_module"
+        ));
+        let result = Compiler::<RasnBackend, _>::new_with_config(RasnConfig {
+            allowed_lints: Default::default(),
+            ..Default::default()
+        })
+        .add_asn_literal(asn1)
+        .compile_to_string()
+        .unwrap();
+        assert!(!result.generated.contains("allow"));
+    }
+
+    #[test]
+    fn generates_modules_lazily() {
+        let generated = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let progress = generated.clone();
+        let mut modules = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"Second-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Id FROM First-Module;
+                    Message ::= SEQUENCE { id Id }
+                END"#,
+            )
+            .add_asn_literal(
+                r#"First-Module DEFINITIONS AUTOMATIC TAGS ::= BEGIN Id ::= INTEGER (0..255) END"#,
+            )
+            .set_progress_callback(move |event| {
+                if let ProgressEvent::ModuleGenerated { module, .. } = event {
+                    progress.borrow_mut().push(module);
+                }
+            })
+            .compile_to_iter()
+            .unwrap();
+        assert!(generated.borrow().is_empty());
+        let first = modules.next().unwrap().unwrap();
+        assert_eq!(first.module, "First-Module");
+        assert!(first.generated.contains("pub struct Id"));
+        assert_eq!(*generated.borrow(), vec!["First-Module"]);
+        let second = modules.next().unwrap().unwrap();
+        assert_eq!(second.module, "Second-Module");
+        assert!(second.generated.contains("pub struct Message"));
+        assert!(modules.next().is_none());
+        assert!(modules.warnings().is_empty());
+    }
+
+    #[test]
+    fn returns_dependency_graph() {
+        let graph = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    max-items INTEGER ::= 8
+                    Item ::= INTEGER (0..255)
+                    Items ::= SEQUENCE (SIZE(1..max-items)) OF Item
+                    Message ::= CHOICE { single Item, multiple Items }
+                    Envelope ::= SEQUENCE { message Message, count INTEGER (0..max-items) }
+                END"#,
+            )
+            .dependency_graph()
+            .unwrap();
+        assert_eq!(graph["Item"], Vec::<String>::new());
+        assert_eq!(graph["Items"], vec!["Item", "max-items"]);
+        assert_eq!(graph["Message"], vec!["Item", "Items"]);
+        assert_eq!(graph["Envelope"], vec!["Message", "max-items"]);
+    }
+}
+
+#[cfg(all(test, feature = "syn"))]
+mod syn_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn compiles_to_syn_file() {
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Test-Int ::= INTEGER (1..128)
+                    Sized-Flag ::= BOOLEAN (SIZE(1..4))
+                END"#,
+            )
+            .compile_to_syn()
+            .unwrap();
+        let syn::Item::Mod(module) = &result.generated.items[0] else {
+            panic!("Expected a module item!");
+        };
+        assert_eq!(module.ident, "test_module");
+        let items = &module.content.as_ref().unwrap().1;
+        assert!(items
+            .iter()
+            .any(|item| matches!(item, syn::Item::Struct(s) if s.ident == "TestInt")));
+        assert!(!items
+            .iter()
+            .any(|item| matches!(item, syn::Item::Struct(s) if s.ident == "SizedFlag")));
+        assert_eq!(result.warnings.len(), 1);
     }
 }
@@ -153,6 +153,7 @@ fn validator_io(literal: &str) -> (String, String) {
             let header_ref = Rc::new(RefCell::new(header));
             tlds.into_iter().enumerate().map(move |(index, mut tld)| {
                 tld.apply_tagging_environment(&header_ref.borrow().tagging_environment);
+                tld.apply_extensibility_environment(&header_ref.borrow().extensibility_environment);
                 tld.set_index(header_ref.clone(), index);
                 tld
             })
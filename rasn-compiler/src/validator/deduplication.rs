@@ -0,0 +1,198 @@
+//! The `deduplication` module merges structurally identical
+//! type definitions that are declared in different ASN.1 modules.
+//! It runs after linking, so that references to other types and
+//! values are already resolved when definitions are compared.
+use crate::intermediate::*;
+
+/// Replaces every type definition that is structurally identical to a type definition of
+/// another module with an alias of the latter (canonical) definition.
+/// The canonical definition is the first matching definition in the order of module names.
+/// Definitions are only considered identical if both their types (including constraints)
+/// and their tags match. The referencing module's imports are extended accordingly.
+pub(crate) fn deduplicate_types(tlds: &mut [ToplevelDefinition]) {
+    let mut candidates = tlds
+        .iter()
+        .enumerate()
+        .filter_map(|(i, tld)| match tld {
+            ToplevelDefinition::Type(t) if is_deduplicable(t) => {
+                module_name(t).map(|module| (module, i))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    candidates.sort();
+
+    let mut replacements = vec![];
+    for (position, (module, index)) in candidates.iter().enumerate() {
+        let ToplevelDefinition::Type(duplicate) = &tlds[*index] else {
+            continue;
+        };
+        if let Some((canonical_module, canonical_index)) =
+            candidates[..position].iter().find(|(other_module, other)| {
+                other_module != module
+                    && matches!(
+                        &tlds[*other],
+                        ToplevelDefinition::Type(t) if t.is_structurally_equal(duplicate)
+                    )
+            })
+        {
+            replacements.push((
+                *index,
+                canonical_module.clone(),
+                tlds[*canonical_index].name().clone(),
+            ));
+        }
+    }
+
+    for (index, canonical_module, canonical_name) in replacements {
+        if let ToplevelDefinition::Type(duplicate) = &mut tlds[index] {
+            if let Some((module_ref, _)) = &duplicate.index {
+                add_import(
+                    &mut module_ref.borrow_mut(),
+                    &canonical_module,
+                    &canonical_name,
+                );
+            }
+            duplicate.tag = None;
+            duplicate.is_alias = true;
+            duplicate.ty = ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier: canonical_name,
+                constraints: vec![],
+            });
+        }
+    }
+}
+
+/// Plain references and parameterized types are not deduplicated,
+/// since they do not result in separate definitions.
+fn is_deduplicable(tld: &ToplevelTypeDefinition) -> bool {
+    tld.parameterization.is_none() && !matches!(tld.ty, ASN1Type::ElsewhereDeclaredType(_))
+}
+
+fn module_name(tld: &ToplevelTypeDefinition) -> Option<String> {
+    tld.index
+        .as_ref()
+        .map(|(module_ref, _)| module_ref.borrow().name.clone())
+}
+
+fn add_import(module: &mut ModuleReference, from_module: &str, identifier: &str) {
+    if let Some(import) = module
+        .imports
+        .iter_mut()
+        .find(|i| i.global_module_reference.module_reference == from_module)
+    {
+        if !import.types.iter().any(|t| t == identifier) {
+            import.types.push(identifier.to_owned());
+        }
+    } else {
+        module.imports.push(Import {
+            types: vec![identifier.to_owned()],
+            global_module_reference: GlobalModuleReference {
+                module_reference: from_module.to_owned(),
+                assigned_identifier: AssignedIdentifier::Empty,
+            },
+            with: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::intermediate::{types::*, *};
+
+    use super::deduplicate_types;
+
+    fn module(name: &str) -> Rc<RefCell<ModuleReference>> {
+        Rc::new(RefCell::new(ModuleReference {
+            name: name.into(),
+            module_identifier: None,
+            encoding_reference_default: None,
+            tagging_environment: TaggingEnvironment::Automatic,
+            extensibility_environment: ExtensibilityEnvironment::Explicit,
+            imports: vec![],
+            exports: None,
+            encoding_control: vec![],
+            macros: vec![],
+        }))
+    }
+
+    fn boolean_type(
+        name: &str,
+        tag: Option<AsnTag>,
+        module: &Rc<RefCell<ModuleReference>>,
+    ) -> ToplevelDefinition {
+        ToplevelDefinition::Type(ToplevelTypeDefinition {
+            comments: String::new(),
+            tag,
+            name: name.into(),
+            ty: ASN1Type::Boolean(Boolean {
+                constraints: vec![],
+            }),
+            parameterization: None,
+            index: Some((module.clone(), 0)),
+            selection: None,
+            is_alias: false,
+        })
+    }
+
+    #[test]
+    fn deduplicates_identical_types_of_different_modules() {
+        let (module_a, module_b) = (module("Module-A"), module("Module-B"));
+        let mut tlds = vec![
+            boolean_type("Flag-B", None, &module_b),
+            boolean_type("Flag-A", None, &module_a),
+        ];
+        deduplicate_types(&mut tlds);
+        assert_eq!(tlds[1], boolean_type("Flag-A", None, &module_a));
+        assert_eq!(
+            tlds[0],
+            ToplevelDefinition::Type(ToplevelTypeDefinition {
+                comments: String::new(),
+                tag: None,
+                name: "Flag-B".into(),
+                ty: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                    parent: None,
+                    identifier: "Flag-A".into(),
+                    constraints: vec![]
+                }),
+                parameterization: None,
+                index: Some((module_b.clone(), 0)),
+                selection: None,
+                is_alias: true,
+            })
+        );
+        assert_eq!(
+            module_b.borrow().imports,
+            vec![Import {
+                types: vec!["Flag-A".into()],
+                global_module_reference: GlobalModuleReference {
+                    module_reference: "Module-A".into(),
+                    assigned_identifier: AssignedIdentifier::Empty
+                },
+                with: None
+            }]
+        );
+    }
+
+    #[test]
+    fn keeps_types_with_different_tags_or_same_module() {
+        let (module_a, module_b) = (module("Module-A"), module("Module-B"));
+        let tag = AsnTag {
+            environment: TaggingEnvironment::Explicit,
+            tag_class: TagClass::Application,
+            id: 2,
+        };
+        let mut tlds = vec![
+            boolean_type("Flag-A", None, &module_a),
+            boolean_type("Other-Flag-A", None, &module_a),
+            boolean_type("Flag-B", Some(tag), &module_b),
+        ];
+        let expected = tlds.clone();
+        deduplicate_types(&mut tlds);
+        assert_eq!(tlds, expected);
+        assert!(module_b.borrow().imports.is_empty());
+    }
+}
@@ -0,0 +1,452 @@
+//! The `dependencies` module orders type definitions for linking.
+//! Linking a type may copy parts of the types that it references,
+//! e.g. the members of a `COMPONENTS OF` notation, so that
+//! referenced types need to be linked first.
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::intermediate::{constraints::*, information_object::*, *};
+
+use super::error::{ValidatorError, ValidatorErrorType};
+
+/// Returns the names of the type definitions among the `tlds` in an order in which every type
+/// follows the types that it references. References that form a cycle are ordered arbitrarily.
+/// Cycles that values of the types cannot terminate, because every reference of the cycle is
+/// mandatory, are reported as errors. Cycles that pass through an `OPTIONAL` or `DEFAULT`
+/// component, a `CHOICE` alternative, or a `SEQUENCE OF`/`SET OF` element can be broken up
+/// by boxing and are not reported.
+pub(crate) fn link_order(
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> (Vec<String>, Vec<ValidatorError>) {
+    let graph = tlds
+        .iter()
+        .filter_map(|(name, tld)| match tld {
+            ToplevelDefinition::Type(t) => {
+                let mut references = vec![];
+                collect_references(&t.ty, true, &mut references);
+                references.retain(|(reference, _)| {
+                    matches!(tlds.get(*reference), Some(ToplevelDefinition::Type(_)))
+                });
+                Some((name, references))
+            }
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+    let mut sorter = Sorter {
+        graph: &graph,
+        path: vec![],
+        linked: HashSet::new(),
+        order: vec![],
+        errors: vec![],
+    };
+    for name in graph.keys() {
+        sorter.visit(name, false);
+    }
+    (sorter.order, sorter.errors)
+}
+
+/// Collects the names of the types that a type references. A reference is `mandatory`
+/// if every value of the referencing type contains a value of the referenced type.
+fn collect_references<'a>(
+    ty: &'a ASN1Type,
+    mandatory: bool,
+    references: &mut Vec<(&'a String, bool)>,
+) {
+    match ty {
+        ASN1Type::ElsewhereDeclaredType(e) => references.push((&e.identifier, mandatory)),
+        ASN1Type::ChoiceSelectionType(c) => references.push((&c.choice_name, mandatory)),
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            references.extend(s.components_of.iter().map(|c| (c, mandatory)));
+            for member in &s.members {
+                collect_references(
+                    &member.ty,
+                    mandatory && !member.is_optional && member.default_value.is_none(),
+                    references,
+                );
+            }
+        }
+        ASN1Type::Choice(c) => {
+            for option in &c.options {
+                collect_references(&option.ty, false, references);
+            }
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            collect_references(&s.element_type, false, references)
+        }
+        _ => (),
+    }
+}
+
+/// Returns the dependency graph of the `tlds`, which maps the name of every top-level declaration
+/// to the names of the declarations that it references, in alphabetical order. References are
+/// collected from components, collection elements, alternatives, default values, constraints,
+/// and information object classes and sets. Names that are not declared among the `tlds`,
+/// such as dummy references of parameterized declarations, are omitted.
+pub(crate) fn dependency_graph(tlds: &[ToplevelDefinition]) -> BTreeMap<String, Vec<String>> {
+    let declared = tlds.iter().map(|tld| tld.name()).collect::<HashSet<_>>();
+    tlds.iter()
+        .map(|tld| {
+            let mut references = References::default();
+            match tld {
+                ToplevelDefinition::Type(t) => references.ty(&t.ty),
+                ToplevelDefinition::Value(v) => {
+                    references.ty(&v.associated_type);
+                    references.value(&v.value);
+                }
+                ToplevelDefinition::Information(i) => {
+                    if let Some(ClassLink::ByName(class)) = &i.class {
+                        references.0.insert(class);
+                    }
+                    references.information(&i.value);
+                }
+            }
+            let edges = references
+                .0
+                .into_iter()
+                .filter(|reference| declared.contains(reference))
+                .cloned()
+                .collect();
+            (tld.name().clone(), edges)
+        })
+        .collect()
+}
+
+/// Collects the names that a declaration references.
+#[derive(Default)]
+struct References<'a>(BTreeSet<&'a String>);
+
+impl<'a> References<'a> {
+    fn ty(&mut self, ty: &'a ASN1Type) {
+        match ty {
+            ASN1Type::ElsewhereDeclaredType(e) => {
+                self.0.insert(&e.identifier);
+            }
+            ASN1Type::ChoiceSelectionType(c) => {
+                self.0.insert(&c.choice_name);
+            }
+            ASN1Type::InformationObjectFieldReference(f) => {
+                self.0.insert(&f.class);
+            }
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                self.0.extend(&s.components_of);
+                for member in &s.members {
+                    self.ty(&member.ty);
+                    self.constraints(&member.constraints);
+                    if let Some(default) = &member.default_value {
+                        self.value(default);
+                    }
+                }
+            }
+            ASN1Type::Choice(c) => {
+                for option in &c.options {
+                    self.ty(&option.ty);
+                    self.constraints(&option.constraints);
+                }
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => self.ty(&s.element_type),
+            _ => (),
+        }
+        if let Some(constraints) = ty.constraints() {
+            self.constraints(constraints);
+        }
+    }
+
+    fn value(&mut self, value: &'a ASN1Value) {
+        match value {
+            ASN1Value::ElsewhereDeclaredValue { identifier, .. }
+            | ASN1Value::LinkedElsewhereDefinedValue { identifier, .. } => {
+                self.0.insert(identifier);
+            }
+            ASN1Value::ParameterizedValueReference {
+                identifier,
+                parameters,
+            } => {
+                self.0.insert(identifier);
+                for parameter in parameters {
+                    if let Parameter::ValueParameter(value) = parameter {
+                        self.value(value);
+                    }
+                }
+            }
+            ASN1Value::Choice { inner_value, .. } => self.value(inner_value),
+            ASN1Value::Arithmetic { lhs, rhs, .. } => {
+                self.value(lhs);
+                self.value(rhs);
+            }
+            ASN1Value::SequenceOrSet(values) => {
+                for (_, value) in values {
+                    self.value(value);
+                }
+            }
+            ASN1Value::ObjectIdentifier(oid) => {
+                self.0
+                    .extend(oid.0.iter().filter_map(|arc| arc.name.as_ref()));
+            }
+            ASN1Value::LinkedNestedValue { value, .. } => self.value(value),
+            _ => (),
+        }
+    }
+
+    fn constraints(&mut self, constraints: &'a [Constraint]) {
+        for constraint in constraints {
+            match constraint {
+                Constraint::SubtypeConstraint(set) => self.element_or_set(&set.set),
+                Constraint::TableConstraint(table) => self.object_set(&table.object_set),
+                Constraint::Parameter(parameters) => self.parameters(parameters),
+                Constraint::ContentConstraint(content) => match content {
+                    ContentConstraint::Containing(ty) => self.ty(ty),
+                    ContentConstraint::EncodedBy(value) => self.value(value),
+                    ContentConstraint::ContainingEncodedBy {
+                        containing,
+                        encoded_by,
+                    } => {
+                        self.ty(containing);
+                        self.value(encoded_by);
+                    }
+                },
+            }
+        }
+    }
+
+    fn element_or_set(&mut self, set: &'a ElementOrSetOperation) {
+        match set {
+            ElementOrSetOperation::Element(element) => self.subtype_element(element),
+            ElementOrSetOperation::SetOperation(operation) => self.set_operation(operation),
+        }
+    }
+
+    fn set_operation(&mut self, operation: &'a SetOperation) {
+        self.subtype_element(&operation.base);
+        self.element_or_set(&operation.operant);
+    }
+
+    fn subtype_element(&mut self, element: &'a SubtypeElement) {
+        match element {
+            SubtypeElement::SingleValue { value, .. } => self.value(value),
+            SubtypeElement::ContainedSubtype { subtype, .. } => self.ty(subtype),
+            SubtypeElement::ValueRange { min, max, .. } => {
+                for value in min.iter().chain(max) {
+                    self.value(value);
+                }
+            }
+            SubtypeElement::PermittedAlphabet(set) | SubtypeElement::SizeConstraint(set) => {
+                self.element_or_set(set)
+            }
+            SubtypeElement::TypeConstraint(ty) => self.ty(ty),
+            SubtypeElement::SingleTypeConstraint(inner)
+            | SubtypeElement::MultipleTypeConstraints(inner) => {
+                for component in &inner.constraints {
+                    self.constraints(&component.constraints);
+                }
+            }
+            SubtypeElement::ComponentTypeConstraint(constraints) => self.constraints(constraints),
+            SubtypeElement::NestedSet(operation) => self.set_operation(operation),
+            _ => (),
+        }
+    }
+
+    fn information(&mut self, information: &'a ASN1Information) {
+        match information {
+            ASN1Information::ObjectClass(class) => {
+                for field in &class.fields {
+                    if let Some(ty) = &field.ty {
+                        self.ty(ty);
+                    }
+                    if let Some(default) = &field.default {
+                        self.field(default);
+                    }
+                }
+            }
+            ASN1Information::ObjectSet(set) => self.object_set(set),
+            ASN1Information::Object(object) => {
+                self.0.insert(&object.class_name);
+                self.fields(&object.fields);
+            }
+        }
+    }
+
+    fn parameters(&mut self, parameters: &'a [Parameter]) {
+        for parameter in parameters {
+            match parameter {
+                Parameter::ValueParameter(v) => self.value(v),
+                Parameter::TypeParameter(t) => self.ty(t),
+                Parameter::InformationObjectParameter(fields) => self.fields(fields),
+                Parameter::ObjectSetParameter(set) => self.object_set(set),
+            }
+        }
+    }
+
+    fn object_set(&mut self, set: &'a ObjectSet) {
+        for value in &set.values {
+            match value {
+                ObjectSetValue::Reference(reference) => {
+                    self.0.insert(reference);
+                }
+                ObjectSetValue::ParameterizedReference {
+                    identifier,
+                    parameters,
+                } => {
+                    self.0.insert(identifier);
+                    self.parameters(parameters);
+                }
+                ObjectSetValue::Inline(fields) => self.fields(fields),
+            }
+        }
+    }
+
+    fn fields(&mut self, fields: &'a InformationObjectFields) {
+        match fields {
+            InformationObjectFields::DefaultSyntax(fields) => {
+                for field in fields {
+                    self.field(field);
+                }
+            }
+            InformationObjectFields::CustomSyntax(applications) => {
+                for application in applications {
+                    match application {
+                        SyntaxApplication::ObjectSetDeclaration(set) => self.object_set(set),
+                        SyntaxApplication::ValueReference(value) => self.value(value),
+                        SyntaxApplication::TypeReference(ty) => self.ty(ty),
+                        SyntaxApplication::LiteralOrTypeReference(e) => {
+                            self.0.insert(&e.identifier);
+                        }
+                        SyntaxApplication::Comma | SyntaxApplication::Literal(_) => (),
+                    }
+                }
+            }
+        }
+    }
+
+    fn field(&mut self, field: &'a InformationObjectField) {
+        match field {
+            InformationObjectField::TypeField(f) => self.ty(&f.ty),
+            InformationObjectField::FixedValueField(f) => self.value(&f.value),
+            InformationObjectField::ObjectSetField(f) => self.object_set(&f.value),
+        }
+    }
+}
+
+struct Sorter<'a> {
+    graph: &'a BTreeMap<&'a String, Vec<(&'a String, bool)>>,
+    /// The types that are currently visited, together with whether they are referenced mandatorily
+    path: Vec<(&'a String, bool)>,
+    linked: HashSet<&'a String>,
+    order: Vec<String>,
+    errors: Vec<ValidatorError>,
+}
+
+impl<'a> Sorter<'a> {
+    fn visit(&mut self, name: &'a String, mandatory: bool) {
+        if self.linked.contains(name) {
+            return;
+        }
+        if let Some(start) = self.path.iter().position(|(n, _)| *n == name) {
+            if mandatory && self.path[start + 1..].iter().all(|(_, m)| *m) {
+                let cycle = self.path[start..]
+                    .iter()
+                    .map(|(n, _)| n.as_str())
+                    .chain([name.as_str()])
+                    .collect::<Vec<_>>();
+                self.errors.push(ValidatorError::new(
+                    Some(name.clone()),
+                    &format!(
+                        "Cyclic type reference {} without an OPTIONAL component, CHOICE alternative, or collection element that could break it up!",
+                        cycle.join(" -> ")
+                    ),
+                    ValidatorErrorType::CyclicReference,
+                ));
+            }
+            return;
+        }
+        self.path.push((name, mandatory));
+        for (reference, mandatory) in self.graph.get(name).into_iter().flatten() {
+            self.visit(reference, *mandatory);
+        }
+        self.path.pop();
+        self.linked.insert(name);
+        self.order.push(name.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::intermediate::{types::*, *};
+
+    use super::link_order;
+
+    fn type_tld(name: &str, ty: ASN1Type) -> (String, ToplevelDefinition) {
+        (
+            name.into(),
+            ToplevelDefinition::Type(ToplevelTypeDefinition {
+                comments: String::new(),
+                tag: None,
+                name: name.into(),
+                ty,
+                parameterization: None,
+                index: None,
+                selection: None,
+                is_alias: false,
+            }),
+        )
+    }
+
+    fn reference(name: &str) -> ASN1Type {
+        ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+            parent: None,
+            identifier: name.into(),
+            constraints: vec![],
+        })
+    }
+
+    fn sequence(members: Vec<(&str, ASN1Type, bool)>, components_of: Vec<&str>) -> ASN1Type {
+        ASN1Type::Sequence(SequenceOrSet {
+            components_of: components_of.into_iter().map(String::from).collect(),
+            extensible: None,
+            constraints: vec![],
+            members: members
+                .into_iter()
+                .map(|(name, ty, is_optional)| SequenceOrSetMember {
+                    name: name.into(),
+                    tag: None,
+                    ty,
+                    default_value: None,
+                    is_optional,
+                    constraints: vec![],
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn orders_referenced_types_first() {
+        let tlds = BTreeMap::from([
+            type_tld("Base", sequence(vec![], vec![])),
+            type_tld("Mid", sequence(vec![], vec!["Base"])),
+            type_tld("Alias", reference("Top")),
+            type_tld(
+                "Top",
+                sequence(vec![("mid", reference("Mid"), true)], vec![]),
+            ),
+        ]);
+        let (order, errors) = link_order(&tlds);
+        assert_eq!(order, vec!["Base", "Mid", "Top", "Alias"]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn reports_cycles_of_mandatory_references_only() {
+        let tlds = BTreeMap::from([
+            type_tld(
+                "List",
+                sequence(vec![("next", reference("List"), true)], vec![]),
+            ),
+            type_tld("A", sequence(vec![("b", reference("B"), false)], vec![])),
+            type_tld("B", reference("A")),
+        ]);
+        let (order, errors) = link_order(&tlds);
+        assert_eq!(order, vec!["B", "A", "List"]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].details.contains("A -> B -> A"));
+    }
+}
@@ -28,6 +28,10 @@ impl ValidatorError {
 pub enum ValidatorErrorType {
     MissingDependency,
     InvalidConstraintsError,
+    InvalidEnumerationError,
+    InvalidTaggingError,
+    InvalidValueError,
+    CyclicReference,
     Unknown,
 }
 
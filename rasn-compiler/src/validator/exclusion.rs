@@ -0,0 +1,65 @@
+//! The `exclusion` module removes the definitions of modules and types
+//! that the user explicitly excluded from generation.
+//! It runs after linking, so that the excluded definitions can still
+//! be used to link the definitions that are kept.
+use std::collections::BTreeSet;
+
+use crate::intermediate::*;
+
+use super::{
+    dependencies::dependency_graph,
+    error::{ValidatorError, ValidatorErrorType},
+};
+
+/// Removes the definitions of the excluded modules and the excluded types from the `tlds`.
+/// Imports of the excluded modules and types are removed from the headers of the remaining modules.
+/// Returns an error if a remaining definition references an excluded definition, since the
+/// bindings of the remaining definition could not be compiled without it.
+pub(crate) fn exclude_definitions(
+    tlds: &mut Vec<ToplevelDefinition>,
+    excluded_modules: &BTreeSet<String>,
+    excluded_types: &BTreeSet<String>,
+) -> Result<(), ValidatorError> {
+    if excluded_modules.is_empty() && excluded_types.is_empty() {
+        return Ok(());
+    }
+    let is_excluded = |tld: &ToplevelDefinition| {
+        excluded_types.contains(tld.name())
+            || tld
+                .get_module_reference()
+                .is_some_and(|module| excluded_modules.contains(&module.borrow().name))
+    };
+    let excluded = tlds
+        .iter()
+        .filter(|tld| is_excluded(tld))
+        .map(|tld| tld.name().clone())
+        .collect::<BTreeSet<_>>();
+    let graph = dependency_graph(tlds);
+    for tld in tlds.iter().filter(|tld| !is_excluded(tld)) {
+        if let Some(reference) = graph
+            .get(tld.name())
+            .into_iter()
+            .flatten()
+            .find(|reference| excluded.contains(*reference))
+        {
+            return Err(ValidatorError::new(
+                Some(tld.name().clone()),
+                &format!("References {reference}, which is excluded from generation!"),
+                ValidatorErrorType::MissingDependency,
+            ));
+        }
+    }
+    tlds.retain(|tld| !is_excluded(tld));
+    for tld in tlds.iter() {
+        if let Some(module) = tld.get_module_reference() {
+            let mut module = module.borrow_mut();
+            module.imports.retain_mut(|import| {
+                let imported = import.types.len();
+                import.types.retain(|ty| !excluded.contains(ty));
+                !excluded_modules.contains(&import.global_module_reference.module_reference)
+                    && (import.types.len() == imported || !import.types.is_empty())
+            });
+        }
+    }
+    Ok(())
+}
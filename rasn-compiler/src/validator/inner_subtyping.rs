@@ -0,0 +1,255 @@
+//! The `inner_subtyping` module checks that the inner subtyping constraints of a type,
+//! i.e. its `WITH COMPONENT` and `WITH COMPONENTS` constraints, are consistent with the
+//! type that they constrain. The components that a `WITH COMPONENTS` constraint names
+//! need to exist, and neither the presence constraints nor the constraints on the
+//! components themselves may contradict the declaration of the constrained type.
+use std::collections::BTreeMap;
+
+use crate::intermediate::{constraints::*, types::*, *};
+
+use super::{
+    error::{ValidatorError, ValidatorErrorType},
+    validate_applicability,
+};
+
+/// Checks the inner subtyping constraints of a top-level type, including the ones of its inner types.
+/// References to other types are resolved, so that the constraints can be checked against the
+/// components of the referenced type. Constraints on unresolvable types are not checked.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §51.8*
+pub(crate) fn validate_inner_subtyping(
+    tld: &ToplevelTypeDefinition,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    validate_type(&tld.ty, tlds).map_err(|mut e| {
+        e.specify_data_element(tld.name.clone());
+        e
+    })
+}
+
+fn validate_type(
+    ty: &ASN1Type,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    validate_constraints(ty, ty.constraints().map_or(&[], |c| c.as_slice()), tlds)?;
+    match ty {
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => s.members.iter().try_for_each(|m| {
+            validate_type(&m.ty, tlds)?;
+            validate_constraints(&m.ty, &m.constraints, tlds)
+        }),
+        ASN1Type::Choice(c) => c.options.iter().try_for_each(|o| {
+            validate_type(&o.ty, tlds)?;
+            validate_constraints(&o.ty, &o.constraints, tlds)
+        }),
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => validate_type(&s.element_type, tlds),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the inner subtyping elements of `constraints` against `ty`.
+fn validate_constraints(
+    ty: &ASN1Type,
+    constraints: &[Constraint],
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    let Some(resolved) = resolve(ty, tlds) else {
+        return Ok(());
+    };
+    constraints.iter().try_for_each(|c| match c {
+        Constraint::SubtypeConstraint(ElementSet { set, .. }) => validate_set(resolved, set, tlds),
+        _ => Ok(()),
+    })
+}
+
+fn validate_set(
+    ty: &ASN1Type,
+    set: &ElementOrSetOperation,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    match set {
+        ElementOrSetOperation::Element(element) => validate_element(ty, element, tlds),
+        ElementOrSetOperation::SetOperation(SetOperation { base, operant, .. }) => {
+            validate_element(ty, base, tlds)?;
+            validate_set(ty, operant, tlds)
+        }
+    }
+}
+
+fn validate_element(
+    ty: &ASN1Type,
+    element: &SubtypeElement,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    match (element, ty) {
+        (SubtypeElement::NestedSet(set), _) => {
+            validate_element(ty, &set.base, tlds)?;
+            validate_set(ty, &set.operant, tlds)
+        }
+        (SubtypeElement::SingleTypeConstraint(inner), ASN1Type::Sequence(s) | ASN1Type::Set(s)) => {
+            validate_components(inner, &s.members, tlds)
+        }
+        (SubtypeElement::SingleTypeConstraint(inner), ASN1Type::Choice(c)) => {
+            validate_alternatives(inner, c, tlds)
+        }
+        // The components of a REAL value are checked when the REAL type is validated
+        (SubtypeElement::SingleTypeConstraint(_), ASN1Type::Real(_)) => Ok(()),
+        (SubtypeElement::SingleTypeConstraint(_), _) => not_applicable(WITH_COMPONENTS, ty),
+        (
+            SubtypeElement::MultipleTypeConstraints(inner),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s),
+        ) => match resolve(&s.element_type, tlds) {
+            Some(element_type) => validate_element(
+                element_type,
+                &SubtypeElement::SingleTypeConstraint(inner.clone()),
+                tlds,
+            ),
+            None => Ok(()),
+        },
+        (
+            SubtypeElement::ComponentTypeConstraint(constraints),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s),
+        ) => validate_component_constraints(&s.element_type, constraints, tlds),
+        (
+            SubtypeElement::MultipleTypeConstraints(_) | SubtypeElement::ComponentTypeConstraint(_),
+            _,
+        ) => not_applicable(WITH_COMPONENT, ty),
+        _ => Ok(()),
+    }
+}
+
+/// Checks a `WITH COMPONENTS` constraint on a `SEQUENCE` or `SET`.
+/// Each component may only be constrained once, and components that are neither
+/// `OPTIONAL` nor `DEFAULT` cannot be constrained `ABSENT`.
+fn validate_components(
+    inner: &InnerTypeConstraint,
+    members: &[SequenceOrSetMember],
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    for (index, component) in inner.constraints.iter().enumerate() {
+        check_unique(inner, index)?;
+        let Some(member) = members.iter().find(|m| m.name == component.identifier) else {
+            return Err(unknown_component(&component.identifier));
+        };
+        if component.presence == ComponentPresence::Absent
+            && !member.is_optional
+            && member.default_value.is_none()
+        {
+            return Err(ValidatorError::new(
+                None,
+                &format!(
+                    "The mandatory component `{}` cannot be constrained ABSENT!",
+                    component.identifier
+                ),
+                ValidatorErrorType::InvalidConstraintsError,
+            ));
+        }
+        validate_component_constraints(&member.ty, &component.constraints, tlds)?;
+    }
+    Ok(())
+}
+
+/// Checks a `WITH COMPONENTS` constraint on a `CHOICE`.
+/// Since a value of a `CHOICE` consists of a single alternative,
+/// at most one of the alternatives can be constrained `PRESENT`.
+fn validate_alternatives(
+    inner: &InnerTypeConstraint,
+    choice: &Choice,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    for (index, component) in inner.constraints.iter().enumerate() {
+        check_unique(inner, index)?;
+        let Some(option) = choice
+            .options
+            .iter()
+            .find(|o| o.name == component.identifier)
+        else {
+            return Err(unknown_component(&component.identifier));
+        };
+        validate_component_constraints(&option.ty, &component.constraints, tlds)?;
+    }
+    if inner
+        .constraints
+        .iter()
+        .filter(|c| c.presence == ComponentPresence::Present)
+        .count()
+        > 1
+    {
+        return Err(ValidatorError::new(
+            None,
+            "At most one alternative of a CHOICE can be constrained PRESENT!",
+            ValidatorErrorType::InvalidConstraintsError,
+        ));
+    }
+    Ok(())
+}
+
+/// Checks the constraints that a `WITH COMPONENT` or `WITH COMPONENTS` constraint
+/// applies to a component against the type of the component.
+fn validate_component_constraints(
+    ty: &ASN1Type,
+    constraints: &[Constraint],
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    if let Some(resolved) = resolve(ty, tlds) {
+        for c in constraints {
+            if let Constraint::SubtypeConstraint(ElementSet { set, .. }) = c {
+                validate_applicability(resolved, set)?;
+            }
+        }
+    }
+    validate_constraints(ty, constraints, tlds)
+}
+
+fn check_unique(inner: &InnerTypeConstraint, index: usize) -> Result<(), ValidatorError> {
+    let identifier = &inner.constraints[index].identifier;
+    if inner.constraints[..index]
+        .iter()
+        .any(|c| &c.identifier == identifier)
+    {
+        Err(ValidatorError::new(
+            None,
+            &format!("The component `{identifier}` is constrained more than once!"),
+            ValidatorErrorType::InvalidConstraintsError,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn unknown_component(identifier: &str) -> ValidatorError {
+    ValidatorError::new(
+        None,
+        &format!("The constrained type has no component `{identifier}`!"),
+        ValidatorErrorType::InvalidConstraintsError,
+    )
+}
+
+fn not_applicable(kind: &str, ty: &ASN1Type) -> Result<(), ValidatorError> {
+    Err(ValidatorError::new(
+        None,
+        &format!("A {kind} constraint cannot be applied to {}!", ty.as_str()),
+        ValidatorErrorType::InvalidConstraintsError,
+    ))
+}
+
+/// Follows references to other types to the type that they denote.
+fn resolve<'a>(
+    ty: &'a ASN1Type,
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+) -> Option<&'a ASN1Type> {
+    let mut resolved = ty;
+    let mut visited = vec![];
+    while let ASN1Type::ElsewhereDeclaredType(e) = resolved {
+        if e.parent.is_some() || visited.contains(&&e.identifier) {
+            return None;
+        }
+        visited.push(&e.identifier);
+        match tlds.get(&e.identifier) {
+            Some(ToplevelDefinition::Type(t)) => resolved = &t.ty,
+            _ => return None,
+        }
+    }
+    match resolved {
+        ASN1Type::InformationObjectFieldReference(_) | ASN1Type::ChoiceSelectionType(_) => None,
+        _ => Some(resolved),
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use crate::intermediate::{constraints::*, error::*, *};
+use crate::intermediate::{constraints::*, error::*, types::Enumerated, *};
 
 impl Constraint {
     pub(super) fn link_cross_reference(
@@ -14,6 +14,41 @@ impl Constraint {
         }
     }
 
+    /// Returns the identifier of a value reference in a `SIZE` constraint
+    /// that could not be resolved during linking.
+    pub(super) fn unresolved_size_reference(&self) -> Option<&String> {
+        match self {
+            Constraint::SubtypeConstraint(t) => t.set.unresolved_size_reference(),
+            _ => None,
+        }
+    }
+
+    /// Resolves the enumerals that a constraint of a reference to an `ENUMERATED` type permits.
+    /// Enumerals that are not members of the referenced type are rejected.
+    /// Since the enumerals of the referenced type are known, the exclusion of enumerals
+    /// from `ALL` values is replaced by the union of the remaining enumerals.
+    /// ### Example
+    /// ```ignore
+    /// Color ::= ENUMERATED { red, green, blue }
+    /// Warm-Color ::= Color (red | green)
+    /// Cold-Color ::= Color (ALL EXCEPT (red | green))
+    /// ```
+    pub(super) fn link_enumerals(
+        &mut self,
+        enumerated_name: &str,
+        enumerated: &Enumerated,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        match self {
+            Constraint::SubtypeConstraint(t) => {
+                t.set.link_enumerals(enumerated_name, enumerated, tlds)?;
+                t.set.complement_enumerals(enumerated_name, enumerated);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub(super) fn has_cross_reference(&self) -> bool {
         match self {
             Self::SubtypeConstraint(c) => c.set.has_cross_reference(),
@@ -34,7 +69,9 @@ impl SubtypeElement {
                 value,
                 extensible: _,
             } => {
+                value.link_arithmetic(tlds)?;
                 value.link_elsewhere_declared(identifier, tlds)?;
+                value.link_value_alias(tlds);
             }
             SubtypeElement::PermittedAlphabet(e) => {
                 e.link_cross_reference(identifier, tlds)?;
@@ -50,15 +87,14 @@ impl SubtypeElement {
                 max,
                 extensible: _,
             } => {
-                min.as_mut()
-                    .map(|m| m.link_elsewhere_declared(identifier, tlds))
-                    .transpose()?;
-                max.as_mut()
-                    .map(|m| m.link_elsewhere_declared(identifier, tlds))
-                    .transpose()?;
+                for bound in [min, max].into_iter().flatten() {
+                    bound.link_arithmetic(tlds)?;
+                    bound.link_elsewhere_declared(identifier, tlds)?;
+                    bound.link_value_alias(tlds);
+                }
             }
             SubtypeElement::SizeConstraint(s) => {
-                s.link_cross_reference(identifier, tlds)?;
+                s.link_size_reference(tlds)?;
             }
             SubtypeElement::TypeConstraint(t) => {
                 t.link_constraint_reference(identifier, tlds)?;
@@ -69,17 +105,111 @@ impl SubtypeElement {
                     b.link_cross_reference(identifier, tlds)?;
                 }
             }
+            SubtypeElement::ComponentTypeConstraint(c) => {
+                for b in c {
+                    b.link_cross_reference(identifier, tlds)?;
+                }
+            }
+            SubtypeElement::NestedSet(s) => {
+                s.base.link_cross_reference(identifier, tlds)?;
+                s.operant.link_cross_reference(identifier, tlds)?;
+            }
             _ => (),
         }
         Ok(())
     }
 
+    fn link_enumerals(
+        &mut self,
+        enumerated_name: &str,
+        enumerated: &Enumerated,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        match self {
+            SubtypeElement::SingleValue { value, .. } => {
+                value.link_enumeral(enumerated_name, enumerated, tlds)
+            }
+            SubtypeElement::ContainedSubtype { subtype, .. } => {
+                subtype.link_subtype_constraint(tlds)
+            }
+            SubtypeElement::NestedSet(s) => {
+                s.base.link_enumerals(enumerated_name, enumerated, tlds)?;
+                s.operant.link_enumerals(enumerated_name, enumerated, tlds)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn collect_enumerals<'a>(&'a self, enumerals: &mut Vec<&'a String>) -> bool {
+        match self {
+            SubtypeElement::SingleValue {
+                value: ASN1Value::EnumeratedValue { enumerable, .. },
+                ..
+            } => {
+                enumerals.push(enumerable);
+                true
+            }
+            SubtypeElement::NestedSet(s) => s.collect_enumerals(enumerals),
+            _ => false,
+        }
+    }
+
+    fn link_size_reference(
+        &mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        match self {
+            SubtypeElement::SingleValue { value, .. } => value.link_size_bound(tlds),
+            SubtypeElement::ValueRange { min, max, .. } => {
+                min.as_mut().map(|m| m.link_size_bound(tlds)).transpose()?;
+                max.as_mut().map(|m| m.link_size_bound(tlds)).transpose()?;
+                Ok(())
+            }
+            // e.g. `SIZE(Lengths)` with the value set `Lengths INTEGER ::= { 8 | 16 }`
+            SubtypeElement::ContainedSubtype { subtype, .. } => {
+                subtype.link_subtype_constraint(tlds)
+            }
+            SubtypeElement::NestedSet(s) => {
+                s.base.link_size_reference(tlds)?;
+                s.operant.link_size_reference(tlds)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn unresolved_size_reference(&self) -> Option<&String> {
+        match self {
+            SubtypeElement::SizeConstraint(s) => s.unresolved_size_bound(),
+            SubtypeElement::NestedSet(s) => s
+                .base
+                .unresolved_size_reference()
+                .or_else(|| s.operant.unresolved_size_reference()),
+            _ => None,
+        }
+    }
+
+    fn unresolved_size_bound(&self) -> Option<&String> {
+        match self {
+            SubtypeElement::SingleValue { value, .. } => value.unresolved_size_bound(),
+            SubtypeElement::ValueRange { min, max, .. } => min
+                .as_ref()
+                .and_then(|m| m.unresolved_size_bound())
+                .or_else(|| max.as_ref().and_then(|m| m.unresolved_size_bound())),
+            SubtypeElement::NestedSet(s) => s
+                .base
+                .unresolved_size_bound()
+                .or_else(|| s.operant.unresolved_size_bound()),
+            _ => None,
+        }
+    }
+
     pub(super) fn has_cross_reference(&self) -> bool {
         match self {
             SubtypeElement::SingleValue {
                 value,
                 extensible: _,
-            } => value.is_elsewhere_declared(),
+            } => value.is_bound_reference(),
+            SubtypeElement::All => false,
             SubtypeElement::PatternConstraint(_) => false,
             SubtypeElement::UserDefinedConstraint(_) => false,
             SubtypeElement::PropertySettings(_) => false,
@@ -87,22 +217,31 @@ impl SubtypeElement {
             SubtypeElement::ContainedSubtype {
                 subtype,
                 extensible: _,
-            } => subtype.contains_constraint_reference(),
+            } => {
+                matches!(
+                    subtype,
+                    ASN1Type::ChoiceSelectionType(_) | ASN1Type::ElsewhereDeclaredType(_)
+                ) || subtype.contains_constraint_reference()
+            }
             SubtypeElement::ValueRange {
                 min,
                 max,
                 extensible: _,
             } => {
-                min.as_ref().map_or(false, |s| s.is_elsewhere_declared())
-                    || max.as_ref().map_or(false, |s| s.is_elsewhere_declared())
+                min.as_ref().map_or(false, |s| s.is_bound_reference())
+                    || max.as_ref().map_or(false, |s| s.is_bound_reference())
             }
             SubtypeElement::SizeConstraint(s) => s.has_cross_reference(),
+            SubtypeElement::NestedSet(s) => {
+                s.base.has_cross_reference() || s.operant.has_cross_reference()
+            }
             SubtypeElement::TypeConstraint(t) => t.references_class_by_name(),
             SubtypeElement::MultipleTypeConstraints(s)
             | SubtypeElement::SingleTypeConstraint(s) => s
                 .constraints
                 .iter()
                 .any(|cc| cc.constraints.iter().any(|c| c.has_cross_reference())),
+            SubtypeElement::ComponentTypeConstraint(c) => c.iter().any(|c| c.has_cross_reference()),
         }
     }
 }
@@ -122,6 +261,111 @@ impl ElementOrSetOperation {
         }
     }
 
+    fn link_enumerals(
+        &mut self,
+        enumerated_name: &str,
+        enumerated: &Enumerated,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        match self {
+            ElementOrSetOperation::Element(e) => {
+                e.link_enumerals(enumerated_name, enumerated, tlds)
+            }
+            ElementOrSetOperation::SetOperation(s) => {
+                s.base.link_enumerals(enumerated_name, enumerated, tlds)?;
+                s.operant.link_enumerals(enumerated_name, enumerated, tlds)
+            }
+        }
+    }
+
+    /// Replaces an exclusion of single enumerals from `ALL` values by the union of the
+    /// remaining enumerals. Other exclusions, or exclusions of all enumerals, are left as they are.
+    fn complement_enumerals(&mut self, enumerated_name: &str, enumerated: &Enumerated) {
+        let ElementOrSetOperation::SetOperation(SetOperation {
+            base: SubtypeElement::All,
+            operator: SetOperator::Except,
+            operant,
+        }) = self
+        else {
+            return;
+        };
+        let mut excluded = vec![];
+        if !operant.collect_enumerals(&mut excluded) {
+            return;
+        }
+        let mut remaining = enumerated
+            .members
+            .iter()
+            .filter(|m| !excluded.contains(&&m.name))
+            .map(|m| SubtypeElement::SingleValue {
+                value: ASN1Value::EnumeratedValue {
+                    enumerated: enumerated_name.to_owned(),
+                    enumerable: m.name.clone(),
+                },
+                extensible: false,
+            })
+            .rev();
+        if let Some(last) = remaining.next() {
+            *self = remaining.fold(ElementOrSetOperation::Element(last), |operant, base| {
+                ElementOrSetOperation::SetOperation(SetOperation {
+                    base,
+                    operator: SetOperator::Union,
+                    operant: Box::new(operant),
+                })
+            });
+        }
+    }
+
+    /// Collects the enumerals of a union of single enumerals.
+    /// Returns `false` if the set contains other elements or operations.
+    fn collect_enumerals<'a>(&'a self, enumerals: &mut Vec<&'a String>) -> bool {
+        match self {
+            ElementOrSetOperation::Element(e) => e.collect_enumerals(enumerals),
+            ElementOrSetOperation::SetOperation(s) => s.collect_enumerals(enumerals),
+        }
+    }
+
+    /// Resolves value references in the bounds of a `SIZE` constraint.
+    /// In contrast to other value references, size bounds must resolve
+    /// to integer values, otherwise the constraint cannot be represented.
+    /// ### Example
+    /// ```ignore
+    /// maxLen INTEGER ::= 32
+    /// Payload ::= OCTET STRING (SIZE(1..maxLen))
+    /// ```
+    pub(super) fn link_size_reference(
+        &mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        match self {
+            ElementOrSetOperation::Element(e) => e.link_size_reference(tlds),
+            ElementOrSetOperation::SetOperation(s) => {
+                s.base.link_size_reference(tlds)?;
+                s.operant.link_size_reference(tlds)
+            }
+        }
+    }
+
+    fn unresolved_size_reference(&self) -> Option<&String> {
+        match self {
+            ElementOrSetOperation::Element(e) => e.unresolved_size_reference(),
+            ElementOrSetOperation::SetOperation(s) => s
+                .base
+                .unresolved_size_reference()
+                .or_else(|| s.operant.unresolved_size_reference()),
+        }
+    }
+
+    fn unresolved_size_bound(&self) -> Option<&String> {
+        match self {
+            ElementOrSetOperation::Element(e) => e.unresolved_size_bound(),
+            ElementOrSetOperation::SetOperation(s) => s
+                .base
+                .unresolved_size_bound()
+                .or_else(|| s.operant.unresolved_size_bound()),
+        }
+    }
+
     pub(super) fn has_cross_reference(&self) -> bool {
         match self {
             ElementOrSetOperation::Element(e) => e.has_cross_reference(),
@@ -131,3 +375,247 @@ impl ElementOrSetOperation {
         }
     }
 }
+
+impl SetOperation {
+    fn collect_enumerals<'a>(&'a self, enumerals: &mut Vec<&'a String>) -> bool {
+        self.operator == SetOperator::Union
+            && self.base.collect_enumerals(enumerals)
+            && self.operant.collect_enumerals(enumerals)
+    }
+}
+
+impl ASN1Value {
+    fn link_size_bound(
+        &mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        self.link_arithmetic(tlds)?;
+        if let ASN1Value::ElsewhereDeclaredValue {
+            parent: None,
+            identifier,
+        } = self
+        {
+            // Unresolved references are reported once the rest of the type is linked
+            if let Some(ToplevelDefinition::Value(v)) = tlds.get(identifier) {
+                let mut value = v.value.clone();
+                value.link_value_alias(tlds);
+                *self = ASN1Value::Integer(value.as_integer().ok_or_else(|| GrammarError {
+                    details: format!("Value {identifier} of size constraint is not an integer!"),
+                    kind: GrammarErrorType::LinkerError,
+                })?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Follows a value that is declared as a reference to another value to the value
+    /// that the chain of references ends in. The referenced values may be imported
+    /// from other modules.
+    /// ### Example
+    /// ```ignore
+    /// maxCells INTEGER ::= 16
+    /// maxNrOfCells INTEGER ::= maxCells
+    /// Cells ::= SEQUENCE (SIZE(1..maxNrOfCells)) OF Cell
+    /// ```
+    fn link_value_alias(&mut self, tlds: &BTreeMap<String, ToplevelDefinition>) {
+        let mut visited = vec![];
+        while let ASN1Value::ElsewhereDeclaredValue {
+            parent: None,
+            identifier,
+        }
+        | ASN1Value::LinkedElsewhereDefinedValue {
+            parent: None,
+            identifier,
+            ..
+        } = self
+        {
+            match tlds.get(identifier) {
+                Some(ToplevelDefinition::Value(v)) if !visited.contains(identifier) => {
+                    visited.push(identifier.clone());
+                    *self = v.value.clone();
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn link_enumeral(
+        &mut self,
+        enumerated_name: &str,
+        enumerated: &Enumerated,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        let identifier = match self {
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            }
+            | ASN1Value::EnumeratedValue {
+                enumerable: identifier,
+                ..
+            } => identifier,
+            _ => return Ok(()),
+        };
+        let is_enumeral = |name: &String| enumerated.members.iter().any(|m| &m.name == name);
+        let enumeral = if is_enumeral(identifier) {
+            identifier.clone()
+        } else {
+            // The constraint may reference a value of the enumerated type instead
+            match tlds.get(identifier) {
+                Some(ToplevelDefinition::Value(ToplevelValueDefinition {
+                    value:
+                        ASN1Value::EnumeratedValue {
+                            enumerable: name, ..
+                        }
+                        | ASN1Value::ElsewhereDeclaredValue {
+                            parent: None,
+                            identifier: name,
+                        },
+                    ..
+                })) if is_enumeral(name) => name.clone(),
+                _ => {
+                    return Err(GrammarError {
+                        details: format!("{identifier} is not an enumeral of {enumerated_name}!"),
+                        kind: GrammarErrorType::LinkerError,
+                    })
+                }
+            }
+        };
+        *self = ASN1Value::EnumeratedValue {
+            enumerated: enumerated_name.to_owned(),
+            enumerable: enumeral,
+        };
+        Ok(())
+    }
+
+    /// Replaces an arithmetic expression in the bound of a constraint with the resulting integer.
+    /// Since hyphens are valid characters of identifiers, the lexer reads a subtraction without
+    /// whitespace, such as `maxEntries-1`, as a single reference. If such a reference does not
+    /// resolve to a declaration, it is evaluated as a subtraction.
+    /// ### Example
+    /// ```ignore
+    /// maxEntries INTEGER ::= 16
+    /// Index ::= INTEGER (0..maxEntries-1)
+    /// Buffer ::= OCTET STRING (SIZE(1..maxEntries * 2))
+    /// ```
+    fn link_arithmetic(
+        &mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        match self {
+            ASN1Value::Arithmetic { .. } => *self = ASN1Value::Integer(self.evaluate(tlds)?),
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            } if !tlds.contains_key(identifier) => {
+                if let Some(result) = evaluate_hyphenated_subtraction(identifier, tlds) {
+                    *self = ASN1Value::Integer(result);
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Evaluates an integer value, a reference to an integer value, or an arithmetic expression of these.
+    fn evaluate(&self, tlds: &BTreeMap<String, ToplevelDefinition>) -> Result<i128, GrammarError> {
+        let error = |details: String| GrammarError {
+            details,
+            kind: GrammarErrorType::LinkerError,
+        };
+        match self {
+            ASN1Value::Arithmetic { operator, lhs, rhs } => {
+                let (lhs, rhs) = (lhs.evaluate(tlds)?, rhs.evaluate(tlds)?);
+                let result = match operator {
+                    ArithmeticOperator::Add => lhs.checked_add(rhs),
+                    ArithmeticOperator::Subtract => lhs.checked_sub(rhs),
+                    ArithmeticOperator::Multiply => lhs.checked_mul(rhs),
+                    ArithmeticOperator::Divide if rhs == 0 => {
+                        return Err(error(format!(
+                            "Division by zero in arithmetic expression {lhs} / {rhs} of constraint!"
+                        )))
+                    }
+                    ArithmeticOperator::Divide if lhs % rhs != 0 => {
+                        return Err(error(format!(
+                            "Arithmetic expression {lhs} / {rhs} of constraint does not result in an integer!"
+                        )))
+                    }
+                    ArithmeticOperator::Divide => lhs.checked_div(rhs),
+                };
+                result.ok_or_else(|| {
+                    error(format!(
+                        "Arithmetic expression of constraint overflows with operands {lhs} and {rhs}!"
+                    ))
+                })
+            }
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            } => match tlds.get(identifier) {
+                Some(ToplevelDefinition::Value(v)) => v.value.evaluate(tlds),
+                Some(_) => Err(error(format!(
+                    "Value {identifier} in arithmetic expression of constraint is not an integer!"
+                ))),
+                None => evaluate_hyphenated_subtraction(identifier, tlds).ok_or_else(|| {
+                    error(format!(
+                        "Failed to find value {identifier} in arithmetic expression of constraint!"
+                    ))
+                }),
+            },
+            v => v.as_integer().ok_or_else(|| {
+                error(format!(
+                    "Value {v:?} in arithmetic expression of constraint is not an integer!"
+                ))
+            }),
+        }
+    }
+
+    /// Whether the value in the bound of a constraint needs to be resolved by the linker
+    fn is_bound_reference(&self) -> bool {
+        self.is_elsewhere_declared() || matches!(self, ASN1Value::Arithmetic { .. })
+    }
+
+    fn unresolved_size_bound(&self) -> Option<&String> {
+        match self {
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            } => Some(identifier),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_integer(&self) -> Option<i128> {
+        match self {
+            ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => Some(*i),
+            ASN1Value::LinkedNestedValue { value, .. } => value.as_integer(),
+            _ => None,
+        }
+    }
+}
+
+/// Evaluates a reference that the lexer read as a single identifier, such as `maxEntries-1`,
+/// as a subtraction of its hyphen-separated parts. Parts are integer literals or references
+/// to integer values. Returns `None` if the reference cannot be split into such parts.
+fn evaluate_hyphenated_subtraction(
+    identifier: &str,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Option<i128> {
+    let operand = |part: &str| {
+        part.parse::<i128>().ok().or_else(|| {
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier: part.to_owned(),
+            }
+            .evaluate(tlds)
+            .ok()
+        })
+    };
+    identifier
+        .match_indices(HYPHEN_MINUS)
+        .rev()
+        .find_map(|(index, _)| {
+            let (lhs, rhs) = (&identifier[..index], &identifier[index + 1..]);
+            operand(lhs)?.checked_sub(operand(rhs)?)
+        })
+}
@@ -1,6 +1,11 @@
 use std::collections::BTreeMap;
 
-use crate::intermediate::{information_object::*, *};
+use crate::intermediate::{
+    constraints::{Constraint, Parameter},
+    information_object::*,
+    parameterization::{ParameterGovernor, Parameterization, ParameterizationArgument},
+    *,
+};
 
 use super::{
     utils::{resolve_custom_syntax, walk_object_field_ref_path},
@@ -52,7 +57,8 @@ impl ToplevelInformationDefinition {
             }
             (ASN1Information::ObjectSet(ref mut o), Some(ClassLink::ByReference(class))) => {
                 o.values.iter_mut().try_for_each(|value| match value {
-                    ObjectSetValue::Reference(_) => Ok(()),
+                    ObjectSetValue::Reference(_)
+                    | ObjectSetValue::ParameterizedReference { .. } => Ok(()),
                     ObjectSetValue::Inline(ref mut fields) => {
                         resolve_custom_syntax(fields, class)?;
                         link_object_fields(fields, class, tlds)
@@ -64,6 +70,156 @@ impl ToplevelInformationDefinition {
     }
 }
 
+impl ToplevelTypeDefinition {
+    /// Returns the class that `self` assigns if `self` references a class rather than a type,
+    /// e.g. `PROTOCOL-IES ::= BOUNDED-IES {255}`. The lexer cannot tell class references from
+    /// type references, so such class assignments are parsed as type assignments.
+    pub fn as_class_assignment(
+        &self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Option<Result<ToplevelInformationDefinition, GrammarError>> {
+        let ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+            parent: None,
+            identifier,
+            constraints,
+        }) = &self.ty
+        else {
+            return None;
+        };
+        let Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
+            value: ASN1Information::ObjectClass(class),
+            parameterization,
+            ..
+        })) = tlds.get(identifier)
+        else {
+            return None;
+        };
+        let args = constraints
+            .iter()
+            .find_map(|c| match c {
+                Constraint::Parameter(args) => Some(args.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        Some(
+            class
+                .instantiate(identifier, parameterization.as_ref(), args, tlds)
+                .map(|class| ToplevelInformationDefinition {
+                    comments: self.comments.clone(),
+                    name: self.name.clone(),
+                    parameterization: self.parameterization.clone(),
+                    class: None,
+                    value: ASN1Information::ObjectClass(class),
+                    index: self.index.clone(),
+                }),
+        )
+    }
+}
+
+impl InformationObjectClass {
+    /// Instantiates the parameterized class `identifier` with the actual parameters `args`.
+    /// Dummy references in the types of the class fields are replaced by the actual parameters.
+    fn instantiate(
+        &self,
+        identifier: &String,
+        parameterization: Option<&Parameterization>,
+        args: &[Parameter],
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<InformationObjectClass, GrammarError> {
+        let mut instance = self.clone();
+        let Some(Parameterization { parameters }) = parameterization else {
+            return Ok(instance);
+        };
+        let mut impl_tlds = tlds.clone();
+        let mut type_replacements = BTreeMap::new();
+        for (
+            index,
+            ParameterizationArgument {
+                dummy_reference,
+                param_governor,
+            },
+        ) in parameters.iter().enumerate()
+        {
+            match (args.get(index), param_governor) {
+                (Some(Parameter::ValueParameter(v)), ParameterGovernor::TypeOrClass(gov)) => {
+                    impl_tlds.insert(
+                        dummy_reference.clone(),
+                        ToplevelDefinition::Value(ToplevelValueDefinition::from((
+                            dummy_reference.as_str(),
+                            ASN1Type::dereference_value_argument(v, tlds),
+                            gov.clone(),
+                        ))),
+                    );
+                }
+                (Some(Parameter::TypeParameter(t)), ParameterGovernor::None) => {
+                    type_replacements.insert(dummy_reference, t);
+                }
+                (None, _) => {
+                    return Err(GrammarError {
+                        details: format!(
+                        "Did not find an argument for parameter {dummy_reference} of {identifier}"
+                    ),
+                        kind: GrammarErrorType::LinkerError,
+                    })
+                }
+                _ => {
+                    return Err(GrammarError {
+                        details: format!(
+                            "Mismatching argument for parameter {dummy_reference} of {identifier}"
+                        ),
+                        kind: GrammarErrorType::LinkerError,
+                    })
+                }
+            }
+        }
+        for ty in instance.fields.iter_mut().filter_map(|f| f.ty.as_mut()) {
+            if let ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier: dummy_reference,
+                ..
+            }) = ty
+            {
+                if let Some(replacement) = type_replacements.get(dummy_reference) {
+                    *ty = (*replacement).clone();
+                }
+            }
+            if let Some(replacement) = ty.link_constraint_reference(identifier, &impl_tlds)? {
+                *ty = replacement;
+            }
+        }
+        Ok(instance)
+    }
+}
+
+impl ObjectSet {
+    /// Returns the first referenced object or object set of `self`
+    /// that is declared with another class than `class`.
+    pub(super) fn nonconforming_reference<'a>(
+        &'a self,
+        class: &str,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Option<&'a String> {
+        let expected = tlds
+            .get(class)
+            .and_then(|tld| tld.is_class_with_name(&class.to_owned()));
+        self.values.iter().find_map(|value| match value {
+            ObjectSetValue::Reference(r)
+            | ObjectSetValue::ParameterizedReference { identifier: r, .. } => match tlds.get(r) {
+                Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
+                    class: Some(ClassLink::ByName(name)),
+                    ..
+                })) if name != class => Some(r),
+                Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
+                    class: Some(ClassLink::ByReference(c)),
+                    ..
+                })) if Some(c) != expected => Some(r),
+                _ => None,
+            },
+            ObjectSetValue::Inline(_) => None,
+        })
+    }
+}
+
 fn resolve_and_link(
     fields: &mut InformationObjectFields,
     class: &InformationObjectClass,
@@ -100,6 +256,7 @@ fn link_object_fields(
 ) -> Result<(), GrammarError> {
     match fields {
         InformationObjectFields::DefaultSyntax(ref mut fields) => {
+            class.apply_defaults(fields);
             fields.iter_mut().try_for_each(|field| match field {
                 InformationObjectField::FixedValueField(fixed) => class
                     .fields
@@ -122,10 +279,7 @@ fn link_object_fields(
                             .value
                             .link_with_type(tlds, ty, Some(&ty.as_str().to_string()))
                     }),
-                InformationObjectField::ObjectSetField(_) => Err(GrammarError {
-                    details: "Linking object set fields is not yet supported!".to_string(),
-                    kind: GrammarErrorType::NotYetInplemented,
-                }),
+                // Object set fields only reference other objects, which are linked on their own
                 _ => Ok(()),
             })
         }
@@ -178,11 +332,32 @@ impl SyntaxApplication {
 }
 
 impl InformationObjectClass {
+    /// Returns the class field that a field path identifies. Paths with multiple segments
+    /// descend into the classes of object fields, which are looked up in the `tlds`.
     pub fn get_field<'a>(
         &'a self,
-        path: &'a Vec<ObjectFieldIdentifier>,
-    ) -> Option<&InformationObjectClassField> {
-        walk_object_field_ref_path(&self.fields, path, 0)
+        path: &[ObjectFieldIdentifier],
+        tlds: &'a BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<Option<&'a InformationObjectClassField>, GrammarError> {
+        walk_object_field_ref_path(self, path, tlds, &mut vec![])
+    }
+
+    /// Adds the defaults of the fields that an object of the class omits to the object's `fields`.
+    /// The completed fields are sorted in the order in which the class declares them.
+    pub fn apply_defaults(&self, fields: &mut Vec<InformationObjectField>) {
+        let mut completed = Vec::with_capacity(self.fields.len());
+        for class_field in &self.fields {
+            if let Some(index) = fields
+                .iter()
+                .position(|f| f.identifier() == class_field.identifier.identifier())
+            {
+                completed.push(fields.remove(index));
+            } else if let Some(default) = &class_field.default {
+                completed.push(default.clone());
+            }
+        }
+        completed.append(fields);
+        *fields = completed;
     }
 }
 
@@ -233,6 +408,8 @@ impl ObjectSetValue {
                 })) => Some(obj.values.clone()),
                 _ => None,
             },
+            // Parameterized object sets are instantiated when the referencing set is flattened
+            ObjectSetValue::ParameterizedReference { .. } => None,
             ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(c)) => {
                 c.iter_mut()
                     .any(|field| field.link_object_set_reference(tlds));
@@ -248,7 +425,7 @@ impl ObjectSetValue {
 
     pub fn references_object_set_by_name(&self) -> bool {
         match self {
-            ObjectSetValue::Reference(_) => true,
+            ObjectSetValue::Reference(_) | ObjectSetValue::ParameterizedReference { .. } => true,
             ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(c)) => {
                 c.iter().any(|field| field.references_object_set_by_name())
             }
@@ -269,7 +446,9 @@ impl ObjectSet {
             .iter_mut()
             .flat_map(|val| val.link_object_set_reference(tlds).unwrap_or_default())
             .collect();
-        self.values.append(&mut flattened);
+        if self.resolve_object_set_references(tlds).is_err() {
+            self.values.append(&mut flattened);
+        }
         true
     }
 
@@ -279,44 +458,206 @@ impl ObjectSet {
             .any(|val| val.references_object_set_by_name())
     }
 
+    /// Replaces the references of `self` to objects and object sets with the referenced objects.
+    /// Referenced object sets are flattened recursively, and objects that appear in multiple
+    /// referenced sets are only included once. If a referenced object set is extensible,
+    /// `self` becomes extensible, and the extension additions of the referenced object set
+    /// become extension additions of `self`.
     pub fn resolve_object_set_references(
         &mut self,
         tlds: &BTreeMap<String, ToplevelDefinition>,
     ) -> Result<(), GrammarError> {
-        let mut flattened_members = Vec::new();
-        let mut needs_recursing = false;
-        'resolving_references: for mut value in std::mem::take(&mut self.values) {
-            if let ObjectSetValue::Reference(id) = value {
-                match tlds.get(&id) {
+        *self = self.flatten(tlds, &mut vec![])?;
+        Ok(())
+    }
+
+    fn flatten(
+        &self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+        resolving: &mut Vec<String>,
+    ) -> Result<ObjectSet, GrammarError> {
+        fn push_unique(members: &mut Vec<ObjectSetValue>, value: ObjectSetValue) {
+            if !members.contains(&value) {
+                members.push(value);
+            }
+        }
+
+        let first_extension = self.extensible.unwrap_or(self.values.len());
+        let mut extensible = self.extensible.is_some();
+        let (mut root, mut additions) = (Vec::new(), Vec::new());
+        for (index, value) in self.values.iter().enumerate() {
+            let (resolved_root, resolved_additions) = match value {
+                ObjectSetValue::Reference(id) => match tlds.get(id) {
                     Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
                         value: ASN1Information::ObjectSet(set),
                         ..
-                    })) => {
-                        set.values
-                            .iter()
-                            .for_each(|v| flattened_members.push(v.clone()));
-                        needs_recursing = true;
-                        continue 'resolving_references;
-                    }
+                    })) => set.flatten_referenced(id, tlds, resolving, &mut extensible)?,
                     Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
                         value: ASN1Information::Object(obj),
                         ..
-                    })) => value = ObjectSetValue::Inline(obj.fields.clone()),
+                    })) => (vec![ObjectSetValue::Inline(obj.fields.clone())], vec![]),
                     _ => {
                         return Err(GrammarError {
                             details: "Failed to resolve reference in object set.".to_owned(),
                             kind: GrammarErrorType::LinkerError,
                         })
                     }
+                },
+                ObjectSetValue::ParameterizedReference {
+                    identifier,
+                    parameters,
+                } => ObjectSet::instantiate(identifier, parameters, tlds)?.flatten_referenced(
+                    identifier,
+                    tlds,
+                    resolving,
+                    &mut extensible,
+                )?,
+                inline => (vec![inline.clone()], vec![]),
+            };
+            for value in resolved_root {
+                if index < first_extension {
+                    additions.retain(|v| v != &value);
+                    push_unique(&mut root, value);
+                } else if !root.contains(&value) {
+                    push_unique(&mut additions, value);
                 }
             }
-            flattened_members.push(value)
+            for value in resolved_additions {
+                if !root.contains(&value) {
+                    push_unique(&mut additions, value);
+                }
+            }
+        }
+        let first_addition = root.len();
+        root.append(&mut additions);
+        Ok(ObjectSet {
+            values: root,
+            extensible: extensible.then_some(first_addition),
+        })
+    }
+
+    /// Flattens the object set that `self` references as `id`. Returns the root
+    /// and the extension additions of the flattened set.
+    fn flatten_referenced(
+        &self,
+        id: &String,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+        resolving: &mut Vec<String>,
+        extensible: &mut bool,
+    ) -> Result<(Vec<ObjectSetValue>, Vec<ObjectSetValue>), GrammarError> {
+        if resolving.contains(id) {
+            return Err(GrammarError {
+                details: format!("Object set {id} references itself."),
+                kind: GrammarErrorType::LinkerError,
+            });
+        }
+        resolving.push(id.clone());
+        let mut flattened = self.flatten(tlds, resolving)?;
+        resolving.pop();
+        *extensible |= flattened.extensible.is_some();
+        let additions = flattened
+            .values
+            .split_off(flattened.extensible.unwrap_or(flattened.values.len()));
+        Ok((flattened.values, additions))
+    }
+
+    /// Instantiates the parameterized object set `identifier` with the actual `parameters`.
+    /// References to the dummy references of the parameterized object set are replaced by the
+    /// objects and object sets that are passed in. Consider the following example:
+    /// ```ignore
+    /// BaseIEs {PROTOCOL-IES: Extra} PROTOCOL-IES ::= { ie-a | ie-b | Extra, ... }
+    ///
+    /// AllIEs PROTOCOL-IES ::= { BaseIEs { {ie-c | ie-d} } }
+    /// ```
+    /// `BaseIEs { {ie-c | ie-d} }` is instantiated as `{ ie-a | ie-b | ie-c | ie-d, ... }`.
+    fn instantiate(
+        identifier: &String,
+        parameters: &[Parameter],
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<ObjectSet, GrammarError> {
+        let Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
+            value: ASN1Information::ObjectSet(set),
+            parameterization:
+                Some(Parameterization {
+                    parameters: dummies,
+                }),
+            ..
+        })) = tlds.get(identifier)
+        else {
+            return Err(GrammarError {
+                details: format!("Failed to resolve parameterized object set {identifier}."),
+                kind: GrammarErrorType::LinkerError,
+            });
+        };
+        let mut instance = ObjectSet {
+            values: vec![],
+            extensible: None,
+        };
+        for (index, value) in set.values.iter().enumerate() {
+            if set.extensible == Some(index) {
+                instance.extensible = Some(instance.values.len());
+            }
+            let dummy_index = match value {
+                ObjectSetValue::Reference(r) => {
+                    dummies.iter().position(|d| &d.dummy_reference == r)
+                }
+                _ => None,
+            };
+            let Some(dummy_index) = dummy_index else {
+                instance.values.push(value.clone());
+                continue;
+            };
+            let dummy_reference = &dummies[dummy_index].dummy_reference;
+            let argument = parameters.get(dummy_index).ok_or_else(|| GrammarError {
+                details: format!(
+                    "Did not find an argument for parameter {dummy_reference} of {identifier}"
+                ),
+                kind: GrammarErrorType::LinkerError,
+            })?;
+            let values = argument.object_set_values().ok_or_else(|| GrammarError {
+                details: format!(
+                    "Mismatching argument for parameter {dummy_reference} of {identifier}"
+                ),
+                kind: GrammarErrorType::LinkerError,
+            })?;
+            instance.values.extend(values);
+        }
+        if set.extensible == Some(set.values.len()) {
+            instance.extensible = Some(instance.values.len());
         }
-        self.values = flattened_members;
-        if needs_recursing {
-            self.resolve_object_set_references(tlds)
-        } else {
-            Ok(())
+        Ok(instance)
+    }
+}
+
+impl Parameter {
+    /// Returns the objects and object sets that an actual parameter passes in for an object
+    /// or object set parameter. The lexer cannot tell an object set with a single reference,
+    /// e.g. `{ie-c}`, from an object identifier value, so those are read as object sets as well.
+    pub(super) fn object_set_values(&self) -> Option<Vec<ObjectSetValue>> {
+        match self {
+            Parameter::ObjectSetParameter(o) => Some(o.values.clone()),
+            Parameter::InformationObjectParameter(fields) => {
+                Some(vec![ObjectSetValue::Inline(fields.clone())])
+            }
+            Parameter::TypeParameter(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier,
+                ..
+            }))
+            | Parameter::ValueParameter(ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            }) => Some(vec![ObjectSetValue::Reference(identifier.clone())]),
+            Parameter::ValueParameter(ASN1Value::ObjectIdentifier(ObjectIdentifierValue(arcs))) => {
+                match arcs.as_slice() {
+                    [ObjectIdentifierArc {
+                        name: Some(name),
+                        number: None,
+                    }] => Some(vec![ObjectSetValue::Reference(name.clone())]),
+                    _ => None,
+                }
+            }
+            _ => None,
         }
     }
 }
@@ -22,7 +22,10 @@ use crate::{
 
 use self::{
     parameterization::ParameterGovernor,
-    utils::{built_in_type, find_tld_or_enum_value_by_name, octet_string_to_bit_string},
+    utils::{
+        built_in_type, find_tld_or_enum_value_by_name, named_bits_to_bit_string,
+        octet_string_to_bit_string, parse_time_value, relative_oid_to_octet_string, validate_iri,
+    },
 };
 
 use super::{Constraint, Parameter, TableConstraint};
@@ -176,10 +179,24 @@ impl ToplevelDefinition {
         tlds: &BTreeMap<String, ToplevelDefinition>,
     ) -> Result<bool, GrammarError> {
         match self {
+            // Parameterized types are only linked where they are instantiated with actual parameters
+            ToplevelDefinition::Type(t) if t.parameterization.is_some() => Ok(false),
             ToplevelDefinition::Type(t) => {
                 if let Some(replacement) = t.ty.link_constraint_reference(&t.name, tlds)? {
                     t.ty = replacement;
                 }
+                // Size bounds of parameterized types may refer to dummy parameters
+                if let Some(reference) = t
+                    .parameterization
+                    .is_none()
+                    .then(|| t.ty.unresolved_size_reference())
+                    .flatten()
+                {
+                    return Err(error!(
+                        LinkerError,
+                        "Failed to find value {reference} of size constraint in {}!", t.name
+                    ));
+                }
                 Ok(true)
             }
             // TODO: Cover constraint references in other types of top-level declarations
@@ -216,6 +233,10 @@ impl ToplevelValueDefinition {
         &mut self,
         tlds: &BTreeMap<String, ToplevelDefinition>,
     ) -> Result<(), GrammarError> {
+        // Parameterized values are only linked where they are referenced with actual parameters
+        if self.parameterization.is_some() {
+            return Ok(());
+        }
         if let Some(ToplevelDefinition::Type(tld)) =
             tlds.get(self.associated_type.as_str().as_ref())
         {
@@ -267,34 +288,71 @@ impl ASN1Type {
         }
     }
 
+    /// Replaces selection types with the types of the selected `CHOICE` alternatives.
+    /// Components of `SEQUENCE`, `SET`, and `CHOICE` types that do not have a tag of their own
+    /// take the tag of the selected alternative, unless the components are tagged automatically
+    /// in an `AUTOMATIC TAGS` environment. Elements of `SEQUENCE OF` and `SET OF` types do not
+    /// take the tag, since element tags are not represented. If `self` is a selection type, the
+    /// tag of the selected alternative is returned, so that top-level definitions can take it.
+    /// *See Rec. ITU-T X.680 (02/2021) §25.3 and §30.2*
     pub fn link_choice_selection_type(
         &mut self,
         tlds: &BTreeMap<String, ToplevelDefinition>,
-    ) -> Result<(), GrammarError> {
+        automatic_tagging: bool,
+    ) -> Result<Option<AsnTag>, GrammarError> {
         match self {
-            ASN1Type::ChoiceSelectionType(c) => {
-                if let Some(ToplevelDefinition::Type(parent)) = tlds.get(&c.choice_name) {
-                    *self = parent.ty.clone();
-                    Ok(())
-                } else {
-                    Err(error!(
-                        LinkerError,
-                        "Could not find Choice {} of selection type.", c.choice_name
-                    ))
+            ASN1Type::ChoiceSelectionType(c) => match tlds.get(&c.choice_name) {
+                Some(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                    ty: ASN1Type::Choice(choice),
+                    ..
+                })) => {
+                    let option = choice
+                        .options
+                        .iter()
+                        .find(|o| o.name == c.selected_option)
+                        .ok_or_else(|| {
+                            error!(
+                                LinkerError,
+                                "Choice {} has no alternative {} to select.",
+                                c.choice_name,
+                                c.selected_option
+                            )
+                        })?;
+                    let tag = option.tag.clone();
+                    *self = option.ty.clone();
+                    // The selected alternative may itself be a selection type
+                    Ok(tag.or(self.link_choice_selection_type(tlds, automatic_tagging)?))
+                }
+                _ => Err(error!(
+                    LinkerError,
+                    "Could not find Choice {} of selection type.", c.choice_name
+                )),
+            },
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                let automatic = automatic_tagging && s.members.iter().all(|m| m.tag.is_none());
+                for m in s.members.iter_mut() {
+                    let tag = m.ty.link_choice_selection_type(tlds, automatic_tagging)?;
+                    if !automatic {
+                        m.tag = m.tag.take().or(tag);
+                    }
                 }
+                Ok(None)
             }
-            ASN1Type::Sequence(s) | ASN1Type::Set(s) => s
-                .members
-                .iter_mut()
-                .try_for_each(|m| m.ty.link_choice_selection_type(tlds)),
-            ASN1Type::Choice(c) => c
-                .options
-                .iter_mut()
-                .try_for_each(|o: &mut ChoiceOption| o.ty.link_choice_selection_type(tlds)),
-            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
-                s.element_type.link_choice_selection_type(tlds)
+            ASN1Type::Choice(c) => {
+                let automatic = automatic_tagging && c.options.iter().all(|o| o.tag.is_none());
+                for o in c.options.iter_mut() {
+                    let tag = o.ty.link_choice_selection_type(tlds, automatic_tagging)?;
+                    if !automatic {
+                        o.tag = o.tag.take().or(tag);
+                    }
+                }
+                Ok(None)
             }
-            _ => Ok(()),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => s
+                .element_type
+                .link_choice_selection_type(tlds, automatic_tagging)
+                .map(|_| None),
+            _ => Ok(None),
         }
     }
 
@@ -332,22 +390,24 @@ impl ASN1Type {
                 // TODO: properly link components of in extensions
                 // TODO: link components of Class field, such as COMPONENTS OF BILATERAL.&id
                 for comp_link in &s.components_of {
-                    if let Some(ToplevelDefinition::Type(linked)) = tlds.get(comp_link) {
-                        if let ASN1Type::Sequence(linked_seq) = &linked.ty {
-                            linked_seq
-                                .members
-                                .iter()
-                                .enumerate()
-                                .for_each(|(index, member)| {
-                                    if index < linked_seq.extensible.unwrap_or(usize::MAX) {
-                                        if let Some(index_of_first_ext) = s.extensible {
-                                            s.extensible = Some(index_of_first_ext + 1)
+                    if let Some(linked_seq) = components_of_base(comp_link, tlds) {
+                        linked_seq
+                            .members
+                            .iter()
+                            .enumerate()
+                            .for_each(|(index, member)| {
+                                if index < linked_seq.extensible.unwrap_or(usize::MAX) {
+                                    // keep the root components in front of the extension additions
+                                    match s.extensible.as_mut() {
+                                        Some(index_of_first_ext) => {
+                                            s.members.insert(*index_of_first_ext, member.clone());
+                                            *index_of_first_ext += 1;
                                         }
-                                        s.members.push(member.clone());
+                                        None => s.members.push(member.clone()),
                                     }
-                                });
-                            member_linking = true;
-                        }
+                                }
+                            });
+                        member_linking = true;
                     }
                 }
                 member_linking
@@ -411,6 +471,12 @@ impl ASN1Type {
                         tlds,
                         args,
                     )?);
+                } else if let Some((enumerated_name, enumerated)) =
+                    Self::referenced_enumerated(&e.identifier, tlds)
+                {
+                    for c in e.constraints_mut() {
+                        c.link_enumerals(enumerated_name, enumerated, tlds)?;
+                    }
                 } else {
                     let id_clone = e.identifier.clone();
                     for c in e.constraints_mut() {
@@ -418,6 +484,12 @@ impl ASN1Type {
                     }
                 }
             }
+            ASN1Type::Enumerated(e) => {
+                for c in e.constraints.iter_mut() {
+                    c.link_cross_reference(name, tlds)?;
+                }
+                e.link_enumeral_references(tlds)?;
+            }
             ASN1Type::InformationObjectFieldReference(iofr) => {
                 if let Some(ToplevelDefinition::Information(ToplevelInformationDefinition {
                     value: ASN1Information::ObjectClass(clazz),
@@ -425,7 +497,7 @@ impl ASN1Type {
                 })) = tlds.get(&iofr.class)
                 {
                     if let Some(InformationObjectClassField { ty: Some(ty), .. }) =
-                        clazz.get_field(&iofr.field_path)
+                        clazz.get_field(&iofr.field_path, tlds)?
                     {
                         self_replacement = Some(ty.clone());
                     }
@@ -442,6 +514,52 @@ impl ASN1Type {
         Ok(self_replacement)
     }
 
+    /// Follows a chain of type references to an `ENUMERATED` type.
+    /// Returns the name and definition of the `ENUMERATED` type, if any.
+    fn referenced_enumerated<'a>(
+        identifier: &String,
+        tlds: &'a BTreeMap<String, ToplevelDefinition>,
+    ) -> Option<(&'a String, &'a Enumerated)> {
+        match tlds.get(identifier) {
+            Some(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                name,
+                ty: ASN1Type::Enumerated(enumerated),
+                ..
+            })) => Some((name, enumerated)),
+            Some(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                ty: ASN1Type::ElsewhereDeclaredType(e),
+                ..
+            })) if &e.identifier != identifier => Self::referenced_enumerated(&e.identifier, tlds),
+            _ => None,
+        }
+    }
+
+    /// Replaces a value reference that is passed as the argument of a dummy parameter
+    /// by the referenced value. Otherwise, the dummy reference would need to be resolved
+    /// in a scope that the dummy reference itself shadows, e.g. if the argument is a dummy
+    /// reference of an enclosing parameterized type or has the same name as the dummy reference.
+    fn dereference_value_argument(
+        value: &ASN1Value,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> ASN1Value {
+        let mut value = value;
+        let mut visited = vec![];
+        while let ASN1Value::ElsewhereDeclaredValue {
+            parent: None,
+            identifier,
+        } = value
+        {
+            match tlds.get(identifier) {
+                Some(ToplevelDefinition::Value(v)) if !visited.contains(&identifier) => {
+                    visited.push(identifier);
+                    value = &v.value;
+                }
+                _ => break,
+            }
+        }
+        value.clone()
+    }
+
     pub(crate) fn resolve_parameters(
         identifier: &String,
         _parent: Option<&String>,
@@ -457,6 +575,7 @@ impl ASN1Type {
                 let mut impl_template = ty.clone();
                 let mut impl_tlds = tlds.clone();
                 let mut table_constraint_replacements = BTreeMap::new();
+                let mut class_replacements = BTreeMap::new();
                 for (
                     index,
                     ParameterizationArgument {
@@ -475,11 +594,23 @@ impl ASN1Type {
                                     dummy_reference.clone(),
                                     ToplevelDefinition::Value(ToplevelValueDefinition::from((
                                         dummy_reference.as_str(),
-                                        v.clone(),
+                                        Self::dereference_value_argument(v, tlds),
                                         gov.clone(),
                                     ))),
                                 );
                             }
+                            (
+                                Parameter::TypeParameter(ASN1Type::ElsewhereDeclaredType(
+                                    DeclarationElsewhere { identifier: class_name, .. },
+                                )),
+                                ParameterGovernor::None,
+                            ) if tlds
+                                .get(class_name)
+                                .is_some_and(|tld| tld.is_class_with_name(class_name).is_some()) =>
+                            {
+                                impl_tlds.insert(dummy_reference.clone(), tlds[class_name].clone());
+                                class_replacements.insert(dummy_reference, class_name);
+                            }
                             (Parameter::TypeParameter(t), _) => {
                                 impl_tlds.insert(
                                     dummy_reference.clone(),
@@ -491,6 +622,13 @@ impl ASN1Type {
                             },
                             (Parameter::InformationObjectParameter(_), _) => todo!(),
                             (Parameter::ObjectSetParameter(o), ParameterGovernor::Class(c)) => {
+                                let c = class_replacements.get(c).copied().unwrap_or(c);
+                                if let Some(reference) = o.nonconforming_reference(c, tlds) {
+                                    return Err(GrammarError {
+                                        details: format!("Object set argument {reference} for parameter {dummy_reference} of {identifier} does not conform to class {c}!"),
+                                        kind: GrammarErrorType::LinkerError,
+                                    });
+                                }
                                 match &o.values.first() {
                                     Some(osv) if o.values.len() == 1 => {
                                         #[allow(suspicious_double_ref_op)]
@@ -529,6 +667,9 @@ impl ASN1Type {
                 for (dummy_reference, osv) in table_constraint_replacements {
                     impl_template.reassign_table_constraint(dummy_reference, osv)?;
                 }
+                for (dummy_reference, class) in class_replacements {
+                    impl_template.reassign_class_reference(dummy_reference, class);
+                }
                 Ok(impl_template)
             }
             _ => Err(GrammarError {
@@ -597,6 +738,37 @@ impl ASN1Type {
         }
     }
 
+    /// Class parameters of parameterized types are replaced by the actual classes
+    /// that are passed in by the implementations. Consider the following example:
+    /// ```ignore
+    /// AlgorithmIdentifier{ALGORITHM-TYPE, ALGORITHM-TYPE:AlgorithmSet} ::= SEQUENCE {
+    ///     algorithm   ALGORITHM-TYPE.&id({AlgorithmSet}),
+    ///     parameters  ALGORITHM-TYPE.&Params({AlgorithmSet}{@algorithm}) OPTIONAL
+    /// }
+    ///
+    /// SignatureAlgorithmIdentifier ::= AlgorithmIdentifier{SIGNATURE-ALGORITHM, {SignatureAlgorithms}}
+    /// ```
+    /// The `ALGORITHM-TYPE` field references that remain after linking are reassigned to `SIGNATURE-ALGORITHM`.
+    fn reassign_class_reference(&mut self, dummy_reference: &str, class: &str) {
+        match self {
+            ASN1Type::Choice(c) => c
+                .options
+                .iter_mut()
+                .for_each(|o| o.ty.reassign_class_reference(dummy_reference, class)),
+            ASN1Type::Set(s) | ASN1Type::Sequence(s) => s
+                .members
+                .iter_mut()
+                .for_each(|m| m.ty.reassign_class_reference(dummy_reference, class)),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => s
+                .element_type
+                .reassign_class_reference(dummy_reference, class),
+            ASN1Type::InformationObjectFieldReference(iofr) if iofr.class == dummy_reference => {
+                class.clone_into(&mut iofr.class)
+            }
+            _ => (),
+        }
+    }
+
     fn link_elsewhere_declared(
         &mut self,
         tlds: &BTreeMap<String, ToplevelDefinition>,
@@ -613,6 +785,15 @@ impl ASN1Type {
             ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
                 s.element_type.link_elsewhere_declared(tlds)
             }
+            // References to parameterized types are instantiated with their own arguments
+            // once the constraint references of the implementation are linked
+            ASN1Type::ElsewhereDeclaredType(e)
+                if e.constraints
+                    .iter()
+                    .any(|c| matches!(c, Constraint::Parameter(_))) =>
+            {
+                Ok(())
+            }
             ASN1Type::ElsewhereDeclaredType(e) => {
                 if let Some(ToplevelDefinition::Type(tld)) = tlds.get(&e.identifier) {
                     *self = tld.ty.clone();
@@ -633,7 +814,7 @@ impl ASN1Type {
                     ..
                 })) = tlds.get(&iofr.class)
                 {
-                    if let Some(field) = c.get_field(&iofr.field_path) {
+                    if let Some(field) = c.get_field(&iofr.field_path, tlds)? {
                         if let Some(ref ty) = field.ty {
                             *self = ty.clone();
                         }
@@ -653,10 +834,9 @@ impl ASN1Type {
                     kind: GrammarErrorType::LinkerError,
                 })
             }
-            ASN1Type::ChoiceSelectionType(_) => Err(GrammarError {
-                details: "Linking choice selection type is not yet supported!".to_string(),
-                kind: GrammarErrorType::NotYetInplemented,
-            }),
+            ASN1Type::ChoiceSelectionType(_) => {
+                self.link_choice_selection_type(tlds, true).map(|_| ())
+            }
             _ => Ok(()),
         }
     }
@@ -670,7 +850,12 @@ impl ASN1Type {
             ASN1Type::BitString(b) => b.constraints.iter().any(|c| c.has_cross_reference()),
             ASN1Type::OctetString(o) => o.constraints.iter().any(|c| c.has_cross_reference()),
             ASN1Type::CharacterString(c) => c.constraints.iter().any(|c| c.has_cross_reference()),
-            ASN1Type::Enumerated(e) => e.constraints.iter().any(|c| c.has_cross_reference()),
+            ASN1Type::Enumerated(e) => {
+                e.constraints.iter().any(|c| c.has_cross_reference())
+                    || e.members
+                        .iter()
+                        .any(|m| matches!(m.numbering, EnumeralNumbering::Reference(_)))
+            }
             ASN1Type::Choice(c) => {
                 c.constraints.iter().any(|c| c.has_cross_reference())
                     || c.options.iter().any(|o| {
@@ -699,11 +884,45 @@ impl ASN1Type {
         }
     }
 
+    /// Returns the first value reference in the `SIZE` constraints of the type
+    /// or its constituents that could not be resolved during linking.
+    pub fn unresolved_size_reference(&self) -> Option<&String> {
+        fn first_of(constraints: &[Constraint]) -> Option<&String> {
+            constraints
+                .iter()
+                .find_map(|c| c.unresolved_size_reference())
+        }
+        match self {
+            ASN1Type::BitString(b) => first_of(&b.constraints),
+            ASN1Type::OctetString(o) => first_of(&o.constraints),
+            ASN1Type::CharacterString(c) => first_of(&c.constraints),
+            ASN1Type::Choice(c) => first_of(&c.constraints).or_else(|| {
+                c.options.iter().find_map(|o| {
+                    first_of(&o.constraints).or_else(|| o.ty.unresolved_size_reference())
+                })
+            }),
+            ASN1Type::Set(s) | ASN1Type::Sequence(s) => first_of(&s.constraints).or_else(|| {
+                s.members.iter().find_map(|m| {
+                    first_of(&m.constraints).or_else(|| m.ty.unresolved_size_reference())
+                })
+            }),
+            ASN1Type::SetOf(s) | ASN1Type::SequenceOf(s) => {
+                first_of(&s.constraints).or_else(|| s.element_type.unresolved_size_reference())
+            }
+            ASN1Type::ElsewhereDeclaredType(e) => first_of(&e.constraints),
+            _ => None,
+        }
+    }
+
     pub fn references_class_by_name(&self) -> bool {
         match self {
             ASN1Type::Choice(c) => c.options.iter().any(|o| o.ty.references_class_by_name()),
-            ASN1Type::Sequence(s) => s.members.iter().any(|m| m.ty.references_class_by_name()),
-            ASN1Type::SequenceOf(so) => so.element_type.references_class_by_name(),
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                s.members.iter().any(|m| m.ty.references_class_by_name())
+            }
+            ASN1Type::SequenceOf(so) | ASN1Type::SetOf(so) => {
+                so.element_type.references_class_by_name()
+            }
             ASN1Type::InformationObjectFieldReference(io_ref) => {
                 matches!(
                     io_ref.field_path.last(),
@@ -714,66 +933,110 @@ impl ASN1Type {
         }
     }
 
-    pub fn resolve_class_reference(self, tlds: &BTreeMap<String, ToplevelDefinition>) -> Self {
-        match self {
+    pub fn resolve_class_reference(
+        self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<Self, GrammarError> {
+        Ok(match self {
             ASN1Type::Choice(c) => ASN1Type::Choice(Choice {
                 extensible: c.extensible,
                 options: c
                     .options
                     .into_iter()
-                    .map(|option| ChoiceOption {
-                        name: option.name,
-                        tag: option.tag,
-                        ty: option.ty.resolve_class_reference(tlds),
-                        constraints: vec![],
+                    .map(|option| {
+                        Ok(ChoiceOption {
+                            name: option.name,
+                            tag: option.tag,
+                            ty: option.ty.resolve_class_reference(tlds)?,
+                            constraints: vec![],
+                        })
                     })
-                    .collect(),
+                    .collect::<Result<_, GrammarError>>()?,
                 constraints: c.constraints,
             }),
-            ASN1Type::Sequence(s) => ASN1Type::Sequence(SequenceOrSet {
-                extensible: s.extensible,
-                constraints: s.constraints,
-                components_of: s.components_of,
-                members: s
-                    .members
-                    .into_iter()
-                    .map(|mut member| {
-                        member.constraints = vec![];
-                        member.ty = member.ty.resolve_class_reference(tlds);
-                        member
-                    })
-                    .collect(),
-            }),
-            ASN1Type::InformationObjectFieldReference(_) => self.reassign_type_for_ref(tlds),
+            ASN1Type::Sequence(s) => {
+                ASN1Type::Sequence(Self::resolve_member_class_references(s, tlds)?)
+            }
+            ASN1Type::Set(s) => ASN1Type::Set(Self::resolve_member_class_references(s, tlds)?),
+            ASN1Type::SequenceOf(mut so) => {
+                so.element_type = Box::new(so.element_type.resolve_class_reference(tlds)?);
+                ASN1Type::SequenceOf(so)
+            }
+            ASN1Type::SetOf(mut so) => {
+                so.element_type = Box::new(so.element_type.resolve_class_reference(tlds)?);
+                ASN1Type::SetOf(so)
+            }
+            ASN1Type::InformationObjectFieldReference(_) => self.reassign_type_for_ref(tlds)?,
             _ => self,
-        }
+        })
+    }
+
+    fn resolve_member_class_references(
+        s: SequenceOrSet,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<SequenceOrSet, GrammarError> {
+        Ok(SequenceOrSet {
+            extensible: s.extensible,
+            constraints: s.constraints,
+            components_of: s.components_of,
+            members: s
+                .members
+                .into_iter()
+                .map(|mut member| {
+                    member.constraints = vec![];
+                    member.ty = member.ty.resolve_class_reference(tlds)?;
+                    Ok(member)
+                })
+                .collect::<Result<_, GrammarError>>()?,
+        })
     }
 
-    fn reassign_type_for_ref(mut self, tlds: &BTreeMap<String, ToplevelDefinition>) -> Self {
+    fn reassign_type_for_ref(
+        mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<Self, GrammarError> {
         if let Self::InformationObjectFieldReference(ref ior) = self {
             if let Some(t) = tlds
-                .iter()
-                .find_map(|(_, c)| {
-                    c.is_class_with_name(&ior.class)
-                        .map(|clazz| clazz.get_field(&ior.field_path))
-                })
+                .values()
+                .find_map(|c| c.is_class_with_name(&ior.class))
+                .map(|clazz| clazz.get_field(&ior.field_path, tlds))
+                .transpose()?
                 .flatten()
                 .and_then(|class_field| class_field.ty.clone())
             {
                 self = t;
             }
         }
-        self
+        Ok(self)
     }
 
     pub fn link_subtype_constraint(
         &mut self,
         tlds: &BTreeMap<String, ToplevelDefinition>,
     ) -> Result<(), GrammarError> {
-        if let Self::ElsewhereDeclaredType(e) = self {
-            if let Some(ToplevelDefinition::Type(t)) = tlds.get(&e.identifier) {
-                *self = t.ty.clone();
+        match self {
+            Self::ElsewhereDeclaredType(e) => {
+                if let Some(ToplevelDefinition::Type(t)) = tlds.get(&e.identifier) {
+                    *self = t.ty.clone();
+                }
+                // The enumerals of a value set of an ENUMERATED type, e.g. `Warm Color ::= { red | green }`,
+                // are linked like the ones of a constraint on the ENUMERATED type itself
+                if let Self::ElsewhereDeclaredType(e) = self {
+                    let identifier = e.identifier.clone();
+                    if let Some((enumerated_name, enumerated)) =
+                        Self::referenced_enumerated(&identifier, tlds)
+                    {
+                        for c in e.constraints_mut() {
+                            c.link_enumerals(enumerated_name, enumerated, tlds)?;
+                        }
+                    }
+                }
+            }
+            Self::ChoiceSelectionType(_) => {
+                self.link_choice_selection_type(tlds, true)?;
+                self.link_subtype_constraint(tlds)?;
             }
+            _ => (),
         }
         Ok(())
     }
@@ -786,8 +1049,21 @@ impl ASN1Value {
         ty: &ASN1Type,
         type_name: Option<&String>,
     ) -> Result<(), GrammarError> {
+        if let ASN1Value::ParameterizedValueReference {
+            identifier,
+            parameters,
+        } = self
+        {
+            *self = Self::resolve_parameters(identifier, tlds, parameters)?;
+            return self.link_with_type(tlds, ty, type_name);
+        }
         #[allow(clippy::useless_asref)] // false positive
         match (ty, self.as_mut()) {
+            // Objects referenced in object sets are linked again after their own definition
+            (
+                ASN1Type::ElsewhereDeclaredType(e),
+                ASN1Value::LinkedNestedValue { supertypes, .. },
+            ) if supertypes.contains(&e.identifier) => Ok(()),
             (
                 ASN1Type::ElsewhereDeclaredType(e),
                 ASN1Value::LinkedNestedValue { supertypes, value },
@@ -943,6 +1219,42 @@ impl ASN1Value {
                 }
                 Ok(())
             }
+            (
+                ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                    is_relative: true, ..
+                }),
+                ASN1Value::ObjectIdentifier(oid),
+            ) => {
+                *self =
+                    ASN1Value::OctetString(relative_oid_to_octet_string(oid, tlds, &mut vec![])?);
+                Ok(())
+            }
+            (
+                ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                    is_relative: true, ..
+                }),
+                ASN1Value::LinkedNestedValue { value, .. },
+            ) if matches![**value, ASN1Value::ObjectIdentifier(_)] => {
+                if let ASN1Value::ObjectIdentifier(oid) = value.as_ref() {
+                    **value = ASN1Value::OctetString(relative_oid_to_octet_string(
+                        oid,
+                        tlds,
+                        &mut vec![],
+                    )?);
+                }
+                Ok(())
+            }
+            (ASN1Type::ObjectIdentifier(_), ASN1Value::ObjectIdentifier(oid)) => {
+                oid.resolve_leading_reference(tlds, &mut vec![])
+            }
+            (ASN1Type::ObjectIdentifier(_), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![**value, ASN1Value::ObjectIdentifier(_)] =>
+            {
+                if let ASN1Value::ObjectIdentifier(oid) = &mut **value {
+                    oid.resolve_leading_reference(tlds, &mut vec![])?;
+                }
+                Ok(())
+            }
             (ASN1Type::Integer(i), ASN1Value::Integer(val)) => {
                 *self = ASN1Value::LinkedIntValue {
                     integer_type: i.int_type(),
@@ -950,6 +1262,18 @@ impl ASN1Value {
                 };
                 Ok(())
             }
+            (ASN1Type::Real(_), ASN1Value::Integer(val)) => {
+                *self = ASN1Value::Real(*val as f64);
+                Ok(())
+            }
+            (ASN1Type::Real(_), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![**value, ASN1Value::Integer(_)] =>
+            {
+                if let ASN1Value::Integer(v) = **value {
+                    **value = ASN1Value::Real(v as f64);
+                }
+                Ok(())
+            }
             (ASN1Type::CharacterString(t), ASN1Value::String(s)) => {
                 *self = ASN1Value::LinkedCharStringValue(t.ty, s.clone());
                 Ok(())
@@ -962,6 +1286,76 @@ impl ASN1Value {
                 }
                 Ok(())
             }
+            // OID-IRI values are represented as their IRI character strings
+            (ASN1Type::ObjectIdentifierIri(iri), ASN1Value::String(s)) => {
+                *self = ASN1Value::LinkedCharStringValue(
+                    CharacterStringType::UTF8String,
+                    validate_iri(iri, s.as_str())?.to_owned(),
+                );
+                Ok(())
+            }
+            (ASN1Type::ObjectIdentifierIri(iri), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![**value, ASN1Value::String(_)] =>
+            {
+                if let ASN1Value::String(s) = value.as_mut() {
+                    let s = validate_iri(iri, core::mem::take(s))?;
+                    **value = ASN1Value::LinkedCharStringValue(CharacterStringType::UTF8String, s);
+                }
+                Ok(())
+            }
+            // TIME types are represented as their ISO 8601 character strings
+            (ASN1Type::Time(_), ASN1Value::Time(s) | ASN1Value::String(s)) => {
+                *self =
+                    ASN1Value::LinkedCharStringValue(CharacterStringType::VisibleString, s.clone());
+                Ok(())
+            }
+            (ASN1Type::Time(_), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![**value, ASN1Value::Time(_) | ASN1Value::String(_)] =>
+            {
+                if let ASN1Value::Time(s) | ASN1Value::String(s) = value.as_mut() {
+                    let s = core::mem::take(s);
+                    **value =
+                        ASN1Value::LinkedCharStringValue(CharacterStringType::VisibleString, s);
+                }
+                Ok(())
+            }
+            // Time values without a time difference, e.g. "20240101120000", are lexed as character strings
+            (
+                ASN1Type::GeneralizedTime(_) | ASN1Type::UTCTime(_),
+                ASN1Value::Time(s) | ASN1Value::String(s),
+            ) => {
+                *self = ASN1Value::LinkedTimeValue(parse_time_value(ty, s)?);
+                Ok(())
+            }
+            (
+                ASN1Type::GeneralizedTime(_) | ASN1Type::UTCTime(_),
+                ASN1Value::LinkedNestedValue { value, .. },
+            ) if matches![**value, ASN1Value::Time(_) | ASN1Value::String(_)] => {
+                if let ASN1Value::Time(s) | ASN1Value::String(s) = &**value {
+                    **value = ASN1Value::LinkedTimeValue(parse_time_value(ty, s)?);
+                }
+                Ok(())
+            }
+            (
+                ASN1Type::BitString(b),
+                ASN1Value::ObjectIdentifier(_) | ASN1Value::SequenceOrSet(_),
+            ) => {
+                if let Some(bits) = named_bits_to_bit_string(self, b)? {
+                    *self = ASN1Value::BitString(bits);
+                }
+                Ok(())
+            }
+            (ASN1Type::BitString(b), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![
+                    **value,
+                    ASN1Value::ObjectIdentifier(_) | ASN1Value::SequenceOrSet(_)
+                ] =>
+            {
+                if let Some(bits) = named_bits_to_bit_string(value, b)? {
+                    **value = ASN1Value::BitString(bits);
+                }
+                Ok(())
+            }
             (ASN1Type::BitString(_), ASN1Value::OctetString(o)) => {
                 *self = ASN1Value::BitString(octet_string_to_bit_string(o));
                 Ok(())
@@ -1215,6 +1609,186 @@ impl ASN1Value {
             .map(ASN1Value::LinkedStructLikeValue)
     }
 
+    /// Resolves a reference to a parameterized value definition by substituting
+    /// the actual parameters for the dummy references in the definition's value.
+    /// ### Example
+    /// `maxOf{5}` resolves to `5`
+    /// ```ignore
+    /// maxOf{INTEGER: n} INTEGER ::= n
+    /// ```
+    fn resolve_parameters(
+        identifier: &String,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+        args: &[Parameter],
+    ) -> Result<ASN1Value, GrammarError> {
+        let Some(ToplevelDefinition::Value(ToplevelValueDefinition {
+            value,
+            parameterization: Some(Parameterization { parameters }),
+            ..
+        })) = tlds.get(identifier)
+        else {
+            return Err(error!(
+                LinkerError,
+                "Failed to find parameterized value definition {identifier}!"
+            ));
+        };
+        if parameters.len() != args.len() {
+            return Err(error!(
+                LinkerError,
+                "Expected {} argument(s) for parameterized value {identifier}, found {}!",
+                parameters.len(),
+                args.len()
+            ));
+        }
+        let mut actuals = BTreeMap::new();
+        for (
+            ParameterizationArgument {
+                dummy_reference,
+                param_governor,
+            },
+            arg,
+        ) in parameters.iter().zip(args)
+        {
+            match (arg, param_governor) {
+                (Parameter::ValueParameter(v), ParameterGovernor::TypeOrClass(gov))
+                    if v.conforms_to(gov, tlds) =>
+                {
+                    actuals.insert(dummy_reference.as_str(), v.clone());
+                }
+                _ => {
+                    return Err(error!(
+                        LinkerError,
+                        "Mismatching argument for parameter {dummy_reference} of {identifier}!"
+                    ))
+                }
+            }
+        }
+        let mut value = value.clone();
+        value.substitute_dummy_references(&actuals);
+        Ok(value)
+    }
+
+    /// Replaces references to dummy parameters with the respective actual parameters.
+    fn substitute_dummy_references(&mut self, actuals: &BTreeMap<&str, ASN1Value>) {
+        match self {
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            } => {
+                if let Some(actual) = actuals.get(identifier.as_str()) {
+                    *self = actual.clone();
+                }
+            }
+            ASN1Value::Choice { inner_value, .. } => {
+                inner_value.substitute_dummy_references(actuals)
+            }
+            ASN1Value::SequenceOrSet(values) => values
+                .iter_mut()
+                .for_each(|(_, v)| v.substitute_dummy_references(actuals)),
+            ASN1Value::ParameterizedValueReference { parameters, .. } => {
+                parameters.iter_mut().for_each(|p| {
+                    if let Parameter::ValueParameter(v) = p {
+                        v.substitute_dummy_references(actuals)
+                    }
+                })
+            }
+            ASN1Value::LinkedNestedValue { value, .. } => {
+                value.substitute_dummy_references(actuals)
+            }
+            ASN1Value::Arithmetic { lhs, rhs, .. } => {
+                lhs.substitute_dummy_references(actuals);
+                rhs.substitute_dummy_references(actuals);
+            }
+            _ => (),
+        }
+    }
+
+    /// Checks whether a value can be a value of the given type.
+    /// Values whose type cannot be determined before linking, such as
+    /// references to enumerals or distinguished values, are accepted.
+    fn conforms_to(&self, ty: &ASN1Type, tlds: &BTreeMap<String, ToplevelDefinition>) -> bool {
+        match (ty, self) {
+            (ASN1Type::ElsewhereDeclaredType(e), _) => match tlds.get(&e.identifier) {
+                Some(ToplevelDefinition::Type(t)) => self.conforms_to(&t.ty, tlds),
+                _ => true,
+            },
+            (
+                _,
+                ASN1Value::ElsewhereDeclaredValue {
+                    parent: None,
+                    identifier,
+                },
+            ) => match tlds.get(identifier) {
+                Some(ToplevelDefinition::Value(v)) => v.value.conforms_to(ty, tlds),
+                _ => true,
+            },
+            (_, ASN1Value::LinkedNestedValue { value, .. }) => value.conforms_to(ty, tlds),
+            (
+                _,
+                ASN1Value::ElsewhereDeclaredValue { .. }
+                | ASN1Value::ParameterizedValueReference { .. }
+                | ASN1Value::LinkedElsewhereDefinedValue { .. },
+            ) => true,
+            (ASN1Type::Null, v) => matches!(v, ASN1Value::Null),
+            (ASN1Type::Boolean(_), v) => matches!(v, ASN1Value::Boolean(_)),
+            (ASN1Type::Integer(_), v) => {
+                matches!(v, ASN1Value::Integer(_) | ASN1Value::LinkedIntValue { .. })
+            }
+            (ASN1Type::Real(_), v) => matches!(
+                v,
+                ASN1Value::Real(_) | ASN1Value::Integer(_) | ASN1Value::LinkedIntValue { .. }
+            ),
+            (ASN1Type::BitString(_) | ASN1Type::OctetString(_), v) => matches!(
+                v,
+                ASN1Value::BitString(_) | ASN1Value::OctetString(_) | ASN1Value::SequenceOrSet(_)
+            ),
+            (ASN1Type::CharacterString(_), v) => matches!(
+                v,
+                ASN1Value::String(_)
+                    | ASN1Value::LinkedCharStringValue(..)
+                    | ASN1Value::SequenceOrSet(_)
+            ),
+            (ASN1Type::GeneralizedTime(_) | ASN1Type::UTCTime(_), v) => matches!(
+                v,
+                ASN1Value::Time(_) | ASN1Value::String(_) | ASN1Value::LinkedTimeValue(_)
+            ),
+            (ASN1Type::ObjectIdentifierIri(_), v) => matches!(
+                v,
+                ASN1Value::String(_)
+                    | ASN1Value::LinkedCharStringValue(CharacterStringType::UTF8String, _)
+            ),
+            (ASN1Type::Time(_), v) => matches!(
+                v,
+                ASN1Value::Time(_) | ASN1Value::String(_) | ASN1Value::LinkedCharStringValue(..)
+            ),
+            (
+                ASN1Type::ObjectIdentifier(ObjectIdentifier {
+                    is_relative: true, ..
+                }),
+                v,
+            ) => matches!(
+                v,
+                ASN1Value::ObjectIdentifier(_) | ASN1Value::OctetString(_)
+            ),
+            (ASN1Type::ObjectIdentifier(_), v) => matches!(v, ASN1Value::ObjectIdentifier(_)),
+            (ASN1Type::Choice(_), v) => matches!(v, ASN1Value::Choice { .. }),
+            (
+                ASN1Type::Sequence(_)
+                | ASN1Type::Set(_)
+                | ASN1Type::SequenceOf(_)
+                | ASN1Type::SetOf(_),
+                v,
+            ) => matches!(
+                v,
+                ASN1Value::SequenceOrSet(_)
+                    | ASN1Value::LinkedStructLikeValue(_)
+                    | ASN1Value::LinkedArrayLikeValue(_)
+            ),
+            (ASN1Type::Enumerated(_), _) => false,
+            _ => true,
+        }
+    }
+
     pub fn is_elsewhere_declared(&self) -> bool {
         let is = matches!(
             self,
@@ -1360,11 +1934,101 @@ impl ASN1Value {
     }
 }
 
+impl ObjectIdentifierValue {
+    /// Resolves the leading arc of an object identifier value if it references another
+    /// object identifier value or names one of the root arcs `itu-t`, `iso`, and `joint-iso-itu-t`.
+    /// The reference is replaced with the arcs of the referenced value.
+    /// ### Example
+    /// `id-sub` resolves to the arcs `1 2 840 113549`
+    /// ```ignore
+    /// id-base OBJECT IDENTIFIER ::= { iso member-body(2) 840 }
+    /// id-sub OBJECT IDENTIFIER ::= { id-base 113549 }
+    /// ```
+    fn resolve_leading_reference(
+        &mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+        resolving: &mut Vec<String>,
+    ) -> Result<(), GrammarError> {
+        let Some(ObjectIdentifierArc {
+            name: Some(name),
+            number: None,
+        }) = self.0.first()
+        else {
+            return Ok(());
+        };
+        if resolving.contains(name) {
+            return Err(error!(
+                LinkerError,
+                "Object identifier value {name} references itself."
+            ));
+        }
+        let referenced = match tlds.get(name) {
+            Some(ToplevelDefinition::Value(ToplevelValueDefinition { value, .. })) => match value {
+                ASN1Value::ObjectIdentifier(oid) => Some(oid),
+                ASN1Value::LinkedNestedValue { value, .. } => match &**value {
+                    ASN1Value::ObjectIdentifier(oid) => Some(oid),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        let resolved = match (referenced, name.as_str()) {
+            (Some(oid), _) => {
+                let mut oid = oid.clone();
+                resolving.push(name.clone());
+                oid.resolve_leading_reference(tlds, resolving)?;
+                oid.0
+            }
+            (None, "itu-t" | "ccitt") => vec![ObjectIdentifierArc::from((name.as_str(), 0))],
+            (None, "iso") => vec![ObjectIdentifierArc::from((name.as_str(), 1))],
+            (None, "joint-iso-itu-t" | "joint-iso-ccitt") => {
+                vec![ObjectIdentifierArc::from((name.as_str(), 2))]
+            }
+            _ => return Ok(()),
+        };
+        self.0.splice(0..1, resolved);
+        Ok(())
+    }
+}
+
+/// Resolves the `SEQUENCE` or `SET` type whose components `COMPONENTS OF` adds to a type.
+/// The top-level definitions of all supplied modules are looked up, so that the referenced
+/// type may be imported, and references to other types are followed to the type they denote.
+/// Since referenced types are linked first, the components of the returned type include the
+/// ones that its own `COMPONENTS OF` notation adds.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §25.5*
+pub(crate) fn components_of_base<'a>(
+    name: &String,
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+) -> Option<&'a SequenceOrSet> {
+    let mut visited = vec![name];
+    let mut current = name;
+    loop {
+        match tlds.get(current) {
+            Some(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                ty: ASN1Type::Sequence(s) | ASN1Type::Set(s),
+                ..
+            })) => return Some(s),
+            Some(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                ty: ASN1Type::ElsewhereDeclaredType(e),
+                ..
+            })) if !visited.contains(&&e.identifier) => {
+                visited.push(&e.identifier);
+                current = &e.identifier;
+            }
+            _ => return None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
-    use crate::intermediate::{types::*, *};
+    use crate::intermediate::{
+        constraints::*, information_object::*, parameterization::*, types::*, *,
+    };
 
     macro_rules! tld {
         ($name:literal, $ty:expr) => {
@@ -1372,6 +2036,8 @@ mod tests {
                 comments: String::new(),
                 tag: None,
                 index: None,
+                selection: None,
+                is_alias: false,
                 name: $name.into(),
                 ty: $ty,
                 parameterization: None,
@@ -1465,4 +2131,399 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn links_size_constraint_value_reference() {
+        let size_constraint = |bound: &str| {
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::SizeConstraint(Box::new(
+                    ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                        value: ASN1Value::ElsewhereDeclaredValue {
+                            parent: None,
+                            identifier: bound.into(),
+                        },
+                        extensible: false,
+                    }),
+                ))),
+                extensible: false,
+            })]
+        };
+        let tlds: BTreeMap<String, ToplevelDefinition> = [
+            ("maxLen", ASN1Value::Integer(32)),
+            ("flag", ASN1Value::Boolean(true)),
+        ]
+        .into_iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                ToplevelDefinition::Value(ToplevelValueDefinition::from((
+                    name,
+                    value,
+                    ASN1Type::Null,
+                ))),
+            )
+        })
+        .collect();
+        let mut octet_string = ASN1Type::OctetString(OctetString {
+            constraints: size_constraint("maxLen"),
+        });
+        octet_string
+            .link_constraint_reference(&"Test".into(), &tlds)
+            .unwrap();
+        assert_eq!(
+            octet_string,
+            ASN1Type::OctetString(OctetString {
+                constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                    set: ElementOrSetOperation::Element(SubtypeElement::SizeConstraint(Box::new(
+                        ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                            value: ASN1Value::Integer(32),
+                            extensible: false,
+                        }),
+                    ))),
+                    extensible: false,
+                })]
+            })
+        );
+        assert!(ASN1Type::OctetString(OctetString {
+            constraints: size_constraint("flag"),
+        })
+        .link_constraint_reference(&"Test".into(), &tlds)
+        .is_err());
+        assert!(ToplevelDefinition::Type(ToplevelTypeDefinition {
+            comments: String::new(),
+            tag: None,
+            name: "Test".into(),
+            ty: ASN1Type::OctetString(OctetString {
+                constraints: size_constraint("missing"),
+            }),
+            parameterization: None,
+            index: None,
+            selection: None,
+            is_alias: false,
+        })
+        .link_constraint_reference(&tlds)
+        .is_err());
+    }
+
+    #[test]
+    fn detects_object_set_argument_of_other_class() {
+        let class = |name: &str, fields| {
+            (
+                name.to_string(),
+                ToplevelDefinition::Information(ToplevelInformationDefinition::from((
+                    name,
+                    ASN1Information::ObjectClass(InformationObjectClass {
+                        fields,
+                        syntax: None,
+                    }),
+                    "CLASS",
+                ))),
+            )
+        };
+        let object_set = |name: &str, class: &str| {
+            (
+                name.to_string(),
+                ToplevelDefinition::Information(ToplevelInformationDefinition::from((
+                    name,
+                    ASN1Information::ObjectSet(ObjectSet {
+                        values: vec![],
+                        extensible: None,
+                    }),
+                    class,
+                ))),
+            )
+        };
+        let tlds: BTreeMap<String, ToplevelDefinition> = [
+            class("SIGNATURE-ALGORITHM", vec![]),
+            object_set("SignatureAlgorithms", "SIGNATURE-ALGORITHM"),
+            object_set("OtherSet", "OTHER-CLASS"),
+        ]
+        .into_iter()
+        .collect();
+        let argument = ObjectSet {
+            values: vec![
+                ObjectSetValue::Reference("SignatureAlgorithms".into()),
+                ObjectSetValue::Reference("OtherSet".into()),
+            ],
+            extensible: None,
+        };
+        assert_eq!(
+            argument.nonconforming_reference("SIGNATURE-ALGORITHM", &tlds),
+            Some(&"OtherSet".to_string())
+        );
+        assert_eq!(
+            ObjectSet {
+                values: argument.values[..1].to_vec(),
+                extensible: None,
+            }
+            .nonconforming_reference("SIGNATURE-ALGORITHM", &tlds),
+            None
+        );
+    }
+
+    #[test]
+    fn links_presence_constraint_of_full_specification() {
+        let member = |name: &str, is_optional| SequenceOrSetMember {
+            name: name.into(),
+            tag: None,
+            ty: ASN1Type::Boolean(Boolean {
+                constraints: vec![],
+            }),
+            default_value: None,
+            is_optional,
+            constraints: vec![],
+        };
+        let sequence = |members| {
+            ASN1Type::Sequence(SequenceOrSet {
+                components_of: vec![],
+                extensible: None,
+                constraints: vec![],
+                members,
+            })
+        };
+        let reference = |components: Vec<(&str, ComponentPresence)>| DeclarationElsewhere {
+            parent: None,
+            identifier: "Base".into(),
+            constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::SingleTypeConstraint(
+                    InnerTypeConstraint {
+                        is_partial: false,
+                        constraints: components
+                            .into_iter()
+                            .map(|(identifier, presence)| ConstrainedComponent {
+                                identifier: identifier.into(),
+                                constraints: vec![],
+                                presence,
+                            })
+                            .collect(),
+                    },
+                )),
+                extensible: false,
+            })],
+        };
+        let mut tlds = BTreeMap::new();
+        tlds.insert(
+            "Base".into(),
+            ToplevelDefinition::Type(tld!(
+                "Base",
+                sequence(vec![
+                    member("a", false),
+                    member("b", true),
+                    member("c", true)
+                ])
+            )),
+        );
+        let mut c = member("c", false);
+        c.tag = Some(AsnTag {
+            environment: TaggingEnvironment::Implicit,
+            tag_class: TagClass::ContextSpecific,
+            id: 2,
+        });
+        let mut a = member("a", false);
+        a.tag = Some(AsnTag {
+            environment: TaggingEnvironment::Implicit,
+            tag_class: TagClass::ContextSpecific,
+            id: 0,
+        });
+        assert_eq!(
+            reference(vec![
+                ("a", ComponentPresence::Unspecified),
+                ("c", ComponentPresence::Present)
+            ])
+            .link_presence_constraint(&tlds)
+            .unwrap(),
+            Some(sequence(vec![a, c]))
+        );
+        assert!(reference(vec![("d", ComponentPresence::Absent)])
+            .link_presence_constraint(&tlds)
+            .is_err());
+    }
+
+    #[test]
+    fn links_enumerals_of_enumerated_reference() {
+        let enumeral = |name: &str, index| Enumeral {
+            name: name.into(),
+            description: None,
+            index,
+            numbering: EnumeralNumbering::Explicit,
+        };
+        let single_value = |value| SubtypeElement::SingleValue {
+            value,
+            extensible: false,
+        };
+        let reference = |enumerals: [&str; 2]| {
+            ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier: "Color".into(),
+                constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                    set: ElementOrSetOperation::SetOperation(SetOperation {
+                        base: single_value(ASN1Value::ElsewhereDeclaredValue {
+                            parent: None,
+                            identifier: enumerals[0].into(),
+                        }),
+                        operator: SetOperator::Union,
+                        operant: Box::new(ElementOrSetOperation::Element(single_value(
+                            ASN1Value::ElsewhereDeclaredValue {
+                                parent: None,
+                                identifier: enumerals[1].into(),
+                            },
+                        ))),
+                    }),
+                    extensible: false,
+                })],
+            })
+        };
+        let mut tlds = BTreeMap::new();
+        tlds.insert(
+            "Color".into(),
+            ToplevelDefinition::Type(tld!(
+                "Color",
+                ASN1Type::Enumerated(Enumerated {
+                    members: vec![
+                        enumeral("red", 0),
+                        enumeral("green", 1),
+                        enumeral("blue", 2)
+                    ],
+                    extensible: None,
+                    constraints: vec![],
+                })
+            )),
+        );
+        let mut warm_color = reference(["red", "green"]);
+        warm_color
+            .link_constraint_reference(&"Warm-Color".into(), &tlds)
+            .unwrap();
+        let ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere { constraints, .. }) = warm_color
+        else {
+            panic!("Expected a type reference!");
+        };
+        assert_eq!(
+            constraints,
+            vec![Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::SetOperation(SetOperation {
+                    base: single_value(ASN1Value::EnumeratedValue {
+                        enumerated: "Color".into(),
+                        enumerable: "red".into(),
+                    }),
+                    operator: SetOperator::Union,
+                    operant: Box::new(ElementOrSetOperation::Element(single_value(
+                        ASN1Value::EnumeratedValue {
+                            enumerated: "Color".into(),
+                            enumerable: "green".into(),
+                        }
+                    ))),
+                }),
+                extensible: false,
+            })]
+        );
+        assert!(reference(["red", "purple"])
+            .link_constraint_reference(&"Bad-Color".into(), &tlds)
+            .is_err());
+    }
+
+    #[test]
+    fn walks_field_path_of_nested_classes() {
+        let field = |id: &str, ty| InformationObjectClassField {
+            identifier: ObjectFieldIdentifier::SingleValue(id.into()),
+            ty: Some(ty),
+            is_optional: false,
+            default: None,
+            is_unique: false,
+        };
+        let class_ref = |name: &str| {
+            ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier: name.into(),
+                constraints: vec![],
+            })
+        };
+        let class = |name: &str, fields| {
+            (
+                name.to_string(),
+                ToplevelDefinition::Information(ToplevelInformationDefinition::from((
+                    name,
+                    ASN1Information::ObjectClass(InformationObjectClass {
+                        fields,
+                        syntax: None,
+                    }),
+                    "CLASS",
+                ))),
+            )
+        };
+        let id = field("&id", ASN1Type::Boolean(Boolean::default()));
+        let tlds: BTreeMap<String, ToplevelDefinition> = [
+            class(
+                "INNER",
+                vec![id.clone(), field("&outer", class_ref("OUTER"))],
+            ),
+            class("OUTER", vec![field("&inner", class_ref("INNER"))]),
+        ]
+        .into_iter()
+        .collect();
+        let outer = tlds["OUTER"].is_class_with_name(&"OUTER".into()).unwrap();
+        let path = |ids: &[&str]| {
+            ids.iter()
+                .map(|id| ObjectFieldIdentifier::SingleValue(id.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            outer.get_field(&path(&["&inner", "&id"]), &tlds).unwrap(),
+            Some(&id)
+        );
+        assert_eq!(
+            outer
+                .get_field(&path(&["&inner", "&missing"]), &tlds)
+                .unwrap(),
+            None
+        );
+        assert!(outer
+            .get_field(&path(&["&inner", "&outer", "&inner", "&id"]), &tlds)
+            .is_err());
+    }
+
+    #[test]
+    fn resolves_parameterized_value_reference() {
+        let integer = || ASN1Type::Integer(Integer::default());
+        let mut tlds = BTreeMap::new();
+        tlds.insert(
+            "maxOf".into(),
+            ToplevelDefinition::Value(ToplevelValueDefinition {
+                comments: String::new(),
+                name: "maxOf".into(),
+                associated_type: integer(),
+                parameterization: Some(Parameterization {
+                    parameters: vec![ParameterizationArgument::from((integer(), "n"))],
+                }),
+                value: ASN1Value::ElsewhereDeclaredValue {
+                    parent: None,
+                    identifier: "n".into(),
+                },
+                index: None,
+            }),
+        );
+        let reference = |parameters| ASN1Value::ParameterizedValueReference {
+            identifier: "maxOf".into(),
+            parameters,
+        };
+        let mut value = reference(vec![Parameter::ValueParameter(ASN1Value::Integer(5))]);
+        value.link_with_type(&tlds, &integer(), None).unwrap();
+        assert_eq!(
+            value,
+            ASN1Value::LinkedIntValue {
+                integer_type: IntegerType::Unbounded,
+                value: 5
+            }
+        );
+        assert!(reference(vec![
+            Parameter::ValueParameter(ASN1Value::Integer(5)),
+            Parameter::ValueParameter(ASN1Value::Integer(6))
+        ])
+        .link_with_type(&tlds, &integer(), None)
+        .is_err());
+        assert!(
+            reference(vec![Parameter::ValueParameter(ASN1Value::Boolean(true))])
+                .link_with_type(&tlds, &integer(), None)
+                .is_err()
+        );
+    }
 }
@@ -1,7 +1,13 @@
 use std::collections::BTreeMap;
 
+use crate::intermediate::{
+    constraints::*,
+    types::{EnumeralNumbering, Enumerated, SequenceOrSet, SequenceOrSetMember},
+};
+
 use super::{
-    ASN1Type, DeclarationElsewhere, GrammarError, ToplevelDefinition, ToplevelTypeDefinition,
+    ASN1Type, AsnTag, DeclarationElsewhere, GrammarError, GrammarErrorType, TagClass,
+    TaggingEnvironment, ToplevelDefinition, ToplevelTypeDefinition,
 };
 
 impl DeclarationElsewhere {
@@ -22,4 +28,349 @@ impl DeclarationElsewhere {
             })
         }
     }
+
+    /// Returns the component constraint of the reference if it affects
+    /// which optional components of a `SEQUENCE` or `SET` may be present.
+    /// This is the case for components that are constrained `PRESENT` or `ABSENT`,
+    /// as well as for full specifications, in which omitted components are implied to be absent.
+    pub fn presence_constraint(&self) -> Option<&InnerTypeConstraint> {
+        self.constraints.iter().find_map(|c| match c {
+            // The lexer represents a `WITH COMPONENTS` constraint of a `SEQUENCE` or `SET` as single type constraint
+            Constraint::SubtypeConstraint(ElementSet {
+                set: ElementOrSetOperation::Element(SubtypeElement::SingleTypeConstraint(inner)),
+                ..
+            }) if !inner.is_partial
+                || inner
+                    .constraints
+                    .iter()
+                    .any(|cc| cc.presence != ComponentPresence::Unspecified) =>
+            {
+                Some(inner)
+            }
+            _ => None,
+        })
+    }
+
+    /// Resolves a reference to a `SEQUENCE` or `SET` that is subject to presence constraints.
+    /// Returns a copy of the referenced type in which components constrained `PRESENT` are mandatory,
+    /// and components constrained `ABSENT` are removed, or `None` if the presence constraints do not
+    /// change the shape of the referenced type.
+    /// ### Example
+    /// ```ignore
+    /// Base ::= SEQUENCE { a INTEGER, b BOOLEAN OPTIONAL, c BOOLEAN OPTIONAL }
+    /// Profile ::= Base (WITH COMPONENTS { ..., b ABSENT, c PRESENT })
+    /// -- Profile is linked as SEQUENCE { a INTEGER, c BOOLEAN }
+    /// ```
+    pub fn link_presence_constraint(
+        &self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<Option<ASN1Type>, GrammarError> {
+        let Some(presence_constraint) = self.presence_constraint() else {
+            return Ok(None);
+        };
+        let Some(ToplevelDefinition::Type(referenced)) = tlds.get(&self.identifier) else {
+            return Ok(None);
+        };
+        let automatic_tags = match &referenced.index {
+            Some((module, _)) => {
+                module.borrow().tagging_environment == TaggingEnvironment::Automatic
+            }
+            None => true,
+        };
+        match &referenced.ty {
+            ASN1Type::Sequence(s) => Ok(s
+                .with_presence_constraint(
+                    presence_constraint,
+                    &self.identifier,
+                    automatic_tags,
+                    tlds,
+                )?
+                .map(ASN1Type::Sequence)),
+            ASN1Type::Set(s) => Ok(s
+                .with_presence_constraint(
+                    presence_constraint,
+                    &self.identifier,
+                    automatic_tags,
+                    tlds,
+                )?
+                .map(ASN1Type::Set)),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Enumerated {
+    /// Resolves the value references that number enumerals
+    /// and renumbers the automatically numbered enumerals accordingly.
+    /// ### Example
+    /// ```ignore
+    /// base-value INTEGER ::= 4
+    /// Test-Enum ::= ENUMERATED { first(base-value), second }
+    /// ```
+    pub(super) fn link_enumeral_references(
+        &mut self,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<(), GrammarError> {
+        let mut has_references = false;
+        for member in &mut self.members {
+            if let EnumeralNumbering::Reference(reference) = &member.numbering {
+                let Some(ToplevelDefinition::Value(v)) = tlds.get(reference) else {
+                    return Err(GrammarError {
+                        details: format!(
+                            "Failed to find value {reference} numbering enumeral {}!",
+                            member.name
+                        ),
+                        kind: super::GrammarErrorType::LinkerError,
+                    });
+                };
+                member.index = v.value.as_integer().ok_or_else(|| GrammarError {
+                    details: format!(
+                        "Value {reference} numbering enumeral {} is not an integer!",
+                        member.name
+                    ),
+                    kind: super::GrammarErrorType::LinkerError,
+                })?;
+                member.numbering = EnumeralNumbering::Explicit;
+                has_references = true;
+            }
+        }
+        if has_references {
+            self.number_enumerals();
+        }
+        Ok(())
+    }
+}
+
+impl SequenceOrSet {
+    /// Applies a presence constraint to the components of a `SEQUENCE` or `SET`.
+    /// If components are removed from a type with automatic tags, the remaining components are
+    /// tagged with their original automatic tags, so that the constrained type is encoded
+    /// just like a value of the original type.
+    fn with_presence_constraint(
+        &self,
+        presence_constraint: &InnerTypeConstraint,
+        identifier: &str,
+        automatic_tags: bool,
+        tlds: &BTreeMap<String, ToplevelDefinition>,
+    ) -> Result<Option<SequenceOrSet>, GrammarError> {
+        if let Some(unknown) = presence_constraint
+            .constraints
+            .iter()
+            .find(|cc| !self.members.iter().any(|m| m.name == cc.identifier))
+        {
+            return Err(GrammarError {
+                details: format!(
+                    "Component {} of presence constraint is not a member of {identifier}!",
+                    unknown.identifier
+                ),
+                kind: super::GrammarErrorType::LinkerError,
+            });
+        }
+        let mut constrained = self.clone();
+        let mut changed = false;
+        let mut original_indices = vec![];
+        let mut index = 0;
+        for original_index in 0..self.members.len() {
+            let member = &mut constrained.members[index];
+            let presence = presence_constraint
+                .constraints
+                .iter()
+                .find(|cc| cc.identifier == member.name)
+                .map(|cc| &cc.presence);
+            let absent = match presence {
+                _ if !member.is_optional || member.default_value.is_some() => false,
+                Some(ComponentPresence::Present) => {
+                    member.is_optional = false;
+                    changed = true;
+                    false
+                }
+                Some(ComponentPresence::Absent) => true,
+                Some(ComponentPresence::Unspecified) => false,
+                None => !presence_constraint.is_partial,
+            };
+            if absent {
+                constrained.members.remove(index);
+                if let Some(extensible) = constrained.extensible.as_mut() {
+                    if index < *extensible {
+                        *extensible -= 1;
+                    }
+                }
+            } else {
+                original_indices.push(original_index);
+                index += 1;
+            }
+        }
+        let removed_members = constrained.members.len() < self.members.len();
+        if removed_members && automatic_tags && self.members.iter().all(|m| m.tag.is_none()) {
+            for (member, original_index) in constrained.members.iter_mut().zip(original_indices) {
+                member.tag = Some(AsnTag {
+                    environment: if member.ty.is_choice_like(tlds) {
+                        TaggingEnvironment::Explicit
+                    } else {
+                        TaggingEnvironment::Implicit
+                    },
+                    tag_class: TagClass::ContextSpecific,
+                    id: original_index as u64,
+                });
+            }
+        }
+        changed |= removed_members;
+        Ok(changed.then_some(constrained))
+    }
+}
+
+impl ASN1Type {
+    /// Untagged `CHOICE` types and open types can only be tagged explicitly.
+    pub(crate) fn is_choice_like(&self, tlds: &BTreeMap<String, ToplevelDefinition>) -> bool {
+        match self {
+            ASN1Type::Choice(_) | ASN1Type::InformationObjectFieldReference(_) => true,
+            ASN1Type::ElsewhereDeclaredType(e) => match tlds.get(&e.identifier) {
+                Some(ToplevelDefinition::Type(t)) => t.tag.is_none() && t.ty.is_choice_like(tlds),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Resolves the relational constraints of the table constraints within a type against the
+    /// components of its `SEQUENCE`, `SET`, and `CHOICE` levels. `@component` references a component
+    /// of the outermost level, `@.component` a component of the innermost level that encloses the
+    /// constrained component, and every additional `.` moves one level outwards.
+    /// Resolved levels count outwards from the innermost level, starting with 1, so that
+    /// they remain valid if the type is nested into another type, e.g. by parameterization.
+    /// Returns an error for every relational constraint that cannot be resolved.
+    /// _See: ITU-T X.682 (02/2021) 10.7_
+    pub(crate) fn link_relational_constraints(&mut self) -> Vec<GrammarError> {
+        let mut errors = vec![];
+        self.link_nested_relational_constraints(&mut vec![], &mut errors);
+        errors
+    }
+
+    /// `levels` holds the component identifiers of the enclosing levels, outermost first.
+    /// The identifiers of levels with `COMPONENTS OF` notation are not known before linking.
+    fn link_nested_relational_constraints(
+        &mut self,
+        levels: &mut Vec<Option<Vec<String>>>,
+        errors: &mut Vec<GrammarError>,
+    ) {
+        match self {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                levels.push(
+                    s.components_of
+                        .is_empty()
+                        .then(|| component_identifiers(&s.members)),
+                );
+                link_members_relational_constraints(&mut s.members, levels, errors);
+                levels.pop();
+            }
+            ASN1Type::Choice(c) => {
+                levels.push(Some(c.options.iter().map(|o| o.name.clone()).collect()));
+                for option in &mut c.options {
+                    link_relational_constraints(
+                        &mut option.constraints,
+                        &option.name,
+                        levels,
+                        errors,
+                    );
+                    if let Some(constraints) = option.ty.constraints_mut() {
+                        link_relational_constraints(constraints, &option.name, levels, errors);
+                    }
+                    option.ty.link_nested_relational_constraints(levels, errors);
+                }
+                levels.pop();
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                if let Some(constraints) = s.element_type.constraints_mut() {
+                    link_relational_constraints(constraints, "item", levels, errors);
+                }
+                s.element_type
+                    .link_nested_relational_constraints(levels, errors)
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Extension addition groups do not form a level of their own,
+/// so their components belong to the level of the enclosing type.
+fn component_identifiers(members: &[SequenceOrSetMember]) -> Vec<String> {
+    members
+        .iter()
+        .flat_map(|m| match &m.ty {
+            ASN1Type::Sequence(group) if m.name.starts_with("ext_group_") => {
+                component_identifiers(&group.members)
+            }
+            _ => vec![m.name.clone()],
+        })
+        .collect()
+}
+
+fn link_members_relational_constraints(
+    members: &mut [SequenceOrSetMember],
+    levels: &mut Vec<Option<Vec<String>>>,
+    errors: &mut Vec<GrammarError>,
+) {
+    for member in members {
+        if let ASN1Type::Sequence(group) = &mut member.ty {
+            if member.name.starts_with("ext_group_") {
+                link_members_relational_constraints(&mut group.members, levels, errors);
+                continue;
+            }
+        }
+        link_relational_constraints(&mut member.constraints, &member.name, levels, errors);
+        if let Some(constraints) = member.ty.constraints_mut() {
+            link_relational_constraints(constraints, &member.name, levels, errors);
+        }
+        member.ty.link_nested_relational_constraints(levels, errors);
+    }
+}
+
+/// Relations that cannot be resolved are reset to the null level, which linked relations never have.
+fn link_relational_constraints(
+    constraints: &mut [Constraint],
+    component: &str,
+    levels: &[Option<Vec<String>>],
+    errors: &mut Vec<GrammarError>,
+) {
+    for constraint in constraints {
+        let Constraint::TableConstraint(table) = constraint else {
+            continue;
+        };
+        for relation in &mut table.linked_fields {
+            let notation = format!(
+                "@{}{}",
+                ".".repeat(relation.level),
+                [&relation.field_name]
+                    .into_iter()
+                    .chain(&relation.component_path)
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(".")
+            );
+            let index = match relation.level {
+                0 if !levels.is_empty() => Some(0),
+                level if level > 0 && level <= levels.len() => Some(levels.len() - level),
+                _ => None,
+            };
+            let details = match index {
+                Some(index)
+                    if levels[index]
+                        .as_ref()
+                        .is_none_or(|identifiers| identifiers.contains(&relation.field_name)) =>
+                {
+                    relation.level = levels.len() - index;
+                    continue;
+                }
+                Some(_) => format!(
+                    "Relational constraint {notation} of component {component} references an unknown component {}.",
+                    relation.field_name
+                ),
+                None => format!(
+                    "Relational constraint {notation} of component {component} escapes the outermost level of the type."
+                ),
+            };
+            relation.level = 0;
+            errors.push(GrammarError::new(&details, GrammarErrorType::LinkerError));
+        }
+    }
 }
@@ -1,7 +1,10 @@
 use std::collections::BTreeMap;
 
+use chrono::{DateTime, FixedOffset, Utc};
+
 use crate::{
     intermediate::{
+        constraints::{Constraint, Parameter},
         error::{GrammarError, GrammarErrorType},
         information_object::*,
         *,
@@ -35,6 +38,55 @@ pub(crate) fn find_tld_or_enum_value_by_name(
     None
 }
 
+/// Checks that a value of an OID-IRI type starts with a slash and that a value of a
+/// RELATIVE-OID-IRI type does not, and that neither contains empty arcs or whitespace.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §34.3 and §35.3*
+pub(crate) fn validate_iri<S: AsRef<str>>(
+    ty: &ObjectIdentifierIri,
+    value: S,
+) -> Result<S, GrammarError> {
+    let iri = value.as_ref();
+    let arcs = match (iri.strip_prefix('/'), ty.is_relative) {
+        (Some(arcs), false) => Some(arcs),
+        (None, true) => Some(iri),
+        _ => None,
+    };
+    match arcs {
+        Some(arcs)
+            if !arcs
+                .split('/')
+                .any(|arc| arc.is_empty() || arc.contains(char::is_whitespace)) =>
+        {
+            Ok(value)
+        }
+        _ => Err(GrammarError {
+            details: format!(
+                "Invalid {} value \"{iri}\"!",
+                if ty.is_relative {
+                    RELATIVE_OID_IRI
+                } else {
+                    OID_IRI
+                }
+            ),
+            kind: GrammarErrorType::LinkerError,
+        }),
+    }
+}
+
+/// Parses the date and time denoted by a GeneralizedTime or UTCTime value.
+/// UTCTime values are normalized to UTC, as rasn's `UtcTime` does not retain the time difference.
+pub(crate) fn parse_time_value(
+    ty: &ASN1Type,
+    value: &str,
+) -> Result<DateTime<FixedOffset>, GrammarError> {
+    match ty {
+        ASN1Type::UTCTime(_) => {
+            UTCTime::parse_value(value).map(|time| time.with_timezone(&Utc).fixed_offset())
+        }
+        _ => GeneralizedTime::parse_value(value),
+    }
+}
+
 pub(crate) fn octet_string_to_bit_string(bytes: &[u8]) -> Vec<bool> {
     let mut bits = vec![];
     for byte in bytes {
@@ -50,6 +102,58 @@ fn is_bit_set(rem: u8, limit: u8, bits: &mut Vec<bool>) {
     }
 }
 
+/// Converts a value in the `{ IdentifierList }` notation of a `BIT STRING` with named bits
+/// into its bits. The value is lexed either as an object identifier value or as a list of
+/// value references, depending on the number of identifiers.
+/// Returns `None` if the value does not consist of identifiers only.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §22.9*
+pub(crate) fn named_bits_to_bit_string(
+    value: &ASN1Value,
+    bit_string: &BitString,
+) -> Result<Option<Vec<bool>>, GrammarError> {
+    let identifiers = match value {
+        ASN1Value::ObjectIdentifier(ObjectIdentifierValue(arcs)) => arcs
+            .iter()
+            .map(|arc| arc.name.as_deref().filter(|_| arc.number.is_none()))
+            .collect::<Option<Vec<_>>>(),
+        ASN1Value::SequenceOrSet(fields) => fields
+            .iter()
+            .map(|(name, value)| match (name, value.as_ref()) {
+                (
+                    None,
+                    ASN1Value::ElsewhereDeclaredValue {
+                        parent: None,
+                        identifier,
+                    },
+                ) => Some(identifier.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    };
+    let Some(identifiers) = identifiers else {
+        return Ok(None);
+    };
+    let mut bits = vec![];
+    for identifier in identifiers {
+        let index = bit_string
+            .distinguished_values
+            .iter()
+            .flatten()
+            .find_map(|bit| (bit.name == identifier).then_some(bit.value))
+            .and_then(|index| usize::try_from(index).ok())
+            .ok_or_else(|| GrammarError {
+                details: format!("BIT STRING has no named bit `{identifier}`!"),
+                kind: GrammarErrorType::LinkerError,
+            })?;
+        if bits.len() <= index {
+            bits.resize(index + 1, false);
+        }
+        bits[index] = true;
+    }
+    Ok(Some(bits))
+}
+
 pub(crate) fn bit_string_to_octet_string(bits: &[bool]) -> Result<Vec<u8>, GrammarError> {
     let mut octets = vec![];
     for byte in bits.chunks(8) {
@@ -66,26 +170,111 @@ pub(crate) fn bit_string_to_octet_string(bits: &[bool]) -> Result<Vec<u8>, Gramm
     Ok(octets)
 }
 
-pub(crate) fn walk_object_field_ref_path<'a>(
-    fields: &'a Vec<InformationObjectClassField>,
-    path: &'a Vec<ObjectFieldIdentifier>,
-    mut index: usize,
-) -> Option<&'a InformationObjectClassField> {
-    fields
-        .iter()
-        .find_map(|f| {
-            path.get(index).and_then(|id| {
-                (&f.identifier == id).then(|| {
-                    if path.len() == (index + 1) {
-                        Some(f)
-                    } else {
-                        index += 1;
-                        walk_object_field_ref_path(fields, path, index)
+/// Encodes the arcs of a RELATIVE-OID value as the contents octets of
+/// Rec. ITU-T X.690 (02/2021) §8.20, which the packed encoding rules reuse.
+/// Arcs that reference other RELATIVE-OID values are replaced by the referenced arcs.
+/// `resolving` holds the names of the values whose arcs are currently being encoded.
+pub(crate) fn relative_oid_to_octet_string(
+    oid: &ObjectIdentifierValue,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+    resolving: &mut Vec<String>,
+) -> Result<Vec<u8>, GrammarError> {
+    let mut octets = vec![];
+    for arc in &oid.0 {
+        match (arc.number, &arc.name) {
+            (Some(mut number), _) => {
+                let mut arc_octets = vec![(number & 0x7F) as u8];
+                number >>= 7;
+                while number > 0 {
+                    arc_octets.push((number & 0x7F) as u8 | 0x80);
+                    number >>= 7;
+                }
+                octets.extend(arc_octets.into_iter().rev());
+            }
+            (None, Some(name)) if !resolving.contains(name) => {
+                let referenced = match tlds.get(name) {
+                    Some(ToplevelDefinition::Value(ToplevelValueDefinition {
+                        value: ASN1Value::LinkedNestedValue { value, .. },
+                        ..
+                    })) => Some(&**value),
+                    Some(ToplevelDefinition::Value(ToplevelValueDefinition { value, .. })) => {
+                        Some(value)
+                    }
+                    _ => None,
+                };
+                match referenced {
+                    // The referenced value has already been linked
+                    Some(ASN1Value::OctetString(o)) => octets.extend(o),
+                    Some(ASN1Value::ObjectIdentifier(referenced)) => {
+                        resolving.push(name.clone());
+                        octets.extend(relative_oid_to_octet_string(referenced, tlds, resolving)?);
+                        resolving.pop();
                     }
+                    _ => {
+                        return Err(GrammarError {
+                            details: format!("Failed to resolve arc {name} of RELATIVE-OID value!"),
+                            kind: GrammarErrorType::LinkerError,
+                        })
+                    }
+                }
+            }
+            (None, name) => {
+                return Err(GrammarError {
+                    details: format!(
+                        "RELATIVE-OID value {} references itself!",
+                        name.as_deref().unwrap_or_default()
+                    ),
+                    kind: GrammarErrorType::LinkerError,
                 })
-            })
-        })
-        .flatten()
+            }
+        }
+    }
+    Ok(octets)
+}
+
+/// Walks the field path of an information object field reference, such as `&outer.&inner.&id`.
+/// Every segment except for the last one needs to identify an object field, whose class is
+/// looked up in the `tlds` to resolve the next segment.
+/// `visited` holds the classes of the path that have already been walked.
+pub(crate) fn walk_object_field_ref_path<'a>(
+    class: &'a InformationObjectClass,
+    path: &[ObjectFieldIdentifier],
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+    visited: &mut Vec<&'a InformationObjectClass>,
+) -> Result<Option<&'a InformationObjectClassField>, GrammarError> {
+    let Some((id, remaining_path)) = path.split_first() else {
+        return Ok(None);
+    };
+    let Some(field) = class.fields.iter().find(|f| &f.identifier == id) else {
+        return Ok(None);
+    };
+    if remaining_path.is_empty() {
+        return Ok(Some(field));
+    }
+    let Some(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+        identifier: class_name,
+        ..
+    })) = &field.ty
+    else {
+        return Ok(None);
+    };
+    let Some(nested_class) = tlds
+        .get(class_name)
+        .and_then(|c| c.is_class_with_name(class_name))
+    else {
+        return Ok(None);
+    };
+    visited.push(class);
+    if visited.iter().any(|c| std::ptr::eq(*c, nested_class)) {
+        return Err(GrammarError {
+            details: format!(
+                "Cyclic reference to information object class {class_name} in field {}!",
+                id.identifier()
+            ),
+            kind: GrammarErrorType::LinkerError,
+        });
+    }
+    walk_object_field_ref_path(nested_class, remaining_path, tlds, visited)
 }
 
 /// Resolves the custom syntax declared in an information object class' WITH SYNTAX clause
@@ -104,6 +293,8 @@ pub fn resolve_custom_syntax(
             }
         };
 
+        split_literals_with_arguments(application, &tokens);
+
         let mut unsorted_default_syntax = Vec::<(usize, InformationObjectField)>::new();
 
         let mut application_index = 0;
@@ -209,7 +400,7 @@ pub fn resolve_custom_syntax(
                                 ));
                             }
                         }
-                        _ => continue 'syntax_matching,
+                        SyntaxApplication::Literal(_) | SyntaxApplication::Comma => (),
                     }
                     application_index += 1;
                 } else if *required {
@@ -240,6 +431,59 @@ pub fn resolve_custom_syntax(
     Ok(())
 }
 
+/// The lexer cannot tell a syntax literal followed by a braced object set or value,
+/// e.g. `ERRORS { err-a }`, from a parameterized type reference.
+/// Such references are split back into the literal and the object set or value
+/// if the identifier is a literal of the class syntax.
+fn split_literals_with_arguments(
+    application: &mut Vec<SyntaxApplication>,
+    tokens: &[(bool, SyntaxToken)],
+) {
+    *application = std::mem::take(application)
+        .into_iter()
+        .flat_map(|expr| {
+            let SyntaxApplication::LiteralOrTypeReference(DeclarationElsewhere {
+                identifier,
+                constraints,
+                ..
+            }) = &expr
+            else {
+                return vec![expr];
+            };
+            let [Constraint::Parameter(parameters)] = constraints.as_slice() else {
+                return vec![expr];
+            };
+            let next_token = tokens
+                .iter()
+                .position(|(_, t)| matches!(t, SyntaxToken::Literal(l) if l == identifier))
+                .and_then(|i| tokens.get(i + 1));
+            let argument = match (next_token, parameters.as_slice()) {
+                (Some((_, SyntaxToken::Field(ObjectFieldIdentifier::MultipleValue(_)))), _) => {
+                    parameters
+                        .iter()
+                        .map(Parameter::object_set_values)
+                        .collect::<Option<Vec<_>>>()
+                        .map(|values| {
+                            SyntaxApplication::ObjectSetDeclaration(ObjectSet {
+                                values: values.concat(),
+                                extensible: None,
+                            })
+                        })
+                }
+                (
+                    Some((_, SyntaxToken::Field(ObjectFieldIdentifier::SingleValue(_)))),
+                    [Parameter::ValueParameter(v)],
+                ) => Some(SyntaxApplication::ValueReference(v.clone())),
+                _ => None,
+            };
+            match argument {
+                Some(argument) => vec![SyntaxApplication::Literal(identifier.clone()), argument],
+                None => vec![expr],
+            }
+        })
+        .collect();
+}
+
 pub(crate) fn built_in_type(associated_type: &str) -> Option<ASN1Type> {
     match associated_type {
         INTEGER => Some(ASN1Type::Integer(Integer {
@@ -264,6 +508,11 @@ pub(crate) fn built_in_type(associated_type: &str) -> Option<ASN1Type> {
         })),
         OBJECT_IDENTIFIER => Some(ASN1Type::ObjectIdentifier(ObjectIdentifier {
             constraints: vec![],
+            is_relative: false,
+        })),
+        RELATIVE_OID => Some(ASN1Type::ObjectIdentifier(ObjectIdentifier {
+            constraints: vec![],
+            is_relative: true,
         })),
         ty if ty.contains(SEQUENCE_OF) => {
             let identifier = ty.replace(SEQUENCE_OF, "").trim().to_string();
@@ -297,7 +546,18 @@ pub(crate) fn built_in_type(associated_type: &str) -> Option<ASN1Type> {
 
 #[cfg(test)]
 mod tests {
-    use crate::validator::linking::utils::octet_string_to_bit_string;
+    use std::collections::BTreeMap;
+
+    use crate::{
+        intermediate::{
+            types::{BitString, DistinguishedValue, ObjectIdentifierIri},
+            ASN1Value, ObjectIdentifierArc, ObjectIdentifierValue,
+        },
+        validator::linking::utils::{
+            named_bits_to_bit_string, octet_string_to_bit_string, relative_oid_to_octet_string,
+            validate_iri,
+        },
+    };
 
     #[test]
     fn converts_octet_to_bit_string() {
@@ -329,4 +589,105 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn converts_named_bits_to_bit_string() {
+        let bit_string = BitString {
+            constraints: vec![],
+            distinguished_values: Some(vec![
+                DistinguishedValue {
+                    name: "ack".into(),
+                    value: 0,
+                },
+                DistinguishedValue {
+                    name: "retry".into(),
+                    value: 2,
+                },
+            ]),
+        };
+        let reference = |identifier: &str| {
+            (
+                None,
+                Box::new(ASN1Value::ElsewhereDeclaredValue {
+                    parent: None,
+                    identifier: identifier.into(),
+                }),
+            )
+        };
+        assert_eq!(
+            named_bits_to_bit_string(
+                &ASN1Value::SequenceOrSet(vec![reference("ack"), reference("retry")]),
+                &bit_string
+            )
+            .unwrap(),
+            Some(vec![true, false, true])
+        );
+        assert_eq!(
+            named_bits_to_bit_string(
+                &ASN1Value::ObjectIdentifier(ObjectIdentifierValue(vec![
+                    ObjectIdentifierArc::from("retry")
+                ])),
+                &bit_string
+            )
+            .unwrap(),
+            Some(vec![false, false, true])
+        );
+        assert_eq!(
+            named_bits_to_bit_string(
+                &ASN1Value::ObjectIdentifier(ObjectIdentifierValue(vec![
+                    ObjectIdentifierArc::from(1)
+                ])),
+                &bit_string
+            )
+            .unwrap(),
+            None
+        );
+        assert!(named_bits_to_bit_string(
+            &ASN1Value::SequenceOrSet(vec![reference("nack")]),
+            &bit_string
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn encodes_relative_oid_arcs() {
+        assert_eq!(
+            relative_oid_to_octet_string(
+                &ObjectIdentifierValue(vec![
+                    ObjectIdentifierArc::from(5),
+                    ObjectIdentifierArc::from(("large", 200)),
+                    ObjectIdentifierArc::from(16384),
+                ]),
+                &BTreeMap::new(),
+                &mut vec![]
+            )
+            .unwrap(),
+            vec![5, 0x81, 0x48, 0x81, 0x80, 0x00]
+        );
+        assert!(relative_oid_to_octet_string(
+            &ObjectIdentifierValue(vec![ObjectIdentifierArc::from("unknown")]),
+            &BTreeMap::new(),
+            &mut vec![]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn validates_iri_values() {
+        let oid_iri = ObjectIdentifierIri {
+            constraints: vec![],
+            is_relative: false,
+        };
+        let relative_oid_iri = ObjectIdentifierIri {
+            constraints: vec![],
+            is_relative: true,
+        };
+        assert!(validate_iri(&oid_iri, "/ISO/Registration_Authority/19785.CBEFF").is_ok());
+        assert!(validate_iri(&oid_iri, "ISO/Registration_Authority").is_err());
+        assert!(validate_iri(&oid_iri, "/ISO//19785.CBEFF").is_err());
+        assert!(validate_iri(&oid_iri, "/").is_err());
+        assert!(validate_iri(&relative_oid_iri, "Organizations/JTC1-SC37").is_ok());
+        assert!(validate_iri(&relative_oid_iri, "/Organizations").is_err());
+        assert!(validate_iri(&relative_oid_iri, "Organizations/ JTC1-SC37").is_err());
+    }
 }
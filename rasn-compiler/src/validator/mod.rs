@@ -5,8 +5,13 @@
 //! assures that all dependencies of the individual
 //! data elements resolve, and checks for conflicting
 //! constraints and value definitions.
+mod deduplication;
+mod dependencies;
 pub(crate) mod error;
+mod exclusion;
+mod inner_subtyping;
 mod linking;
+mod tagging;
 #[cfg(test)]
 mod tests;
 
@@ -20,12 +25,18 @@ use std::{
 
 use crate::intermediate::{
     constraints::*,
+    encoding_rules::per_visible::{per_visible_range_constraints, PerVisibleRangeConstraints},
     information_object::{ClassLink, ToplevelInformationDefinition},
     types::*,
     *,
 };
 
+pub(crate) use self::{
+    deduplication::deduplicate_types, dependencies::dependency_graph,
+    exclusion::exclude_definitions, tagging::materialize_automatic_tags,
+};
 use self::{
+    dependencies::link_order,
     error::{ValidatorError, ValidatorErrorType},
     information_object::{
         ASN1Information, InformationObjectClass, InformationObjectClassField, ObjectSet,
@@ -34,38 +45,116 @@ use self::{
 
 pub struct Validator {
     tlds: BTreeMap<String, ToplevelDefinition>,
+    /// Names of the built-in information object classes that were added to `tlds`
+    built_in_classes: Vec<String>,
+    /// Types with inconsistent inner subtyping constraints, which are checked before
+    /// the presence constraints among them reshape the constrained types
+    inner_subtyping_errors: BTreeMap<String, ValidatorError>,
 }
 
 impl Validator {
     pub fn new(tlds: Vec<ToplevelDefinition>) -> Validator {
+        let mut tlds = tlds
+            .into_iter()
+            .map(|tld| (tld.name().to_owned(), tld))
+            .collect::<BTreeMap<_, _>>();
+        // Built-in classes are only added if the specification does not declare a class of the same name
+        let mut built_in_classes = vec![];
+        for name in [TYPE_IDENTIFIER, ABSTRACT_SYNTAX] {
+            if let (false, Some(class)) = (
+                tlds.contains_key(name),
+                InformationObjectClass::built_in(name),
+            ) {
+                tlds.insert(
+                    name.to_owned(),
+                    ToplevelDefinition::Information(ToplevelInformationDefinition {
+                        comments: String::new(),
+                        name: name.to_owned(),
+                        parameterization: None,
+                        class: None,
+                        value: ASN1Information::ObjectClass(class),
+                        index: None,
+                    }),
+                );
+                built_in_classes.push(name.to_owned());
+            }
+        }
         Self {
-            tlds: tlds
-                .into_iter()
-                .map(|tld| (tld.name().to_owned(), tld))
-                .collect(),
+            tlds,
+            built_in_classes,
+            inner_subtyping_errors: BTreeMap::new(),
         }
     }
 
     fn link(mut self) -> Result<(Self, Vec<Box<dyn Error>>), ValidatorError> {
         let mut warnings: Vec<Box<dyn Error>> = vec![];
+        // Class assignments like `PROTOCOL-IES ::= BOUNDED-IES {255}` are lexed as type assignments.
+        // They are replaced by the assigned classes until no assignments of (aliased) classes remain.
+        let mut failed_class_assignments = HashSet::<String>::new();
+        loop {
+            let class_assignments = self
+                .tlds
+                .iter()
+                .filter_map(|(key, tld)| match tld {
+                    ToplevelDefinition::Type(t) if !failed_class_assignments.contains(key) => t
+                        .as_class_assignment(&self.tlds)
+                        .map(|assignment| (key.clone(), assignment)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if class_assignments.is_empty() {
+                break;
+            }
+            for (key, assignment) in class_assignments {
+                match assignment {
+                    Ok(class) => {
+                        self.tlds
+                            .insert(key, ToplevelDefinition::Information(class));
+                    }
+                    Err(e) => {
+                        failed_class_assignments.insert(key);
+                        warnings.push(Box::new(e));
+                    }
+                }
+            }
+        }
+        let (type_order, cycle_errors) = link_order(&self.tlds);
+        warnings.extend(
+            cycle_errors
+                .into_iter()
+                .map(|e| Box::new(e) as Box<dyn Error>),
+        );
+        // Relational constraints are resolved against the textual nesting of a type,
+        // before parameterized types and references are inlined
+        for tld in self.tlds.values_mut() {
+            if let ToplevelDefinition::Type(t) = tld {
+                warnings.extend(
+                    t.ty.link_relational_constraints()
+                        .into_iter()
+                        .map(|e| Box::new(e) as Box<dyn Error>),
+                );
+            }
+        }
         // Linking of ASN1 values depends on linked ASN1 types, so we order the key colelction accordingly (note that we pop keys)
+        // Types are linked after the types that they reference
         let mut keys = self
             .tlds
             .iter()
             .filter_map(|(k, v)| matches![v, ToplevelDefinition::Value(_)].then_some(k.clone()))
             .chain(self.tlds.iter().filter_map(|(k, v)| {
-                matches![v, ToplevelDefinition::Value(_)]
-                    .not()
-                    .then_some(k.clone())
+                matches![v, ToplevelDefinition::Information(_)].then_some(k.clone())
             }))
+            .chain(type_order.into_iter().rev())
             .collect::<Vec<String>>();
         let mut visited_headers = HashSet::<String>::new();
         while let Some(key) = keys.pop() {
+            // Parameterized object sets are only resolved where they are instantiated
             if matches![
                 self.tlds.get(&key),
                 Some(ToplevelDefinition::Information(
                     ToplevelInformationDefinition {
                         value: ASN1Information::ObjectSet(ObjectSet { .. }),
+                        parameterization: None,
                         ..
                     }
                 ))
@@ -90,7 +179,10 @@ impl Validator {
             if self.references_class_by_name(&key) {
                 match self.tlds.remove_entry(&key) {
                     Some((k, ToplevelDefinition::Type(mut tld))) => {
-                        tld.ty = tld.ty.resolve_class_reference(&self.tlds);
+                        match tld.ty.clone().resolve_class_reference(&self.tlds) {
+                            Ok(ty) => tld.ty = ty,
+                            Err(e) => warnings.push(Box::new(e)),
+                        }
                         self.tlds.insert(k, ToplevelDefinition::Type(tld));
                     }
                     Some((k, ToplevelDefinition::Information(mut tld))) => {
@@ -116,8 +208,18 @@ impl Validator {
             }
             if self.has_choice_selection_type(&key) {
                 if let Some((k, ToplevelDefinition::Type(mut tld))) = self.tlds.remove_entry(&key) {
-                    if let Err(e) = tld.ty.link_choice_selection_type(&self.tlds) {
-                        warnings.push(Box::new(e));
+                    let automatic_tagging = match &tld.index {
+                        Some((module, _)) => {
+                            module.borrow().tagging_environment == TaggingEnvironment::Automatic
+                        }
+                        None => true,
+                    };
+                    match tld
+                        .ty
+                        .link_choice_selection_type(&self.tlds, automatic_tagging)
+                    {
+                        Ok(tag) => tld.tag = tld.tag.take().or(tag),
+                        Err(e) => warnings.push(Box::new(e)),
                     }
                     self.tlds.insert(k, ToplevelDefinition::Type(tld));
                 }
@@ -162,6 +264,51 @@ impl Validator {
                 self.fill_in_associated_type_imports(key, &mut visited_headers);
             }
         }
+        self.inner_subtyping_errors = self
+            .tlds
+            .values()
+            .filter_map(|tld| match tld {
+                ToplevelDefinition::Type(t) => {
+                    inner_subtyping::validate_inner_subtyping(t, &self.tlds)
+                        .err()
+                        .map(|e| (t.name.clone(), e))
+                }
+                _ => None,
+            })
+            .collect();
+        // Presence constraints are linked once all referenced types are complete
+        let presence_constrained = self
+            .tlds
+            .iter()
+            .filter_map(|(k, v)| self.has_presence_constraint(v).then_some(k.clone()))
+            .collect::<Vec<String>>();
+        for key in presence_constrained {
+            if let Some((k, ToplevelDefinition::Type(mut tld))) = self.tlds.remove_entry(&key) {
+                match &mut tld.ty {
+                    ASN1Type::ElsewhereDeclaredType(e) => {
+                        match e.link_presence_constraint(&self.tlds) {
+                            Ok(Some(linked)) => tld.ty = linked,
+                            Ok(None) => (),
+                            Err(e) => warnings.push(Box::new(e)),
+                        }
+                    }
+                    // Components with presence constraints are replaced by an inner type
+                    ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                        for member in &mut s.members {
+                            if let ASN1Type::ElsewhereDeclaredType(e) = &member.ty {
+                                match e.link_presence_constraint(&self.tlds) {
+                                    Ok(Some(linked)) => member.ty = linked,
+                                    Ok(None) => (),
+                                    Err(e) => warnings.push(Box::new(e)),
+                                }
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+                self.tlds.insert(k, ToplevelDefinition::Type(tld));
+            }
+        }
 
         Ok((self, warnings))
     }
@@ -369,15 +516,52 @@ impl Validator {
             .unwrap_or(false)
     }
 
+    fn has_presence_constraint(&self, tld: &ToplevelDefinition) -> bool {
+        match tld {
+            ToplevelDefinition::Type(ToplevelTypeDefinition {
+                ty: ASN1Type::ElsewhereDeclaredType(e),
+                parameterization: None,
+                ..
+            }) => e.presence_constraint().is_some(),
+            ToplevelDefinition::Type(ToplevelTypeDefinition {
+                ty: ASN1Type::Sequence(s) | ASN1Type::Set(s),
+                parameterization: None,
+                ..
+            }) => s.members.iter().any(|m| {
+                matches!(&m.ty, ASN1Type::ElsewhereDeclaredType(e) if e.presence_constraint().is_some())
+            }),
+            _ => false,
+        }
+    }
+
     pub fn validate(
         mut self,
     ) -> Result<(Vec<ToplevelDefinition>, Vec<Box<dyn Error>>), Box<dyn Error>> {
         let warnings: Vec<Box<dyn Error>>;
         (self, warnings) = self.link()?;
+        for name in &self.built_in_classes {
+            self.tlds.remove(name);
+        }
+        let mut tagging_errors = self
+            .tlds
+            .values()
+            .filter_map(|tld| match tld {
+                ToplevelDefinition::Type(t) => tagging::validate_choice_tags(t, &self.tlds)
+                    .and_then(|_| tagging::validate_components_of_tags(t, &self.tlds))
+                    .err()
+                    .map(|e| (t.name.clone(), e)),
+                _ => None,
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut inner_subtyping_errors = std::mem::take(&mut self.inner_subtyping_errors);
         Ok(self.tlds.into_iter().fold(
             (Vec::<ToplevelDefinition>::new(), warnings),
-            |(mut tlds, mut errors), (_, tld)| {
-                match tld.validate() {
+            |(mut tlds, mut errors), (name, tld)| {
+                match inner_subtyping_errors
+                    .remove(&name)
+                    .or_else(|| tagging_errors.remove(&name))
+                    .map_or_else(|| tld.validate(), Err)
+                {
                     Ok(_) => tlds.push(tld),
                     Err(e) => errors.push(Box::new(e)),
                 }
@@ -401,7 +585,12 @@ impl Validate for ToplevelDefinition {
                 }
                 Ok(())
             }
-            ToplevelDefinition::Value(_v) => Ok(()),
+            ToplevelDefinition::Value(v) => validate_time_value(&v.associated_type, &v.value)
+                .and_then(|_| validate_string_value(&v.associated_type, &v.value))
+                .map_err(|mut e| {
+                    e.specify_data_element(v.name.clone());
+                    e
+                }),
             ToplevelDefinition::Information(_i) => Ok(()),
         }
     }
@@ -409,15 +598,182 @@ impl Validate for ToplevelDefinition {
 
 impl Validate for ASN1Type {
     fn validate(&self) -> Result<(), ValidatorError> {
+        for c in self.constraints().into_iter().flatten() {
+            match c {
+                Constraint::SubtypeConstraint(ElementSet { set, .. }) => {
+                    validate_applicability(self, set)?
+                }
+                Constraint::ContentConstraint(_) => validate_contents_applicability(self)?,
+                _ => (),
+            }
+        }
         match self {
             ASN1Type::Integer(ref i) => i.validate(),
+            ASN1Type::Real(ref r) => r.validate(),
             ASN1Type::BitString(ref b) => b.validate(),
+            ASN1Type::OctetString(ref o) => o.validate(),
             ASN1Type::CharacterString(ref o) => o.validate(),
+            ASN1Type::Enumerated(ref e) => e.validate(),
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => s.members.iter().try_for_each(|m| {
+                m.ty.validate()?;
+                m.default_value.as_ref().map_or(Ok(()), |value| {
+                    validate_time_value(&m.ty, value)?;
+                    validate_string_value(&m.ty, value)
+                })
+            }),
+            ASN1Type::Choice(c) => c.options.iter().try_for_each(|o| o.ty.validate()),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => s.element_type.validate(),
             _ => Ok(()),
         }
     }
 }
 
+/// Time values must denote a valid date and time of day.
+/// *See Rec. ITU-T X.680 (02/2021) §46.3 and §47.3*
+fn validate_time_value(ty: &ASN1Type, value: &ASN1Value) -> Result<(), ValidatorError> {
+    let parsed = match (ty, value) {
+        (ASN1Type::GeneralizedTime(_), ASN1Value::Time(t)) => GeneralizedTime::parse_value(t),
+        (ASN1Type::UTCTime(_), ASN1Value::Time(t)) => UTCTime::parse_value(t),
+        _ => return Ok(()),
+    };
+    parsed
+        .map(|_| ())
+        .map_err(|e| ValidatorError::new(None, &e.details, ValidatorErrorType::InvalidValueError))
+}
+
+/// Character string values must only contain characters of the repertoire of their string type.
+/// Values are linked to their base string type, so that values of references are checked as well.
+fn validate_string_value(ty: &ASN1Type, value: &ASN1Value) -> Result<(), ValidatorError> {
+    match (ty, value) {
+        (ASN1Type::CharacterString(c), ASN1Value::String(s)) => validate_characters(c.ty, s),
+        (_, ASN1Value::LinkedCharStringValue(string_type, s)) => {
+            validate_characters(*string_type, s)
+        }
+        (_, ASN1Value::LinkedNestedValue { value, .. }) => validate_string_value(ty, value),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the characters of a string against the permitted characters of `NumericString`,
+/// `PrintableString`, and `IA5String`, and checks that `GraphicString` and `ObjectDescriptor`
+/// values do not contain control characters. *See Rec. ITU-T X.680 (02/2021) §41.2, §41.4, and §48.1*
+fn validate_characters(string_type: CharacterStringType, s: &str) -> Result<(), ValidatorError> {
+    let (name, permitted): (&str, fn(&char) -> bool) = match string_type {
+        CharacterStringType::NumericString => {
+            (NUMERIC_STRING, |c| NUMERIC_STRING_CHARSET.contains(c))
+        }
+        CharacterStringType::PrintableString => {
+            (PRINTABLE_STRING, |c| PRINTABLE_STRING_CHARSET.contains(c))
+        }
+        CharacterStringType::IA5String => (IA5_STRING, char::is_ascii),
+        CharacterStringType::GraphicString => (GRAPHIC_STRING, |c| !c.is_control()),
+        CharacterStringType::ObjectDescriptor => (OBJECT_DESCRIPTOR, |c| !c.is_control()),
+        _ => return Ok(()),
+    };
+    match s.chars().enumerate().find(|(_, c)| !permitted(c)) {
+        Some((index, c)) => Err(ValidatorError::new(
+            None,
+            &format!(
+                "The {name} value \"{s}\" contains the character '{c}' at index {index}, which {name} does not permit!"
+            ),
+            ValidatorErrorType::InvalidValueError,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the kinds of the subtype elements of a constraint apply to the constrained type.
+/// Value ranges only apply to numeric, enumerated, and time types, `SIZE` constraints only
+/// apply to string and collection types, and `FROM` constraints only apply to character strings.
+/// The constraints of references are not checked, since their type is not known at this point.
+fn validate_applicability(
+    ty: &ASN1Type,
+    set: &ElementOrSetOperation,
+) -> Result<(), ValidatorError> {
+    match set {
+        ElementOrSetOperation::Element(element) => validate_element_applicability(ty, element),
+        ElementOrSetOperation::SetOperation(SetOperation { base, operant, .. }) => {
+            validate_element_applicability(ty, base)?;
+            validate_applicability(ty, operant)
+        }
+    }
+}
+
+fn validate_element_applicability(
+    ty: &ASN1Type,
+    element: &SubtypeElement,
+) -> Result<(), ValidatorError> {
+    let (kind, applicable) = match element {
+        SubtypeElement::ValueRange { min, max, .. } => (
+            "value range",
+            match ty {
+                ASN1Type::Integer(_)
+                | ASN1Type::Real(_)
+                | ASN1Type::Enumerated(_)
+                | ASN1Type::Time(_) => true,
+                // Some specifications restrict the content of OCTET STRINGs by ranges of hexadecimal values
+                ASN1Type::OctetString(_) => min
+                    .iter()
+                    .chain(max)
+                    .all(|endpoint| endpoint.as_octets().is_some()),
+                _ => false,
+            },
+        ),
+        SubtypeElement::SizeConstraint(_) => (
+            "SIZE",
+            matches!(
+                ty,
+                ASN1Type::BitString(_)
+                    | ASN1Type::OctetString(_)
+                    | ASN1Type::CharacterString(_)
+                    | ASN1Type::SequenceOf(_)
+                    | ASN1Type::SetOf(_)
+            ),
+        ),
+        SubtypeElement::PermittedAlphabet(_) => {
+            ("FROM", matches!(ty, ASN1Type::CharacterString(_)))
+        }
+        SubtypeElement::NestedSet(set) => {
+            validate_element_applicability(ty, &set.base)?;
+            return validate_applicability(ty, &set.operant);
+        }
+        _ => return Ok(()),
+    };
+    if applicable
+        || matches!(
+            ty,
+            ASN1Type::ElsewhereDeclaredType(_) | ASN1Type::InformationObjectFieldReference(_)
+        )
+    {
+        Ok(())
+    } else {
+        Err(ValidatorError::new(
+            None,
+            &format!("A {kind} constraint cannot be applied to {}!", ty.as_str()),
+            ValidatorErrorType::InvalidConstraintsError,
+        ))
+    }
+}
+
+/// Contents constraints can only be applied to `BIT STRING` and `OCTET STRING` types.
+/// *As defined in Rec. ITU-T X.682 (02/2021) §11.1*
+fn validate_contents_applicability(ty: &ASN1Type) -> Result<(), ValidatorError> {
+    match ty {
+        ASN1Type::BitString(_)
+        | ASN1Type::OctetString(_)
+        | ASN1Type::ElsewhereDeclaredType(_)
+        | ASN1Type::InformationObjectFieldReference(_) => Ok(()),
+        _ => Err(ValidatorError::new(
+            None,
+            &format!(
+                "A contents constraint cannot be applied to {}!",
+                ty.as_str()
+            ),
+            ValidatorErrorType::InvalidConstraintsError,
+        )),
+    }
+}
+
 impl Validate for Integer {
     fn validate(&self) -> Result<(), ValidatorError> {
         for c in &self.constraints {
@@ -427,24 +783,257 @@ impl Validate for Integer {
     }
 }
 
-impl Validate for BitString {
+impl Validate for Real {
     fn validate(&self) -> Result<(), ValidatorError> {
         for c in &self.constraints {
             c.validate()?;
+            if let Constraint::SubtypeConstraint(ElementSet {
+                set:
+                    ElementOrSetOperation::Element(SubtypeElement::SingleTypeConstraint(
+                        InnerTypeConstraint { constraints, .. },
+                    )),
+                ..
+            }) = c
+            {
+                for component in constraints {
+                    for inner in &component.constraints {
+                        inner.validate()?;
+                    }
+                    if component.identifier == "base" {
+                        validate_real_base(&component.constraints)?;
+                    }
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// Rec. ITU-T X.680 §21.2 only permits the bases 2 and 10 for REAL values.
+fn validate_real_base(constraints: &[Constraint]) -> Result<(), ValidatorError> {
+    for c in constraints {
+        if let Constraint::SubtypeConstraint(ElementSet { set, .. }) = c {
+            let (min, max) = match set {
+                ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                    value: ASN1Value::Integer(v),
+                    ..
+                }) => (Some(*v), Some(*v)),
+                ElementOrSetOperation::Element(SubtypeElement::ValueRange { min, max, .. }) => (
+                    min.as_ref().and_then(|v| v.unwrap_as_integer().ok()),
+                    max.as_ref().and_then(|v| v.unwrap_as_integer().ok()),
+                ),
+                _ => continue,
+            };
+            if min != max || !matches!(min, Some(2) | Some(10)) {
+                return Err(ValidatorError::new(
+                    None,
+                    "The base of a REAL value must be either 2 or 10!",
+                    ValidatorErrorType::InvalidConstraintsError,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The indices of the named bits of a BIT STRING must not be negative
+/// and must lie within the maximum size of the BIT STRING.
+impl Validate for BitString {
+    fn validate(&self) -> Result<(), ValidatorError> {
+        for c in &self.constraints {
+            c.validate()?;
+        }
+        let Some(named_bits) = &self.distinguished_values else {
+            return Ok(());
+        };
+        let error = |details: String| {
+            ValidatorError::new(None, &details, ValidatorErrorType::InvalidConstraintsError)
+        };
+        if let Some(bit) = named_bits.iter().find(|bit| bit.value < 0) {
+            return Err(error(format!(
+                "The named bit `{}` of a BIT STRING has the negative index {}!",
+                bit.name, bit.value
+            )));
+        }
+        let size = per_visible_range_constraints(false, &self.constraints)
+            .map_err(|e| error(e.details))?;
+        if !size.is_size_constraint() || size.is_extensible() {
+            return Ok(());
+        }
+        match (
+            size.max::<i128>(),
+            named_bits.iter().max_by_key(|bit| bit.value),
+        ) {
+            (Some(max_size), Some(bit)) if bit.value >= max_size => Err(error(format!(
+                "The named bit `{}` has the index {}, which exceeds the maximum size {max_size} of the BIT STRING!",
+                bit.name, bit.value
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The endpoints of a value range of an OCTET STRING must have the same length,
+/// which must satisfy the size constraint of the OCTET STRING.
+impl Validate for OctetString {
+    fn validate(&self) -> Result<(), ValidatorError> {
+        for c in &self.constraints {
+            c.validate()?;
+        }
+        let Some(OctetStringValueRange { min, max }) = self.value_range() else {
+            return Ok(());
+        };
+        let error = |details: String| {
+            ValidatorError::new(None, &details, ValidatorErrorType::InvalidConstraintsError)
+        };
+        let lengths = min.iter().chain(&max).map(Vec::len).collect::<Vec<_>>();
+        if lengths.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(error(format!(
+                "The endpoints of the value range of an OCTET STRING differ in length ({} and {} octets)!",
+                lengths[0], lengths[1]
+            )));
+        }
+        let size = per_visible_range_constraints(false, &self.constraints)
+            .map_err(|e| error(e.details))?;
+        if !size.is_size_constraint() {
+            return Ok(());
+        }
+        let (min_size, max_size) = (size.min::<usize>(), size.max::<usize>());
+        match lengths.first() {
+            Some(length)
+                if min_size.is_some_and(|min| *length < min)
+                    || max_size.is_some_and(|max| *length > max) =>
+            {
+                Err(error(format!(
+                    "The endpoints of the value range of an OCTET STRING are {length} octets long, which violates its size constraint!"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Members of an ENUMERATED are identifiers as defined in Rec. ITU-T X.680 (02/2021) §12.3.
+/// Since enumerals need not be separated by commas, a typed alternative such as `a INTEGER`
+/// is parsed as two enumerals, the second of which does not start with a lowercase letter.
+impl Validate for Enumerated {
+    fn validate(&self) -> Result<(), ValidatorError> {
+        for c in &self.constraints {
+            c.validate()?;
+        }
+        match self
+            .members
+            .iter()
+            .find(|m| !m.name.starts_with(|c: char| c.is_ascii_lowercase()))
+        {
+            Some(member) => Err(ValidatorError::new(
+                None,
+                &format!(
+                    "ENUMERATED member `{}` is not an identifier. Did you mean CHOICE?",
+                    member.name
+                ),
+                ValidatorErrorType::InvalidEnumerationError,
+            )),
+            None => validate_enumeral_numbers(self),
+        }
+    }
+}
+
+/// The numbers of all enumerals must be distinct, across the boundary between the root and the
+/// extension additions. The numbers of the extension additions must be ascending.
+/// Enumerals numbered by an unresolved value reference are not checked.
+/// *See Rec. ITU-T X.680 (02/2021) §20.3 and §20.5*
+fn validate_enumeral_numbers(enumerated: &Enumerated) -> Result<(), ValidatorError> {
+    let numbered = enumerated
+        .members
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| !matches!(m.numbering, EnumeralNumbering::Reference(_)))
+        .collect::<Vec<_>>();
+    for (i, (_, member)) in numbered.iter().enumerate() {
+        if let Some((_, other)) = numbered[..i].iter().find(|(_, o)| o.index == member.index) {
+            return Err(ValidatorError::new(
+                None,
+                &format!(
+                    "ENUMERATED members `{}` and `{}` share the number {}!",
+                    other.name, member.name, member.index
+                ),
+                ValidatorErrorType::InvalidEnumerationError,
+            ));
+        }
+    }
+    let first_extension = enumerated.extensible.unwrap_or(usize::MAX);
+    let additions = numbered
+        .iter()
+        .filter(|(index, _)| *index >= first_extension)
+        .map(|(_, m)| m)
+        .collect::<Vec<_>>();
+    match additions.windows(2).find(|pair| pair[1].index < pair[0].index) {
+        Some(pair) => Err(ValidatorError::new(
+            None,
+            &format!(
+                "ENUMERATED extension addition `{}`({}) must be numbered higher than the preceding extension addition `{}`({})!",
+                pair[1].name, pair[1].index, pair[0].name, pair[0].index
+            ),
+            ValidatorErrorType::InvalidEnumerationError,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The string values of single value, value range, and permitted alphabet constraints
+/// must only contain characters that the constrained string type permits.
 impl Validate for CharacterString {
     fn validate(&self) -> Result<(), ValidatorError> {
         for c in &self.constraints {
             c.validate()?;
+            if let Constraint::SubtypeConstraint(ElementSet { set, .. }) = c {
+                validate_constraint_strings(self.ty, set)?;
+            }
         }
         Ok(())
     }
 }
 
+fn validate_constraint_strings(
+    string_type: CharacterStringType,
+    set: &ElementOrSetOperation,
+) -> Result<(), ValidatorError> {
+    match set {
+        ElementOrSetOperation::Element(element) => {
+            validate_constraint_element_strings(string_type, element)
+        }
+        ElementOrSetOperation::SetOperation(SetOperation { base, operant, .. }) => {
+            validate_constraint_element_strings(string_type, base)?;
+            validate_constraint_strings(string_type, operant)
+        }
+    }
+}
+
+fn validate_constraint_element_strings(
+    string_type: CharacterStringType,
+    element: &SubtypeElement,
+) -> Result<(), ValidatorError> {
+    let values = match element {
+        SubtypeElement::SingleValue { value, .. } => vec![value],
+        SubtypeElement::ValueRange { min, max, .. } => min.iter().chain(max).collect(),
+        SubtypeElement::PermittedAlphabet(set) => {
+            return validate_constraint_strings(string_type, set)
+        }
+        SubtypeElement::NestedSet(set) => {
+            validate_constraint_element_strings(string_type, &set.base)?;
+            return validate_constraint_strings(string_type, &set.operant);
+        }
+        _ => vec![],
+    };
+    values.into_iter().try_for_each(|value| match value {
+        ASN1Value::String(s) | ASN1Value::LinkedCharStringValue(_, s) => {
+            validate_characters(string_type, s)
+        }
+        _ => Ok(()),
+    })
+}
+
 impl Validate for Constraint {
     fn validate(&self) -> Result<(), ValidatorError> {
         if let Constraint::SubtypeConstraint(c) = self {
@@ -467,6 +1056,14 @@ impl Validate for Constraint {
                 }
             }
         }
+        // The set operations of a constraint may leave no value at all
+        PerVisibleRangeConstraints::try_from(self).map_err(|e| {
+            ValidatorError::new(
+                None,
+                &format!("{}!", e.details),
+                ValidatorErrorType::InvalidConstraintsError,
+            )
+        })?;
         Ok(())
     }
 }
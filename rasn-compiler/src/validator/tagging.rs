@@ -0,0 +1,378 @@
+//! The `tagging` module makes the tags that automatic tagging
+//! assigns to the components of `SEQUENCE`, `SET`, and `CHOICE` types explicit,
+//! so that the tags do not depend on the order of the components any longer.
+//! It also checks that components which need a distinct tag are not untagged `CHOICE` types,
+//! and that `COMPONENTS OF` does not add components whose tags collide with the declared ones.
+use std::collections::BTreeMap;
+
+use crate::intermediate::*;
+
+use super::{
+    error::{ValidatorError, ValidatorErrorType},
+    linking::components_of_base,
+};
+
+/// Assigns the context-specific tags that automatic tagging implies to the components of the
+/// `SEQUENCE`, `SET`, and `CHOICE` types of modules with `AUTOMATIC TAGS`, including their inner types.
+/// Components are numbered in order of their declaration, the components of an extension addition
+/// group are numbered as if the group's brackets were not present. Types with tagged components
+/// are left untouched, since automatic tagging does not apply to them.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §25.3*
+pub(crate) fn materialize_automatic_tags(tlds: &mut [ToplevelDefinition]) {
+    let types = tlds
+        .iter()
+        .filter(|tld| matches!(tld, ToplevelDefinition::Type(_)))
+        .map(|tld| (tld.name().clone(), tld.clone()))
+        .collect::<BTreeMap<_, _>>();
+    for tld in tlds.iter_mut() {
+        if let ToplevelDefinition::Type(t) = tld {
+            let automatic = t.index.as_ref().is_some_and(|(module, _)| {
+                module.borrow().tagging_environment == TaggingEnvironment::Automatic
+            });
+            if automatic {
+                materialize(&mut t.ty, &types);
+            }
+        }
+    }
+}
+
+fn materialize(ty: &mut ASN1Type, types: &BTreeMap<String, ToplevelDefinition>) {
+    match ty {
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            if s.members.iter().all(|m| m.tag.is_none()) {
+                let mut next_id = 0;
+                for member in s.members.iter_mut() {
+                    match &mut member.ty {
+                        ASN1Type::Sequence(group) if member.name.starts_with("ext_group_") => {
+                            for group_member in group.members.iter_mut() {
+                                group_member.tag =
+                                    Some(automatic_tag(&group_member.ty, &mut next_id, types));
+                            }
+                        }
+                        ty => member.tag = Some(automatic_tag(ty, &mut next_id, types)),
+                    }
+                }
+            }
+            for member in s.members.iter_mut() {
+                materialize(&mut member.ty, types);
+            }
+        }
+        ASN1Type::Choice(c) => {
+            if c.options.iter().all(|o| o.tag.is_none()) {
+                let mut next_id = 0;
+                for option in c.options.iter_mut() {
+                    option.tag = Some(automatic_tag(&option.ty, &mut next_id, types));
+                }
+            }
+            for option in c.options.iter_mut() {
+                materialize(&mut option.ty, types);
+            }
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => materialize(&mut s.element_type, types),
+        _ => (),
+    }
+}
+
+fn automatic_tag(
+    ty: &ASN1Type,
+    next_id: &mut u64,
+    types: &BTreeMap<String, ToplevelDefinition>,
+) -> AsnTag {
+    let tag = AsnTag {
+        environment: if ty.is_choice_like(types) {
+            TaggingEnvironment::Explicit
+        } else {
+            TaggingEnvironment::Implicit
+        },
+        tag_class: TagClass::ContextSpecific,
+        id: *next_id,
+    };
+    *next_id += 1;
+    tag
+}
+
+/// Checks that untagged `CHOICE` components can be told apart from the components that their
+/// tags need to be distinct from, i.e. the components following an `OPTIONAL` or `DEFAULT`
+/// component of a `SEQUENCE` up to the next mandatory one, and all other components of a `SET`.
+/// A `CHOICE` has no tag of its own, so its alternatives' tags are compared instead.
+/// Components whose tags cannot be determined, such as open types, are not checked.
+/// Types to which automatic tagging applies are not checked either.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §25.5 and §27.3*
+pub(crate) fn validate_choice_tags(
+    tld: &ToplevelTypeDefinition,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    validate_component_tags(&tld.ty, is_automatic(tld), tlds).map_err(|mut e| {
+        e.specify_data_element(tld.name.clone());
+        e
+    })
+}
+
+/// Checks that the components that `COMPONENTS OF` splices into a `SEQUENCE` or `SET` do not share
+/// a tag with a component that is declared by the type itself. The same components as in
+/// [validate_choice_tags] need to be distinct, i.e. the components following an `OPTIONAL` or
+/// `DEFAULT` component of a `SEQUENCE` up to the next mandatory one, and all components of a `SET`.
+/// Types to which automatic tagging applies are not checked, since their components are retagged.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §25.5 and §27.3*
+pub(crate) fn validate_components_of_tags(
+    tld: &ToplevelTypeDefinition,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    validate_spliced_component_tags(&tld.ty, is_automatic(tld), tlds).map_err(|mut e| {
+        e.specify_data_element(tld.name.clone());
+        e
+    })
+}
+
+fn validate_spliced_component_tags(
+    ty: &ASN1Type,
+    automatic: bool,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    match ty {
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            // The base type of each of the root components that `COMPONENTS OF` adds
+            let spliced = s
+                .components_of
+                .iter()
+                .filter_map(|base| components_of_base(base, tlds).map(|base_seq| (base, base_seq)))
+                .flat_map(|(base, base_seq)| {
+                    base_seq
+                        .members
+                        .iter()
+                        .take(base_seq.extensible.unwrap_or(usize::MAX))
+                        .map(move |m| (m.name.as_str(), base))
+                })
+                .collect::<BTreeMap<_, _>>();
+            if !spliced.is_empty() && (!automatic || s.members.iter().any(|m| m.tag.is_some())) {
+                let is_set = matches!(ty, ASN1Type::Set(_));
+                let tags = s
+                    .members
+                    .iter()
+                    .map(|m| {
+                        if m.name.starts_with("ext_group_") {
+                            None
+                        } else {
+                            outer_tags(&m.ty, m.tag.as_ref(), automatic, tlds, &mut vec![])
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                for (index, member) in s.members.iter().enumerate() {
+                    let Some(member_tags) = &tags[index] else {
+                        continue;
+                    };
+                    let others: Vec<usize> = if is_set {
+                        (index + 1..s.members.len()).collect()
+                    } else if member.is_optional || member.default_value.is_some() {
+                        let following = s.members.iter().enumerate().skip(index + 1);
+                        let mut others = vec![];
+                        for (i, m) in following {
+                            others.push(i);
+                            if !m.is_optional && m.default_value.is_none() {
+                                break;
+                            }
+                        }
+                        others
+                    } else {
+                        vec![]
+                    };
+                    for other in others {
+                        let other_member = &s.members[other];
+                        let (spliced_member, local_member, base) = match (
+                            spliced.get(member.name.as_str()),
+                            spliced.get(other_member.name.as_str()),
+                        ) {
+                            (Some(base), None) => (member, other_member, base),
+                            (None, Some(base)) => (other_member, member, base),
+                            _ => continue,
+                        };
+                        if let Some(tag) = tags[other]
+                            .iter()
+                            .flatten()
+                            .find(|tag| member_tags.contains(tag))
+                        {
+                            return Err(ValidatorError::new(
+                                None,
+                                &format!(
+                                    "The component `{}` that `COMPONENTS OF {base}` adds shares the tag {} with the component `{}`, so decoders cannot tell which of the components is present.",
+                                    spliced_member.name,
+                                    tag_notation(tag),
+                                    local_member.name,
+                                ),
+                                ValidatorErrorType::InvalidTaggingError,
+                            ));
+                        }
+                    }
+                }
+            }
+            s.members
+                .iter()
+                .try_for_each(|m| validate_spliced_component_tags(&m.ty, automatic, tlds))
+        }
+        ASN1Type::Choice(c) => c
+            .options
+            .iter()
+            .try_for_each(|o| validate_spliced_component_tags(&o.ty, automatic, tlds)),
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            validate_spliced_component_tags(&s.element_type, automatic, tlds)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn tag_notation((class, id): &(TagClass, u64)) -> String {
+    match class {
+        TagClass::Universal => format!("[{UNIVERSAL} {id}]"),
+        TagClass::Application => format!("[{APPLICATION} {id}]"),
+        TagClass::Private => format!("[{PRIVATE} {id}]"),
+        TagClass::ContextSpecific => format!("[{id}]"),
+    }
+}
+
+fn is_automatic(tld: &ToplevelTypeDefinition) -> bool {
+    tld.index.as_ref().is_some_and(|(module, _)| {
+        module.borrow().tagging_environment == TaggingEnvironment::Automatic
+    })
+}
+
+fn validate_component_tags(
+    ty: &ASN1Type,
+    automatic: bool,
+    tlds: &BTreeMap<String, ToplevelDefinition>,
+) -> Result<(), ValidatorError> {
+    match ty {
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            if !automatic || s.members.iter().any(|m| m.tag.is_some()) {
+                let is_set = matches!(ty, ASN1Type::Set(_));
+                let tags = s
+                    .members
+                    .iter()
+                    .map(|m| {
+                        if m.name.starts_with("ext_group_") {
+                            None
+                        } else {
+                            outer_tags(&m.ty, m.tag.as_ref(), automatic, tlds, &mut vec![])
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                for (index, member) in s.members.iter().enumerate() {
+                    if member.tag.is_some() || !is_untagged_choice(&member.ty, tlds) {
+                        continue;
+                    }
+                    let Some(choice_tags) = &tags[index] else {
+                        continue;
+                    };
+                    let others: Vec<usize> = if is_set {
+                        (0..s.members.len()).filter(|i| *i != index).collect()
+                    } else if member.is_optional || member.default_value.is_some() {
+                        let following = s.members.iter().enumerate().skip(index + 1);
+                        let mut others = vec![];
+                        for (i, m) in following {
+                            others.push(i);
+                            if !m.is_optional && m.default_value.is_none() {
+                                break;
+                            }
+                        }
+                        others
+                    } else {
+                        vec![]
+                    };
+                    let conflict = others.into_iter().find(|i| {
+                        tags[*i]
+                            .as_ref()
+                            .is_some_and(|t| t.iter().any(|tag| choice_tags.contains(tag)))
+                    });
+                    if let Some(other) = conflict {
+                        let free_id = (0..)
+                            .find(|id| {
+                                !tags.iter().flatten().flatten().any(|(class, i)| {
+                                    *class == TagClass::ContextSpecific && i == id
+                                })
+                            })
+                            .unwrap_or_default();
+                        return Err(ValidatorError::new(
+                            None,
+                            &format!(
+                                "The untagged CHOICE component `{}` shares a tag with the component `{}`, so decoders cannot tell which of the components is present. Add an explicit tag to the CHOICE component, e.g. `{} [{free_id}] EXPLICIT ...`.",
+                                member.name, s.members[other].name, member.name,
+                            ),
+                            ValidatorErrorType::InvalidTaggingError,
+                        ));
+                    }
+                }
+            }
+            s.members
+                .iter()
+                .try_for_each(|m| validate_component_tags(&m.ty, automatic, tlds))
+        }
+        ASN1Type::Choice(c) => c
+            .options
+            .iter()
+            .try_for_each(|o| validate_component_tags(&o.ty, automatic, tlds)),
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            validate_component_tags(&s.element_type, automatic, tlds)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn is_untagged_choice(ty: &ASN1Type, tlds: &BTreeMap<String, ToplevelDefinition>) -> bool {
+    match ty {
+        ASN1Type::Choice(_) => true,
+        ASN1Type::ElsewhereDeclaredType(e) => match tlds.get(&e.identifier) {
+            Some(ToplevelDefinition::Type(t)) => t.tag.is_none() && is_untagged_choice(&t.ty, tlds),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the tags that an encoding of the given type can start with, or `None`
+/// if they cannot be determined. An untagged `CHOICE` can start with the tags of all of
+/// its alternatives. `visited` guards against circular references.
+fn outer_tags<'a>(
+    ty: &'a ASN1Type,
+    tag: Option<&AsnTag>,
+    automatic: bool,
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+    visited: &mut Vec<&'a str>,
+) -> Option<Vec<(TagClass, u64)>> {
+    if let Some(tag) = tag {
+        return Some(vec![(tag.tag_class, tag.id)]);
+    }
+    match ty {
+        ASN1Type::Choice(c) => {
+            if automatic && c.options.iter().all(|o| o.tag.is_none()) {
+                return Some(
+                    (0..c.options.len() as u64)
+                        .map(|id| (TagClass::ContextSpecific, id))
+                        .collect(),
+                );
+            }
+            let mut tags = vec![];
+            for option in &c.options {
+                tags.extend(outer_tags(
+                    &option.ty,
+                    option.tag.as_ref(),
+                    automatic,
+                    tlds,
+                    visited,
+                )?);
+            }
+            Some(tags)
+        }
+        ASN1Type::ElsewhereDeclaredType(e) => {
+            if visited.contains(&e.identifier.as_str()) {
+                return None;
+            }
+            visited.push(&e.identifier);
+            match tlds.get(&e.identifier) {
+                Some(ToplevelDefinition::Type(t)) => {
+                    outer_tags(&t.ty, t.tag.as_ref(), is_automatic(t), tlds, visited)
+                }
+                _ => None,
+            }
+        }
+        ty => ty.universal_tag().map(|id| vec![(TagClass::Universal, id)]),
+    }
+}
@@ -1,2 +1,737 @@
 #[allow(unused_imports)]
 use super::*;
+use crate::intermediate::information_object::*;
+
+fn real_with_base(base: SubtypeElement) -> Real {
+    Real {
+        constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+            set: ElementOrSetOperation::Element(SubtypeElement::SingleTypeConstraint(
+                InnerTypeConstraint {
+                    is_partial: false,
+                    constraints: vec![ConstrainedComponent {
+                        identifier: "base".into(),
+                        constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+                            set: ElementOrSetOperation::Element(base),
+                            extensible: false,
+                        })],
+                        presence: ComponentPresence::Unspecified,
+                    }],
+                },
+            )),
+            extensible: false,
+        })],
+    }
+}
+
+#[test]
+fn validates_real_base_constraint() {
+    assert!(real_with_base(SubtypeElement::SingleValue {
+        value: ASN1Value::Integer(2),
+        extensible: false
+    })
+    .validate()
+    .is_ok());
+    assert!(real_with_base(SubtypeElement::SingleValue {
+        value: ASN1Value::Integer(10),
+        extensible: false
+    })
+    .validate()
+    .is_ok());
+    assert!(real_with_base(SubtypeElement::SingleValue {
+        value: ASN1Value::Integer(8),
+        extensible: false
+    })
+    .validate()
+    .is_err());
+    assert!(real_with_base(SubtypeElement::ValueRange {
+        min: Some(ASN1Value::Integer(2)),
+        max: Some(ASN1Value::Integer(10)),
+        extensible: false
+    })
+    .validate()
+    .is_err());
+}
+
+fn subtype_constraint(element: SubtypeElement) -> Vec<Constraint> {
+    vec![Constraint::SubtypeConstraint(ElementSet {
+        set: ElementOrSetOperation::Element(element),
+        extensible: false,
+    })]
+}
+
+fn size_constraint() -> SubtypeElement {
+    SubtypeElement::SizeConstraint(Box::new(ElementOrSetOperation::Element(
+        SubtypeElement::SingleValue {
+            value: ASN1Value::Integer(4),
+            extensible: false,
+        },
+    )))
+}
+
+fn value_range() -> SubtypeElement {
+    SubtypeElement::ValueRange {
+        min: Some(ASN1Value::Integer(1)),
+        max: Some(ASN1Value::Integer(4)),
+        extensible: false,
+    }
+}
+
+#[test]
+fn rejects_inapplicable_constraints() {
+    let sized_integer = ASN1Type::Integer(Integer {
+        constraints: subtype_constraint(size_constraint()),
+        distinguished_values: None,
+    });
+    let error = sized_integer.validate().unwrap_err();
+    assert!(error.details.contains("SIZE") && error.details.contains("INTEGER"));
+    let ranged_boolean = ASN1Type::Boolean(Boolean {
+        constraints: subtype_constraint(value_range()),
+    });
+    let error = ranged_boolean.validate().unwrap_err();
+    assert!(error.details.contains("value range") && error.details.contains("BOOLEAN"));
+    let alphabet_octets = ASN1Type::OctetString(OctetString {
+        constraints: subtype_constraint(SubtypeElement::PermittedAlphabet(Box::new(
+            ElementOrSetOperation::Element(value_range()),
+        ))),
+    });
+    assert!(alphabet_octets.validate().is_err());
+    let sequence = ASN1Type::Sequence(SequenceOrSet {
+        components_of: vec![],
+        extensible: None,
+        constraints: vec![],
+        members: vec![SequenceOrSetMember {
+            name: "member".into(),
+            tag: None,
+            ty: sized_integer,
+            default_value: None,
+            is_optional: false,
+            constraints: vec![],
+        }],
+    });
+    assert!(sequence.validate().is_err());
+}
+
+#[test]
+fn accepts_applicable_constraints() {
+    assert!(ASN1Type::Integer(Integer {
+        constraints: subtype_constraint(value_range()),
+        distinguished_values: None,
+    })
+    .validate()
+    .is_ok());
+    assert!(ASN1Type::OctetString(OctetString {
+        constraints: subtype_constraint(size_constraint()),
+    })
+    .validate()
+    .is_ok());
+    assert!(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+        parent: None,
+        identifier: "Some-Type".into(),
+        constraints: subtype_constraint(size_constraint()),
+    })
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn validates_contents_constraint_applicability() {
+    let containing = || {
+        vec![Constraint::ContentConstraint(
+            ContentConstraint::Containing(ASN1Type::Boolean(Boolean {
+                constraints: vec![],
+            })),
+        )]
+    };
+    assert!(ASN1Type::OctetString(OctetString {
+        constraints: containing(),
+    })
+    .validate()
+    .is_ok());
+    let error = ASN1Type::Integer(Integer {
+        constraints: containing(),
+        distinguished_values: None,
+    })
+    .validate()
+    .unwrap_err();
+    assert!(error.details.contains("contents") && error.details.contains("INTEGER"));
+}
+
+fn enumerated(members: &[&str]) -> ASN1Type {
+    ASN1Type::Enumerated(Enumerated {
+        members: members
+            .iter()
+            .enumerate()
+            .map(|(index, name)| Enumeral {
+                name: (*name).into(),
+                description: None,
+                index: index as i128,
+                numbering: EnumeralNumbering::Automatic,
+            })
+            .collect(),
+        extensible: None,
+        constraints: vec![],
+    })
+}
+
+#[test]
+fn rejects_typed_enumerals() {
+    assert!(enumerated(&["forward", "backward"]).validate().is_ok());
+    let error = enumerated(&["forward", "INTEGER", "backward"])
+        .validate()
+        .unwrap_err();
+    assert!(error.details.contains("Did you mean CHOICE?"));
+}
+
+#[test]
+fn validates_enumeral_numbers_across_extension_marker() {
+    let validate = |notation: &str| crate::lexer::asn1_type(notation).unwrap().1.validate();
+    assert!(validate("ENUMERATED { a(0), b(1), ..., c(5), d(8) }").is_ok());
+    assert!(validate("ENUMERATED { a(3), b, ..., c, d(7), e }").is_ok());
+    assert_eq!(
+        validate("ENUMERATED { a, b, ..., c(1) }")
+            .unwrap_err()
+            .details,
+        "ENUMERATED members `b` and `c` share the number 1!"
+    );
+    assert_eq!(
+        validate("ENUMERATED { a, ..., c(7), d(5) }")
+            .unwrap_err()
+            .details,
+        "ENUMERATED extension addition `d`(5) must be numbered higher than the preceding extension addition `c`(7)!"
+    );
+}
+
+fn bit_string_with_named_bits(named_bits: &[(&str, i128)]) -> ASN1Type {
+    ASN1Type::BitString(BitString {
+        constraints: subtype_constraint(size_constraint()),
+        distinguished_values: Some(
+            named_bits
+                .iter()
+                .map(|(name, value)| DistinguishedValue {
+                    name: (*name).into(),
+                    value: *value,
+                })
+                .collect(),
+        ),
+    })
+}
+
+#[test]
+fn validates_named_bits_within_size() {
+    assert!(bit_string_with_named_bits(&[("first", 0), ("last", 3)])
+        .validate()
+        .is_ok());
+    let error = bit_string_with_named_bits(&[("first", 0), ("beyond", 4)])
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        error.details,
+        "The named bit `beyond` has the index 4, which exceeds the maximum size 4 of the BIT STRING!"
+    );
+    assert!(bit_string_with_named_bits(&[("negative", -1)])
+        .validate()
+        .is_err());
+}
+
+fn sized_octet_string_range(size: i128, min: &[u8], max: &[u8]) -> ASN1Type {
+    ASN1Type::OctetString(OctetString {
+        constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+            set: ElementOrSetOperation::SetOperation(SetOperation {
+                base: SubtypeElement::SizeConstraint(Box::new(ElementOrSetOperation::Element(
+                    SubtypeElement::SingleValue {
+                        value: ASN1Value::Integer(size),
+                        extensible: false,
+                    },
+                ))),
+                operator: SetOperator::Intersection,
+                operant: Box::new(ElementOrSetOperation::Element(SubtypeElement::ValueRange {
+                    min: Some(ASN1Value::OctetString(min.to_vec())),
+                    max: Some(ASN1Value::OctetString(max.to_vec())),
+                    extensible: false,
+                })),
+            }),
+            extensible: false,
+        })],
+    })
+}
+
+#[test]
+fn validates_octet_string_value_range_endpoints() {
+    assert!(sized_octet_string_range(2, &[0, 0], &[0, 255])
+        .validate()
+        .is_ok());
+    let error = sized_octet_string_range(2, &[0, 0], &[255])
+        .validate()
+        .unwrap_err();
+    assert!(error.details.contains("differ in length"));
+    let error = sized_octet_string_range(4, &[0, 0], &[0, 255])
+        .validate()
+        .unwrap_err();
+    assert!(error.details.contains("size constraint"));
+}
+
+fn object(literal: &str) -> ObjectSetValue {
+    ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(vec![
+        SyntaxApplication::Literal(literal.into()),
+    ]))
+}
+
+fn object_set_tld(
+    name: &str,
+    values: Vec<ObjectSetValue>,
+    extensible: Option<usize>,
+) -> (String, ToplevelDefinition) {
+    (
+        name.into(),
+        ToplevelDefinition::Information(ToplevelInformationDefinition::from((
+            name,
+            ASN1Information::ObjectSet(ObjectSet { values, extensible }),
+            "ALGORITHM",
+        ))),
+    )
+}
+
+#[test]
+fn flattens_nested_object_set_references() {
+    let tlds = BTreeMap::from([
+        object_set_tld(
+            "Hashes",
+            vec![object("a"), object("b"), object("x")],
+            Some(2),
+        ),
+        object_set_tld("Signatures", vec![object("b"), object("c")], None),
+        object_set_tld(
+            "Registry",
+            vec![
+                ObjectSetValue::Reference("Hashes".into()),
+                ObjectSetValue::Reference("Signatures".into()),
+            ],
+            None,
+        ),
+    ]);
+    let mut set = ObjectSet {
+        values: vec![
+            ObjectSetValue::Reference("Registry".into()),
+            object("a"),
+            object("d"),
+        ],
+        extensible: None,
+    };
+    set.resolve_object_set_references(&tlds).unwrap();
+    assert_eq!(
+        set,
+        ObjectSet {
+            values: vec![
+                object("a"),
+                object("b"),
+                object("c"),
+                object("d"),
+                object("x")
+            ],
+            extensible: Some(4),
+        }
+    );
+}
+
+#[test]
+fn rejects_cyclic_object_set_references() {
+    let tlds = BTreeMap::from([
+        object_set_tld("A", vec![ObjectSetValue::Reference("B".into())], None),
+        object_set_tld("B", vec![ObjectSetValue::Reference("A".into())], None),
+    ]);
+    let mut set = ObjectSet {
+        values: vec![ObjectSetValue::Reference("A".into())],
+        extensible: None,
+    };
+    assert!(set.resolve_object_set_references(&tlds).is_err());
+}
+
+fn sequence_with_time_default(ty: ASN1Type, time: &str) -> ASN1Type {
+    ASN1Type::Sequence(SequenceOrSet {
+        components_of: vec![],
+        extensible: None,
+        constraints: vec![],
+        members: vec![SequenceOrSetMember {
+            name: "time".into(),
+            tag: None,
+            ty,
+            default_value: Some(ASN1Value::Time(time.into())),
+            is_optional: true,
+            constraints: vec![],
+        }],
+    })
+}
+
+#[test]
+fn validates_time_default_values() {
+    let generalized = || {
+        ASN1Type::GeneralizedTime(GeneralizedTime {
+            constraints: vec![],
+        })
+    };
+    let utc = || {
+        ASN1Type::UTCTime(UTCTime {
+            constraints: vec![],
+        })
+    };
+    assert!(sequence_with_time_default(generalized(), "20240101000000Z")
+        .validate()
+        .is_ok());
+    assert!(
+        sequence_with_time_default(generalized(), "2024010112.5+0130")
+            .validate()
+            .is_ok()
+    );
+    assert!(sequence_with_time_default(utc(), "240101120000Z")
+        .validate()
+        .is_ok());
+    assert_eq!(
+        sequence_with_time_default(generalized(), "20241301000000Z")
+            .validate()
+            .unwrap_err()
+            .details,
+        "Invalid time value \"20241301000000Z\": month 13 is out of range!"
+    );
+    assert!(sequence_with_time_default(generalized(), "20240230000000Z")
+        .validate()
+        .is_err());
+    assert!(sequence_with_time_default(utc(), "2401011200Z0")
+        .validate()
+        .is_err());
+}
+
+fn sequence_with_string_default(ty: CharacterStringType, value: &str) -> ASN1Type {
+    ASN1Type::Sequence(SequenceOrSet {
+        components_of: vec![],
+        extensible: None,
+        constraints: vec![],
+        members: vec![SequenceOrSetMember {
+            name: "text".into(),
+            tag: None,
+            ty: ASN1Type::CharacterString(CharacterString {
+                constraints: vec![],
+                ty,
+            }),
+            default_value: Some(ASN1Value::LinkedCharStringValue(ty, value.into())),
+            is_optional: true,
+            constraints: vec![],
+        }],
+    })
+}
+
+#[test]
+fn validates_characters_of_string_values() {
+    assert!(
+        sequence_with_string_default(CharacterStringType::PrintableString, "Hello, World")
+            .validate()
+            .is_ok()
+    );
+    assert_eq!(
+        sequence_with_string_default(CharacterStringType::PrintableString, "a@b")
+            .validate()
+            .unwrap_err()
+            .details,
+        "The PrintableString value \"a@b\" contains the character '@' at index 1, which PrintableString does not permit!"
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::NumericString, "12 34")
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::NumericString, "12a")
+            .validate()
+            .is_err()
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::IA5String, "caf\u{e9}")
+            .validate()
+            .is_err()
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::UTF8String, "caf\u{e9}")
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::GraphicString, "caf\u{e9}")
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::ObjectDescriptor, "line\nbreak")
+            .validate()
+            .is_err()
+    );
+    assert!(
+        sequence_with_string_default(CharacterStringType::GeneralString, "line\nbreak")
+            .validate()
+            .is_ok()
+    );
+    let value = ToplevelDefinition::Value(ToplevelValueDefinition {
+        comments: String::new(),
+        name: "address".into(),
+        associated_type: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+            parent: None,
+            identifier: "Address".into(),
+            constraints: vec![],
+        }),
+        parameterization: None,
+        value: ASN1Value::LinkedNestedValue {
+            supertypes: vec!["Address".into()],
+            value: Box::new(ASN1Value::LinkedCharStringValue(
+                CharacterStringType::PrintableString,
+                "user@example".into(),
+            )),
+        },
+        index: None,
+    });
+    assert!(value.validate().is_err());
+    assert!(ASN1Type::CharacterString(CharacterString {
+        constraints: vec![Constraint::SubtypeConstraint(ElementSet {
+            set: ElementOrSetOperation::Element(SubtypeElement::PermittedAlphabet(Box::new(
+                ElementOrSetOperation::Element(SubtypeElement::SingleValue {
+                    value: ASN1Value::String("@".into()),
+                    extensible: false,
+                }),
+            ))),
+            extensible: false,
+        })],
+        ty: CharacterStringType::PrintableString,
+    })
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn parses_time_values() {
+    assert_eq!(
+        GeneralizedTime::parse_value("20240101123015.25-0500")
+            .unwrap()
+            .to_rfc3339(),
+        "2024-01-01T12:30:15.250-05:00"
+    );
+    assert_eq!(
+        GeneralizedTime::parse_value("2024010112.5")
+            .unwrap()
+            .to_rfc3339(),
+        "2024-01-01T12:30:00+00:00"
+    );
+    assert_eq!(
+        UTCTime::parse_value("491231235959Z").unwrap().to_rfc3339(),
+        "2049-12-31T23:59:59+00:00"
+    );
+    assert_eq!(
+        UTCTime::parse_value("5001010000+0100")
+            .unwrap()
+            .to_rfc3339(),
+        "1950-01-01T00:00:00+01:00"
+    );
+}
+
+/// Parses the top-level declarations of the given ASN.1 modules
+/// and applies their modules' environments like the compiler does
+fn parse_modules(modules: &[&str]) -> Vec<ToplevelDefinition> {
+    modules
+        .iter()
+        .flat_map(|module| crate::lexer::asn_spec(module).unwrap())
+        .flat_map(|(header, tlds)| {
+            let header = Rc::new(RefCell::new(header));
+            tlds.into_iter().enumerate().map(move |(index, mut tld)| {
+                tld.apply_tagging_environment(&header.borrow().tagging_environment);
+                tld.apply_extensibility_environment(&header.borrow().extensibility_environment);
+                tld.set_index(header.clone(), index);
+                tld
+            })
+        })
+        .collect()
+}
+
+/// Validates the given ASN.1 modules, returning the valid top-level declarations
+/// and the error messages of the invalid ones
+fn validate_modules(modules: &[&str]) -> (Vec<ToplevelDefinition>, Vec<String>) {
+    let (tlds, errors) = Validator::new(parse_modules(modules)).validate().unwrap();
+    (tlds, errors.iter().map(ToString::to_string).collect())
+}
+
+fn declared_names(tlds: &[ToplevelDefinition]) -> Vec<&str> {
+    tlds.iter().map(|tld| tld.name().as_str()).collect()
+}
+
+fn choice_tag_errors(module: &str) -> BTreeMap<String, String> {
+    let tlds = parse_modules(&[module])
+        .into_iter()
+        .map(|tld| (tld.name().clone(), tld))
+        .collect::<BTreeMap<_, _>>();
+    tlds.values()
+        .filter_map(|tld| match tld {
+            ToplevelDefinition::Type(t) => tagging::validate_choice_tags(t, &tlds)
+                .err()
+                .map(|e| (t.name.clone(), e.to_string())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn rejects_indistinguishable_untagged_choice_components() {
+    let (tlds, errors) = validate_modules(&[r#"TestModule DEFINITIONS EXPLICIT TAGS ::= BEGIN
+            Time ::= CHOICE { utcTime UTCTime, generalTime GeneralizedTime }
+            Ambiguous ::= SEQUENCE { thisUpdate Time OPTIONAL, nextUpdate UTCTime }
+            Distinct ::= SEQUENCE { thisUpdate Time OPTIONAL, serial INTEGER }
+            Tagged ::= SEQUENCE { thisUpdate [0] Time OPTIONAL, nextUpdate UTCTime }
+            Unordered ::= SET { time Time, stamp GeneralizedTime }
+        END"#]);
+    let names = declared_names(&tlds);
+    assert!(names.contains(&"Distinct") && names.contains(&"Tagged"));
+    assert!(!names.contains(&"Ambiguous") && !names.contains(&"Unordered"));
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|e| e.contains(
+        "The untagged CHOICE component `thisUpdate` shares a tag with the component `nextUpdate`"
+    )));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("e.g. `time [0] EXPLICIT ...`")));
+}
+
+#[test]
+fn validates_choice_tags_of_implicitly_tagged_components() {
+    let errors = choice_tag_errors(
+        r#"TestModule DEFINITIONS IMPLICIT TAGS ::= BEGIN
+            Time ::= CHOICE { utcTime UTCTime, generalTime GeneralizedTime }
+            Ambiguous ::= SEQUENCE { thisUpdate Time OPTIONAL, nextUpdate [1] UTCTime, last UTCTime }
+            Following ::= SEQUENCE { thisUpdate Time DEFAULT utcTime : "491231235959Z", nextUpdate GeneralizedTime }
+            Retagged ::= SEQUENCE { thisUpdate Time OPTIONAL, nextUpdate [23] IMPLICIT UTCTime }
+            Universal ::= SEQUENCE { thisUpdate Time OPTIONAL, nextUpdate [UNIVERSAL 23] IMPLICIT OCTET STRING }
+        END"#,
+    );
+    assert_eq!(
+        errors.keys().collect::<Vec<_>>(),
+        vec!["Following", "Universal"]
+    );
+    assert!(errors["Following"].contains(
+        "The untagged CHOICE component `thisUpdate` shares a tag with the component `nextUpdate`"
+    ));
+    assert!(errors["Universal"].contains("e.g. `thisUpdate [0] EXPLICIT ...`"));
+}
+
+#[test]
+fn validates_choice_tags_of_manually_tagged_automatic_types() {
+    let errors = choice_tag_errors(
+        r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Time ::= CHOICE { utcTime UTCTime, generalTime GeneralizedTime }
+            Untagged ::= SEQUENCE { time Time OPTIONAL, stamp UTCTime }
+            Colliding ::= SEQUENCE { id [2] INTEGER, time Time OPTIONAL, stamp [1] INTEGER }
+            Distinct ::= SEQUENCE { id [2] INTEGER, time Time OPTIONAL, stamp [3] INTEGER }
+            Universal ::= SEQUENCE { id [2] INTEGER, time Time OPTIONAL, stamp UTCTime }
+        END"#,
+    );
+    assert_eq!(errors.keys().collect::<Vec<_>>(), vec!["Colliding"]);
+    assert!(errors["Colliding"]
+        .contains("The untagged CHOICE component `time` shares a tag with the component `stamp`"));
+    assert!(errors["Colliding"].contains("e.g. `time [3] EXPLICIT ...`"));
+}
+
+#[test]
+fn rejects_tag_collisions_introduced_by_components_of() {
+    let (tlds, errors) = validate_modules(&[r#"TestModule DEFINITIONS EXPLICIT TAGS ::= BEGIN
+            Base ::= SEQUENCE { id [0] INTEGER OPTIONAL, name [1] UTF8String }
+            Colliding ::= SEQUENCE { flag [0] BOOLEAN OPTIONAL, COMPONENTS OF Base }
+            Ordered ::= SEQUENCE { COMPONENTS OF Base, flag [1] BOOLEAN }
+            Unordered ::= SET { COMPONENTS OF Base, flag [0] BOOLEAN }
+        END"#]);
+    let names = declared_names(&tlds);
+    assert!(names.contains(&"Ordered"));
+    assert!(!names.contains(&"Colliding") && !names.contains(&"Unordered"));
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|e| e.contains(
+        "The component `id` that `COMPONENTS OF Base` adds shares the tag [0] with the component `flag`"
+    )));
+}
+
+#[test]
+fn links_selection_types_in_components_and_constraints() {
+    let (tlds, errors) = validate_modules(&[r#"TestModule DEFINITIONS IMPLICIT TAGS ::= BEGIN
+            Alternatives ::= CHOICE { name [0] IA5String, id [1] INTEGER (0..255) }
+            Record ::= SEQUENCE { name name < Alternatives, ids SEQUENCE OF id < Alternatives }
+            Id ::= INTEGER (INCLUDES id < Alternatives)
+        END"#]);
+    assert!(errors.is_empty());
+    let ty = |name: &str| match tlds.iter().find(|tld| tld.name() == name) {
+        Some(ToplevelDefinition::Type(t)) => t.ty.clone(),
+        _ => panic!("Missing type {name}"),
+    };
+    let ASN1Type::Choice(alternatives) = ty("Alternatives") else {
+        panic!("Expected CHOICE");
+    };
+    let ASN1Type::Sequence(record) = ty("Record") else {
+        panic!("Expected SEQUENCE");
+    };
+    assert_eq!(record.members[0].tag, alternatives.options[0].tag);
+    assert_eq!(record.members[0].ty, alternatives.options[0].ty);
+    assert_eq!(
+        record.members[1].ty,
+        ASN1Type::SequenceOf(SequenceOrSetOf {
+            constraints: vec![],
+            element_type: Box::new(alternatives.options[1].ty.clone()),
+        })
+    );
+    assert_eq!(
+        ty("Id").constraints(),
+        Some(&vec![Constraint::SubtypeConstraint(ElementSet {
+            set: ElementOrSetOperation::Element(SubtypeElement::ContainedSubtype {
+                subtype: alternatives.options[1].ty.clone(),
+                extensible: false,
+            }),
+            extensible: false,
+        })])
+    );
+}
+
+#[test]
+fn validates_inner_subtyping() {
+    let (tlds, errors) = validate_modules(&[r#"TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Base ::= SEQUENCE { id INTEGER, flag BOOLEAN OPTIONAL, note IA5String OPTIONAL }
+            Alternatives ::= CHOICE { a INTEGER, b BOOLEAN }
+            Bases ::= SEQUENCE OF Base
+            Profile ::= Base (WITH COMPONENTS { ..., note (SIZE(1..4)) PRESENT })
+            Small-Ids ::= Bases (WITH COMPONENT (WITH COMPONENTS { ..., id (0..7) }))
+            Holder ::= SEQUENCE { base Base (WITH COMPONENTS { ..., flag ABSENT }) }
+            Unknown ::= Base (WITH COMPONENTS { ..., unknown PRESENT })
+            Absent-Id ::= Base (WITH COMPONENTS { ..., id ABSENT })
+            Sized-Flag ::= Base (WITH COMPONENTS { ..., flag (SIZE(1..4)) })
+            Both ::= Alternatives (WITH COMPONENTS { a PRESENT, b PRESENT })
+            Twice ::= SEQUENCE { base Base (WITH COMPONENTS { ..., id, id }) }
+            Sized-Bases ::= Bases (WITH COMPONENT (SIZE(1..4)))
+        END"#]);
+    let names = declared_names(&tlds);
+    for name in ["Profile", "Small-Ids", "Holder"] {
+        assert!(names.contains(&name));
+    }
+    for (name, details) in [
+        (
+            "Unknown",
+            "The constrained type has no component `unknown`!",
+        ),
+        (
+            "Absent-Id",
+            "The mandatory component `id` cannot be constrained ABSENT!",
+        ),
+        (
+            "Sized-Flag",
+            "A SIZE constraint cannot be applied to BOOLEAN!",
+        ),
+        (
+            "Both",
+            "At most one alternative of a CHOICE can be constrained PRESENT!",
+        ),
+        ("Twice", "The component `id` is constrained more than once!"),
+        (
+            "Sized-Bases",
+            "A SIZE constraint cannot be applied to SEQUENCE!",
+        ),
+    ] {
+        assert!(!names.contains(&name));
+        assert!(errors.iter().any(|e| e.contains(details)));
+    }
+}